@@ -0,0 +1,19 @@
+/// words are in little endian order
+/// using two's complement representation
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct I1024 {
+    pub words: [u64; 16],
+}
+
+impl From<crate::integer::I1024> for I1024 {
+    fn from(value: crate::integer::I1024) -> Self {
+        Self { words: value.0 }
+    }
+}
+
+impl From<I1024> for crate::integer::I1024 {
+    fn from(value: I1024) -> Self {
+        Self(value.words)
+    }
+}
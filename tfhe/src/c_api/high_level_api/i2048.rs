@@ -0,0 +1,19 @@
+/// words are in little endian order
+/// using two's complement representation
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct I2048 {
+    pub words: [u64; 32],
+}
+
+impl From<crate::integer::I2048> for I2048 {
+    fn from(value: crate::integer::I2048) -> Self {
+        Self { words: value.0 }
+    }
+}
+
+impl From<I2048> for crate::integer::I2048 {
+    fn from(value: I2048) -> Self {
+        Self(value.words)
+    }
+}
@@ -0,0 +1,19 @@
+/// words are in little endian order
+/// using two's complement representation
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct I512 {
+    pub words: [u64; 8],
+}
+
+impl From<crate::integer::I512> for I512 {
+    fn from(value: crate::integer::I512) -> Self {
+        Self { words: value.0 }
+    }
+}
+
+impl From<I512> for crate::integer::I512 {
+    fn from(value: I512) -> Self {
+        Self(value.words)
+    }
+}
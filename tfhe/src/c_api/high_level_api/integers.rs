@@ -1,10 +1,16 @@
 use super::utils::*;
 use crate::c_api::high_level_api::booleans::FheBool;
+use crate::c_api::high_level_api::i1024::I1024;
 use crate::c_api::high_level_api::i128::I128;
+use crate::c_api::high_level_api::i2048::I2048;
 use crate::c_api::high_level_api::i256::I256;
+use crate::c_api::high_level_api::i512::I512;
 use crate::c_api::high_level_api::keys::CompactPublicKey;
+use crate::c_api::high_level_api::u1024::U1024;
 use crate::c_api::high_level_api::u128::U128;
+use crate::c_api::high_level_api::u2048::U2048;
 use crate::c_api::high_level_api::u256::U256;
+use crate::c_api::high_level_api::u512::U512;
 use crate::c_api::utils::*;
 use crate::high_level_api::prelude::*;
 use std::ops::{
@@ -16,8 +22,8 @@ use std::os::raw::c_int;
 macro_rules! define_all_cast_into_for_integer_type {
     ($from:ty) => {
         define_casting_operation!($from =>
-            FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint160, FheUint256,
-            FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16, FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+            FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint160, FheUint256, FheUint512, FheUint1024, FheUint2048,
+            FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16, FheInt32, FheInt64, FheInt128, FheInt160, FheInt256, FheInt512, FheInt1024, FheInt2048
         );
     };
 }
@@ -593,6 +599,9 @@ create_integer_wrapper_type!(name: FheUint64, clear_scalar_type: u64);
 create_integer_wrapper_type!(name: FheUint128, clear_scalar_type: U128);
 create_integer_wrapper_type!(name: FheUint160, clear_scalar_type: U256);
 create_integer_wrapper_type!(name: FheUint256, clear_scalar_type: U256);
+create_integer_wrapper_type!(name: FheUint512, clear_scalar_type: U512);
+create_integer_wrapper_type!(name: FheUint1024, clear_scalar_type: U1024);
+create_integer_wrapper_type!(name: FheUint2048, clear_scalar_type: U2048);
 
 // compact list encryption is not part of the crate_integer_wrapper_type
 // as for U128 and U256 clear scalar types, the function to use is different
@@ -685,6 +694,24 @@ create_integer_wrapper_type!(
     clear_scalar_type: I256,
     clear_shift_type: U256,
 );
+create_integer_wrapper_type!(
+    name: FheInt512,
+    fhe_unsigned_type: FheUint512,
+    clear_scalar_type: I512,
+    clear_shift_type: U512,
+);
+create_integer_wrapper_type!(
+    name: FheInt1024,
+    fhe_unsigned_type: FheUint1024,
+    clear_scalar_type: I1024,
+    clear_shift_type: U1024,
+);
+create_integer_wrapper_type!(
+    name: FheInt2048,
+    fhe_unsigned_type: FheUint2048,
+    clear_scalar_type: I2048,
+    clear_shift_type: U2048,
+);
 
 // compact list encryption is not part of the crate_integer_wrapper_type
 // as for U128 and U256 clear scalar types, the function to use is different
@@ -767,6 +794,78 @@ pub unsafe extern "C" fn compact_fhe_uint256_list_try_encrypt_with_compact_publi
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn compact_fhe_uint512_list_try_encrypt_with_compact_public_key_u512(
+    input: *const U512,
+    input_len: usize,
+    public_key: *const CompactPublicKey,
+    result: *mut *mut CompactFheUint512List,
+) -> c_int {
+    catch_panic(|| {
+        let public_key = get_ref_checked(public_key).unwrap();
+
+        let slc = ::std::slice::from_raw_parts(input, input_len);
+        let values = slc
+            .iter()
+            .copied()
+            .map(crate::integer::U512::from)
+            .collect::<Vec<_>>();
+        let inner =
+            <crate::high_level_api::CompactFheUint512List>::try_encrypt(&values, &public_key.0)
+                .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactFheUint512List(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_fhe_uint1024_list_try_encrypt_with_compact_public_key_u1024(
+    input: *const U1024,
+    input_len: usize,
+    public_key: *const CompactPublicKey,
+    result: *mut *mut CompactFheUint1024List,
+) -> c_int {
+    catch_panic(|| {
+        let public_key = get_ref_checked(public_key).unwrap();
+
+        let slc = ::std::slice::from_raw_parts(input, input_len);
+        let values = slc
+            .iter()
+            .copied()
+            .map(crate::integer::U1024::from)
+            .collect::<Vec<_>>();
+        let inner =
+            <crate::high_level_api::CompactFheUint1024List>::try_encrypt(&values, &public_key.0)
+                .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactFheUint1024List(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_fhe_uint2048_list_try_encrypt_with_compact_public_key_u2048(
+    input: *const U2048,
+    input_len: usize,
+    public_key: *const CompactPublicKey,
+    result: *mut *mut CompactFheUint2048List,
+) -> c_int {
+    catch_panic(|| {
+        let public_key = get_ref_checked(public_key).unwrap();
+
+        let slc = ::std::slice::from_raw_parts(input, input_len);
+        let values = slc
+            .iter()
+            .copied()
+            .map(crate::integer::U2048::from)
+            .collect::<Vec<_>>();
+        let inner =
+            <crate::high_level_api::CompactFheUint2048List>::try_encrypt(&values, &public_key.0)
+                .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactFheUint2048List(inner)));
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn compact_fhe_int128_list_try_encrypt_with_compact_public_key_i128(
     input: *const I128,
@@ -835,6 +934,78 @@ pub unsafe extern "C" fn compact_fhe_int256_list_try_encrypt_with_compact_public
     })
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn compact_fhe_int512_list_try_encrypt_with_compact_public_key_i512(
+    input: *const I512,
+    input_len: usize,
+    public_key: *const CompactPublicKey,
+    result: *mut *mut CompactFheInt512List,
+) -> c_int {
+    catch_panic(|| {
+        let public_key = get_ref_checked(public_key).unwrap();
+
+        let slc = ::std::slice::from_raw_parts(input, input_len);
+        let values = slc
+            .iter()
+            .copied()
+            .map(crate::integer::I512::from)
+            .collect::<Vec<_>>();
+        let inner =
+            <crate::high_level_api::CompactFheInt512List>::try_encrypt(&values, &public_key.0)
+                .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactFheInt512List(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_fhe_int1024_list_try_encrypt_with_compact_public_key_i1024(
+    input: *const I1024,
+    input_len: usize,
+    public_key: *const CompactPublicKey,
+    result: *mut *mut CompactFheInt1024List,
+) -> c_int {
+    catch_panic(|| {
+        let public_key = get_ref_checked(public_key).unwrap();
+
+        let slc = ::std::slice::from_raw_parts(input, input_len);
+        let values = slc
+            .iter()
+            .copied()
+            .map(crate::integer::I1024::from)
+            .collect::<Vec<_>>();
+        let inner =
+            <crate::high_level_api::CompactFheInt1024List>::try_encrypt(&values, &public_key.0)
+                .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactFheInt1024List(inner)));
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn compact_fhe_int2048_list_try_encrypt_with_compact_public_key_i2048(
+    input: *const I2048,
+    input_len: usize,
+    public_key: *const CompactPublicKey,
+    result: *mut *mut CompactFheInt2048List,
+) -> c_int {
+    catch_panic(|| {
+        let public_key = get_ref_checked(public_key).unwrap();
+
+        let slc = ::std::slice::from_raw_parts(input, input_len);
+        let values = slc
+            .iter()
+            .copied()
+            .map(crate::integer::I2048::from)
+            .collect::<Vec<_>>();
+        let inner =
+            <crate::high_level_api::CompactFheInt2048List>::try_encrypt(&values, &public_key.0)
+                .unwrap();
+
+        *result = Box::into_raw(Box::new(CompactFheInt2048List(inner)));
+    })
+}
+
 define_all_cast_into_for_integer_type!(FheBool);
 
 macro_rules! impl_oprf_for_uint {
@@ -952,6 +1123,9 @@ impl_oprf_for_uint!(name: FheUint64);
 impl_oprf_for_uint!(name: FheUint128);
 impl_oprf_for_uint!(name: FheUint160);
 impl_oprf_for_uint!(name: FheUint256);
+impl_oprf_for_uint!(name: FheUint512);
+impl_oprf_for_uint!(name: FheUint1024);
+impl_oprf_for_uint!(name: FheUint2048);
 
 impl_oprf_for_int!(name: FheInt2);
 impl_oprf_for_int!(name: FheInt4);
@@ -966,3 +1140,6 @@ impl_oprf_for_int!(name: FheInt64);
 impl_oprf_for_int!(name: FheInt128);
 impl_oprf_for_int!(name: FheInt160);
 impl_oprf_for_int!(name: FheInt256);
+impl_oprf_for_int!(name: FheInt512);
+impl_oprf_for_int!(name: FheInt1024);
+impl_oprf_for_int!(name: FheInt2048);
@@ -1,13 +1,19 @@
 #[cfg(feature = "boolean")]
 pub mod booleans;
 pub mod config;
+pub mod i1024;
 pub mod i128;
+pub mod i2048;
 pub mod i256;
+pub mod i512;
 pub mod integers;
 pub mod keys;
 mod threading;
+pub mod u1024;
 pub mod u128;
+pub mod u2048;
 pub mod u256;
+pub mod u512;
 mod utils;
 #[cfg(feature = "zk-pok-experimental")]
 mod zk;
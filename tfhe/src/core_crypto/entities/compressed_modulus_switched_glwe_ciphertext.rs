@@ -0,0 +1,290 @@
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::fft_impl::common::modulus_switch;
+use crate::core_crypto::prelude::packed_integers::PackedIntegers;
+use crate::core_crypto::prelude::*;
+
+/// An object to store a GLWE ciphertext in little memory
+///
+/// The modulus of the ciphertext is decreased by rounding and the result is stored in a compact
+/// way. Only the first `bodies_count` coefficients of the body are kept, which allows to only
+/// pay for the coefficients that were actually filled (e.g. by a packing keyswitch).
+///
+/// The uncompacted result, once sample extracted, can be used as the input of a blind rotation to
+/// recover a low noise lwe ciphertext.
+///
+/// ```rust
+/// use concrete_csprng::seeders::Seed;
+/// use tfhe::core_crypto::fft_impl::common::modulus_switch;
+/// use tfhe::core_crypto::prelude::compressed_modulus_switched_glwe_ciphertext::CompressedModulusSwitchedGlweCiphertext;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let log_modulus = 12;
+///
+/// let glwe_dimension = GlweDimension(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut secret_generator = SecretRandomGenerator::<ActivatedRandomGenerator>::new(Seed(0));
+///
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key::<u64, _>(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+///
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let plaintexts = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// let mut glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// // Unsecure parameters, do not use them
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintexts,
+///     Gaussian::from_standard_dev(StandardDev(0.), 0.),
+///     &mut encryption_generator,
+/// );
+///
+/// // Only the first 100 bodies are kept
+/// let compressed = CompressedModulusSwitchedGlweCiphertext::compress(
+///     &glwe,
+///     CiphertextModulusLog(log_modulus),
+///     LweCiphertextCount(100),
+/// );
+///
+/// let glwe_ms_ed = compressed.extract();
+///
+/// let mut output_plaintexts = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe_ms_ed, &mut output_plaintexts);
+///
+/// for plaintext in output_plaintexts.iter().take(100) {
+///     assert_eq!(modulus_switch(*plaintext.0, CiphertextModulusLog(5)), 0);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompressedModulusSwitchedGlweCiphertext<Scalar: UnsignedInteger> {
+    packed_integers: PackedIntegers<Scalar>,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    bodies_count: LweCiphertextCount,
+    uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger> CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the number of body coefficients that are stored
+    pub fn bodies_count(&self) -> LweCiphertextCount {
+        self.bodies_count
+    }
+
+    pub fn log_modulus(&self) -> CiphertextModulusLog {
+        self.packed_integers.log_modulus()
+    }
+
+    pub fn uncompressed_ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.uncompressed_ciphertext_modulus
+    }
+}
+
+impl<Scalar: UnsignedTorus> CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    /// Compresses a ciphertext by reducing its modulus
+    /// This operation adds a lot of noise
+    ///
+    /// Only the first `bodies_count` coefficients of the body are kept.
+    pub fn compress<Cont: Container<Element = Scalar>>(
+        ct: &GlweCiphertext<Cont>,
+        log_modulus: CiphertextModulusLog,
+        bodies_count: LweCiphertextCount,
+    ) -> Self {
+        let uncompressed_ciphertext_modulus = ct.ciphertext_modulus();
+
+        assert!(
+            ct.ciphertext_modulus().is_power_of_two(),
+            "Modulus switch compression does not support non power of 2 input moduli",
+        );
+
+        let uncompressed_ciphertext_modulus_log =
+            if uncompressed_ciphertext_modulus.is_native_modulus() {
+                Scalar::BITS
+            } else {
+                uncompressed_ciphertext_modulus.get_custom_modulus().ilog2() as usize
+            };
+
+        assert!(
+            log_modulus.0 <= uncompressed_ciphertext_modulus_log,
+            "The log_modulus (={}) for modulus switch compression must be smaller than the uncompressed ciphertext_modulus_log (={uncompressed_ciphertext_modulus_log})",
+            log_modulus.0,
+        );
+
+        let polynomial_size = ct.polynomial_size();
+
+        assert!(
+            bodies_count.0 <= polynomial_size.0,
+            "Cannot keep more bodies (={}) than there are coefficients in a polynomial (={})",
+            bodies_count.0,
+            polynomial_size.0,
+        );
+
+        let glwe_dimension = ct.glwe_size().to_glwe_dimension();
+
+        let modulus_switched: Vec<_> = ct
+            .get_mask()
+            .as_ref()
+            .iter()
+            .chain(ct.get_body().as_ref()[0..bodies_count.0].iter())
+            .map(|a| modulus_switch(*a, log_modulus))
+            .collect();
+
+        let packed_integers = PackedIntegers::pack(&modulus_switched, log_modulus);
+
+        Self {
+            packed_integers,
+            glwe_dimension,
+            polynomial_size,
+            bodies_count,
+            uncompressed_ciphertext_modulus,
+        }
+    }
+
+    /// Converts back a compressed ciphertext to its initial modulus
+    /// The noise added during the compression stays in the output
+    /// The output must go through a PBS to reduce the noise
+    ///
+    /// The body coefficients that were not kept during compression are set to zero.
+    pub fn extract(&self) -> GlweCiphertextOwned<Scalar> {
+        let log_modulus = self.packed_integers.log_modulus().0;
+
+        let mut container: Vec<_> = self
+            .packed_integers
+            .unpack()
+            // Scaling
+            .map(|a| a << (Scalar::BITS - log_modulus))
+            .collect();
+
+        let glwe_size = self.glwe_dimension.to_glwe_size();
+
+        container.resize(glwe_size.0 * self.polynomial_size.0, Scalar::ZERO);
+
+        GlweCiphertextOwned::from_container(
+            container,
+            self.polynomial_size,
+            self.uncompressed_ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger> ParameterSetConformant
+    for CompressedModulusSwitchedGlweCiphertext<Scalar>
+{
+    type ParameterSet = GlweCiphertextParameters<Scalar>;
+
+    fn is_conformant(&self, params: &GlweCiphertextParameters<Scalar>) -> bool {
+        let GlweCiphertextParameters {
+            glwe_dim,
+            polynomial_size,
+            ct_modulus,
+        } = *params;
+
+        let expected_len = self.glwe_dimension.0 * self.polynomial_size.0 + self.bodies_count.0;
+
+        self.packed_integers.is_well_formed()
+            && self.packed_integers.initial_len() == expected_len
+            && self.bodies_count.0 <= self.polynomial_size.0
+            && self.glwe_dimension == glwe_dim
+            && self.polynomial_size == polynomial_size
+            && ct_modulus.is_power_of_two()
+            && self.uncompressed_ciphertext_modulus == ct_modulus
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core_crypto::prelude::test::TestResources;
+
+    #[test]
+    fn glwe_ms_compression_() {
+        glwe_ms_compression::<u32>(1, GlweDimension(1), PolynomialSize(256), 100);
+        glwe_ms_compression::<u32>(12, GlweDimension(2), PolynomialSize(512), 512);
+
+        glwe_ms_compression::<u64>(1, GlweDimension(1), PolynomialSize(256), 0);
+        glwe_ms_compression::<u64>(11, GlweDimension(4), PolynomialSize(256), 256);
+        glwe_ms_compression::<u64>(12, GlweDimension(1), PolynomialSize(2048), 37);
+        glwe_ms_compression::<u64>(63, GlweDimension(2), PolynomialSize(64), 10);
+    }
+
+    fn glwe_ms_compression<Scalar: UnsignedTorus>(
+        log_modulus: usize,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        bodies_count: usize,
+    ) {
+        let mut rsc: TestResources = TestResources::new();
+
+        let ciphertext_modulus = CiphertextModulus::new_native();
+
+        let mut glwe = vec![Scalar::ZERO; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+
+        rsc.encryption_random_generator
+            .fill_slice_with_random_uniform_mask(&mut glwe);
+
+        let glwe = GlweCiphertextOwned::from_container(glwe, polynomial_size, ciphertext_modulus);
+
+        let compressed = CompressedModulusSwitchedGlweCiphertext::compress(
+            &glwe,
+            CiphertextModulusLog(log_modulus),
+            LweCiphertextCount(bodies_count),
+        );
+
+        assert!(compressed.is_conformant(&GlweCiphertextParameters {
+            glwe_dim: glwe_dimension,
+            polynomial_size,
+            ct_modulus: ciphertext_modulus,
+        }));
+
+        let glwe_ms_ed = compressed.extract();
+
+        let mask_len = glwe_dimension.0 * polynomial_size.0;
+        let kept_len = mask_len + bodies_count;
+
+        for (i, (input, output)) in glwe
+            .as_ref()
+            .iter()
+            .zip(glwe_ms_ed.as_ref().iter())
+            .enumerate()
+        {
+            if i < kept_len {
+                assert_eq!(
+                    *output,
+                    (*output >> (Scalar::BITS - log_modulus)) << (Scalar::BITS - log_modulus),
+                );
+
+                assert_eq!(
+                    *output >> (Scalar::BITS - log_modulus),
+                    modulus_switch(*input, CiphertextModulusLog(log_modulus))
+                );
+            } else {
+                assert_eq!(*output, Scalar::ZERO);
+            }
+        }
+    }
+}
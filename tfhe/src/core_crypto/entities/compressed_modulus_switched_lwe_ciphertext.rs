@@ -1,5 +1,6 @@
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::fft_impl::common::modulus_switch;
+use crate::core_crypto::prelude::packed_integers::{pack_integers, unpack_integers};
 use crate::core_crypto::prelude::*;
 
 /// An object to store a ciphertext in little memory
@@ -93,66 +94,9 @@ impl<Scalar: UnsignedTorus> CompressedModulusSwitchedLweCiphertext<Scalar> {
             "The log_modulus (={log_modulus}) for modulus switch compression must be smaller than the uncompressed ciphertext_modulus_log (={uncompressed_ciphertext_modulus_log})",
         );
 
-        let lwe_size = ct.lwe_size().0;
+        let modulus_switched: Vec<_> = ct.as_ref().iter().copied().map(switch_modulus).collect();
 
-        let number_bits_to_pack = lwe_size * log_modulus;
-
-        let len = number_bits_to_pack.div_ceil(Scalar::BITS);
-
-        let slice = ct.as_ref();
-        // Lowest bits are on the right
-        //
-        // Target mapping:
-        //                          log_modulus
-        //                           |-------|
-        //
-        // slice        :    |  k+2  |  k+1  |   k   |
-        // packed_coeffs:  i+1   |       i       |     i-1
-        //
-        //                       |---------------|
-        //                         Scalar::BITS
-        //
-        //                                       |---|
-        //                                    start_shift
-        //
-        //                                   |---|
-        //                                   shift1
-        //                             (1st loop iteration)
-        //
-        //                           |-----------|
-        //                               shift2
-        //                        (2nd loop iteration)
-        //
-        // packed_coeffs[i] =
-        //                    slice[k] >> start_shift
-        //                  | slice[k+1] << shift1
-        //                  | slice[k+2] << shift2
-        //
-        // In the lowest bits of packed_coeffs[i], we want the highest bits of slice[k],
-        // hence the right shift
-        // The next bits should be the bits of slice[k+1] which we must left shifted to avoid
-        // overlapping
-        // This goes on
-        let packed_coeffs = (0..len)
-            .map(|i| {
-                let k = Scalar::BITS * i / log_modulus;
-                let mut j = k;
-
-                let start_shift = i * Scalar::BITS - j * log_modulus;
-
-                let mut value = switch_modulus(slice[j]) >> start_shift;
-                j += 1;
-
-                while j * log_modulus < ((i + 1) * Scalar::BITS) && j < lwe_size {
-                    let shift = j * log_modulus - i * Scalar::BITS;
-
-                    value |= switch_modulus(slice[j]) << shift;
-
-                    j += 1;
-                }
-                value
-            })
-            .collect();
+        let packed_coeffs = pack_integers(&modulus_switched, CiphertextModulusLog(log_modulus));
 
         let log_modulus = CiphertextModulusLog(log_modulus);
 
@@ -170,92 +114,9 @@ impl<Scalar: UnsignedTorus> CompressedModulusSwitchedLweCiphertext<Scalar> {
     pub fn extract(&self) -> LweCiphertextOwned<Scalar> {
         let lwe_size = self.lwe_dimension.to_lwe_size().0;
 
-        let number_bits_to_pack = lwe_size * self.log_modulus.0;
-
-        let len = number_bits_to_pack.div_ceil(Scalar::BITS);
-
-        assert_eq!(
-            self.packed_coeffs.len(),
-            len,
-            "Mismatch between actual(={}) and expected(={len}) CompressedModulusSwitchedLweCiphertext packed_coeffs size",
-            self.packed_coeffs.len(),
-        );
-
         let log_modulus = self.log_modulus.0;
 
-        let container = (0..(self.lwe_dimension.to_lwe_size().0))
-            .map(|i| {
-                let start = i * log_modulus;
-                let end = (i + 1) * log_modulus;
-
-                let start_block = start / Scalar::BITS;
-                let start_remainder = start % Scalar::BITS;
-
-                let end_block_inclusive = (end - 1) / Scalar::BITS;
-
-                if start_block == end_block_inclusive {
-                    // Lowest bits are on the right
-                    //
-                    // Target mapping:
-                    //                                   Scalar::BITS
-                    //                                |---------------|
-                    //
-                    // packed_coeffs: | start_block+1 |  start_block  |
-                    // container    :             |  i+1  |   i   |  i-1  |
-                    //
-                    //                                    |-------|
-                    //                                   log_modulus
-                    //
-                    //                                            |---|
-                    //                                       start_remainder
-                    //
-                    // In container[i] we want the bits of packed_coeffs[start_block] starting from
-                    // index start_remainder
-                    //
-                    // container[i] = lowest_bits of single_part
-                    //
-                    // The highest bits of single_part will be discarded during scaling
-                    //
-                    // single_part =
-                    self.packed_coeffs[start_block] >> start_remainder
-                } else {
-                    // Lowest bits are on the right
-                    //
-                    // Target mapping:
-                    //                                   Scalar::BITS
-                    //                                 |---------------|
-                    //
-                    // packed_coeffs:  | start_block+1 |  start_block  |
-                    // container    :      |  i+1  |   i   |  i-1  |
-                    //
-                    //                             |-------|
-                    //                            log_modulus
-                    //
-                    //                                     |-----------|
-                    //                                    start_remainder
-                    //
-                    //                                 |---|
-                    //                     Scalar::BITS - start_remainder
-                    //
-                    // In the lowest bits of container[i] we want the highest bits of
-                    // packed_coeffs[start_block] starting from index start_remainder
-                    //
-                    // In the next bits, we want the lowest bits of packed_coeffs[start_block + 1]
-                    // left shifted to avoid overlapping
-                    //
-                    // container[i] = lowest_bits of (first_part|second_part)
-                    //
-                    // The highest bits of (first_part|second_part) will be discarded during scaling
-                    assert_eq!(end_block_inclusive, start_block + 1);
-
-                    let first_part = self.packed_coeffs[start_block] >> start_remainder;
-
-                    let second_part =
-                        self.packed_coeffs[start_block + 1] << (Scalar::BITS - start_remainder);
-
-                    first_part | second_part
-                }
-            })
+        let container = unpack_integers(&self.packed_coeffs, self.log_modulus, lwe_size)
             // Scaling
             .map(|a| a << (Scalar::BITS - log_modulus))
             .collect();
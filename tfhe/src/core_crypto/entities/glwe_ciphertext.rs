@@ -577,6 +577,16 @@ impl<Scalar: UnsignedInteger> GlweCiphertextOwned<Scalar> {
     }
 }
 
+/// Structure to store the expected properties of a GLWE ciphertext
+/// Can be used on a server to check if inputs are well formed
+/// before running a computation on them
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GlweCiphertextParameters<T: UnsignedInteger> {
+    pub glwe_dim: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub ct_modulus: CiphertextModulus<T>,
+}
+
 /// Metadata used in the [`CreateFrom`] implementation to create [`GlweCiphertext`] entities.
 #[derive(Clone, Copy)]
 pub struct GlweCiphertextCreationMetadata<Scalar: UnsignedInteger>(
@@ -4,6 +4,7 @@
 //! associated to the object, e.g., `get_mask` for the entity `LweCiphertext`.
 
 pub mod cleartext;
+pub mod compressed_modulus_switched_glwe_ciphertext;
 pub mod compressed_modulus_switched_lwe_ciphertext;
 pub mod ggsw_ciphertext;
 pub mod ggsw_ciphertext_list;
@@ -23,6 +24,7 @@ pub mod lwe_private_functional_packing_keyswitch_key;
 pub mod lwe_private_functional_packing_keyswitch_key_list;
 pub mod lwe_public_key;
 pub mod lwe_secret_key;
+pub mod packed_integers;
 pub mod plaintext;
 pub mod plaintext_list;
 pub mod polynomial;
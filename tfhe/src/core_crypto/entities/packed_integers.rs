@@ -0,0 +1,256 @@
+use crate::core_crypto::prelude::*;
+
+/// A list of integers of `log_modulus` bits each, stored contiguously in a buffer of `Scalar`
+///
+/// This is used to store modulus switched ciphertexts with as few bits as possible.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PackedIntegers<Scalar: UnsignedInteger> {
+    packed_coeffs: Vec<Scalar>,
+    log_modulus: CiphertextModulusLog,
+    initial_len: usize,
+}
+
+impl<Scalar: UnsignedInteger> PackedIntegers<Scalar> {
+    /// Packs the given slice
+    ///
+    /// Each element of the slice must fit in `log_modulus` bits.
+    pub fn pack(slice: &[Scalar], log_modulus: CiphertextModulusLog) -> Self {
+        Self {
+            packed_coeffs: pack_integers(slice, log_modulus),
+            log_modulus,
+            initial_len: slice.len(),
+        }
+    }
+
+    /// Returns an iterator over the packed integers, each one being in the lowest bits of the
+    /// yielded `Scalar`
+    pub fn unpack(&self) -> impl Iterator<Item = Scalar> + '_ {
+        unpack_integers(&self.packed_coeffs, self.log_modulus, self.initial_len)
+    }
+
+    pub fn log_modulus(&self) -> CiphertextModulusLog {
+        self.log_modulus
+    }
+
+    /// Returns the number of integers stored
+    pub fn initial_len(&self) -> usize {
+        self.initial_len
+    }
+
+    pub fn packed_coeffs(&self) -> &[Scalar] {
+        &self.packed_coeffs
+    }
+
+    /// Returns `true` if the size of the packed buffer is consistent with the number of integers
+    /// and their bit size
+    pub fn is_well_formed(&self) -> bool {
+        self.log_modulus.0 <= Scalar::BITS
+            && self.packed_coeffs.len() == packed_len::<Scalar>(self.initial_len, self.log_modulus)
+    }
+}
+
+pub(crate) fn packed_len<Scalar: UnsignedInteger>(
+    len: usize,
+    log_modulus: CiphertextModulusLog,
+) -> usize {
+    (len * log_modulus.0).div_ceil(Scalar::BITS)
+}
+
+pub(crate) fn pack_integers<Scalar: UnsignedInteger>(
+    slice: &[Scalar],
+    log_modulus: CiphertextModulusLog,
+) -> Vec<Scalar> {
+    let len = packed_len::<Scalar>(slice.len(), log_modulus);
+
+    let log_modulus = log_modulus.0;
+
+    let in_len = slice.len();
+
+    // Lowest bits are on the right
+    //
+    // Target mapping:
+    //                          log_modulus
+    //                           |-------|
+    //
+    // slice        :    |  k+2  |  k+1  |   k   |
+    // packed_coeffs:  i+1   |       i       |     i-1
+    //
+    //                       |---------------|
+    //                         Scalar::BITS
+    //
+    //                                       |---|
+    //                                    start_shift
+    //
+    //                                   |---|
+    //                                   shift1
+    //                             (1st loop iteration)
+    //
+    //                           |-----------|
+    //                               shift2
+    //                        (2nd loop iteration)
+    //
+    // packed_coeffs[i] =
+    //                    slice[k] >> start_shift
+    //                  | slice[k+1] << shift1
+    //                  | slice[k+2] << shift2
+    //
+    // In the lowest bits of packed_coeffs[i], we want the highest bits of slice[k],
+    // hence the right shift
+    // The next bits should be the bits of slice[k+1] which we must left shifted to avoid
+    // overlapping
+    // This goes on
+    (0..len)
+        .map(|i| {
+            let k = Scalar::BITS * i / log_modulus;
+            let mut j = k;
+
+            let start_shift = i * Scalar::BITS - j * log_modulus;
+
+            let mut value = slice[j] >> start_shift;
+            j += 1;
+
+            while j * log_modulus < ((i + 1) * Scalar::BITS) && j < in_len {
+                let shift = j * log_modulus - i * Scalar::BITS;
+
+                value |= slice[j] << shift;
+
+                j += 1;
+            }
+            value
+        })
+        .collect()
+}
+
+pub(crate) fn unpack_integers<Scalar: UnsignedInteger>(
+    packed_coeffs: &[Scalar],
+    log_modulus: CiphertextModulusLog,
+    len: usize,
+) -> impl Iterator<Item = Scalar> + '_ {
+    let expected_len = packed_len::<Scalar>(len, log_modulus);
+    assert_eq!(
+        packed_coeffs.len(),
+        expected_len,
+        "Mismatch between actual(={}) and expected(={expected_len}) packed_coeffs size",
+        packed_coeffs.len(),
+    );
+
+    let log_modulus = log_modulus.0;
+
+    let mask = if log_modulus == Scalar::BITS {
+        Scalar::MAX
+    } else {
+        (Scalar::ONE << log_modulus) - Scalar::ONE
+    };
+
+    (0..len).map(move |i| {
+        let start = i * log_modulus;
+        let end = (i + 1) * log_modulus;
+
+        let start_block = start / Scalar::BITS;
+        let start_remainder = start % Scalar::BITS;
+
+        let end_block_inclusive = (end - 1) / Scalar::BITS;
+
+        let value = if start_block == end_block_inclusive {
+            // Lowest bits are on the right
+            //
+            // Target mapping:
+            //                                   Scalar::BITS
+            //                                |---------------|
+            //
+            // packed_coeffs: | start_block+1 |  start_block  |
+            // container    :             |  i+1  |   i   |  i-1  |
+            //
+            //                                    |-------|
+            //                                   log_modulus
+            //
+            //                                            |---|
+            //                                       start_remainder
+            //
+            // In container[i] we want the bits of packed_coeffs[start_block] starting from
+            // index start_remainder
+            //
+            // container[i] = lowest_bits of single_part
+            //
+            // The highest bits of single_part are discarded by the mask
+            packed_coeffs[start_block] >> start_remainder
+        } else {
+            // Lowest bits are on the right
+            //
+            // Target mapping:
+            //                                   Scalar::BITS
+            //                                 |---------------|
+            //
+            // packed_coeffs:  | start_block+1 |  start_block  |
+            // container    :      |  i+1  |   i   |  i-1  |
+            //
+            //                             |-------|
+            //                            log_modulus
+            //
+            //                                     |-----------|
+            //                                    start_remainder
+            //
+            //                                 |---|
+            //                     Scalar::BITS - start_remainder
+            //
+            // In the lowest bits of container[i] we want the highest bits of
+            // packed_coeffs[start_block] starting from index start_remainder
+            //
+            // In the next bits, we want the lowest bits of packed_coeffs[start_block + 1]
+            // left shifted to avoid overlapping
+            //
+            // container[i] = lowest_bits of (first_part|second_part)
+            //
+            // The highest bits of (first_part|second_part) are discarded by the mask
+            assert_eq!(end_block_inclusive, start_block + 1);
+
+            let first_part = packed_coeffs[start_block] >> start_remainder;
+
+            let second_part = packed_coeffs[start_block + 1] << (Scalar::BITS - start_remainder);
+
+            first_part | second_part
+        };
+
+        value & mask
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core_crypto::prelude::test::TestResources;
+
+    #[test]
+    fn pack_unpack_integers() {
+        pack_unpack::<u32>(1, 100);
+        pack_unpack::<u32>(12, 751);
+        pack_unpack::<u32>(32, 10);
+
+        pack_unpack::<u64>(1, 100);
+        pack_unpack::<u64>(11, 700);
+        pack_unpack::<u64>(12, 2048);
+        pack_unpack::<u64>(53, 37);
+        pack_unpack::<u64>(64, 64);
+    }
+
+    fn pack_unpack<Scalar: UnsignedTorus>(log_modulus: usize, len: usize) {
+        let mut rsc: TestResources = TestResources::new();
+
+        let mut values = vec![Scalar::ZERO; len];
+        rsc.encryption_random_generator
+            .fill_slice_with_random_uniform_mask(&mut values);
+
+        if log_modulus < Scalar::BITS {
+            for value in values.iter_mut() {
+                *value >>= Scalar::BITS - log_modulus;
+            }
+        }
+
+        let packed = PackedIntegers::pack(&values, CiphertextModulusLog(log_modulus));
+        assert!(packed.is_well_formed());
+        assert_eq!(packed.initial_len(), len);
+
+        let unpacked: Vec<Scalar> = packed.unpack().collect();
+        assert_eq!(unpacked, values);
+    }
+}
@@ -0,0 +1,622 @@
+//! This module defines [FheArray], a list of encrypted integers that can be indexed
+//! with encrypted indices.
+//!
+//! Accessing an element at an encrypted index is done obliviously: every element is compared
+//! to the index, and the selected element is gathered using a balanced tree of additions,
+//! so the cost of an access is linear in the length of the array.
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::{
+    BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey, SignedRadixCiphertext,
+};
+use crate::named::{NameBuilder, Named};
+use crate::prelude::{FheDecrypt, FheTryEncrypt};
+use crate::shortint::MessageModulus;
+use crate::{ClientKey, FheBool, FheInt, FheUint};
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+
+/// Trait implemented by the types that can be stored in a [FheArray]
+pub trait FheArrayElement: Sized {
+    /// The CPU integer ciphertext the element is made of
+    type Ciphertext: IntegerRadixCiphertext + Serialize + DeserializeOwned;
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize;
+
+    fn into_cpu_ciphertext(self) -> Self::Ciphertext;
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self;
+}
+
+impl<Id: FheUintId> FheArrayElement for FheUint<Id> {
+    type Ciphertext = RadixCiphertext;
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        Id::num_blocks(message_modulus)
+    }
+
+    fn into_cpu_ciphertext(self) -> Self::Ciphertext {
+        self.ciphertext.into_cpu()
+    }
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self {
+        Self::new(ciphertext)
+    }
+}
+
+impl<Id: FheIntId> FheArrayElement for FheInt<Id> {
+    type Ciphertext = SignedRadixCiphertext;
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        Id::num_blocks(message_modulus)
+    }
+
+    fn into_cpu_ciphertext(self) -> Self::Ciphertext {
+        self.ciphertext.into_cpu()
+    }
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self {
+        Self::new(ciphertext)
+    }
+}
+
+/// An array of encrypted integers
+///
+/// Elements can be read and written at encrypted indices, in which case no information
+/// about the index is leaked.
+///
+/// The array is stored on the CPU, and its operations use the server key set in
+/// the current thread.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheArray, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let mut array = FheArray::<FheUint8>::try_encrypt([1u8, 2, 3].as_slice(), &client_key).unwrap();
+/// let index = FheUint8::encrypt(1u8, &client_key);
+///
+/// let element = array.get(&index);
+/// let decrypted: u8 = element.decrypt(&client_key);
+/// assert_eq!(decrypted, 2);
+///
+/// let new_value = FheUint8::encrypt(42u8, &client_key);
+/// array.set(&index, &new_value);
+/// let decrypted: Vec<u8> = array.decrypt(&client_key);
+/// assert_eq!(decrypted, vec![1, 42, 3]);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FheArray<T: FheArrayElement> {
+    elements: Vec<T::Ciphertext>,
+    _element: PhantomData<T>,
+}
+
+impl<T: FheArrayElement + Named> FheArray<T> {
+    const NAME_BUILDER: NameBuilder = NameBuilder::new()
+        .push_str("high_level_api::FheArray<")
+        .push_str(T::NAME)
+        .push_str(">");
+}
+
+impl<T: FheArrayElement + Named> Named for FheArray<T> {
+    const NAME: &'static str = Self::NAME_BUILDER.as_str();
+}
+
+impl<T: FheArrayElement> From<Vec<T>> for FheArray<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Self::new(elements)
+    }
+}
+
+impl<T: FheArrayElement> FromIterator<T> for FheArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_cpu_ciphertexts(iter.into_iter().map(T::into_cpu_ciphertext).collect())
+    }
+}
+
+impl<T: FheArrayElement> FheArray<T> {
+    pub fn new(elements: Vec<T>) -> Self {
+        elements.into_iter().collect()
+    }
+
+    fn from_cpu_ciphertexts(elements: Vec<T::Ciphertext>) -> Self {
+        Self {
+            elements,
+            _element: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn into_elements(self) -> Vec<T> {
+        self.elements
+            .into_iter()
+            .map(T::from_cpu_ciphertext)
+            .collect()
+    }
+
+    /// Returns the element at the given encrypted index
+    ///
+    /// If the index is out of bounds, an encryption of 0 is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    pub fn get<IndexId: FheUintId>(&self, index: &FheUint<IndexId>) -> T {
+        assert!(!self.is_empty(), "Cannot get an element of an empty array");
+
+        with_cpu_server_key(|sks| {
+            let is_at_index = one_hot_index(sks, &index.ciphertext.on_cpu(), self.len());
+            T::from_cpu_ciphertext(select(sks, &self.elements, &is_at_index))
+        })
+    }
+
+    /// Replaces the element at the given encrypted index by `value`
+    ///
+    /// If the index is out of bounds, the array is left unchanged.
+    pub fn set<IndexId: FheUintId>(&mut self, index: &FheUint<IndexId>, value: &T)
+    where
+        T: Clone,
+    {
+        with_cpu_server_key(|sks| {
+            let is_at_index = one_hot_index(sks, &index.ciphertext.on_cpu(), self.len());
+            let value = value.clone().into_cpu_ciphertext();
+            self.elements
+                .par_iter_mut()
+                .zip(is_at_index.par_iter())
+                .for_each(|(element, is_at_index)| {
+                    *element = sks.if_then_else_parallelized(is_at_index, &value, element);
+                });
+        });
+    }
+
+    /// Swaps the elements at the two given encrypted indices
+    ///
+    /// If one of the indices is out of bounds, the array is left unchanged, like with
+    /// [set](Self::set).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    pub fn swap<IndexId: FheUintId>(
+        &mut self,
+        first_index: &FheUint<IndexId>,
+        second_index: &FheUint<IndexId>,
+    ) {
+        assert!(!self.is_empty(), "Cannot swap elements of an empty array");
+
+        with_cpu_server_key(|sks| {
+            let len = self.len();
+            let first_index = first_index.ciphertext.on_cpu();
+            let second_index = second_index.ciphertext.on_cpu();
+            let one_hot_index_in_bounds = |index: &RadixCiphertext| {
+                rayon::join(
+                    || one_hot_index(sks, index, len),
+                    || sks.scalar_lt_parallelized(index, len as u64),
+                )
+            };
+            let ((is_at_first, first_in_bounds), (is_at_second, second_in_bounds)) = rayon::join(
+                || one_hot_index_in_bounds(&first_index),
+                || one_hot_index_in_bounds(&second_index),
+            );
+            let elements = &self.elements;
+            let (first, second) = rayon::join(
+                || select(sks, elements, &is_at_first),
+                || select(sks, elements, &is_at_second),
+            );
+
+            // An element is only written if the index of the element it is swapped with is
+            // in bounds, so that an out of bounds index leaves the array unchanged
+            self.elements
+                .par_iter_mut()
+                .zip(is_at_first.par_iter().zip(is_at_second.par_iter()))
+                .for_each(|(element, (is_at_first, is_at_second))| {
+                    let (write_first, write_second) = rayon::join(
+                        || sks.boolean_bitand(is_at_first, &second_in_bounds),
+                        || sks.boolean_bitand(is_at_second, &first_in_bounds),
+                    );
+                    let new_value = sks.if_then_else_parallelized(&write_second, &first, element);
+                    *element = sks.if_then_else_parallelized(&write_first, &second, &new_value);
+                });
+        });
+    }
+
+    /// Returns the (wrapping) sum of all the elements
+    pub fn sum(&self) -> T {
+        with_cpu_server_key(|sks| {
+            let sum = sks
+                .sum_ciphertexts_parallelized(&self.elements)
+                .unwrap_or_else(|| {
+                    sks.create_trivial_zero_radix(T::num_blocks(sks.message_modulus()))
+                });
+            T::from_cpu_ciphertext(sum)
+        })
+    }
+
+    /// Returns whether at least one element is equal to `value`
+    pub fn contains(&self, value: &T) -> FheBool
+    where
+        T: Clone,
+    {
+        with_cpu_server_key(|sks| {
+            let mut value = value.clone().into_cpu_ciphertext();
+            if !value.block_carries_are_empty() {
+                sks.full_propagate_parallelized(&mut value);
+            }
+
+            let elements = clean_elements(sks, &self.elements);
+            let comparisons = elements
+                .par_iter()
+                .map(|element| sks.unchecked_eq_parallelized(element, &value).into_inner())
+                .collect::<Vec<_>>();
+
+            let result = if comparisons.is_empty() {
+                sks.create_trivial_boolean_block(false)
+            } else {
+                BooleanBlock::new_unchecked(sks.is_at_least_one_comparisons_block_true(comparisons))
+            };
+            FheBool::new(result)
+        })
+    }
+
+    /// Returns the minimum element, or `None` if the array is empty
+    pub fn min(&self) -> Option<T> {
+        with_cpu_server_key(|sks| {
+            let elements = clean_elements(sks, &self.elements).into_owned();
+            tree_reduce(elements, |lhs, rhs| {
+                sks.unchecked_min_parallelized(&lhs, &rhs)
+            })
+            .map(T::from_cpu_ciphertext)
+        })
+    }
+
+    /// Returns the maximum element, or `None` if the array is empty
+    pub fn max(&self) -> Option<T> {
+        with_cpu_server_key(|sks| {
+            let elements = clean_elements(sks, &self.elements).into_owned();
+            tree_reduce(elements, |lhs, rhs| {
+                sks.unchecked_max_parallelized(&lhs, &rhs)
+            })
+            .map(T::from_cpu_ciphertext)
+        })
+    }
+
+    fn zip_map(
+        &self,
+        other: &Self,
+        func: impl Fn(&ServerKey, &T::Ciphertext, &T::Ciphertext) -> T::Ciphertext + Sync,
+    ) -> Self {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Cannot do an element-wise operation on arrays of different lengths"
+        );
+
+        with_cpu_server_key(|sks| {
+            Self::from_cpu_ciphertexts(
+                self.elements
+                    .par_iter()
+                    .zip(other.elements.par_iter())
+                    .map(|(lhs, rhs)| func(sks, lhs, rhs))
+                    .collect(),
+            )
+        })
+    }
+}
+
+impl<T, Clear> FheTryEncrypt<&[Clear], ClientKey> for FheArray<T>
+where
+    T: FheArrayElement + FheTryEncrypt<Clear, ClientKey>,
+    Clear: Copy,
+{
+    type Error = T::Error;
+
+    fn try_encrypt(values: &[Clear], key: &ClientKey) -> Result<Self, Self::Error> {
+        values
+            .iter()
+            .map(|value| T::try_encrypt(*value, key))
+            .collect()
+    }
+}
+
+impl<T, Clear> FheDecrypt<Vec<Clear>> for FheArray<T>
+where
+    T: FheArrayElement + FheDecrypt<Clear>,
+{
+    fn decrypt(&self, key: &ClientKey) -> Vec<Clear> {
+        self.elements
+            .iter()
+            .map(|element| T::from_cpu_ciphertext(element.clone()).decrypt(key))
+            .collect()
+    }
+}
+
+macro_rules! impl_element_wise_op {
+    ($trait_name:ident, $trait_method:ident, $server_key_method:ident) => {
+        impl<T: FheArrayElement> $trait_name<&FheArray<T>> for &FheArray<T> {
+            type Output = FheArray<T>;
+
+            /// Element-wise operation
+            ///
+            /// # Panics
+            ///
+            /// Panics if the arrays do not have the same length.
+            fn $trait_method(self, rhs: &FheArray<T>) -> Self::Output {
+                self.zip_map(rhs, |sks, lhs, rhs| sks.$server_key_method(lhs, rhs))
+            }
+        }
+
+        impl<T: FheArrayElement> $trait_name<FheArray<T>> for FheArray<T> {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: Self) -> Self::Output {
+                <&Self as $trait_name<&Self>>::$trait_method(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_element_wise_op!(Add, add, add_parallelized);
+impl_element_wise_op!(Sub, sub, sub_parallelized);
+impl_element_wise_op!(Mul, mul, mul_parallelized);
+
+fn with_cpu_server_key<R>(func: impl FnOnce(&ServerKey) -> R) -> R {
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(&cpu_key.key),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support FheArray yet")
+        }
+    })
+}
+
+/// Returns the elements with their carries propagated, so that `unchecked_*` operations
+/// can be used on them
+fn clean_elements<'a, C: IntegerRadixCiphertext>(
+    sks: &ServerKey,
+    elements: &'a [C],
+) -> std::borrow::Cow<'a, [C]> {
+    if elements.iter().all(C::block_carries_are_empty) {
+        return std::borrow::Cow::Borrowed(elements);
+    }
+
+    std::borrow::Cow::Owned(
+        elements
+            .par_iter()
+            .map(|element| {
+                let mut element = element.clone();
+                if !element.block_carries_are_empty() {
+                    sks.full_propagate_parallelized(&mut element);
+                }
+                element
+            })
+            .collect(),
+    )
+}
+
+/// Returns, for each position in `0..len`, whether the index is equal to it
+fn one_hot_index(sks: &ServerKey, index: &RadixCiphertext, len: usize) -> Vec<BooleanBlock> {
+    let mut index = index.clone();
+    if !index.block_carries_are_empty() {
+        sks.full_propagate_parallelized(&mut index);
+    }
+
+    (0..len)
+        .into_par_iter()
+        .map(|i| sks.unchecked_scalar_eq_parallelized(&index, i as u64))
+        .collect()
+}
+
+/// Returns the element for which the condition is true, or 0 if all the conditions are false
+///
+/// At most one condition must be true.
+fn select<C: IntegerRadixCiphertext>(
+    sks: &ServerKey,
+    elements: &[C],
+    conditions: &[BooleanBlock],
+) -> C {
+    let selected = elements
+        .par_iter()
+        .zip(conditions.par_iter())
+        .map(|(element, condition)| {
+            let mut element = element.clone();
+            sks.zero_out_if_condition_is_false(&mut element, condition.as_ref());
+            element
+        })
+        .collect::<Vec<_>>();
+
+    // As at most one element is not zero, the additions never actually carry,
+    // carries are only propagated to keep the degrees within bounds
+    let mut result = tree_reduce(selected, |mut lhs, mut rhs| {
+        if sks.is_add_possible(&lhs, &rhs).is_err() {
+            rayon::join(
+                || sks.full_propagate_parallelized(&mut lhs),
+                || sks.full_propagate_parallelized(&mut rhs),
+            );
+        }
+        sks.unchecked_add_assign(&mut lhs, &rhs);
+        lhs
+    })
+    .expect("Cannot select among zero elements");
+
+    if !result.block_carries_are_empty() {
+        sks.full_propagate_parallelized(&mut result);
+    }
+    result
+}
+
+/// Reduces the values pairwise, level by level, so that the reduction has a logarithmic depth
+fn tree_reduce<C: Send>(mut values: Vec<C>, op: impl Fn(C, C) -> C + Sync) -> Option<C> {
+    while values.len() > 1 {
+        values = values
+            .into_par_iter()
+            .chunks(2)
+            .map(|mut pair| {
+                let rhs = pair.pop().unwrap();
+                match pair.pop() {
+                    Some(lhs) => op(lhs, rhs),
+                    None => rhs,
+                }
+            })
+            .collect();
+    }
+    values.pop()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high_level_api::integers::setup_default_cpu;
+    use crate::prelude::*;
+    use crate::{FheInt8, FheUint4, FheUint8};
+
+    #[test]
+    fn test_fhe_array_indexing() {
+        let client_key = setup_default_cpu();
+
+        let clear_values = [3u8, 250, 7, 18, 0];
+        let mut array =
+            FheArray::<FheUint8>::try_encrypt(clear_values.as_slice(), &client_key).unwrap();
+        assert_eq!(array.len(), clear_values.len());
+
+        for i in [0u8, 3, 4, 5] {
+            let index = FheUint4::encrypt(i, &client_key);
+            let element: u8 = array.get(&index).decrypt(&client_key);
+            let expected = clear_values.get(i as usize).copied().unwrap_or(0);
+            assert_eq!(element, expected);
+        }
+
+        let mut expected = clear_values.to_vec();
+        let index = FheUint4::encrypt(2u8, &client_key);
+        array.set(&index, &FheUint8::encrypt(100u8, &client_key));
+        expected[2] = 100;
+
+        let out_of_bounds = FheUint4::encrypt(9u8, &client_key);
+        array.set(&out_of_bounds, &FheUint8::encrypt(1u8, &client_key));
+        let decrypted: Vec<u8> = array.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+
+        let first = FheUint4::encrypt(1u8, &client_key);
+        let second = FheUint4::encrypt(4u8, &client_key);
+        array.swap(&first, &second);
+        expected.swap(1, 4);
+        let decrypted: Vec<u8> = array.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+
+        array.swap(&first, &first);
+        let decrypted: Vec<u8> = array.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+
+        // Like set, an out of bounds index leaves the array unchanged
+        array.swap(&first, &out_of_bounds);
+        array.swap(&out_of_bounds, &second);
+        let decrypted: Vec<u8> = array.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+    }
+
+    #[test]
+    fn test_fhe_array_names() {
+        assert_ne!(
+            FheArray::<FheUint8>::NAME,
+            FheArray::<FheInt8>::NAME,
+            "Arrays of different element types must not deserialize as one another"
+        );
+        assert_eq!(
+            FheArray::<FheUint8>::NAME,
+            "high_level_api::FheArray<high_level_api::FheUint>"
+        );
+    }
+
+    #[test]
+    fn test_fhe_array_safe_serialization() {
+        let client_key = setup_default_cpu();
+
+        let clear_values = [3u8, 250, 7];
+        let array =
+            FheArray::<FheUint8>::try_encrypt(clear_values.as_slice(), &client_key).unwrap();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&array, &mut serialized, 1 << 20).unwrap();
+        let array: FheArray<FheUint8> =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        let decrypted: Vec<u8> = array.decrypt(&client_key);
+        assert_eq!(decrypted, clear_values);
+
+        // The name of the element type is checked
+        let mut serialized = vec![];
+        crate::safe_serialize(&array, &mut serialized, 1 << 20).unwrap();
+        assert!(
+            crate::safe_deserialization::safe_deserialize::<FheArray<FheInt8>>(
+                serialized.as_slice(),
+                1 << 20
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_fhe_array_reductions() {
+        let client_key = setup_default_cpu();
+
+        let clear_values = [-3i8, 100, 7, -128, 42];
+        let array = FheArray::<FheInt8>::try_encrypt(clear_values.as_slice(), &client_key).unwrap();
+
+        let sum: i8 = array.sum().decrypt(&client_key);
+        let expected_sum = clear_values.iter().copied().fold(0i8, i8::wrapping_add);
+        assert_eq!(sum, expected_sum);
+
+        let min: i8 = array.min().unwrap().decrypt(&client_key);
+        assert_eq!(min, -128);
+        let max: i8 = array.max().unwrap().decrypt(&client_key);
+        assert_eq!(max, 100);
+
+        let contains = array.contains(&FheInt8::encrypt(7i8, &client_key));
+        assert!(contains.decrypt(&client_key));
+        let contains = array.contains(&FheInt8::encrypt(8i8, &client_key));
+        assert!(!contains.decrypt(&client_key));
+
+        let empty = FheArray::<FheInt8>::new(vec![]);
+        assert!(empty.min().is_none());
+        let sum: i8 = empty.sum().decrypt(&client_key);
+        assert_eq!(sum, 0);
+    }
+
+    #[test]
+    fn test_fhe_array_element_wise_ops() {
+        let client_key = setup_default_cpu();
+
+        let lhs_values = [1u8, 200, 15];
+        let rhs_values = [2u8, 100, 17];
+        let lhs = FheArray::<FheUint8>::try_encrypt(lhs_values.as_slice(), &client_key).unwrap();
+        let rhs = FheArray::<FheUint8>::try_encrypt(rhs_values.as_slice(), &client_key).unwrap();
+
+        let check = |result: FheArray<FheUint8>, op: fn(u8, u8) -> u8| {
+            let decrypted: Vec<u8> = result.decrypt(&client_key);
+            let expected = lhs_values
+                .iter()
+                .zip(rhs_values.iter())
+                .map(|(a, b)| op(*a, *b))
+                .collect::<Vec<_>>();
+            assert_eq!(decrypted, expected);
+        };
+
+        check(&lhs + &rhs, u8::wrapping_add);
+        check(&lhs - &rhs, u8::wrapping_sub);
+        check(lhs * rhs, u8::wrapping_mul);
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+use crate::integer::backward_compatibility::CompactCiphertextListV0;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::integer::backward_compatibility::ProvenCompactCiphertextListV0;
+use crate::integer::ciphertext::DataKind;
+use crate::versionable::{versions_dispatch, Upgrade};
+use crate::{CompactFheBool, CompactFheBoolList, CompressedFheBool, FheBool};
+#[cfg(feature = "zk-pok-experimental")]
+use crate::{ProvenCompactFheBool, ProvenCompactFheBoolList};
+
+versions_dispatch!(
+    pub enum FheBoolVersions {
+        V0(FheBool),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedFheBoolVersions {
+        V0(CompressedFheBool),
+    }
+);
+
+/// A compact boolean (or list of booleans) serialized before the
+/// compact list stored the kind of its values
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactBooleanV0 {
+    list: CompactCiphertextListV0,
+}
+
+impl Upgrade<CompactFheBool> for CompactBooleanV0 {
+    fn upgrade(self) -> crate::Result<CompactFheBool> {
+        Ok(CompactFheBool {
+            list: self.list.upgrade_with_kind(|_| DataKind::Boolean)?,
+        })
+    }
+}
+
+impl Upgrade<CompactFheBoolList> for CompactBooleanV0 {
+    fn upgrade(self) -> crate::Result<CompactFheBoolList> {
+        Ok(CompactFheBoolList {
+            list: self.list.upgrade_with_kind(|_| DataKind::Boolean)?,
+        })
+    }
+}
+
+versions_dispatch!(
+    pub enum CompactFheBoolVersions {
+        V0(CompactBooleanV0) => V1,
+        V1(CompactFheBool),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompactFheBoolListVersions {
+        V0(CompactBooleanV0) => V1,
+        V1(CompactFheBoolList),
+    }
+);
+
+/// A proven compact boolean (or list of booleans) serialized before the
+/// compact list stored the kind of its values
+#[cfg(feature = "zk-pok-experimental")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProvenCompactBooleanV0 {
+    inner: ProvenCompactCiphertextListV0,
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl Upgrade<ProvenCompactFheBool> for ProvenCompactBooleanV0 {
+    fn upgrade(self) -> crate::Result<ProvenCompactFheBool> {
+        Ok(ProvenCompactFheBool {
+            inner: self.inner.upgrade_with_kind(|_| DataKind::Boolean)?,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl Upgrade<ProvenCompactFheBoolList> for ProvenCompactBooleanV0 {
+    fn upgrade(self) -> crate::Result<ProvenCompactFheBoolList> {
+        Ok(ProvenCompactFheBoolList {
+            inner: self.inner.upgrade_with_kind(|_| DataKind::Boolean)?,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactFheBoolVersions {
+        V0(ProvenCompactBooleanV0) => V1,
+        V1(ProvenCompactFheBool),
+    }
+);
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactFheBoolListVersions {
+        V0(ProvenCompactBooleanV0) => V1,
+        V1(ProvenCompactFheBoolList),
+    }
+);
@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+
+use crate::high_level_api::integers::{FheIntDyn, FheIntId, FheUintDyn, FheUintId};
+#[cfg(feature = "zk-pok-experimental")]
+use crate::high_level_api::integers::{
+    ProvenCompactFheInt, ProvenCompactFheIntList, ProvenCompactFheUint, ProvenCompactFheUintList,
+};
+use crate::integer::backward_compatibility::CompactCiphertextListV0;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::integer::backward_compatibility::ProvenCompactCiphertextListV0;
+use crate::integer::ciphertext::DataKind;
+use crate::versionable::{versions_dispatch, Upgrade};
+use crate::{
+    CompactFheInt, CompactFheIntList, CompactFheUint, CompactFheUintList, CompressedFheInt,
+    CompressedFheUint, FheInt, FheUint,
+};
+
+versions_dispatch!(
+    pub enum FheUintVersions<Id: FheUintId> {
+        V0(FheUint<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum FheIntVersions<Id: FheIntId> {
+        V0(FheInt<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedFheUintVersions<Id: FheUintId> {
+        V0(CompressedFheUint<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedFheIntVersions<Id: FheIntId> {
+        V0(CompressedFheInt<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum FheUintDynVersions {
+        V0(FheUintDyn),
+    }
+);
+
+versions_dispatch!(
+    pub enum FheIntDynVersions {
+        V0(FheIntDyn),
+    }
+);
+
+/// A compact integer (or list of integers) serialized before the
+/// compact list stored the kind of its values
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactIntegerV0<Id> {
+    list: CompactCiphertextListV0,
+    id: Id,
+}
+
+impl<Id: FheUintId> Upgrade<CompactFheUint<Id>> for CompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<CompactFheUint<Id>> {
+        Ok(CompactFheUint {
+            list: self.list.upgrade_with_kind(DataKind::Unsigned)?,
+            id: self.id,
+        })
+    }
+}
+
+impl<Id: FheUintId> Upgrade<CompactFheUintList<Id>> for CompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<CompactFheUintList<Id>> {
+        Ok(CompactFheUintList {
+            list: self.list.upgrade_with_kind(DataKind::Unsigned)?,
+            id: self.id,
+        })
+    }
+}
+
+impl<Id: FheIntId> Upgrade<CompactFheInt<Id>> for CompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<CompactFheInt<Id>> {
+        Ok(CompactFheInt {
+            list: self.list.upgrade_with_kind(DataKind::Signed)?,
+            id: self.id,
+        })
+    }
+}
+
+impl<Id: FheIntId> Upgrade<CompactFheIntList<Id>> for CompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<CompactFheIntList<Id>> {
+        Ok(CompactFheIntList {
+            list: self.list.upgrade_with_kind(DataKind::Signed)?,
+            id: self.id,
+        })
+    }
+}
+
+versions_dispatch!(
+    pub enum CompactFheUintVersions<Id: FheUintId> {
+        V0(CompactIntegerV0<Id>) => V1,
+        V1(CompactFheUint<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompactFheUintListVersions<Id: FheUintId> {
+        V0(CompactIntegerV0<Id>) => V1,
+        V1(CompactFheUintList<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompactFheIntVersions<Id: FheIntId> {
+        V0(CompactIntegerV0<Id>) => V1,
+        V1(CompactFheInt<Id>),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompactFheIntListVersions<Id: FheIntId> {
+        V0(CompactIntegerV0<Id>) => V1,
+        V1(CompactFheIntList<Id>),
+    }
+);
+
+/// A proven compact integer (or list of integers) serialized before the
+/// compact list stored the kind of its values
+#[cfg(feature = "zk-pok-experimental")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProvenCompactIntegerV0<Id> {
+    inner: ProvenCompactCiphertextListV0,
+    #[serde(rename = "_id")]
+    id: Id,
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl<Id: FheUintId> Upgrade<ProvenCompactFheUint<Id>> for ProvenCompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<ProvenCompactFheUint<Id>> {
+        Ok(ProvenCompactFheUint {
+            inner: self.inner.upgrade_with_kind(DataKind::Unsigned)?,
+            _id: self.id,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl<Id: FheUintId> Upgrade<ProvenCompactFheUintList<Id>> for ProvenCompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<ProvenCompactFheUintList<Id>> {
+        Ok(ProvenCompactFheUintList {
+            inner: self.inner.upgrade_with_kind(DataKind::Unsigned)?,
+            _id: self.id,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl<Id: FheIntId> Upgrade<ProvenCompactFheInt<Id>> for ProvenCompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<ProvenCompactFheInt<Id>> {
+        Ok(ProvenCompactFheInt {
+            inner: self.inner.upgrade_with_kind(DataKind::Signed)?,
+            _id: self.id,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl<Id: FheIntId> Upgrade<ProvenCompactFheIntList<Id>> for ProvenCompactIntegerV0<Id> {
+    fn upgrade(self) -> crate::Result<ProvenCompactFheIntList<Id>> {
+        Ok(ProvenCompactFheIntList {
+            inner: self.inner.upgrade_with_kind(DataKind::Signed)?,
+            _id: self.id,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactFheUintVersions<Id: FheUintId> {
+        V0(ProvenCompactIntegerV0<Id>) => V1,
+        V1(ProvenCompactFheUint<Id>),
+    }
+);
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactFheUintListVersions<Id: FheUintId> {
+        V0(ProvenCompactIntegerV0<Id>) => V1,
+        V1(ProvenCompactFheUintList<Id>),
+    }
+);
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactFheIntVersions<Id: FheIntId> {
+        V0(ProvenCompactIntegerV0<Id>) => V1,
+        V1(ProvenCompactFheInt<Id>),
+    }
+);
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactFheIntListVersions<Id: FheIntId> {
+        V0(ProvenCompactIntegerV0<Id>) => V1,
+        V1(ProvenCompactFheIntList<Id>),
+    }
+);
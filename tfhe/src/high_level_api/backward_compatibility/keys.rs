@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use crate::high_level_api::keys::{
+    ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
+    CompressedServerKey, PublicKey, ServerKey,
+};
+use crate::versionable::{versions_dispatch, Upgrade};
+
+#[derive(Clone, Serialize, Deserialize)]
+struct IntegerClientKeyV0 {
+    key: crate::integer::ClientKey,
+    wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+}
+
+/// [ClientKey] before it could hold the private key used for ciphertext list compression
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ClientKeyV0 {
+    key: IntegerClientKeyV0,
+}
+
+impl Upgrade<ClientKey> for ClientKeyV0 {
+    fn upgrade(self) -> crate::Result<ClientKey> {
+        let IntegerClientKeyV0 {
+            key,
+            wopbs_block_parameters,
+        } = self.key;
+
+        Ok(ClientKey::from_raw_parts(key, wopbs_block_parameters, None))
+    }
+}
+
+versions_dispatch!(
+    pub enum ClientKeyVersions {
+        V0(ClientKeyV0) => V1,
+        V1(ClientKey),
+    }
+);
+
+#[derive(Clone, Serialize, Deserialize)]
+struct IntegerServerKeyV0 {
+    key: crate::integer::ServerKey,
+    wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+}
+
+/// [ServerKey] before it could hold the keys used for ciphertext list compression
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ServerKeyV0 {
+    integer_key: IntegerServerKeyV0,
+}
+
+impl Upgrade<ServerKey> for ServerKeyV0 {
+    fn upgrade(self) -> crate::Result<ServerKey> {
+        let IntegerServerKeyV0 { key, wopbs_key } = self.integer_key;
+
+        Ok(ServerKey::from_raw_parts(key, wopbs_key, None, None))
+    }
+}
+
+versions_dispatch!(
+    pub enum ServerKeyVersions {
+        V0(ServerKeyV0) => V1,
+        V1(ServerKey),
+    }
+);
+
+#[derive(Clone, Serialize, Deserialize)]
+struct IntegerCompressedServerKeyV0 {
+    key: crate::integer::CompressedServerKey,
+}
+
+/// [CompressedServerKey] before it could hold the keys used for ciphertext list compression
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedServerKeyV0 {
+    integer_key: IntegerCompressedServerKeyV0,
+}
+
+impl Upgrade<CompressedServerKey> for CompressedServerKeyV0 {
+    fn upgrade(self) -> crate::Result<CompressedServerKey> {
+        Ok(CompressedServerKey::from_raw_parts(
+            self.integer_key.key,
+            None,
+            None,
+        ))
+    }
+}
+
+versions_dispatch!(
+    pub enum CompressedServerKeyVersions {
+        V0(CompressedServerKeyV0) => V1,
+        V1(CompressedServerKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum PublicKeyVersions {
+        V0(PublicKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedPublicKeyVersions {
+        V0(CompressedPublicKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompactPublicKeyVersions {
+        V0(CompactPublicKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedCompactPublicKeyVersions {
+        V0(CompressedCompactPublicKey),
+    }
+);
@@ -0,0 +1,68 @@
+//! Versions of the serialized high level api types, see [crate::versionable]
+pub mod booleans;
+pub mod integers;
+pub mod keys;
+
+#[cfg(test)]
+mod tests;
+
+use crate::high_level_api::program::Program;
+#[cfg(feature = "strings")]
+use crate::high_level_api::FheAsciiString;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::high_level_api::ProvenCompactCiphertextList;
+use crate::high_level_api::{
+    CompactCiphertextList, CompressedCiphertextList, FheArray, FheArrayElement, FheFixed,
+    FheFixedInteger, FheFloat32,
+};
+use crate::versionable::versions_dispatch;
+
+versions_dispatch!(
+    pub enum CompactCiphertextListVersions {
+        V0(CompactCiphertextList),
+    }
+);
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactCiphertextListVersions {
+        V0(ProvenCompactCiphertextList),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedCiphertextListVersions {
+        V0(CompressedCiphertextList),
+    }
+);
+
+#[cfg(feature = "strings")]
+versions_dispatch!(
+    pub enum FheAsciiStringVersions {
+        V0(FheAsciiString),
+    }
+);
+
+versions_dispatch!(
+    pub enum FheArrayVersions<T: FheArrayElement> {
+        V0(FheArray<T>),
+    }
+);
+
+versions_dispatch!(
+    pub enum FheFixedVersions<T: FheFixedInteger, const FRAC_BITS: u32> {
+        V0(FheFixed<T, FRAC_BITS>),
+    }
+);
+
+versions_dispatch!(
+    pub enum FheFloat32Versions {
+        V0(FheFloat32),
+    }
+);
+
+versions_dispatch!(
+    pub enum ProgramVersions {
+        V0(Program),
+    }
+);
@@ -0,0 +1,91 @@
+//! Checks that data serialized by previous releases can still be loaded.
+//!
+//! The blobs in the `data` directory were written by tfhe 0.6.3, the last release before
+//! versioning: the ciphertexts with its `safe_serialize` (serialization version "0.3"), and the
+//! client keys, which it could not `safe_serialize`, with `bincode::serialize`. Server keys are
+//! not stored because of their size.
+//!
+//! They are generated by `utils/backward_compat_data_gen`, which depends on the released crate.
+use crate::prelude::*;
+use crate::safe_deserialization::{legacy_deserialize, safe_deserialize};
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+use crate::{
+    ClientKey, CompactFheBool, CompactFheInt8, CompactFheUint8List, CompressedFheBool,
+    CompressedFheInt16, CompressedFheUint8, FheBool, FheUint2,
+};
+
+const SIZE_LIMIT: u64 = 1 << 30;
+
+fn load<T>(blob: &[u8]) -> T
+where
+    T: crate::versionable::Versionize + crate::named::Named,
+{
+    safe_deserialize(blob, SIZE_LIMIT).unwrap()
+}
+
+fn load_key(blob: &[u8]) -> ClientKey {
+    legacy_deserialize(blob, SIZE_LIMIT).unwrap()
+}
+
+#[test]
+fn test_load_legacy_ciphertexts() {
+    let client_key = load_key(include_bytes!("data/client_key.bin"));
+
+    let a: FheUint2 = load(include_bytes!("data/fhe_uint2.bin"));
+    let a: u8 = a.decrypt(&client_key);
+    assert_eq!(a, 3);
+
+    let a: CompressedFheUint8 = load(include_bytes!("data/compressed_fhe_uint8.bin"));
+    let a: u8 = a.decompress().decrypt(&client_key);
+    assert_eq!(a, 213);
+
+    let a: CompressedFheInt16 = load(include_bytes!("data/compressed_fhe_int16.bin"));
+    let a: i16 = a.decompress().decrypt(&client_key);
+    assert_eq!(a, -1234);
+
+    let a: FheBool = load(include_bytes!("data/fhe_bool.bin"));
+    assert!(a.decrypt(&client_key));
+
+    let a: CompressedFheBool = load(include_bytes!("data/compressed_fhe_bool.bin"));
+    assert!(!a.decompress().decrypt(&client_key));
+
+    // Keys are now serialized along their version
+    let mut buffer = vec![];
+    crate::safe_serialize(&client_key, &mut buffer, SIZE_LIMIT).unwrap();
+    let client_key: ClientKey = load(&buffer);
+    let a: FheUint2 = load(include_bytes!("data/fhe_uint2.bin"));
+    let a: u8 = a.decrypt(&client_key);
+    assert_eq!(a, 3);
+
+    let (integer_key, ..) = client_key.into_raw_parts();
+    let shortint_key: &crate::shortint::ClientKey = integer_key.as_ref();
+    let a: crate::shortint::Ciphertext = load(include_bytes!("data/shortint_ciphertext.bin"));
+    assert_eq!(shortint_key.decrypt(&a), 2);
+}
+
+#[test]
+fn test_load_legacy_compact_ciphertexts() {
+    let client_key = load_key(include_bytes!("data/compact_client_key.bin"));
+    let (integer_key, ..) = client_key.clone().into_raw_parts();
+    let shortint_key: &crate::shortint::ClientKey = integer_key.as_ref();
+    assert_eq!(
+        shortint_key.parameters.message_modulus(),
+        PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS.message_modulus
+    );
+
+    let list: CompactFheUint8List = load(include_bytes!("data/compact_fhe_uint8_list.bin"));
+    assert_eq!(list.len(), 3);
+    let values: Vec<u8> = list
+        .expand()
+        .iter()
+        .map(|a| a.decrypt(&client_key))
+        .collect();
+    assert_eq!(values, [1, 2, 3]);
+
+    let a: CompactFheInt8 = load(include_bytes!("data/compact_fhe_int8.bin"));
+    let a: i8 = a.expand().decrypt(&client_key);
+    assert_eq!(a, -5);
+
+    let a: CompactFheBool = load(include_bytes!("data/compact_fhe_bool.bin"));
+    assert!(a.expand().decrypt(&client_key));
+}
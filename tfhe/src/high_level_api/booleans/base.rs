@@ -5,6 +5,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_stream;
 use crate::high_level_api::integers::{FheInt, FheIntId, FheUint, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::lazy::{LazyOp, LazyOperand, LazyValue};
 use crate::high_level_api::traits::{FheEq, IfThenElse};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
@@ -88,6 +89,15 @@ impl FheBool {
         }
     }
 
+    /// Records the comparison of the operands, to be computed when the result is needed
+    pub(in crate::high_level_api) fn lazy_comparison<F>(func: F, operands: Vec<LazyOperand>) -> Self
+    where
+        F: Fn(&crate::integer::ServerKey, &[&LazyValue]) -> BooleanBlock + Send + Sync + 'static,
+    {
+        let op = LazyOp::other(move |sks, values| func(sks, values).into());
+        Self::new(InnerBoolean::lazy(op, operands))
+    }
+
     pub fn current_device(&self) -> Device {
         self.ciphertext.current_device()
     }
@@ -99,6 +109,32 @@ impl FheBool {
         self.ciphertext.move_to_device(device)
     }
 
+    /// Computes (in-place) the boolean if it is the result of operations recorded under
+    /// [with_lazy_evaluation](crate::high_level_api::with_lazy_evaluation)
+    ///
+    /// Does nothing if the boolean was not computed lazily.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, with_lazy_evaluation, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint16::encrypt(5u16, &client_key);
+    ///
+    /// let mut result = with_lazy_evaluation(|| a.lt(&b));
+    /// result.eval();
+    ///
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    pub fn eval(&mut self) {
+        self.ciphertext.materialize();
+    }
+
     /// Tries to decrypt a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
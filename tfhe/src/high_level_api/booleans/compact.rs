@@ -1,7 +1,7 @@
 use super::FheBool;
 use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
 use crate::high_level_api::traits::FheTryEncrypt;
-use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::ciphertext::{CompactCiphertextList, DataKind};
 use crate::integer::parameters::RadixCompactCiphertextListConformanceParams;
 use crate::integer::BooleanBlock;
 use crate::named::Named;
@@ -43,8 +43,16 @@ impl CompactFheBool {
     /// Expand to a [FheBool]
     ///
     /// See [CompactFheBool] example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying list is empty, which can only happen with a value that was
+    /// deserialized without checking its conformance.
     pub fn expand(&self) -> FheBool {
-        let ct: crate::integer::RadixCiphertext = self.list.expand_one();
+        let ct: crate::integer::RadixCiphertext = self
+            .list
+            .try_expand_one()
+            .expect("A CompactFheBool holds one value");
         assert_eq!(ct.blocks.len(), 1);
         let mut block = BooleanBlock::new_unchecked(ct.blocks.into_iter().next().unwrap());
         block.0.degree = Degree::new(1);
@@ -58,8 +66,9 @@ impl FheTryEncrypt<bool, CompactPublicKey> for CompactFheBool {
     type Error = crate::Error;
 
     fn try_encrypt(value: bool, key: &CompactPublicKey) -> Result<Self, Self::Error> {
-        let mut ciphertext = key.key.try_encrypt_compact(&[u8::from(value)], 1);
-        ciphertext.ct_list.degree = Degree::new(1);
+        let ciphertext = CompactCiphertextList::builder(&key.key.key)
+            .push(value)
+            .build();
         Ok(Self { list: ciphertext })
     }
 }
@@ -77,7 +86,7 @@ impl ParameterSetConformant for CompactFheBool {
             num_blocks_per_integer: 1,
             num_integers_constraint: ListSizeConstraint::exact_size(1),
         };
-        self.list.is_conformant(&params)
+        self.list.holds_only(DataKind::Boolean) && self.list.is_conformant(&params)
     }
 }
 
@@ -112,7 +121,7 @@ impl ParameterSetConformant for CompactFheBool {
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct CompactFheBoolList {
-    list: CompactCiphertextList,
+    pub(in crate::high_level_api) list: CompactCiphertextList,
 }
 
 impl CompactFheBoolList {
@@ -151,11 +160,9 @@ impl<'a> FheTryEncrypt<&'a [bool], CompactPublicKey> for CompactFheBoolList {
     ///
     /// See [CompactFheBoolList] example.
     fn try_encrypt(values: &'a [bool], key: &CompactPublicKey) -> Result<Self, Self::Error> {
-        let mut ciphertext = key
-            .key
-            .key
-            .encrypt_iter_radix_compact(values.iter().copied().map(|v| v as u8), 1);
-        ciphertext.ct_list.degree = Degree::new(1);
+        let ciphertext = CompactCiphertextList::builder(&key.key.key)
+            .extend(values.iter().copied())
+            .build();
         Ok(Self { list: ciphertext })
     }
 }
@@ -209,6 +216,6 @@ impl ParameterSetConformant for CompactFheBoolList {
             num_blocks_per_integer: 1,
             num_integers_constraint: params.len_constraint,
         };
-        self.list.is_conformant(&params)
+        self.list.holds_only(DataKind::Boolean) && self.list.is_conformant(&params)
     }
 }
@@ -1,15 +1,21 @@
 use crate::high_level_api::details::MaybeCloned;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::{self, with_thread_local_cuda_stream};
+use crate::high_level_api::lazy::{LazyNode, LazyOp, LazyOperand};
 use crate::integer::BooleanBlock;
 use crate::Device;
 use serde::{Deserializer, Serializer};
+use std::sync::Arc;
 
 /// Enum that manages the current inner representation of a boolean.
 pub(in crate::high_level_api) enum InnerBoolean {
     Cpu(BooleanBlock),
     #[cfg(feature = "gpu")]
     Cuda(crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock),
+    /// Result of operations recorded under
+    /// [with_lazy_evaluation](crate::high_level_api::with_lazy_evaluation), computed on the CPU
+    /// when needed
+    Lazy(Arc<LazyNode>),
 }
 
 impl Clone for InnerBoolean {
@@ -20,6 +26,7 @@ impl Clone for InnerBoolean {
             Self::Cuda(inner) => {
                 with_thread_local_cuda_stream(|stream| Self::Cuda(inner.duplicate(stream)))
             }
+            Self::Lazy(node) => Self::Lazy(node.clone()),
         }
     }
 }
@@ -32,6 +39,7 @@ impl serde::Serialize for InnerBoolean {
             Self::Cpu(cpu_ct) => cpu_ct.serialize(serializer),
             #[cfg(feature = "gpu")]
             Self::Cuda(_) => self.on_cpu().serialize(serializer),
+            Self::Lazy(_) => self.on_cpu().serialize(serializer),
         }
     }
 }
@@ -63,7 +71,7 @@ impl From<crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock> for
 impl InnerBoolean {
     pub(crate) fn current_device(&self) -> Device {
         match self {
-            Self::Cpu(_) => Device::Cpu,
+            Self::Cpu(_) | Self::Lazy(_) => Device::Cpu,
             #[cfg(feature = "gpu")]
             Self::Cuda(_) => Device::CudaGpu,
         }
@@ -74,6 +82,7 @@ impl InnerBoolean {
     pub(crate) fn on_cpu(&self) -> MaybeCloned<'_, BooleanBlock> {
         match self {
             Self::Cpu(ct) => MaybeCloned::Borrowed(ct),
+            Self::Lazy(node) => MaybeCloned::Borrowed(node.evaluate().as_boolean()),
             #[cfg(feature = "gpu")]
             Self::Cuda(ct) => with_thread_local_cuda_stream(|stream| {
                 MaybeCloned::Cloned(ct.to_boolean_block(stream))
@@ -99,17 +108,37 @@ impl InnerBoolean {
             }),
             #[cfg(feature = "gpu")]
             Self::Cuda(ct) => MaybeCloned::Borrowed(ct.as_ref()),
+            Self::Lazy(node) => with_thread_local_cuda_stream(|stream| {
+                let ct_as_radix = crate::integer::RadixCiphertext::from(vec![node
+                    .evaluate()
+                    .as_boolean()
+                    .0
+                    .clone()]);
+                let cuda_ct =
+                    crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                        &ct_as_radix,
+                        stream,
+                    );
+                MaybeCloned::Cloned(cuda_ct)
+            }),
         }
     }
 
-    pub(crate) fn as_cpu_mut(&mut self) -> &mut BooleanBlock {
+    pub(crate) fn into_cpu(self) -> BooleanBlock {
         match self {
-            Self::Cpu(block) => block,
+            Self::Cpu(cpu_ct) => cpu_ct,
             #[cfg(feature = "gpu")]
-            _ => {
-                self.move_to_device(Device::Cpu);
-                self.as_cpu_mut()
-            }
+            Self::Cuda(ct) => with_thread_local_cuda_stream(|stream| ct.to_boolean_block(stream)),
+            Self::Lazy(node) => node.evaluate().as_boolean().clone(),
+        }
+    }
+
+    pub(crate) fn as_cpu_mut(&mut self) -> &mut BooleanBlock {
+        if let Self::Cpu(block) = self {
+            block
+        } else {
+            self.move_to_device(Device::Cpu);
+            self.as_cpu_mut()
         }
     }
 
@@ -137,6 +166,12 @@ impl InnerBoolean {
                 )
             }),
             Self::Cuda(ct) => ct,
+            Self::Lazy(node) => with_thread_local_cuda_stream(|stream| {
+                crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock::from_boolean_block(
+                    node.evaluate().as_boolean(),
+                    stream,
+                )
+            }),
         }
     }
 
@@ -164,6 +199,32 @@ impl InnerBoolean {
                 let new_inner = with_thread_local_cuda_stream(|stream| ct.to_boolean_block(stream));
                 *self = Self::Cpu(new_inner);
             }
+            (Self::Lazy(node), Device::Cpu) => {
+                let new_inner = node.evaluate().as_boolean().clone();
+                *self = Self::Cpu(new_inner);
+            }
+            #[cfg(feature = "gpu")]
+            (Self::Lazy(node), Device::CudaGpu) => {
+                let new_inner = with_thread_local_cuda_stream(|stream| {
+                    crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock::from_boolean_block(
+                        node.evaluate().as_boolean(),
+                        stream,
+                    )
+                });
+                *self = Self::Cuda(new_inner);
+            }
+        }
+    }
+
+    /// Records `op` on the operands, to be computed when the result is needed
+    pub(crate) fn lazy(op: LazyOp, operands: Vec<LazyOperand>) -> Self {
+        Self::Lazy(Arc::new(LazyNode::new(op, operands)))
+    }
+
+    /// Computes the boolean if it is the result of lazy operations
+    pub(crate) fn materialize(&mut self) {
+        if matches!(self, Self::Lazy(_)) {
+            self.move_to_device(Device::Cpu);
         }
     }
 
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// encrypted.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactFheBool {
-    inner: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) inner: ProvenCompactCiphertextList,
 }
 
 impl Named for ProvenCompactFheBool {
@@ -70,7 +70,7 @@ impl ProvenCompactFheBool {
 /// encrypted.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactFheBoolList {
-    inner: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) inner: ProvenCompactCiphertextList,
 }
 
 impl Named for ProvenCompactFheBoolList {
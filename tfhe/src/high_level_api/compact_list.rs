@@ -0,0 +1,545 @@
+//! This module defines a compact list that can store values of heterogeneous types.
+//!
+//! - [CompactCiphertextListBuilder] is used on the client side to push clear values and encrypt
+//!   them all at once using a [CompactPublicKey].
+//! - [CompactCiphertextList] is what gets sent to the server.
+//! - [CompactCiphertextListExpander] is what the server gets after expanding the list, each value
+//!   can then be retrieved with its FHE type.
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::ciphertext::{Compactable, DataKind, Expandable};
+use crate::named::Named;
+use crate::shortint::Ciphertext;
+#[cfg(feature = "zk-pok-experimental")]
+use crate::zk::{CompactPkePublicParams, ZkComputeLoad, ZkVerificationOutCome};
+use crate::{CompactPublicKey, FheBool, FheInt, FheUint};
+
+fn num_bits_to_num_blocks(
+    num_bits: usize,
+    message_modulus: crate::shortint::MessageModulus,
+) -> crate::Result<usize> {
+    let bits_per_block = message_modulus.0.ilog2() as usize;
+    if num_bits == 0 || num_bits % bits_per_block != 0 {
+        return Err(crate::Error::new(format!(
+            "The number of bits ({num_bits}) must be a non-zero multiple of \
+            the number of bits per block ({bits_per_block})"
+        )));
+    }
+    Ok(num_bits / bits_per_block)
+}
+
+/// Builder for a [CompactCiphertextList]
+///
+/// The FHE type a value will be expanded into is deduced from the clear type pushed:
+///
+/// - `bool` gives a [FheBool]
+/// - `u8`, `u16`, ..., `U256` give the [FheUint] with the same number of bits
+/// - `i8`, `i16`, ..., `I256` give the [FheInt] with the same number of bits
+///
+/// [push_with_num_bits](Self::push_with_num_bits) allows to choose the number of bits,
+/// e.g. to encrypt an `U256` as a [FheUint160](crate::FheUint160).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::U256;
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     generate_keys, set_server_key, CompactCiphertextList, CompactPublicKey, ConfigBuilder,
+///     FheBool, FheInt8, FheUint160, FheUint64,
+/// };
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// let public_key = CompactPublicKey::new(&client_key);
+///
+/// let compact_list = CompactCiphertextList::builder(&public_key)
+///     .push(u64::MAX)
+///     .push(true)
+///     .push(-5i8)
+///     .push_with_num_bits(U256::from(17u32), 160)
+///     .unwrap()
+///     .build();
+/// assert_eq!(compact_list.len(), 4);
+///
+/// set_server_key(server_key);
+/// let expander = compact_list.expand().unwrap();
+///
+/// let a: FheUint64 = expander.get(0).unwrap().unwrap();
+/// let b: FheBool = expander.get(1).unwrap().unwrap();
+/// let c: FheInt8 = expander.get(2).unwrap().unwrap();
+/// let d: FheUint160 = expander.get(3).unwrap().unwrap();
+///
+/// // Requesting the wrong type results in an error
+/// assert!(expander.get::<FheBool>(0).unwrap().is_err());
+/// // Out of bound accesses return None
+/// assert!(expander.get::<FheBool>(4).is_none());
+///
+/// let a: u64 = a.decrypt(&client_key);
+/// assert_eq!(a, u64::MAX);
+/// let b: bool = b.decrypt(&client_key);
+/// assert!(b);
+/// let c: i8 = c.decrypt(&client_key);
+/// assert_eq!(c, -5);
+/// let d: U256 = d.decrypt(&client_key);
+/// assert_eq!(d, U256::from(17u32));
+/// ```
+pub struct CompactCiphertextListBuilder<'a> {
+    inner: crate::integer::ciphertext::CompactCiphertextListBuilder<'a>,
+    pk: &'a CompactPublicKey,
+}
+
+impl<'a> CompactCiphertextListBuilder<'a> {
+    pub fn new(pk: &'a CompactPublicKey) -> Self {
+        Self {
+            inner: crate::integer::ciphertext::CompactCiphertextListBuilder::new(&pk.key.key),
+            pk,
+        }
+    }
+
+    /// Pushes a value, its FHE type is deduced from its clear type
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Compactable,
+    {
+        self.inner.push(value);
+        self
+    }
+
+    /// Pushes a value that will be expanded into a FHE integer of `num_bits` bits
+    ///
+    /// Returns an error if `num_bits` is not compatible with the parameters of the key,
+    /// or if the value does not fit in `num_bits` bits.
+    pub fn push_with_num_bits<T>(&mut self, value: T, num_bits: usize) -> crate::Result<&mut Self>
+    where
+        T: Compactable,
+    {
+        let num_blocks = num_bits_to_num_blocks(num_bits, self.pk.message_modulus())?;
+        self.inner.push_with_num_blocks(value, num_blocks)?;
+        Ok(self)
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compactable,
+    {
+        self.inner.extend(values);
+        self
+    }
+
+    pub fn extend_with_num_bits<T>(
+        &mut self,
+        values: impl Iterator<Item = T>,
+        num_bits: usize,
+    ) -> crate::Result<&mut Self>
+    where
+        T: Compactable,
+    {
+        let num_blocks = num_bits_to_num_blocks(num_bits, self.pk.message_modulus())?;
+        self.inner.extend_with_num_blocks(values, num_blocks)?;
+        Ok(self)
+    }
+
+    /// Returns the number of values pushed so far
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Encrypts all the pushed values into a single [CompactCiphertextList]
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value was pushed
+    pub fn build(&self) -> CompactCiphertextList {
+        CompactCiphertextList(self.inner.build())
+    }
+
+    /// Encrypts all the pushed values into a single [ProvenCompactCiphertextList],
+    /// with one zero-knowledge proof for the whole list.
+    #[cfg(feature = "zk-pok-experimental")]
+    pub fn build_with_proof(
+        &self,
+        public_params: &CompactPkePublicParams,
+        load: ZkComputeLoad,
+    ) -> crate::Result<ProvenCompactCiphertextList> {
+        self.inner
+            .build_with_proof(public_params, load)
+            .map(ProvenCompactCiphertextList)
+    }
+}
+
+/// Compact list of values of heterogeneous FHE types
+///
+/// See [CompactCiphertextListBuilder] for how to create and use it.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct CompactCiphertextList(crate::integer::ciphertext::CompactCiphertextList);
+
+impl Named for CompactCiphertextList {
+    const NAME: &'static str = "high_level_api::CompactCiphertextList";
+}
+
+impl CompactCiphertextList {
+    pub fn builder(pk: &CompactPublicKey) -> CompactCiphertextListBuilder<'_> {
+        CompactCiphertextListBuilder::new(pk)
+    }
+
+    /// Returns the number of values stored in the list
+    pub fn len(&self) -> usize {
+        self.0.ciphertext_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Expands the list, so that each value can be retrieved with its FHE type
+    ///
+    /// Returns an error if the list is malformed.
+    pub fn expand(&self) -> crate::Result<CompactCiphertextListExpander> {
+        self.0
+            .expand_heterogeneous()
+            .map(|inner| CompactCiphertextListExpander { inner })
+    }
+
+    pub fn into_raw_parts(self) -> crate::integer::ciphertext::CompactCiphertextList {
+        self.0
+    }
+
+    pub fn from_raw_parts(list: crate::integer::ciphertext::CompactCiphertextList) -> Self {
+        Self(list)
+    }
+}
+
+/// Compact list of values of heterogeneous FHE types, tied to a Zero-Knowledge proof
+///
+/// The zero-knowledge proof allows to verify that all the values in the list
+/// are correctly encrypted.
+#[cfg(feature = "zk-pok-experimental")]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct ProvenCompactCiphertextList(crate::integer::ProvenCompactCiphertextList);
+
+#[cfg(feature = "zk-pok-experimental")]
+impl Named for ProvenCompactCiphertextList {
+    const NAME: &'static str = "high_level_api::ProvenCompactCiphertextList";
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl ProvenCompactCiphertextList {
+    pub fn builder(pk: &CompactPublicKey) -> CompactCiphertextListBuilder<'_> {
+        CompactCiphertextListBuilder::new(pk)
+    }
+
+    /// Returns the number of values stored in the list
+    pub fn len(&self) -> usize {
+        self.0.ciphertext_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn verify(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &CompactPublicKey,
+    ) -> ZkVerificationOutCome {
+        self.0.verify(public_params, &public_key.key.key)
+    }
+
+    /// Verifies the proof, and if it is valid, expands the list
+    pub fn verify_and_expand(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &CompactPublicKey,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        self.0
+            .verify_and_expand_heterogeneous(public_params, &public_key.key.key)
+            .map(|inner| CompactCiphertextListExpander { inner })
+    }
+}
+
+/// The expanded content of a [CompactCiphertextList]
+pub struct CompactCiphertextListExpander {
+    inner: crate::integer::ciphertext::CompactCiphertextListExpander,
+}
+
+impl CompactCiphertextListExpander {
+    /// Returns the number of values stored
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value at the given index
+    ///
+    /// - Returns `None` if the index is out of bounds
+    /// - Returns `Some(Err(_))` if the value at the index is not of type `T`
+    pub fn get<T>(&self, index: usize) -> Option<crate::Result<T>>
+    where
+        T: Expandable,
+    {
+        self.inner.get(index)
+    }
+}
+
+fn fhe_type_mismatch_error(expected: &str, kind: DataKind) -> crate::Error {
+    crate::Error::new(format!(
+        "Tried to expand a {expected}, but a {kind:?} is stored in this slot"
+    ))
+}
+
+impl<Id: FheUintId> Expandable for FheUint<Id> {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        match kind {
+            DataKind::Unsigned(num_blocks)
+                if blocks
+                    .first()
+                    .is_some_and(|block| num_blocks == Id::num_blocks(block.message_modulus)) =>
+            {
+                let mut ct = Self::new(crate::integer::RadixCiphertext::from(blocks));
+                ct.move_to_device_of_server_key_if_set();
+                Ok(ct)
+            }
+            _ => Err(fhe_type_mismatch_error(
+                &format!("FheUint{}", Id::num_bits()),
+                kind,
+            )),
+        }
+    }
+}
+
+impl<Id: FheIntId> Expandable for FheInt<Id> {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        match kind {
+            DataKind::Signed(num_blocks)
+                if blocks
+                    .first()
+                    .is_some_and(|block| num_blocks == Id::num_blocks(block.message_modulus)) =>
+            {
+                let mut ct = Self::new(crate::integer::SignedRadixCiphertext::from(blocks));
+                ct.ciphertext.move_to_device_of_server_key_if_set();
+                Ok(ct)
+            }
+            _ => Err(fhe_type_mismatch_error(
+                &format!("FheInt{}", Id::num_bits()),
+                kind,
+            )),
+        }
+    }
+}
+
+impl Expandable for FheBool {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        let block = crate::integer::BooleanBlock::from_expanded_blocks(blocks, kind)
+            .map_err(|_| fhe_type_mismatch_error("FheBool", kind))?;
+        let mut ct = Self::new(block);
+        ct.ciphertext.move_to_device_of_server_key_if_set();
+        Ok(ct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::{I256, U256};
+    use crate::prelude::*;
+    use crate::{
+        generate_keys, set_server_key, ConfigBuilder, FheInt16, FheInt256, FheUint16, FheUint160,
+        FheUint2, FheUint8,
+    };
+
+    #[test]
+    fn test_compact_list_heterogeneous_types() {
+        let config = ConfigBuilder::default().build();
+        let (cks, sks) = generate_keys(config);
+        let pk = CompactPublicKey::new(&cks);
+
+        let mut builder = CompactCiphertextList::builder(&pk);
+        builder
+            .push(17u8)
+            .push(false)
+            .push(-1234i16)
+            .push(I256::from(-3i64))
+            .extend([1u8, 2u8].into_iter());
+        builder
+            .push_with_num_bits(U256::from(u128::MAX), 160)
+            .unwrap()
+            .push_with_num_bits(3u8, 2)
+            .unwrap();
+        assert!(builder.push_with_num_bits(3u8, 3).is_err());
+        assert!(builder.push_with_num_bits(3u8, 0).is_err());
+        // Values which do not fit are errors, not silently truncated
+        assert!(builder.push_with_num_bits(4u8, 2).is_err());
+        assert!(builder.push_with_num_bits(2i8, 2).is_err());
+        assert!(builder.push_with_num_bits(-3i8, 2).is_err());
+
+        let compact_list = builder.build();
+        assert_eq!(compact_list.len(), 8);
+
+        let serialized = bincode::serialize(&compact_list).unwrap();
+        let compact_list: CompactCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        set_server_key(sks);
+        let expander = compact_list.expand().unwrap();
+        assert_eq!(expander.len(), 8);
+
+        let a: FheUint8 = expander.get(0).unwrap().unwrap();
+        let b: FheBool = expander.get(1).unwrap().unwrap();
+        let c: FheInt16 = expander.get(2).unwrap().unwrap();
+        let d: FheInt256 = expander.get(3).unwrap().unwrap();
+        let e: FheUint8 = expander.get(4).unwrap().unwrap();
+        let f: FheUint8 = expander.get(5).unwrap().unwrap();
+        let g: FheUint160 = expander.get(6).unwrap().unwrap();
+        let h: FheUint2 = expander.get(7).unwrap().unwrap();
+
+        let a: u8 = a.decrypt(&cks);
+        assert_eq!(a, 17);
+        let b: bool = b.decrypt(&cks);
+        assert!(!b);
+        let c: i16 = c.decrypt(&cks);
+        assert_eq!(c, -1234);
+        let d: I256 = d.decrypt(&cks);
+        assert_eq!(d, I256::from(-3i64));
+        let e: u8 = e.decrypt(&cks);
+        assert_eq!(e, 1);
+        let f: u8 = f.decrypt(&cks);
+        assert_eq!(f, 2);
+        let g: U256 = g.decrypt(&cks);
+        assert_eq!(g, U256::from(u128::MAX));
+        let h: u8 = h.decrypt(&cks);
+        assert_eq!(h, 3);
+
+        // Wrong types are errors, not panics
+        assert!(expander.get::<FheUint16>(0).unwrap().is_err());
+        assert!(expander.get::<FheInt16>(0).unwrap().is_err());
+        assert!(expander.get::<FheUint8>(1).unwrap().is_err());
+        assert!(expander.get::<FheBool>(2).unwrap().is_err());
+        assert!(expander.get::<FheUint8>(8).is_none());
+    }
+
+    #[test]
+    fn test_compact_list_safe_serialization() {
+        let config = ConfigBuilder::default().build();
+        let (cks, sks) = generate_keys(config);
+        let pk = CompactPublicKey::new(&cks);
+
+        let compact_list = CompactCiphertextList::builder(&pk)
+            .push(17u8)
+            .push(true)
+            .push(-1234i16)
+            .build();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&compact_list, &mut serialized, 1 << 20).unwrap();
+        let compact_list: CompactCiphertextList =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert_eq!(compact_list.len(), 3);
+
+        set_server_key(sks);
+        let expander = compact_list.expand().unwrap();
+        let a: FheUint8 = expander.get(0).unwrap().unwrap();
+        let b: FheBool = expander.get(1).unwrap().unwrap();
+        let c: FheInt16 = expander.get(2).unwrap().unwrap();
+
+        let a: u8 = a.decrypt(&cks);
+        assert_eq!(a, 17);
+        let b: bool = b.decrypt(&cks);
+        assert!(b);
+        let c: i16 = c.decrypt(&cks);
+        assert_eq!(c, -1234);
+    }
+
+    #[test]
+    fn test_expand_empty_values() {
+        // A list received from the network may declare values without any block
+        let expander = CompactCiphertextListExpander {
+            inner: crate::integer::ciphertext::CompactCiphertextListExpander::new(
+                vec![],
+                vec![DataKind::Unsigned(0), DataKind::Signed(0)],
+            ),
+        };
+
+        assert!(expander.get::<FheUint8>(0).unwrap().is_err());
+        assert!(expander.get::<FheInt16>(1).unwrap().is_err());
+    }
+
+    #[cfg(feature = "zk-pok-experimental")]
+    #[test]
+    fn test_proven_compact_list_heterogeneous_types() {
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS_TUNIFORM_2M40;
+        use crate::zk::CompactPkeCrs;
+
+        let params = PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS_TUNIFORM_2M40;
+        let config = ConfigBuilder::with_custom_parameters(params, None).build();
+        let crs = CompactPkeCrs::from_config(config, 32).unwrap();
+        let (cks, sks) = generate_keys(config);
+        let pk = CompactPublicKey::new(&cks);
+
+        let proven_list = ProvenCompactCiphertextList::builder(&pk)
+            .push(17u8)
+            .push(true)
+            .push(-3i16)
+            .build_with_proof(crs.public_params(), ZkComputeLoad::Proof)
+            .unwrap();
+        assert_eq!(proven_list.len(), 3);
+        assert!(proven_list.verify(crs.public_params(), &pk).is_valid());
+
+        set_server_key(sks);
+        let expander = proven_list
+            .verify_and_expand(crs.public_params(), &pk)
+            .unwrap();
+
+        let a: FheUint8 = expander.get(0).unwrap().unwrap();
+        let b: FheBool = expander.get(1).unwrap().unwrap();
+        let c: FheInt16 = expander.get(2).unwrap().unwrap();
+
+        let a: u8 = a.decrypt(&cks);
+        assert_eq!(a, 17);
+        let b: bool = b.decrypt(&cks);
+        assert!(b);
+        let c: i16 = c.decrypt(&cks);
+        assert_eq!(c, -3);
+    }
+
+    #[cfg(feature = "zk-pok-experimental")]
+    #[test]
+    fn test_proven_compact_list_safe_serialization() {
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS_TUNIFORM_2M40;
+        use crate::zk::CompactPkeCrs;
+
+        let params = PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS_TUNIFORM_2M40;
+        let config = ConfigBuilder::with_custom_parameters(params, None).build();
+        let crs = CompactPkeCrs::from_config(config, 16).unwrap();
+        let (cks, sks) = generate_keys(config);
+        let pk = CompactPublicKey::new(&cks);
+
+        let proven_list = ProvenCompactCiphertextList::builder(&pk)
+            .push(17u8)
+            .push(false)
+            .build_with_proof(crs.public_params(), ZkComputeLoad::Proof)
+            .unwrap();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&proven_list, &mut serialized, 1 << 20).unwrap();
+        let proven_list: ProvenCompactCiphertextList =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert_eq!(proven_list.len(), 2);
+
+        set_server_key(sks);
+        let expander = proven_list
+            .verify_and_expand(crs.public_params(), &pk)
+            .unwrap();
+        let a: FheUint8 = expander.get(0).unwrap().unwrap();
+        let b: FheBool = expander.get(1).unwrap().unwrap();
+
+        let a: u8 = a.decrypt(&cks);
+        assert_eq!(a, 17);
+        let b: bool = b.decrypt(&cks);
+        assert!(!b);
+    }
+}
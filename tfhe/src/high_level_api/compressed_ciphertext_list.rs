@@ -0,0 +1,310 @@
+//! This module defines a list that stores already computed values of heterogeneous types
+//! in a compressed form.
+//!
+//! - [CompressedCiphertextListBuilder] is used on the server side to push ciphertexts resulting
+//!   from computations, and pack them all at once.
+//! - [CompressedCiphertextList] is what gets stored or sent, each value can then be retrieved with
+//!   its FHE type.
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::ciphertext::{Compressible, DataKind, Expandable};
+use crate::named::Named;
+use crate::shortint::Ciphertext;
+use crate::{FheBool, FheInt, FheUint};
+
+impl<Id: FheUintId> Compressible for FheUint<Id> {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.into_cpu().compress_into(messages)
+    }
+}
+
+impl<Id: FheIntId> Compressible for FheInt<Id> {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.into_cpu().compress_into(messages)
+    }
+}
+
+impl Compressible for FheBool {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.into_cpu().compress_into(messages)
+    }
+}
+
+/// Builder for a [CompressedCiphertextList]
+///
+/// The compression key used is the one of the server key set in the current thread,
+/// compression has to be enabled in the [Config](crate::Config) using
+/// [enable_compression](crate::ConfigBuilder::enable_compression).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::{
+///     generate_keys, set_server_key, CompressedCiphertextList, ConfigBuilder, FheBool, FheInt8,
+///     FheUint32,
+/// };
+///
+/// let config = ConfigBuilder::default()
+///     .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint32::encrypt(1234u32, &client_key);
+/// let b = FheInt8::encrypt(-3i8, &client_key);
+///
+/// let c = &a + 1u32;
+/// let d = -&b;
+/// let e = a.gt(1000u32);
+///
+/// let compressed_list = CompressedCiphertextList::builder()
+///     .push(c)
+///     .push(d)
+///     .push(e)
+///     .build()
+///     .unwrap();
+/// assert_eq!(compressed_list.len(), 3);
+///
+/// let c: FheUint32 = compressed_list.get(0).unwrap().unwrap();
+/// let d: FheInt8 = compressed_list.get(1).unwrap().unwrap();
+/// let e: FheBool = compressed_list.get(2).unwrap().unwrap();
+///
+/// // Requesting the wrong type results in an error
+/// assert!(compressed_list.get::<FheBool>(0).unwrap().is_err());
+/// // Out of bound accesses return None
+/// assert!(compressed_list.get::<FheBool>(3).is_none());
+///
+/// let c: u32 = c.decrypt(&client_key);
+/// assert_eq!(c, 1235);
+/// let d: i8 = d.decrypt(&client_key);
+/// assert_eq!(d, 3);
+/// let e: bool = e.decrypt(&client_key);
+/// assert!(e);
+/// ```
+#[derive(Clone, Default)]
+pub struct CompressedCiphertextListBuilder {
+    inner: crate::integer::ciphertext::CompressedCiphertextListBuilder,
+}
+
+impl CompressedCiphertextListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Compressible,
+    {
+        self.inner.push(value);
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compressible,
+    {
+        self.inner.extend(values);
+        self
+    }
+
+    /// Returns the number of values pushed so far
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Packs all the pushed values into a [CompressedCiphertextList]
+    ///
+    /// Returns an error if the server key set does not have a compression key.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the server key is not set
+    /// - Panics if no value was pushed
+    pub fn build(&self) -> crate::Result<CompressedCiphertextList> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => cpu_key
+                .compression_key
+                .as_ref()
+                .ok_or_else(|| {
+                    crate::Error::new("Compression key not set in server key".to_owned())
+                })
+                .map(|compression_key| CompressedCiphertextList(self.inner.build(compression_key))),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support compression yet")
+            }
+        })
+    }
+}
+
+/// List of already computed values of heterogeneous FHE types, stored in a compressed form
+///
+/// See [CompressedCiphertextListBuilder] for how to create and use it.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct CompressedCiphertextList(crate::integer::ciphertext::CompressedCiphertextList);
+
+impl Named for CompressedCiphertextList {
+    const NAME: &'static str = "high_level_api::CompressedCiphertextList";
+}
+
+impl CompressedCiphertextList {
+    pub fn builder() -> CompressedCiphertextListBuilder {
+        CompressedCiphertextListBuilder::new()
+    }
+
+    /// Returns the number of values stored in the list
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decompresses the value at the given index
+    ///
+    /// - Returns `None` if the index is out of bounds
+    /// - Returns `Some(Err(_))` if the value at the index is not of type `T`, or if the server key
+    ///   set does not have a decompression key
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set
+    pub fn get<T>(&self, index: usize) -> Option<crate::Result<T>>
+    where
+        T: Expandable,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let Some(decompression_key) = cpu_key.decompression_key.as_ref() else {
+                    // Keep the out of bound semantic even without key
+                    if index >= self.len() {
+                        return None;
+                    }
+                    return Some(Err(crate::Error::new(
+                        "Decompression key not set in server key".to_owned(),
+                    )));
+                };
+                self.0.get(index, decompression_key)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support compression yet")
+            }
+        })
+    }
+
+    pub fn into_raw_parts(self) -> crate::integer::ciphertext::CompressedCiphertextList {
+        self.0
+    }
+
+    pub fn from_raw_parts(list: crate::integer::ciphertext::CompressedCiphertextList) -> Self {
+        Self(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::{generate_keys, set_server_key, ConfigBuilder, FheInt64, FheUint16, FheUint2};
+
+    #[test]
+    fn test_compressed_ciphertext_list_heterogeneous_types() {
+        let config = ConfigBuilder::default()
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+            .build();
+        let (cks, sks) = generate_keys(config);
+        set_server_key(sks);
+
+        let a = FheUint16::encrypt(65535u16, &cks);
+        let b = FheInt64::encrypt(i64::MIN, &cks);
+        let c = FheUint2::encrypt(2u8, &cks);
+
+        let a_plus_one = &a + 1u16;
+        let b_is_min = b.eq(i64::MIN);
+        let c_squared = &c * &c;
+
+        let mut builder = CompressedCiphertextListBuilder::new();
+        builder.push(a_plus_one).push(b).push(b_is_min);
+        builder.extend([c, c_squared].into_iter());
+        assert_eq!(builder.len(), 5);
+
+        let compressed_list = builder.build().unwrap();
+        assert_eq!(compressed_list.len(), 5);
+
+        let serialized = bincode::serialize(&compressed_list).unwrap();
+        let compressed_list: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        let a: FheUint16 = compressed_list.get(0).unwrap().unwrap();
+        let b: FheInt64 = compressed_list.get(1).unwrap().unwrap();
+        let b_is_min: FheBool = compressed_list.get(2).unwrap().unwrap();
+        let c: FheUint2 = compressed_list.get(3).unwrap().unwrap();
+        let c_squared: FheUint2 = compressed_list.get(4).unwrap().unwrap();
+
+        let a: u16 = a.decrypt(&cks);
+        assert_eq!(a, 0);
+        let b: i64 = b.decrypt(&cks);
+        assert_eq!(b, i64::MIN);
+        let b_is_min: bool = b_is_min.decrypt(&cks);
+        assert!(b_is_min);
+        let c: u8 = c.decrypt(&cks);
+        assert_eq!(c, 2);
+        let c_squared: u8 = c_squared.decrypt(&cks);
+        assert_eq!(c_squared, 0);
+
+        assert!(compressed_list.get::<FheUint2>(0).unwrap().is_err());
+        assert!(compressed_list.get::<FheInt64>(0).unwrap().is_err());
+        assert!(compressed_list.get::<FheUint16>(5).is_none());
+    }
+
+    #[test]
+    fn test_compressed_ciphertext_list_safe_serialization() {
+        let config = ConfigBuilder::default()
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+            .build();
+        let (cks, sks) = generate_keys(config);
+        set_server_key(sks);
+
+        let a = FheUint16::encrypt(1234u16, &cks);
+        let b = FheInt64::encrypt(-5i64, &cks);
+
+        let compressed_list = CompressedCiphertextList::builder()
+            .push(a)
+            .push(b)
+            .build()
+            .unwrap();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&compressed_list, &mut serialized, 1 << 20).unwrap();
+        let compressed_list: CompressedCiphertextList =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert_eq!(compressed_list.len(), 2);
+
+        let a: FheUint16 = compressed_list.get(0).unwrap().unwrap();
+        let b: FheInt64 = compressed_list.get(1).unwrap().unwrap();
+
+        let a: u16 = a.decrypt(&cks);
+        assert_eq!(a, 1234);
+        let b: i64 = b.decrypt(&cks);
+        assert_eq!(b, -5);
+    }
+
+    #[test]
+    fn test_compressed_ciphertext_list_without_compression_key() {
+        let (cks, sks) = generate_keys(ConfigBuilder::default());
+        set_server_key(sks);
+
+        let a = FheUint2::encrypt(1u8, &cks);
+
+        assert!(CompressedCiphertextList::builder().push(a).build().is_err());
+    }
+}
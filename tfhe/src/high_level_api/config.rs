@@ -28,6 +28,20 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enables the generation of the keys needed to compress ciphertexts into a
+    /// [crate::CompressedCiphertextList]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the block parameters are not KS_PBS parameters
+    pub fn enable_compression(
+        mut self,
+        compression_parameters: crate::shortint::parameters::list_compression::CompressionParameters,
+    ) -> Self {
+        self.config.inner.enable_compression(compression_parameters);
+        self
+    }
+
     /// Use default parameters with big encryption
     ///
     /// For more information see [crate::core_crypto::prelude::PBSOrder::KeyswitchBootstrap]
@@ -0,0 +1,494 @@
+//! Asynchronous execution of FHE computations.
+//!
+//! FHE operations take tens to hundreds of milliseconds, running them directly in an async
+//! runtime would block its threads. The [FheExecutor] runs them in its own thread pool instead,
+//! and returns futures which resolve once the computations are done.
+//!
+//! The futures do not depend on any particular async runtime.
+//!
+//! The tasks of an executor run one after the other, each one using all the threads of the
+//! pool. The threads check the cancellation of the running task before each PBS, so a cancelled
+//! task stops at its next PBS, even in the middle of an operation.
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::shortint::server_key::cancellation::{
+    set_pbs_cancellation, PbsCancellation, PbsCancelled,
+};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A task waiting to be run by the executor thread
+type Job = Box<dyn FnOnce(&ThreadPool, &PbsCancellation) + Send>;
+
+/// Runs FHE computations in a dedicated thread pool, in which every thread uses the server key
+/// given at construction.
+///
+/// Computations are submitted with [FheExecutor::spawn], which returns a [FheTask] future.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, ConfigBuilder, FheExecutor, FheUint8};
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// let executor = FheExecutor::new(server_key).unwrap();
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+///
+/// let task = executor.spawn(move |_| {
+///     let c = &a + &b;
+///     Ok(c * 2u8)
+/// });
+///
+/// // In an async function, this would be `task.await`
+/// let result = task.wait().unwrap();
+/// let decrypted: u8 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 254);
+/// ```
+pub struct FheExecutor {
+    jobs: mpsc::Sender<Job>,
+    num_threads: usize,
+}
+
+impl FheExecutor {
+    /// Creates an executor with as many threads as there are CPUs
+    pub fn new<T: Into<InternalServerKey>>(keys: T) -> crate::Result<Self> {
+        Self::with_num_threads(keys, 0)
+    }
+
+    /// Creates an executor with `num_threads` threads, 0 meaning as many threads as there are
+    /// CPUs
+    pub fn with_num_threads<T: Into<InternalServerKey>>(
+        keys: T,
+        num_threads: usize,
+    ) -> crate::Result<Self> {
+        let keys = keys.into();
+        let cancellation = Arc::new(PbsCancellation::default());
+        let thread_cancellation = Arc::clone(&cancellation);
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .start_handler(move |_| {
+                global_state::set_server_key(keys.clone());
+                set_pbs_cancellation(Some(Arc::clone(&thread_cancellation)));
+            })
+            .exit_handler(|_| {
+                global_state::unset_server_key();
+                set_pbs_cancellation(None);
+            })
+            .build()
+            .map_err(|err| crate::Error::new(format!("Failed to build the thread pool: {err}")))?;
+        let num_threads = pool.current_num_threads();
+
+        // The tasks are run one at a time, so that all the PBS run by the pool belong to the
+        // task whose cancellation flag is installed
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        std::thread::Builder::new()
+            .name("fhe-executor".to_string())
+            .spawn(move || {
+                for job in receiver {
+                    job(&pool, &cancellation);
+                }
+            })
+            .map_err(|err| {
+                crate::Error::new(format!("Failed to start the executor thread: {err}"))
+            })?;
+
+        Ok(Self { jobs, num_threads })
+    }
+
+    /// The number of threads of the executor
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Runs the function in the thread pool of the executor, returning a future resolving to
+    /// its result
+    ///
+    /// The function may use rayon to parallelize its work, the server key is available in all
+    /// the threads of the pool. The functions run one after the other, in the order they were
+    /// spawned.
+    ///
+    /// Cancelling the task, with [FheTask::cancel] or by dropping it, prevents the function
+    /// from starting if it has not yet, and otherwise interrupts it at its next PBS. The
+    /// ciphertexts the function was computing are then left in an unspecified state.
+    ///
+    /// If the function panics, the panic is resumed when the future is polled.
+    pub fn spawn<R, F>(&self, func: F) -> FheTask<R>
+    where
+        F: FnOnce(&TaskContext) -> crate::Result<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let shared = Arc::new(SharedTask {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            state: Mutex::new(TaskState {
+                outcome: None,
+                waker: None,
+            }),
+            done: Condvar::new(),
+        });
+
+        let task_shared = Arc::clone(&shared);
+        let job = move |pool: &ThreadPool, cancellation: &PbsCancellation| {
+            let context = TaskContext {
+                cancelled: Arc::clone(&task_shared.cancelled),
+            };
+            cancellation.set_flag(Some(Arc::clone(&task_shared.cancelled)));
+            // Panics are caught to be resumed in the thread awaiting the task, which also
+            // catches the unwinding of a cancelled PBS
+            let outcome = pool.install(|| {
+                std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    context.check_cancelled().and_then(|()| func(&context))
+                }))
+            });
+            cancellation.set_flag(None);
+            let outcome = match outcome {
+                Err(payload) if payload.is::<PbsCancelled>() => Ok(Err(cancelled_error())),
+                outcome => outcome,
+            };
+
+            let mut state = task_shared.state.lock().unwrap();
+            state.outcome = Some(outcome);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            task_shared.done.notify_all();
+        };
+        self.jobs
+            .send(Box::new(job))
+            .expect("The executor thread has stopped");
+
+        FheTask { shared }
+    }
+}
+
+fn cancelled_error() -> crate::Error {
+    crate::Error::new("The task was cancelled".to_string())
+}
+
+/// Gives a task spawned on a [FheExecutor] access to its cancellation status
+pub struct TaskContext {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TaskContext {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns an error if the task was cancelled
+    ///
+    /// The PBS of a cancelled task are interrupted, this allows to also stop between
+    /// computations which do not run any.
+    pub fn check_cancelled(&self) -> crate::Result<()> {
+        if self.is_cancelled() {
+            Err(cancelled_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Applies `func` to the items, in parallel batches of `batch_size` items, checking the
+    /// cancellation of the task before each batch
+    ///
+    /// The results are returned in the order of the items. If the task is cancelled, the batch
+    /// which is running is interrupted at its next PBS, and the next one never starts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is 0.
+    pub fn map_batches<T, R, I, F>(
+        &self,
+        items: I,
+        batch_size: usize,
+        func: F,
+    ) -> crate::Result<Vec<R>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Sync + Send,
+    {
+        assert!(batch_size > 0, "The batch size must be greater than 0");
+
+        let mut items = items.into_iter().peekable();
+        let mut results = Vec::new();
+        while items.peek().is_some() {
+            self.check_cancelled()?;
+            let batch = items.by_ref().take(batch_size).collect::<Vec<_>>();
+            results.par_extend(batch.into_par_iter().map(&func));
+        }
+        Ok(results)
+    }
+}
+
+type TaskOutcome<R> = std::thread::Result<crate::Result<R>>;
+
+struct TaskState<R> {
+    outcome: Option<TaskOutcome<R>>,
+    waker: Option<Waker>,
+}
+
+struct SharedTask<R> {
+    cancelled: Arc<AtomicBool>,
+    state: Mutex<TaskState<R>>,
+    done: Condvar,
+}
+
+/// A future resolving to the result of a function spawned on a [FheExecutor]
+///
+/// Dropping the future cancels the task.
+pub struct FheTask<R> {
+    shared: Arc<SharedTask<R>>,
+}
+
+impl<R> FheTask<R> {
+    /// Requests the cancellation of the task
+    ///
+    /// A task cancelled before it started, or interrupted at one of its PBS, resolves to an
+    /// error. A task cancelled after its last PBS resolves to what its function returns.
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Release);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.shared.state.lock().unwrap().outcome.is_some()
+    }
+
+    /// Blocks the current thread until the task is done, returning its result
+    ///
+    /// This is meant for synchronous code, async code should `.await` the task instead.
+    pub fn wait(self) -> crate::Result<R> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            if let Some(outcome) = state.outcome.take() {
+                return unwrap_outcome(outcome);
+            }
+            state = self.shared.done.wait(state).unwrap();
+        }
+    }
+}
+
+fn unwrap_outcome<R>(outcome: TaskOutcome<R>) -> crate::Result<R> {
+    match outcome {
+        Ok(result) => result,
+        Err(panic_payload) => std::panic::resume_unwind(panic_payload),
+    }
+}
+
+impl<R> Future for FheTask<R> {
+    type Output = crate::Result<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.state.lock().unwrap();
+        state.outcome.take().map_or_else(
+            || {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            },
+            |outcome| Poll::Ready(unwrap_outcome(outcome)),
+        )
+    }
+}
+
+impl<R> Drop for FheTask<R> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{generate_keys, ConfigBuilder, FheUint64, FheUint8};
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::mpsc;
+    use std::task::Wake;
+    use std::thread::Thread;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Minimal executor, polling the future in the current thread
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_executor_futures() {
+        let config = ConfigBuilder::default().build();
+        let (cks, sks) = generate_keys(config);
+
+        let executor = FheExecutor::with_num_threads(sks, 2).unwrap();
+        assert_eq!(executor.num_threads(), 2);
+
+        let clears = [(1u8, 2u8), (17, 42), (200, 100)];
+        let tasks = clears
+            .iter()
+            .map(|(a, b)| {
+                let a = FheUint8::encrypt(*a, &cks);
+                let b = FheUint8::encrypt(*b, &cks);
+                executor.spawn(move |_| Ok(a + b))
+            })
+            .collect::<Vec<_>>();
+
+        let results = block_on(async {
+            let mut results = Vec::new();
+            for task in tasks {
+                results.push(task.await.unwrap());
+            }
+            results
+        });
+
+        for ((a, b), result) in clears.iter().zip(results.iter()) {
+            let decrypted: u8 = result.decrypt(&cks);
+            assert_eq!(decrypted, a.wrapping_add(*b));
+        }
+    }
+
+    #[test]
+    fn test_executor_cancellation() {
+        let config = ConfigBuilder::default().build();
+        let (cks, sks) = generate_keys(config);
+
+        let executor = FheExecutor::with_num_threads(sks, 1).unwrap();
+
+        let a = FheUint8::encrypt(3u8, &cks);
+        let b = FheUint8::encrypt(5u8, &cks);
+
+        let (started_sender, started_receiver) = mpsc::channel();
+        let (resume_sender, resume_receiver) = mpsc::channel::<()>();
+        let task = executor.spawn(move |context| {
+            let c = &a + &b;
+            started_sender.send(()).unwrap();
+            resume_receiver.recv().unwrap();
+            context.check_cancelled()?;
+            Ok(c * &a)
+        });
+
+        // Cancelled after its first operation, the task stops at its next check
+        started_receiver.recv().unwrap();
+        task.cancel();
+        resume_sender.send(()).unwrap();
+        assert!(block_on(task).is_err());
+
+        // Cancelled before it starts, the task never runs
+        let (resume_sender, resume_receiver) = mpsc::channel::<()>();
+        let blocking_task = executor.spawn(move |_| {
+            resume_receiver.recv().unwrap();
+            Ok(())
+        });
+        let never_run = Arc::new(AtomicBool::new(true));
+        let never_run_clone = Arc::clone(&never_run);
+        let task = executor.spawn(move |_| {
+            never_run_clone.store(false, Ordering::Release);
+            Ok(())
+        });
+        task.cancel();
+        resume_sender.send(()).unwrap();
+        block_on(blocking_task).unwrap();
+        assert!(block_on(task).is_err());
+        assert!(never_run.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn test_executor_cancellation_between_batches() {
+        let config = ConfigBuilder::default().build();
+        let (cks, sks) = generate_keys(config);
+
+        let executor = FheExecutor::with_num_threads(sks, 2).unwrap();
+
+        let clears = [1u8, 2, 3, 4, 5, 6];
+        let encrypted = clears
+            .iter()
+            .map(|clear| FheUint8::encrypt(*clear, &cks))
+            .collect::<Vec<_>>();
+
+        // Without cancellation, all the batches run
+        let task = executor.spawn(move |context| context.map_batches(encrypted, 4, |ct| ct * 3u8));
+        let results = block_on(task).unwrap();
+        assert_eq!(results.len(), clears.len());
+        for (clear, result) in clears.iter().zip(results.iter()) {
+            let decrypted: u8 = result.decrypt(&cks);
+            assert_eq!(decrypted, clear.wrapping_mul(3));
+        }
+
+        // Cancelled during the first batch, the task does not start the second one
+        let encrypted = clears
+            .iter()
+            .map(|clear| FheUint8::encrypt(*clear, &cks))
+            .collect::<Vec<_>>();
+        let (started_sender, started_receiver) = mpsc::channel();
+        let (resume_sender, resume_receiver) = mpsc::channel::<()>();
+        let started_sender = Mutex::new(started_sender);
+        let resume_receiver = Mutex::new(resume_receiver);
+        let num_processed = Arc::new(AtomicUsize::new(0));
+        let num_processed_clone = Arc::clone(&num_processed);
+        let task = executor.spawn(move |context| {
+            context.map_batches(encrypted, 1, |ct| {
+                let result = ct + 1u8;
+                num_processed_clone.fetch_add(1, Ordering::AcqRel);
+                started_sender.lock().unwrap().send(()).unwrap();
+                resume_receiver.lock().unwrap().recv().unwrap();
+                result
+            })
+        });
+
+        started_receiver.recv().unwrap();
+        task.cancel();
+        resume_sender.send(()).unwrap();
+        assert!(block_on(task).is_err());
+        assert_eq!(num_processed.load(Ordering::Acquire), 1);
+    }
+
+    #[test]
+    fn test_executor_cancellation_interrupts_operations() {
+        let config = ConfigBuilder::default().build();
+        let (cks, sks) = generate_keys(config);
+
+        let executor = FheExecutor::with_num_threads(sks, 2).unwrap();
+
+        let a = FheUint64::encrypt(u64::MAX, &cks);
+        let b = FheUint64::encrypt(3u64, &cks);
+
+        // The function never checks the cancellation itself, the division is interrupted at
+        // one of its PBS
+        let (started_sender, started_receiver) = mpsc::channel();
+        let task = executor.spawn(move |_| {
+            started_sender.send(()).unwrap();
+            Ok(&a / &b)
+        });
+        started_receiver.recv().unwrap();
+        task.cancel();
+        assert!(block_on(task).is_err());
+
+        // The threads of the executor keep working after the interruption
+        let a = FheUint8::encrypt(7u8, &cks);
+        let task = executor.spawn(move |_| Ok(&a * 3u8));
+        let decrypted: u8 = block_on(task).unwrap().decrypt(&cks);
+        assert_eq!(decrypted, 21);
+    }
+}
@@ -0,0 +1,721 @@
+//! This module defines [FheFixed], an encrypted fixed-point number.
+//!
+//! A `FheFixed<T, FRAC_BITS>` stores the integer `x * 2^FRAC_BITS` in the encrypted integer
+//! type `T` (a [FheUint] or a [FheInt]), so the operations which change the scale
+//! (multiplication, division, casts between precisions) rescale their result and round it
+//! according to a [RoundingMode].
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::{
+    BooleanBlock, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, ServerKey,
+    SignedRadixCiphertext,
+};
+use crate::named::{NameBuilder, Named};
+use crate::prelude::{FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTryEncrypt, IfThenElse};
+use crate::shortint::MessageModulus;
+use crate::{ClientKey, FheBool, FheInt, FheUint};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Trait implemented by the integer types a [FheFixed] can be built on
+pub trait FheFixedInteger: Sized {
+    /// The CPU integer ciphertext the integer is made of
+    type Ciphertext: IntegerRadixCiphertext + Serialize + DeserializeOwned;
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize;
+
+    fn into_cpu_ciphertext(self) -> Self::Ciphertext;
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self;
+
+    /// Encrypts `value`, which must be an integer
+    ///
+    /// Returns an error if the value does not fit in the type, or in 128 bits.
+    fn encrypt_integer(value: f64, key: &ClientKey) -> crate::Result<Self>;
+
+    /// Decrypts the integer, which must fit in 128 bits
+    fn decrypt_integer(&self, key: &ClientKey) -> f64;
+}
+
+impl<Id: FheUintId> FheFixedInteger for FheUint<Id> {
+    type Ciphertext = RadixCiphertext;
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        Id::num_blocks(message_modulus)
+    }
+
+    fn into_cpu_ciphertext(self) -> Self::Ciphertext {
+        self.ciphertext.into_cpu()
+    }
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self {
+        Self::new(ciphertext)
+    }
+
+    fn encrypt_integer(value: f64, key: &ClientKey) -> crate::Result<Self> {
+        let num_bits = num_bits::<Self>(key.message_modulus()).min(128);
+        if !(0.0..2f64.powi(num_bits as i32)).contains(&value) {
+            return Err(crate::Error::new(format!(
+                "The value {value} does not fit in an unsigned integer of {num_bits} bits"
+            )));
+        }
+        Self::try_encrypt(value as u128, key)
+    }
+
+    fn decrypt_integer(&self, key: &ClientKey) -> f64 {
+        let value: u128 = self.decrypt(key);
+        value as f64
+    }
+}
+
+impl<Id: FheIntId> FheFixedInteger for FheInt<Id> {
+    type Ciphertext = SignedRadixCiphertext;
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        Id::num_blocks(message_modulus)
+    }
+
+    fn into_cpu_ciphertext(self) -> Self::Ciphertext {
+        self.ciphertext.into_cpu()
+    }
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self {
+        Self::new(ciphertext)
+    }
+
+    fn encrypt_integer(value: f64, key: &ClientKey) -> crate::Result<Self> {
+        let num_bits = num_bits::<Self>(key.message_modulus()).min(128);
+        let bound = 2f64.powi(num_bits as i32 - 1);
+        if !(-bound..bound).contains(&value) {
+            return Err(crate::Error::new(format!(
+                "The value {value} does not fit in a signed integer of {num_bits} bits"
+            )));
+        }
+        Self::try_encrypt(value as i128, key)
+    }
+
+    fn decrypt_integer(&self, key: &ClientKey) -> f64 {
+        let value: i128 = self.decrypt(key);
+        value as f64
+    }
+}
+
+fn num_bits<T: FheFixedInteger>(message_modulus: MessageModulus) -> u32 {
+    T::num_blocks(message_modulus) as u32 * message_modulus.0.ilog2()
+}
+
+/// How the result of an operation is rounded when it cannot be represented exactly
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds towards 0, i.e. truncates the result
+    TowardZero,
+    /// Rounds towards negative infinity
+    Floor,
+    /// Rounds towards positive infinity
+    Ceil,
+    /// Rounds to the nearest representable value, ties are rounded away from 0
+    #[default]
+    Nearest,
+}
+
+/// An encrypted fixed-point number
+///
+/// The number is stored as the integer `x * 2^FRAC_BITS` encrypted in `T`, which can be any
+/// [FheUint] (for unsigned numbers) or [FheInt] (for signed numbers). Additions and
+/// subtractions are exact (and wrap around like the underlying integers), multiplications,
+/// divisions and casts to a lower precision round their result, the operators use
+/// [RoundingMode::Nearest].
+///
+/// The operations use the server key set in the current thread and run on the CPU.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixed, FheInt32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let price = FheFixed::<FheInt32, 8>::try_encrypt(12.5f64, &client_key).unwrap();
+/// let discount = FheFixed::<FheInt32, 8>::try_encrypt(-0.75f64, &client_key).unwrap();
+///
+/// let result = &price * &discount;
+/// let decrypted: f64 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, -9.375);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FheFixed<T: FheFixedInteger, const FRAC_BITS: u32> {
+    ciphertext: T::Ciphertext,
+    // Only the ciphertext is held, so the number is Send and Sync whatever T is
+    _integer: PhantomData<fn() -> T>,
+}
+
+impl<T: FheFixedInteger + Named, const FRAC_BITS: u32> FheFixed<T, FRAC_BITS> {
+    const NAME_BUILDER: NameBuilder = NameBuilder::new()
+        .push_str("high_level_api::FheFixed<")
+        .push_str(T::NAME)
+        .push_str(", ")
+        .push_u32(FRAC_BITS)
+        .push_str(">");
+}
+
+impl<T: FheFixedInteger + Named, const FRAC_BITS: u32> Named for FheFixed<T, FRAC_BITS> {
+    const NAME: &'static str = Self::NAME_BUILDER.as_str();
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheFixed<T, FRAC_BITS> {
+    /// Creates a fixed-point number from the encryption of `x * 2^FRAC_BITS`
+    pub fn from_scaled_integer(integer: T) -> Self {
+        Self::from_cpu_ciphertext(integer.into_cpu_ciphertext())
+    }
+
+    /// Returns the encryption of `x * 2^FRAC_BITS`
+    pub fn into_scaled_integer(self) -> T {
+        T::from_cpu_ciphertext(self.ciphertext)
+    }
+
+    fn from_cpu_ciphertext(ciphertext: T::Ciphertext) -> Self {
+        Self {
+            ciphertext,
+            _integer: PhantomData,
+        }
+    }
+
+    /// Multiplies two fixed-point numbers, rounding the result according to `mode`
+    ///
+    /// The result wraps around if it does not fit in the underlying integer.
+    pub fn mul_with_rounding(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        with_cpu_server_key(|sks| {
+            let num_blocks = self.ciphertext.blocks().len();
+            let wide_num_blocks = 2 * num_blocks;
+            let (lhs, rhs) = rayon::join(
+                || resize(sks, &self.ciphertext, wide_num_blocks),
+                || resize(sks, &rhs.ciphertext, wide_num_blocks),
+            );
+            let product = sks.mul_parallelized(&lhs, &rhs);
+            let result = rounding_shift_right(sks, &product, FRAC_BITS, mode);
+            Self::from_cpu_ciphertext(resize(sks, &result, num_blocks))
+        })
+    }
+
+    /// Divides two fixed-point numbers, rounding the result according to `mode`
+    ///
+    /// The result wraps around if it does not fit in the underlying integer, the result of a
+    /// division by 0 is unspecified.
+    pub fn div_with_rounding(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        with_cpu_server_key(|sks| {
+            let num_blocks = self.ciphertext.blocks().len();
+            let bits_per_block = sks.message_modulus().0.ilog2();
+            // Room for the shifted numerator, and to double the remainder
+            let wide_num_blocks = num_blocks + FRAC_BITS.div_ceil(bits_per_block) as usize + 1;
+
+            let (numerator, divisor) = rayon::join(
+                || {
+                    let numerator = resize(sks, &self.ciphertext, wide_num_blocks);
+                    sks.scalar_left_shift_parallelized(&numerator, FRAC_BITS)
+                },
+                || resize(sks, &rhs.ciphertext, wide_num_blocks),
+            );
+            let (quotient, remainder) = sks.div_rem_parallelized(&numerator, &divisor);
+            let result = round_quotient(sks, quotient, &remainder, &divisor, mode);
+            Self::from_cpu_ciphertext(resize(sks, &result, num_blocks))
+        })
+    }
+
+    /// Converts the number to another precision, rounding it according to `mode` if
+    /// fractional bits are removed
+    ///
+    /// The result wraps around if it does not fit in the underlying integer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixed, FheUint16, RoundingMode};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixed::<FheUint16, 4>::try_encrypt(2.6875f64, &client_key).unwrap();
+    ///
+    /// let b = a.cast_frac_bits::<2>(RoundingMode::Floor);
+    /// let decrypted: f64 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 2.5);
+    ///
+    /// let c = a.cast_frac_bits::<2>(RoundingMode::Nearest);
+    /// let decrypted: f64 = c.decrypt(&client_key);
+    /// assert_eq!(decrypted, 2.75);
+    /// ```
+    pub fn cast_frac_bits<const NEW_FRAC_BITS: u32>(
+        &self,
+        mode: RoundingMode,
+    ) -> FheFixed<T, NEW_FRAC_BITS> {
+        with_cpu_server_key(|sks| {
+            let result = if NEW_FRAC_BITS >= FRAC_BITS {
+                sks.scalar_left_shift_parallelized(&self.ciphertext, NEW_FRAC_BITS - FRAC_BITS)
+            } else {
+                // One more block so that the rounding cannot overflow
+                let num_blocks = self.ciphertext.blocks().len();
+                let ct = resize(sks, &self.ciphertext, num_blocks + 1);
+                let result = rounding_shift_right(sks, &ct, FRAC_BITS - NEW_FRAC_BITS, mode);
+                resize(sks, &result, num_blocks)
+            };
+            FheFixed::from_cpu_ciphertext(result)
+        })
+    }
+
+    fn compare(
+        &self,
+        rhs: &Self,
+        func: impl FnOnce(&ServerKey, &T::Ciphertext, &T::Ciphertext) -> BooleanBlock,
+    ) -> FheBool {
+        with_cpu_server_key(|sks| FheBool::new(func(sks, &self.ciphertext, &rhs.ciphertext)))
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheTryEncrypt<f64, ClientKey>
+    for FheFixed<T, FRAC_BITS>
+{
+    type Error = crate::Error;
+
+    /// Encrypts the value, rounded to the nearest number representable with `FRAC_BITS`
+    /// fractional bits
+    ///
+    /// Returns an error if the value is not finite or does not fit in the type.
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(crate::Error::new(format!(
+                "Cannot encrypt the non finite value {value}"
+            )));
+        }
+        let scaled = (value * 2f64.powi(FRAC_BITS as i32)).round();
+        T::encrypt_integer(scaled, key).map(Self::from_scaled_integer)
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheDecrypt<f64> for FheFixed<T, FRAC_BITS> {
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let integer = T::from_cpu_ciphertext(self.ciphertext.clone());
+        integer.decrypt_integer(key) / 2f64.powi(FRAC_BITS as i32)
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheEq<&Self> for FheFixed<T, FRAC_BITS> {
+    fn eq(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::eq_parallelized)
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::ne_parallelized)
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheOrd<&Self> for FheFixed<T, FRAC_BITS> {
+    fn lt(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::lt_parallelized)
+    }
+
+    fn le(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::le_parallelized)
+    }
+
+    fn gt(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::gt_parallelized)
+    }
+
+    fn ge(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::ge_parallelized)
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheMin<&Self> for FheFixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    fn min(&self, other: &Self) -> Self::Output {
+        with_cpu_server_key(|sks| {
+            Self::from_cpu_ciphertext(sks.min_parallelized(&self.ciphertext, &other.ciphertext))
+        })
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> FheMax<&Self> for FheFixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    fn max(&self, other: &Self) -> Self::Output {
+        with_cpu_server_key(|sks| {
+            Self::from_cpu_ciphertext(sks.max_parallelized(&self.ciphertext, &other.ciphertext))
+        })
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> IfThenElse<FheFixed<T, FRAC_BITS>> for FheBool {
+    fn if_then_else(
+        &self,
+        ct_then: &FheFixed<T, FRAC_BITS>,
+        ct_else: &FheFixed<T, FRAC_BITS>,
+    ) -> FheFixed<T, FRAC_BITS> {
+        with_cpu_server_key(|sks| {
+            FheFixed::from_cpu_ciphertext(sks.if_then_else_parallelized(
+                &self.ciphertext.on_cpu(),
+                &ct_then.ciphertext,
+                &ct_else.ciphertext,
+            ))
+        })
+    }
+}
+
+macro_rules! impl_fixed_binary_op {
+    ($trait_name:ident, $trait_method:ident, |$lhs:ident, $rhs:ident| $body:expr) => {
+        impl<T: FheFixedInteger, const FRAC_BITS: u32> $trait_name<&FheFixed<T, FRAC_BITS>>
+            for &FheFixed<T, FRAC_BITS>
+        {
+            type Output = FheFixed<T, FRAC_BITS>;
+
+            fn $trait_method(self, rhs: &FheFixed<T, FRAC_BITS>) -> Self::Output {
+                let ($lhs, $rhs) = (self, rhs);
+                $body
+            }
+        }
+
+        impl<T: FheFixedInteger, const FRAC_BITS: u32> $trait_name<FheFixed<T, FRAC_BITS>>
+            for FheFixed<T, FRAC_BITS>
+        {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: Self) -> Self::Output {
+                <&Self as $trait_name<&Self>>::$trait_method(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_fixed_binary_op!(Add, add, |lhs, rhs| with_cpu_server_key(|sks| {
+    FheFixed::from_cpu_ciphertext(sks.add_parallelized(&lhs.ciphertext, &rhs.ciphertext))
+}));
+impl_fixed_binary_op!(Sub, sub, |lhs, rhs| with_cpu_server_key(|sks| {
+    FheFixed::from_cpu_ciphertext(sks.sub_parallelized(&lhs.ciphertext, &rhs.ciphertext))
+}));
+impl_fixed_binary_op!(Mul, mul, |lhs, rhs| lhs
+    .mul_with_rounding(rhs, RoundingMode::Nearest));
+impl_fixed_binary_op!(Div, div, |lhs, rhs| lhs
+    .div_with_rounding(rhs, RoundingMode::Nearest));
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> Neg for &FheFixed<T, FRAC_BITS> {
+    type Output = FheFixed<T, FRAC_BITS>;
+
+    fn neg(self) -> Self::Output {
+        with_cpu_server_key(|sks| {
+            FheFixed::from_cpu_ciphertext(sks.neg_parallelized(&self.ciphertext))
+        })
+    }
+}
+
+impl<T: FheFixedInteger, const FRAC_BITS: u32> Neg for FheFixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+fn with_cpu_server_key<R>(func: impl FnOnce(&ServerKey) -> R) -> R {
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(&cpu_key.key),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support FheFixed yet")
+        }
+    })
+}
+
+/// Changes the number of blocks of the ciphertext, extending it with its sign if it is signed,
+/// and with zeros otherwise
+fn resize<C: IntegerRadixCiphertext>(sks: &ServerKey, ct: &C, num_blocks: usize) -> C {
+    C::from_blocks(sks.cast_to_unsigned(ct.clone(), num_blocks).into_blocks())
+}
+
+/// Divides the ciphertext by `2^shift`, rounding the quotient according to `mode`
+///
+/// The ciphertext must have enough room so that adding `2^shift - 1` does not overflow.
+fn rounding_shift_right<C: IntegerRadixCiphertext>(
+    sks: &ServerKey,
+    ct: &C,
+    shift: u32,
+    mode: RoundingMode,
+) -> C {
+    if shift == 0 {
+        return ct.clone();
+    }
+    assert!(shift < 128, "Cannot round more than 127 fractional bits");
+
+    // The bias added before flooring, for non-negative and negative values
+    let half = 1u128 << (shift - 1);
+    let all_ones = (1u128 << shift) - 1;
+    let (non_negative_bias, negative_bias) = match mode {
+        RoundingMode::TowardZero => (0, all_ones),
+        RoundingMode::Floor => (0, 0),
+        RoundingMode::Ceil => (all_ones, all_ones),
+        RoundingMode::Nearest => (half, half - 1),
+    };
+
+    let biased = if C::IS_SIGNED && non_negative_bias != negative_bias {
+        let ((is_negative, negative), non_negative) = rayon::join(
+            || {
+                rayon::join(
+                    || sks.scalar_lt_parallelized(ct, 0i64),
+                    || sks.scalar_add_parallelized(ct, negative_bias),
+                )
+            },
+            || sks.scalar_add_parallelized(ct, non_negative_bias),
+        );
+        sks.if_then_else_parallelized(&is_negative, &negative, &non_negative)
+    } else {
+        sks.scalar_add_parallelized(ct, non_negative_bias)
+    };
+
+    sks.scalar_right_shift_parallelized(&biased, shift)
+}
+
+/// Rounds the quotient of a truncating division according to `mode`, given the remainder and
+/// the divisor of the division
+///
+/// The ciphertexts must have enough room to double the remainder.
+fn round_quotient<C: IntegerRadixCiphertext>(
+    sks: &ServerKey,
+    quotient: C,
+    remainder: &C,
+    divisor: &C,
+    mode: RoundingMode,
+) -> C {
+    // When the remainder is not zero, it has the sign of the numerator, so the exact quotient
+    // is positive if the remainder and the divisor have the same sign
+    let same_sign = || {
+        if C::IS_SIGNED {
+            let (remainder_is_negative, divisor_is_negative) = rayon::join(
+                || sks.scalar_lt_parallelized(remainder, 0i64),
+                || sks.scalar_lt_parallelized(divisor, 0i64),
+            );
+            let different_signs = sks.boolean_bitxor(&remainder_is_negative, &divisor_is_negative);
+            sks.boolean_bitnot(&different_signs)
+        } else {
+            sks.create_trivial_boolean_block(true)
+        }
+    };
+
+    match mode {
+        RoundingMode::TowardZero => quotient,
+        RoundingMode::Floor | RoundingMode::Ceil => {
+            if mode == RoundingMode::Floor && !C::IS_SIGNED {
+                return quotient;
+            }
+            let (is_inexact, same_sign) =
+                rayon::join(|| sks.scalar_ne_parallelized(remainder, 0u64), same_sign);
+            let (condition, adjusted) = if mode == RoundingMode::Floor {
+                // The exact quotient is negative, truncating rounded it up
+                (
+                    sks.boolean_bitand(&is_inexact, &sks.boolean_bitnot(&same_sign)),
+                    sks.scalar_sub_parallelized(&quotient, 1u64),
+                )
+            } else {
+                // The exact quotient is positive, truncating rounded it down
+                (
+                    sks.boolean_bitand(&is_inexact, &same_sign),
+                    sks.scalar_add_parallelized(&quotient, 1u64),
+                )
+            };
+            sks.if_then_else_parallelized(&condition, &adjusted, &quotient)
+        }
+        RoundingMode::Nearest => {
+            // The fractional part of the exact quotient is at least 1/2 if 2|r| >= |d|
+            let (is_at_least_half, same_sign) = rayon::join(
+                || {
+                    let (remainder, divisor) = rayon::join(
+                        || sks.abs_parallelized(remainder),
+                        || sks.abs_parallelized(divisor),
+                    );
+                    let double_remainder = sks.add_parallelized(&remainder, &remainder);
+                    sks.ge_parallelized(&double_remainder, &divisor)
+                },
+                same_sign,
+            );
+            let (incremented, decremented) = rayon::join(
+                || sks.scalar_add_parallelized(&quotient, 1u64),
+                || sks.scalar_sub_parallelized(&quotient, 1u64),
+            );
+            let adjusted = sks.if_then_else_parallelized(&same_sign, &incremented, &decremented);
+            sks.if_then_else_parallelized(&is_at_least_half, &adjusted, &quotient)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high_level_api::integers::setup_default_cpu;
+    use crate::{FheInt16, FheUint16};
+
+    #[test]
+    fn test_fhe_fixed_encrypt_decrypt() {
+        let client_key = setup_default_cpu();
+
+        for value in [0.0f64, 1.5, -3.25, 127.9375, -128.0] {
+            let a = FheFixed::<FheInt16, 8>::try_encrypt(value, &client_key).unwrap();
+            let decrypted: f64 = a.decrypt(&client_key);
+            assert_eq!(decrypted, value);
+        }
+
+        // Rounded to the nearest multiple of 2^-8
+        let a = FheFixed::<FheInt16, 8>::try_encrypt(0.3, &client_key).unwrap();
+        let decrypted: f64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, 77.0 / 256.0);
+
+        assert!(FheFixed::<FheInt16, 8>::try_encrypt(128.0, &client_key).is_err());
+        assert!(FheFixed::<FheUint16, 8>::try_encrypt(-1.0, &client_key).is_err());
+        assert!(FheFixed::<FheUint16, 8>::try_encrypt(f64::NAN, &client_key).is_err());
+    }
+
+    #[test]
+    fn test_fhe_fixed_arithmetic() {
+        let client_key = setup_default_cpu();
+
+        let a = FheFixed::<FheInt16, 4>::try_encrypt(-5.25, &client_key).unwrap();
+        let b = FheFixed::<FheInt16, 4>::try_encrypt(2.5, &client_key).unwrap();
+
+        let decrypted: f64 = (&a + &b).decrypt(&client_key);
+        assert_eq!(decrypted, -2.75);
+        let decrypted: f64 = (&a - &b).decrypt(&client_key);
+        assert_eq!(decrypted, -7.75);
+        let decrypted: f64 = (-&a).decrypt(&client_key);
+        assert_eq!(decrypted, 5.25);
+
+        // -13.125 is exactly representable
+        let decrypted: f64 = (&a * &b).decrypt(&client_key);
+        assert_eq!(decrypted, -13.125);
+
+        // -5.25 / 2.5 = -2.1 = -33.6 / 16
+        let expected = [
+            (RoundingMode::TowardZero, -33.0),
+            (RoundingMode::Floor, -34.0),
+            (RoundingMode::Ceil, -33.0),
+            (RoundingMode::Nearest, -34.0),
+        ];
+        for (mode, expected) in expected {
+            let decrypted: f64 = a.div_with_rounding(&b, mode).decrypt(&client_key);
+            assert_eq!(decrypted, expected / 16.0, "{mode:?}");
+        }
+
+        // 0.3125 * -0.5625 = -0.17578125 = -2.8125 / 16
+        let c = FheFixed::<FheInt16, 4>::try_encrypt(0.3125, &client_key).unwrap();
+        let d = FheFixed::<FheInt16, 4>::try_encrypt(-0.5625, &client_key).unwrap();
+        let expected = [
+            (RoundingMode::TowardZero, -2.0),
+            (RoundingMode::Floor, -3.0),
+            (RoundingMode::Ceil, -2.0),
+            (RoundingMode::Nearest, -3.0),
+        ];
+        for (mode, expected) in expected {
+            let decrypted: f64 = c.mul_with_rounding(&d, mode).decrypt(&client_key);
+            assert_eq!(decrypted, expected / 16.0, "{mode:?}");
+        }
+
+        let e = FheFixed::<FheUint16, 4>::try_encrypt(10.0, &client_key).unwrap();
+        let f = FheFixed::<FheUint16, 4>::try_encrypt(3.0, &client_key).unwrap();
+        // 10 / 3 = 53.33 / 16
+        let decrypted: f64 = (&e / &f).decrypt(&client_key);
+        assert_eq!(decrypted, 53.0 / 16.0);
+        let decrypted: f64 = e
+            .div_with_rounding(&f, RoundingMode::Ceil)
+            .decrypt(&client_key);
+        assert_eq!(decrypted, 54.0 / 16.0);
+    }
+
+    #[test]
+    fn test_fhe_fixed_comparisons_and_casts() {
+        let client_key = setup_default_cpu();
+
+        let a = FheFixed::<FheInt16, 4>::try_encrypt(-1.5, &client_key).unwrap();
+        let b = FheFixed::<FheInt16, 4>::try_encrypt(0.75, &client_key).unwrap();
+
+        assert!(a.lt(&b).decrypt(&client_key));
+        assert!(a.le(&b).decrypt(&client_key));
+        assert!(!a.gt(&b).decrypt(&client_key));
+        assert!(!a.ge(&b).decrypt(&client_key));
+        assert!(!a.eq(&b).decrypt(&client_key));
+        assert!(a.ne(&b).decrypt(&client_key));
+
+        let decrypted: f64 = a.min(&b).decrypt(&client_key);
+        assert_eq!(decrypted, -1.5);
+        let decrypted: f64 = a.max(&b).decrypt(&client_key);
+        assert_eq!(decrypted, 0.75);
+
+        let condition = a.lt(&b);
+        let decrypted: f64 = condition.if_then_else(&a, &b).decrypt(&client_key);
+        assert_eq!(decrypted, -1.5);
+
+        let wider = a.cast_frac_bits::<8>(RoundingMode::Nearest);
+        let decrypted: f64 = wider.decrypt(&client_key);
+        assert_eq!(decrypted, -1.5);
+
+        // -1.5 with no fractional bit is a tie
+        let expected = [
+            (RoundingMode::TowardZero, -1.0),
+            (RoundingMode::Floor, -2.0),
+            (RoundingMode::Ceil, -1.0),
+            (RoundingMode::Nearest, -2.0),
+        ];
+        for (mode, expected) in expected {
+            let decrypted: f64 = a.cast_frac_bits::<0>(mode).decrypt(&client_key);
+            assert_eq!(decrypted, expected, "{mode:?}");
+        }
+
+        let scaled: i16 = b.into_scaled_integer().decrypt(&client_key);
+        assert_eq!(scaled, 12);
+    }
+
+    #[test]
+    fn test_fhe_fixed_safe_serialization() {
+        let client_key = setup_default_cpu();
+
+        let a = FheFixed::<FheInt16, 8>::try_encrypt(-3.25, &client_key).unwrap();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&a, &mut serialized, 1 << 20).unwrap();
+        let a: FheFixed<FheInt16, 8> =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        let decrypted: f64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, -3.25);
+
+        // The precision is checked
+        let mut serialized = vec![];
+        crate::safe_serialize(&a, &mut serialized, 1 << 20).unwrap();
+        assert!(
+            crate::safe_deserialization::safe_deserialize::<FheFixed<FheInt16, 4>>(
+                serialized.as_slice(),
+                1 << 20
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_fhe_fixed_names() {
+        assert_ne!(
+            FheFixed::<FheInt16, 8>::NAME,
+            FheFixed::<FheInt16, 4>::NAME,
+            "Numbers with different precisions must not deserialize as one another"
+        );
+        assert_ne!(
+            FheFixed::<FheInt16, 8>::NAME,
+            FheFixed::<FheUint16, 8>::NAME
+        );
+        assert_eq!(
+            FheFixed::<FheInt16, 8>::NAME,
+            "high_level_api::FheFixed<high_level_api::FheInt, 8>"
+        );
+    }
+}
@@ -0,0 +1,1136 @@
+//! This module defines [FheFloat32], an encrypted floating-point number with the layout of an
+//! IEEE 754 binary32 number (`f32`).
+//!
+//! The sign, the biased exponent and the fraction of the mantissa are encrypted separately, as
+//! a boolean and two radix ciphertexts. The operations follow IEEE 754:
+//!
+//! - results are rounded to the nearest representable number, ties to even,
+//! - subnormal numbers are supported as inputs and as results, they are not flushed to zero,
+//! - results which are too large are rounded to infinity, and operations on infinities behave as
+//!   in IEEE 754,
+//! - invalid operations (`0 / 0`, `inf - inf`, `0 * inf`, ...) and operations on NaN return the
+//!   quiet NaN `0x7fc00000`, the sign and the payload of NaN inputs are not propagated,
+//! - comparisons involving a NaN are false (`ne` is true), and `-0 == +0`.
+use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::{
+    BooleanBlock, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, ServerKey,
+    SignedRadixCiphertext,
+};
+use crate::named::Named;
+use crate::prelude::{FheDecrypt, FheEq, FheOrd, FheTryEncrypt, IfThenElse};
+use crate::{ClientKey, FheBool, FheInt, FheUint};
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+const MANTISSA_BITS: u32 = 23;
+const SIGNIFICAND_BITS: u32 = MANTISSA_BITS + 1;
+const EXPONENT_BITS: u32 = 8;
+const MAGNITUDE_BITS: u32 = EXPONENT_BITS + MANTISSA_BITS;
+const EXPONENT_BIAS: i64 = 127;
+const MAX_EXPONENT: u64 = (1 << EXPONENT_BITS) - 1;
+const QUIET_NAN_MANTISSA: u64 = 1 << (MANTISSA_BITS - 1);
+/// Number of bits of the signed exponents used during the computations, enough to hold the
+/// exponents of products, quotients and of large integers
+const WORK_EXPONENT_BITS: u32 = 16;
+/// Guard, round and sticky bits kept when aligning the operands of an addition
+const ADD_EXTRA_BITS: u32 = 3;
+/// Shift applied to the numerator of a division, so that the quotient of two normalized
+/// significands has at least two bits more than a significand
+const DIV_EXTRA_BITS: u32 = SIGNIFICAND_BITS + 2;
+
+/// An encrypted single precision floating-point number
+///
+/// See the [module documentation](self) for how rounding, subnormal numbers, infinities and
+/// NaN are handled.
+///
+/// The operations use the server key set in the current thread and run on the CPU.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFloat32::encrypt(1.5f32, &client_key);
+/// let b = FheFloat32::encrypt(-0.1f32, &client_key);
+///
+/// let result = &a * &b;
+/// let decrypted: f32 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 1.5f32 * -0.1f32);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheFloat32 {
+    sign: BooleanBlock,
+    /// Biased exponent
+    exponent: RadixCiphertext,
+    /// Mantissa without its implicit leading bit
+    mantissa: RadixCiphertext,
+}
+
+impl Named for FheFloat32 {
+    const NAME: &'static str = "high_level_api::FheFloat32";
+}
+
+/// A number with its significand made explicit, used during computations
+struct Unpacked {
+    sign: BooleanBlock,
+    /// Biased exponent, which is 1 for subnormal numbers
+    exponent: SignedRadixCiphertext,
+    /// Mantissa with its implicit leading bit
+    significand: RadixCiphertext,
+    is_nan: BooleanBlock,
+    is_inf: BooleanBlock,
+    is_zero: BooleanBlock,
+}
+
+impl FheFloat32 {
+    /// Returns the absolute value
+    pub fn abs(&self) -> Self {
+        with_cpu_server_key(|sks| Self {
+            sign: sks.create_trivial_boolean_block(false),
+            exponent: self.exponent.clone(),
+            mantissa: self.mantissa.clone(),
+        })
+    }
+
+    /// Returns whether the number is a NaN
+    pub fn is_nan(&self) -> FheBool {
+        with_cpu_server_key(|sks| FheBool::new(self.is_nan_block(sks)))
+    }
+
+    fn is_nan_block(&self, sks: &ServerKey) -> BooleanBlock {
+        let (exponent_is_max, mantissa_is_not_zero) = rayon::join(
+            || sks.scalar_eq_parallelized(&self.exponent, MAX_EXPONENT),
+            || sks.scalar_ne_parallelized(&self.mantissa, 0u64),
+        );
+        sks.boolean_bitand(&exponent_is_max, &mantissa_is_not_zero)
+    }
+
+    fn unpack(&self, sks: &ServerKey) -> Unpacked {
+        let significand_blocks = num_blocks_of(sks, SIGNIFICAND_BITS);
+
+        let ((exponent_is_zero, exponent_is_max), (mantissa_is_zero, exponent)) = rayon::join(
+            || {
+                rayon::join(
+                    || sks.scalar_eq_parallelized(&self.exponent, 0u64),
+                    || sks.scalar_eq_parallelized(&self.exponent, MAX_EXPONENT),
+                )
+            },
+            || {
+                rayon::join(
+                    || sks.scalar_eq_parallelized(&self.mantissa, 0u64),
+                    || {
+                        let exponent = sks.scalar_max_parallelized(&self.exponent, 1u64);
+                        sks.cast_to_signed(exponent, num_blocks_of(sks, WORK_EXPONENT_BITS))
+                    },
+                )
+            },
+        );
+
+        let hidden_bit: RadixCiphertext = sks
+            .boolean_bitnot(&exponent_is_zero)
+            .into_radix(significand_blocks, sks);
+        let significand = sks.add_parallelized(
+            &sks.cast_to_unsigned(self.mantissa.clone(), significand_blocks),
+            &sks.scalar_left_shift_parallelized(&hidden_bit, MANTISSA_BITS),
+        );
+
+        let mantissa_is_not_zero = sks.boolean_bitnot(&mantissa_is_zero);
+        Unpacked {
+            sign: self.sign.clone(),
+            exponent,
+            significand,
+            is_nan: sks.boolean_bitand(&exponent_is_max, &mantissa_is_not_zero),
+            is_inf: sks.boolean_bitand(&exponent_is_max, &mantissa_is_zero),
+            is_zero: sks.boolean_bitand(&exponent_is_zero, &mantissa_is_zero),
+        }
+    }
+
+    /// Returns the number without its sign, as an unsigned integer which orders the numbers
+    /// which are not NaN
+    fn magnitude(&self, sks: &ServerKey) -> RadixCiphertext {
+        let num_blocks = num_blocks_of(sks, MAGNITUDE_BITS);
+        let exponent = sks.cast_to_unsigned(self.exponent.clone(), num_blocks);
+        sks.add_parallelized(
+            &sks.scalar_left_shift_parallelized(&exponent, MANTISSA_BITS),
+            &sks.cast_to_unsigned(self.mantissa.clone(), num_blocks),
+        )
+    }
+
+    /// Returns a signed integer which orders the numbers which are not NaN, with `-0 == +0`
+    fn comparison_key(&self, sks: &ServerKey) -> SignedRadixCiphertext {
+        let magnitude = self.magnitude(sks);
+        let key = sks.cast_to_signed(magnitude, num_blocks_of(sks, MAGNITUDE_BITS + 1));
+        sks.if_then_else_parallelized(&self.sign, &sks.neg_parallelized(&key), &key)
+    }
+
+    /// Rounds and packs the number `significand * 2^(exponent - EXPONENT_BIAS - (w - 1))`, `w`
+    /// being the number of bits of `significand`
+    ///
+    /// In other words, `exponent` is the biased exponent of the number if the most significant
+    /// bit of `significand` is set. The significand does not have to be normalized.
+    fn round_and_pack(
+        sks: &ServerKey,
+        sign: BooleanBlock,
+        exponent: &SignedRadixCiphertext,
+        significand: RadixCiphertext,
+    ) -> Self {
+        let bits_per_block = bits_per_block(sks);
+        let exponent_blocks = exponent.blocks.len();
+
+        // Two bits at least are kept below the bits of the result,
+        // so that the rounding shift is never 0
+        let mut blocks = significand.blocks;
+        let missing_bits =
+            (SIGNIFICAND_BITS + 2).saturating_sub(blocks.len() as u32 * bits_per_block);
+        let missing_blocks = missing_bits.div_ceil(bits_per_block) as usize;
+        blocks.splice(
+            0..0,
+            std::iter::repeat(sks.key.create_trivial(0)).take(missing_blocks),
+        );
+        let width = blocks.len() as u32 * bits_per_block;
+        let mut significand = RadixCiphertext::from(blocks);
+
+        // Moves the leading one to the bit `width - 1`, the block added on top
+        // leaves room to shift by up to `width` bits
+        let leading_zeros = sks.leading_zeros_parallelized(&significand);
+        sks.extend_radix_with_trivial_zero_blocks_msb_assign(&mut significand, 1);
+        let num_blocks = significand.blocks.len();
+        let (significand, exponent) = rayon::join(
+            || {
+                let shift = sks.cast_to_unsigned(leading_zeros.clone(), num_blocks);
+                sks.left_shift_parallelized(&significand, &shift)
+            },
+            || {
+                let shift = sks.cast_to_signed(leading_zeros.clone(), exponent_blocks);
+                sks.sub_parallelized(exponent, &shift)
+            },
+        );
+
+        // Numbers below the smallest normal number are shifted further to be subnormal,
+        // shifting by more than SIGNIFICAND_BITS more bits always rounds to 0
+        let extra_shift = sks.scalar_add_parallelized(&sks.neg_parallelized(&exponent), 1u64);
+        let extra_shift = sks.scalar_min_parallelized(
+            &sks.scalar_max_parallelized(&extra_shift, 0i64),
+            i64::from(SIGNIFICAND_BITS + 1),
+        );
+        let (underflows, extra_shift) = rayon::join(
+            || sks.scalar_eq_parallelized(&extra_shift, i64::from(SIGNIFICAND_BITS + 1)),
+            || sks.scalar_min_parallelized(&extra_shift, i64::from(SIGNIFICAND_BITS)),
+        );
+        let shift = sks.scalar_add_parallelized(
+            &sks.cast_to_unsigned(extra_shift, num_blocks),
+            width - SIGNIFICAND_BITS,
+        );
+
+        // Rounds to nearest, ties to even
+        let truncated = sks.right_shift_parallelized(&significand, &shift);
+        let (remainder, half) = rayon::join(
+            || {
+                let shifted_back = sks.left_shift_parallelized(&truncated, &shift);
+                sks.sub_parallelized(&significand, &shifted_back)
+            },
+            || {
+                let one: RadixCiphertext = sks.create_trivial_radix(1u64, num_blocks);
+                let unit = sks.left_shift_parallelized(&one, &shift);
+                sks.scalar_right_shift_parallelized(&unit, 1u32)
+            },
+        );
+        let ((is_above_half, is_half), is_odd) = rayon::join(
+            || {
+                rayon::join(
+                    || sks.gt_parallelized(&remainder, &half),
+                    || sks.eq_parallelized(&remainder, &half),
+                )
+            },
+            || BooleanBlock::convert(&sks.scalar_bitand_parallelized(&truncated, 1u64), sks),
+        );
+        let round_up = sks.boolean_bitor(&is_above_half, &sks.boolean_bitand(&is_half, &is_odd));
+        let rounded = sks.add_parallelized(&truncated, &round_up.into_radix(num_blocks, sks));
+        let rounded = sks.if_then_else_parallelized(
+            &underflows,
+            &sks.create_trivial_zero_radix(num_blocks),
+            &rounded,
+        );
+
+        // Rounding up may give 2^SIGNIFICAND_BITS, which has to be normalized again
+        let carried = sks.scalar_eq_parallelized(&rounded, 1u64 << SIGNIFICAND_BITS);
+        let (rounded, exponent) = rayon::join(
+            || {
+                let halved = sks.scalar_right_shift_parallelized(&rounded, 1u32);
+                sks.if_then_else_parallelized(&carried, &halved, &rounded)
+            },
+            || {
+                let exponent = sks.scalar_max_parallelized(&exponent, 1i64);
+                sks.add_parallelized(&exponent, &carried.clone().into_radix(exponent_blocks, sks))
+            },
+        );
+
+        let (is_normal, exponent_overflows) = rayon::join(
+            || sks.scalar_ge_parallelized(&rounded, 1u64 << MANTISSA_BITS),
+            || sks.scalar_ge_parallelized(&exponent, MAX_EXPONENT as i64),
+        );
+        let overflows = sks.boolean_bitand(&is_normal, &exponent_overflows);
+
+        let (exponent, mantissa) = rayon::join(
+            || {
+                let exponent_blocks = num_blocks_of_exponent(sks);
+                let exponent = sks.if_then_else_parallelized(
+                    &is_normal,
+                    &sks.cast_to_unsigned(exponent, exponent_blocks),
+                    &sks.create_trivial_zero_radix(exponent_blocks),
+                );
+                sks.if_then_else_parallelized(
+                    &overflows,
+                    &sks.create_trivial_radix(MAX_EXPONENT, exponent_blocks),
+                    &exponent,
+                )
+            },
+            || {
+                let mantissa_blocks = num_blocks_of_mantissa(sks);
+                let mantissa =
+                    sks.scalar_bitand_parallelized(&rounded, (1u64 << MANTISSA_BITS) - 1);
+                sks.if_then_else_parallelized(
+                    &overflows,
+                    &sks.create_trivial_zero_radix(mantissa_blocks),
+                    &sks.cast_to_unsigned(mantissa, mantissa_blocks),
+                )
+            },
+        );
+
+        Self {
+            sign,
+            exponent,
+            mantissa,
+        }
+    }
+
+    /// Replaces the number by NaN, infinity (with the sign `inf_sign`) or zero (keeping its
+    /// sign) depending on the conditions, in this order of priority
+    fn with_special_values(
+        self,
+        sks: &ServerKey,
+        is_nan: &BooleanBlock,
+        is_inf: &BooleanBlock,
+        inf_sign: &BooleanBlock,
+        is_zero: Option<&BooleanBlock>,
+    ) -> Self {
+        let exponent_blocks = self.exponent.blocks.len();
+        let mantissa_blocks = self.mantissa.blocks.len();
+
+        let ((exponent, mantissa), sign) = rayon::join(
+            || {
+                rayon::join(
+                    || {
+                        let exponent = match is_zero {
+                            Some(is_zero) => sks.if_then_else_parallelized(
+                                is_zero,
+                                &sks.create_trivial_zero_radix(exponent_blocks),
+                                &self.exponent,
+                            ),
+                            None => self.exponent.clone(),
+                        };
+                        sks.if_then_else_parallelized(
+                            &sks.boolean_bitor(is_nan, is_inf),
+                            &sks.create_trivial_radix(MAX_EXPONENT, exponent_blocks),
+                            &exponent,
+                        )
+                    },
+                    || {
+                        let is_zero_or_inf = is_zero.map_or_else(
+                            || is_inf.clone(),
+                            |is_zero| sks.boolean_bitor(is_zero, is_inf),
+                        );
+                        let mantissa = sks.if_then_else_parallelized(
+                            &is_zero_or_inf,
+                            &sks.create_trivial_zero_radix(mantissa_blocks),
+                            &self.mantissa,
+                        );
+                        sks.if_then_else_parallelized(
+                            is_nan,
+                            &sks.create_trivial_radix(QUIET_NAN_MANTISSA, mantissa_blocks),
+                            &mantissa,
+                        )
+                    },
+                )
+            },
+            || {
+                let sign = select_boolean(sks, is_inf, inf_sign, &self.sign);
+                sks.boolean_bitand(&sign, &sks.boolean_bitnot(is_nan))
+            },
+        );
+
+        Self {
+            sign,
+            exponent,
+            mantissa,
+        }
+    }
+
+    fn add_impl(&self, rhs: &Self, sks: &ServerKey) -> Self {
+        let ((lhs, rhs), lhs_is_smaller) = rayon::join(
+            || rayon::join(|| self.unpack(sks), || rhs.unpack(sks)),
+            || {
+                let (lhs_magnitude, rhs_magnitude) =
+                    rayon::join(|| self.magnitude(sks), || rhs.magnitude(sks));
+                sks.lt_parallelized(&lhs_magnitude, &rhs_magnitude)
+            },
+        );
+
+        // Orders the operands by magnitude
+        let select = |if_smaller: &RadixCiphertext, if_not_smaller: &RadixCiphertext| {
+            sks.if_then_else_parallelized(&lhs_is_smaller, if_smaller, if_not_smaller)
+        };
+        let ((big_significand, small_significand), (big_exponent, small_exponent)) = rayon::join(
+            || {
+                rayon::join(
+                    || select(&rhs.significand, &lhs.significand),
+                    || select(&lhs.significand, &rhs.significand),
+                )
+            },
+            || {
+                rayon::join(
+                    || sks.if_then_else_parallelized(&lhs_is_smaller, &rhs.exponent, &lhs.exponent),
+                    || sks.if_then_else_parallelized(&lhs_is_smaller, &lhs.exponent, &rhs.exponent),
+                )
+            },
+        );
+        let big_sign = select_boolean(sks, &lhs_is_smaller, &rhs.sign, &lhs.sign);
+
+        let num_blocks = num_blocks_of(sks, SIGNIFICAND_BITS + ADD_EXTRA_BITS + 1);
+        let width = num_blocks as u32 * bits_per_block(sks);
+        let extend = |significand: RadixCiphertext| {
+            let significand = sks.cast_to_unsigned(significand, num_blocks);
+            sks.scalar_left_shift_parallelized(&significand, ADD_EXTRA_BITS)
+        };
+        let ((big, small), shift) = rayon::join(
+            || rayon::join(|| extend(big_significand), || extend(small_significand)),
+            || {
+                let difference = sks.sub_parallelized(&big_exponent, &small_exponent);
+                let difference = sks.scalar_min_parallelized(
+                    &difference,
+                    i64::from(SIGNIFICAND_BITS + ADD_EXTRA_BITS),
+                );
+                sks.cast_to_unsigned(difference, num_blocks)
+            },
+        );
+
+        // Aligns the smaller operand, the bits shifted out are kept as a sticky bit
+        let aligned = sks.right_shift_parallelized(&small, &shift);
+        let sticky = sks.ne_parallelized(&sks.left_shift_parallelized(&aligned, &shift), &small);
+        let aligned = sks.bitor_parallelized(&aligned, &sticky.into_radix(num_blocks, sks));
+
+        let is_subtraction = sks.boolean_bitxor(&lhs.sign, &rhs.sign);
+        let (sum, difference) = rayon::join(
+            || sks.add_parallelized(&big, &aligned),
+            || sks.sub_parallelized(&big, &aligned),
+        );
+        let significand = sks.if_then_else_parallelized(&is_subtraction, &difference, &sum);
+
+        // An exact 0 obtained by subtraction is +0
+        let is_exact_zero = sks.boolean_bitand(
+            &is_subtraction,
+            &sks.scalar_eq_parallelized(&significand, 0u64),
+        );
+        let sign = sks.boolean_bitand(&big_sign, &sks.boolean_bitnot(&is_exact_zero));
+
+        let exponent = sks.scalar_add_parallelized(
+            &big_exponent,
+            i64::from(width) - i64::from(MANTISSA_BITS + ADD_EXTRA_BITS + 1),
+        );
+        let result = Self::round_and_pack(sks, sign, &exponent, significand);
+
+        let is_nan = sks.boolean_bitor(
+            &sks.boolean_bitor(&lhs.is_nan, &rhs.is_nan),
+            &sks.boolean_bitand(
+                &sks.boolean_bitand(&lhs.is_inf, &rhs.is_inf),
+                &is_subtraction,
+            ),
+        );
+        let is_inf = sks.boolean_bitor(&lhs.is_inf, &rhs.is_inf);
+        result.with_special_values(sks, &is_nan, &is_inf, &big_sign, None)
+    }
+
+    fn mul_impl(&self, rhs: &Self, sks: &ServerKey) -> Self {
+        let (lhs, rhs) = rayon::join(|| self.unpack(sks), || rhs.unpack(sks));
+
+        let num_blocks = num_blocks_of(sks, 2 * SIGNIFICAND_BITS);
+        let width = num_blocks as u32 * bits_per_block(sks);
+        let (product, exponent) = rayon::join(
+            || {
+                sks.mul_parallelized(
+                    &sks.cast_to_unsigned(lhs.significand.clone(), num_blocks),
+                    &sks.cast_to_unsigned(rhs.significand.clone(), num_blocks),
+                )
+            },
+            || {
+                let exponent = sks.add_parallelized(&lhs.exponent, &rhs.exponent);
+                sks.scalar_add_parallelized(
+                    &exponent,
+                    i64::from(width) - (EXPONENT_BIAS + i64::from(2 * MANTISSA_BITS + 1)),
+                )
+            },
+        );
+        let sign = sks.boolean_bitxor(&lhs.sign, &rhs.sign);
+        let result = Self::round_and_pack(sks, sign.clone(), &exponent, product);
+
+        let is_invalid = sks.boolean_bitor(
+            &sks.boolean_bitand(&lhs.is_inf, &rhs.is_zero),
+            &sks.boolean_bitand(&lhs.is_zero, &rhs.is_inf),
+        );
+        let is_nan = sks.boolean_bitor(&sks.boolean_bitor(&lhs.is_nan, &rhs.is_nan), &is_invalid);
+        let is_inf = sks.boolean_bitor(&lhs.is_inf, &rhs.is_inf);
+        result.with_special_values(sks, &is_nan, &is_inf, &sign, None)
+    }
+
+    fn div_impl(&self, rhs: &Self, sks: &ServerKey) -> Self {
+        let (lhs, rhs) = rayon::join(|| self.unpack(sks), || rhs.unpack(sks));
+        let ((lhs_significand, lhs_exponent), (rhs_significand, rhs_exponent)) = rayon::join(
+            || normalize(sks, &lhs.significand, &lhs.exponent),
+            || normalize(sks, &rhs.significand, &rhs.exponent),
+        );
+
+        let num_blocks = num_blocks_of(sks, SIGNIFICAND_BITS + DIV_EXTRA_BITS);
+        let (numerator, divisor) = rayon::join(
+            || {
+                let numerator = sks.cast_to_unsigned(lhs_significand, num_blocks);
+                sks.scalar_left_shift_parallelized(&numerator, DIV_EXTRA_BITS)
+            },
+            || sks.cast_to_unsigned(rhs_significand, num_blocks),
+        );
+        let (quotient, remainder) = sks.div_rem_parallelized(&numerator, &divisor);
+
+        // The quotient has at least SIGNIFICAND_BITS + 2 bits,
+        // so the sticky bit can be put in its least significant bit
+        let sticky = sks.scalar_ne_parallelized(&remainder, 0u64);
+        let quotient = sks.bitor_parallelized(&quotient, &sticky.into_radix(num_blocks, sks));
+        let quotient_blocks = num_blocks_of(sks, SIGNIFICAND_BITS + 3);
+        let quotient = sks.cast_to_unsigned(quotient, quotient_blocks);
+        let width = quotient_blocks as u32 * bits_per_block(sks);
+
+        let exponent = sks.sub_parallelized(&lhs_exponent, &rhs_exponent);
+        let exponent = sks.scalar_add_parallelized(
+            &exponent,
+            i64::from(width) + EXPONENT_BIAS - i64::from(DIV_EXTRA_BITS + 1),
+        );
+        let sign = sks.boolean_bitxor(&lhs.sign, &rhs.sign);
+        let result = Self::round_and_pack(sks, sign.clone(), &exponent, quotient);
+
+        let is_invalid = sks.boolean_bitor(
+            &sks.boolean_bitand(&lhs.is_zero, &rhs.is_zero),
+            &sks.boolean_bitand(&lhs.is_inf, &rhs.is_inf),
+        );
+        let is_nan = sks.boolean_bitor(&sks.boolean_bitor(&lhs.is_nan, &rhs.is_nan), &is_invalid);
+        let is_inf = sks.boolean_bitor(&lhs.is_inf, &rhs.is_zero);
+        let is_zero = sks.boolean_bitor(&lhs.is_zero, &rhs.is_inf);
+        result.with_special_values(sks, &is_nan, &is_inf, &sign, Some(&is_zero))
+    }
+
+    fn compare(
+        &self,
+        rhs: &Self,
+        func: impl Send
+            + FnOnce(&ServerKey, &SignedRadixCiphertext, &SignedRadixCiphertext) -> BooleanBlock,
+    ) -> FheBool {
+        with_cpu_server_key(|sks| {
+            let (result, is_nan) = rayon::join(
+                || {
+                    let (lhs_key, rhs_key) =
+                        rayon::join(|| self.comparison_key(sks), || rhs.comparison_key(sks));
+                    func(sks, &lhs_key, &rhs_key)
+                },
+                || {
+                    let (lhs_is_nan, rhs_is_nan) =
+                        rayon::join(|| self.is_nan_block(sks), || rhs.is_nan_block(sks));
+                    sks.boolean_bitor(&lhs_is_nan, &rhs_is_nan)
+                },
+            );
+            FheBool::new(sks.boolean_bitand(&result, &sks.boolean_bitnot(&is_nan)))
+        })
+    }
+
+    /// Converts an integer, rounding it to the nearest number
+    fn from_integer<T: IntegerRadixCiphertext>(sks: &ServerKey, ct: &T) -> Self {
+        let width = ct.blocks().len() as u32 * bits_per_block(sks);
+        let (sign, magnitude) = rayon::join(
+            || {
+                if T::IS_SIGNED {
+                    sks.scalar_lt_parallelized(ct, 0i64)
+                } else {
+                    sks.create_trivial_boolean_block(false)
+                }
+            },
+            // The absolute value of the minimum of a signed type is correct once seen as unsigned
+            || RadixCiphertext::from_blocks(sks.abs_parallelized(ct).into_blocks()),
+        );
+        let exponent: SignedRadixCiphertext = sks.create_trivial_radix(
+            EXPONENT_BIAS as u64 + u64::from(width) - 1,
+            num_blocks_of(sks, WORK_EXPONENT_BITS),
+        );
+        Self::round_and_pack(sks, sign, &exponent, magnitude)
+    }
+
+    /// Converts the number to an integer of `num_blocks` blocks, rounding it towards 0
+    ///
+    /// Like the `as` conversions of Rust, the values which do not fit saturate,
+    /// and NaN is converted to 0.
+    fn to_integer<T: IntegerRadixCiphertext>(&self, sks: &ServerKey, num_blocks: usize) -> T {
+        let unpacked = self.unpack(sks);
+        let width = num_blocks as u32 * bits_per_block(sks);
+        let wide_blocks = num_blocks_of(sks, width + SIGNIFICAND_BITS + 1);
+        let offset = EXPONENT_BIAS + i64::from(MANTISSA_BITS);
+
+        // The integer is significand * 2^(exponent - offset)
+        let (left_shift, right_shift) = rayon::join(
+            || {
+                let shift = sks.scalar_add_parallelized(&unpacked.exponent, -offset);
+                let shift = sks.scalar_max_parallelized(&shift, 0i64);
+                let shift = sks.scalar_min_parallelized(&shift, i64::from(width));
+                sks.cast_to_unsigned(shift, wide_blocks)
+            },
+            || {
+                let shift = sks.neg_parallelized(&unpacked.exponent);
+                let shift = sks.scalar_add_parallelized(&shift, offset);
+                let shift = sks.scalar_max_parallelized(&shift, 0i64);
+                let shift = sks.scalar_min_parallelized(&shift, i64::from(SIGNIFICAND_BITS));
+                sks.cast_to_unsigned(shift, wide_blocks)
+            },
+        );
+        let significand = sks.cast_to_unsigned(unpacked.significand, wide_blocks);
+        let magnitude = sks.right_shift_parallelized(
+            &sks.left_shift_parallelized(&significand, &left_shift),
+            &right_shift,
+        );
+
+        let value_bits = if T::IS_SIGNED { width - 1 } else { width };
+        let (overflows, value) = rayon::join(
+            || {
+                let high_bits = sks.scalar_right_shift_parallelized(&magnitude, value_bits);
+                sks.scalar_ne_parallelized(&high_bits, 0u64)
+            },
+            || {
+                T::from_blocks(
+                    sks.cast_to_unsigned(magnitude.clone(), num_blocks)
+                        .into_blocks(),
+                )
+            },
+        );
+
+        let result = if T::IS_SIGNED {
+            let (value, saturated) = rayon::join(
+                || {
+                    sks.if_then_else_parallelized(
+                        &unpacked.sign,
+                        &sks.neg_parallelized(&value),
+                        &value,
+                    )
+                },
+                || {
+                    sks.if_then_else_parallelized(
+                        &unpacked.sign,
+                        &sks.create_trivial_min_radix(num_blocks),
+                        &sks.create_trivial_max_radix(num_blocks),
+                    )
+                },
+            );
+            sks.if_then_else_parallelized(&overflows, &saturated, &value)
+        } else {
+            let value = sks.if_then_else_parallelized(
+                &overflows,
+                &sks.create_trivial_max_radix(num_blocks),
+                &value,
+            );
+            sks.if_then_else_parallelized(
+                &unpacked.sign,
+                &sks.create_trivial_zero_radix(num_blocks),
+                &value,
+            )
+        };
+        sks.if_then_else_parallelized(
+            &unpacked.is_nan,
+            &sks.create_trivial_zero_radix(num_blocks),
+            &result,
+        )
+    }
+}
+
+impl FheTryEncrypt<f32, ClientKey> for FheFloat32 {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: f32, key: &ClientKey) -> Result<Self, Self::Error> {
+        let bits = value.to_bits();
+        let integer_key = &key.key.key;
+        let message_modulus = key.message_modulus();
+        let bits_per_block = message_modulus.0.ilog2();
+
+        Ok(Self {
+            sign: integer_key.encrypt_bool(bits >> 31 == 1),
+            exponent: integer_key.encrypt_radix(
+                (bits >> MANTISSA_BITS) & MAX_EXPONENT as u32,
+                EXPONENT_BITS.div_ceil(bits_per_block) as usize,
+            ),
+            mantissa: integer_key.encrypt_radix(
+                bits & ((1 << MANTISSA_BITS) - 1),
+                MANTISSA_BITS.div_ceil(bits_per_block) as usize,
+            ),
+        })
+    }
+}
+
+impl FheDecrypt<f32> for FheFloat32 {
+    fn decrypt(&self, key: &ClientKey) -> f32 {
+        let integer_key = &key.key.key;
+        let sign = integer_key.decrypt_bool(&self.sign);
+        let exponent: u32 = integer_key.decrypt_radix(&self.exponent);
+        let mantissa: u32 = integer_key.decrypt_radix(&self.mantissa);
+        f32::from_bits((u32::from(sign) << 31) | (exponent << MANTISSA_BITS) | mantissa)
+    }
+}
+
+impl FheEq<&Self> for FheFloat32 {
+    fn eq(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::eq_parallelized)
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        let is_eq = self.eq(other);
+        !is_eq
+    }
+}
+
+impl FheOrd<&Self> for FheFloat32 {
+    fn lt(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::lt_parallelized)
+    }
+
+    fn le(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::le_parallelized)
+    }
+
+    fn gt(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::gt_parallelized)
+    }
+
+    fn ge(&self, other: &Self) -> FheBool {
+        self.compare(other, ServerKey::ge_parallelized)
+    }
+}
+
+impl IfThenElse<FheFloat32> for FheBool {
+    fn if_then_else(&self, ct_then: &FheFloat32, ct_else: &FheFloat32) -> FheFloat32 {
+        with_cpu_server_key(|sks| {
+            let condition = self.ciphertext.on_cpu();
+            let ((exponent, mantissa), sign) = rayon::join(
+                || {
+                    rayon::join(
+                        || {
+                            sks.if_then_else_parallelized(
+                                &condition,
+                                &ct_then.exponent,
+                                &ct_else.exponent,
+                            )
+                        },
+                        || {
+                            sks.if_then_else_parallelized(
+                                &condition,
+                                &ct_then.mantissa,
+                                &ct_else.mantissa,
+                            )
+                        },
+                    )
+                },
+                || select_boolean(sks, &condition, &ct_then.sign, &ct_else.sign),
+            );
+            FheFloat32 {
+                sign,
+                exponent,
+                mantissa,
+            }
+        })
+    }
+}
+
+impl<Id: FheIntId> CastFrom<FheInt<Id>> for FheFloat32 {
+    /// Cast a FheInt to a FheFloat32, rounding it to the nearest number
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32, FheInt32};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt32::encrypt(-16777217i32, &client_key);
+    /// let b = FheFloat32::cast_from(a);
+    ///
+    /// let decrypted: f32 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -16777217i32 as f32);
+    /// ```
+    fn cast_from(input: FheInt<Id>) -> Self {
+        with_cpu_server_key(|sks| Self::from_integer(sks, &input.ciphertext.into_cpu()))
+    }
+}
+
+impl<Id: FheUintId> CastFrom<FheUint<Id>> for FheFloat32 {
+    /// Cast a FheUint to a FheFloat32, rounding it to the nearest number
+    fn cast_from(input: FheUint<Id>) -> Self {
+        with_cpu_server_key(|sks| Self::from_integer(sks, &input.ciphertext.into_cpu()))
+    }
+}
+
+impl<Id: FheIntId> CastFrom<FheFloat32> for FheInt<Id> {
+    /// Cast a FheFloat32 to a FheInt, rounding it towards 0
+    ///
+    /// As with the `as` conversions of Rust, the values which do not fit saturate to the minimum
+    /// or maximum of the type, and NaN is converted to 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat32, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat32::encrypt(-1234.75f32, &client_key);
+    /// let b = FheInt16::cast_from(a);
+    ///
+    /// let decrypted: i16 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -1234);
+    /// ```
+    fn cast_from(input: FheFloat32) -> Self {
+        with_cpu_server_key(|sks| {
+            let num_blocks = Id::num_blocks(sks.message_modulus());
+            Self::new(input.to_integer::<SignedRadixCiphertext>(sks, num_blocks))
+        })
+    }
+}
+
+impl<Id: FheUintId> CastFrom<FheFloat32> for FheUint<Id> {
+    /// Cast a FheFloat32 to a FheUint, rounding it towards 0
+    ///
+    /// As with the `as` conversions of Rust, the values which do not fit saturate to 0 or to the
+    /// maximum of the type, and NaN is converted to 0.
+    fn cast_from(input: FheFloat32) -> Self {
+        with_cpu_server_key(|sks| {
+            let num_blocks = Id::num_blocks(sks.message_modulus());
+            Self::new(input.to_integer::<RadixCiphertext>(sks, num_blocks))
+        })
+    }
+}
+
+macro_rules! impl_float_binary_op {
+    ($trait_name:ident, $trait_method:ident, $implementation:ident) => {
+        impl $trait_name<&FheFloat32> for &FheFloat32 {
+            type Output = FheFloat32;
+
+            fn $trait_method(self, rhs: &FheFloat32) -> Self::Output {
+                with_cpu_server_key(|sks| self.$implementation(rhs, sks))
+            }
+        }
+
+        impl $trait_name<FheFloat32> for FheFloat32 {
+            type Output = Self;
+
+            fn $trait_method(self, rhs: Self) -> Self::Output {
+                <&Self as $trait_name<&Self>>::$trait_method(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_float_binary_op!(Add, add, add_impl);
+impl_float_binary_op!(Mul, mul, mul_impl);
+impl_float_binary_op!(Div, div, div_impl);
+
+impl Sub<&FheFloat32> for &FheFloat32 {
+    type Output = FheFloat32;
+
+    fn sub(self, rhs: &FheFloat32) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl Sub<Self> for FheFloat32 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Neg for &FheFloat32 {
+    type Output = FheFloat32;
+
+    fn neg(self) -> Self::Output {
+        with_cpu_server_key(|sks| FheFloat32 {
+            sign: sks.boolean_bitnot(&self.sign),
+            exponent: self.exponent.clone(),
+            mantissa: self.mantissa.clone(),
+        })
+    }
+}
+
+impl Neg for FheFloat32 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+fn with_cpu_server_key<R>(func: impl FnOnce(&ServerKey) -> R) -> R {
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(&cpu_key.key),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support FheFloat32 yet")
+        }
+    })
+}
+
+fn bits_per_block(sks: &ServerKey) -> u32 {
+    sks.message_modulus().0.ilog2()
+}
+
+fn num_blocks_of(sks: &ServerKey, num_bits: u32) -> usize {
+    num_bits.div_ceil(bits_per_block(sks)) as usize
+}
+
+fn num_blocks_of_exponent(sks: &ServerKey) -> usize {
+    num_blocks_of(sks, EXPONENT_BITS)
+}
+
+fn num_blocks_of_mantissa(sks: &ServerKey) -> usize {
+    num_blocks_of(sks, MANTISSA_BITS)
+}
+
+/// Shifts a significand so that its leading one is at the position of the implicit bit,
+/// adjusting the exponent accordingly
+fn normalize(
+    sks: &ServerKey,
+    significand: &RadixCiphertext,
+    exponent: &SignedRadixCiphertext,
+) -> (RadixCiphertext, SignedRadixCiphertext) {
+    let width = significand.blocks.len() as u32 * bits_per_block(sks);
+    let leading_zeros = sks.leading_zeros_parallelized(significand);
+    // The significand of 0 is not shifted by more than its number of bits
+    let shift = sks.scalar_min_parallelized(
+        &sks.scalar_sub_parallelized(&leading_zeros, width - SIGNIFICAND_BITS),
+        MANTISSA_BITS,
+    );
+    rayon::join(
+        || {
+            let shift = sks.cast_to_unsigned(shift.clone(), significand.blocks.len());
+            sks.left_shift_parallelized(significand, &shift)
+        },
+        || {
+            let shift = sks.cast_to_signed(shift.clone(), exponent.blocks.len());
+            sks.sub_parallelized(exponent, &shift)
+        },
+    )
+}
+
+/// Returns `then` if `condition` is true, `otherwise` if not
+fn select_boolean(
+    sks: &ServerKey,
+    condition: &BooleanBlock,
+    then: &BooleanBlock,
+    otherwise: &BooleanBlock,
+) -> BooleanBlock {
+    let (then, otherwise) = rayon::join(
+        || sks.boolean_bitand(condition, then),
+        || sks.boolean_bitand(&sks.boolean_bitnot(condition), otherwise),
+    );
+    sks.boolean_bitor(&then, &otherwise)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high_level_api::integers::setup_default_cpu;
+    use crate::prelude::*;
+    use crate::{FheInt16, FheInt32, FheUint8};
+
+    fn assert_same_float(result: f32, expected: f32) {
+        if expected.is_nan() {
+            assert!(result.is_nan(), "expected NaN, got {result}");
+        } else {
+            assert_eq!(
+                result.to_bits(),
+                expected.to_bits(),
+                "expected {expected:e}, got {result:e}"
+            );
+        }
+    }
+
+    fn check_binary_op(
+        client_key: &ClientKey,
+        values: &[(f32, f32)],
+        fhe_op: impl Fn(&FheFloat32, &FheFloat32) -> FheFloat32,
+        clear_op: impl Fn(f32, f32) -> f32,
+    ) {
+        for &(lhs, rhs) in values {
+            let a = FheFloat32::encrypt(lhs, client_key);
+            let b = FheFloat32::encrypt(rhs, client_key);
+            let decrypted: f32 = fhe_op(&a, &b).decrypt(client_key);
+            assert_same_float(decrypted, clear_op(lhs, rhs));
+        }
+    }
+
+    #[test]
+    fn test_fhe_float_encrypt_decrypt() {
+        let client_key = setup_default_cpu();
+
+        for value in [
+            0.0f32,
+            -0.0,
+            1.5,
+            -3.75e-3,
+            f32::MAX,
+            f32::MIN_POSITIVE,
+            1e-40,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ] {
+            let a = FheFloat32::encrypt(value, &client_key);
+            let decrypted: f32 = a.decrypt(&client_key);
+            assert_same_float(decrypted, value);
+
+            let decrypted: f32 = (-&a).decrypt(&client_key);
+            assert_same_float(decrypted, -value);
+            let decrypted: f32 = a.abs().decrypt(&client_key);
+            assert_same_float(decrypted, value.abs());
+        }
+    }
+
+    #[test]
+    fn test_fhe_float_add_sub() {
+        let client_key = setup_default_cpu();
+
+        let values = [
+            (1.5f32, 2.25f32),
+            (1.0, 1e-8),
+            (16777216.0, 1.0),
+            (3.0, -3.0),
+            (-0.0, -0.0),
+            (1e-40, 2e-40),
+            (f32::MIN_POSITIVE, -1e-40),
+            (f32::MAX, f32::MAX),
+            (f32::INFINITY, f32::NEG_INFINITY),
+        ];
+        check_binary_op(&client_key, &values, |a, b| a + b, |a, b| a + b);
+
+        let values = [(0.1f32, 0.3f32), (1.0, 0.99999994), (-2.5, f32::NAN)];
+        check_binary_op(&client_key, &values, |a, b| a - b, |a, b| a - b);
+    }
+
+    #[test]
+    fn test_fhe_float_mul() {
+        let client_key = setup_default_cpu();
+
+        let values = [
+            (1.5f32, -2.75f32),
+            (0.1, 0.3),
+            (1e-20, 1e-20),
+            (1e-30, 1e-10),
+            (3e38, 2.0),
+            (-0.0, 5.0),
+            (0.0, f32::INFINITY),
+        ];
+        check_binary_op(&client_key, &values, |a, b| a * b, |a, b| a * b);
+    }
+
+    #[test]
+    fn test_fhe_float_div() {
+        let client_key = setup_default_cpu();
+
+        let values = [
+            (1.0f32, 3.0f32),
+            (-7.5, 0.1),
+            (1e-40, 3.0),
+            (1e-45, 2.0),
+            (1e38, 1e-5),
+            (1.0, 0.0),
+            (0.0, 0.0),
+            (-2.0, f32::INFINITY),
+        ];
+        check_binary_op(&client_key, &values, |a, b| a / b, |a, b| a / b);
+    }
+
+    #[test]
+    fn test_fhe_float_comparisons() {
+        let client_key = setup_default_cpu();
+
+        for (lhs, rhs) in [
+            (1.0f32, 2.5f32),
+            (-0.0, 0.0),
+            (-1e-40, -3.0),
+            (f32::NAN, 1.0),
+        ] {
+            let a = FheFloat32::encrypt(lhs, &client_key);
+            let b = FheFloat32::encrypt(rhs, &client_key);
+
+            assert_eq!(a.eq(&b).decrypt(&client_key), lhs == rhs);
+            assert_eq!(a.ne(&b).decrypt(&client_key), lhs != rhs);
+            assert_eq!(a.lt(&b).decrypt(&client_key), lhs < rhs);
+            assert_eq!(a.le(&b).decrypt(&client_key), lhs <= rhs);
+            assert_eq!(a.gt(&b).decrypt(&client_key), lhs > rhs);
+            assert_eq!(a.ge(&b).decrypt(&client_key), lhs >= rhs);
+            assert_eq!(a.is_nan().decrypt(&client_key), lhs.is_nan());
+
+            let decrypted: f32 = a.lt(&b).if_then_else(&a, &b).decrypt(&client_key);
+            assert_same_float(decrypted, if lhs < rhs { lhs } else { rhs });
+        }
+    }
+
+    #[test]
+    fn test_fhe_float_integer_casts() {
+        let client_key = setup_default_cpu();
+
+        for value in [0i32, -5, 16777217, i32::MIN, i32::MAX] {
+            let a = FheInt32::encrypt(value, &client_key);
+            let decrypted: f32 = FheFloat32::cast_from(a).decrypt(&client_key);
+            assert_same_float(decrypted, value as f32);
+        }
+
+        for value in [3.7f32, -3.7, -32768.5, 1e10, -0.0, 1e-40, f32::NAN] {
+            let a = FheFloat32::encrypt(value, &client_key);
+            let decrypted: i16 = FheInt16::cast_from(a.clone()).decrypt(&client_key);
+            assert_eq!(decrypted, value as i16, "{value}");
+            let decrypted: u8 = FheUint8::cast_from(a).decrypt(&client_key);
+            assert_eq!(decrypted, value as u8, "{value}");
+        }
+
+        let a = FheUint8::encrypt(255u8, &client_key);
+        let decrypted: f32 = FheFloat32::cast_from(a).decrypt(&client_key);
+        assert_eq!(decrypted, 255.0);
+    }
+
+    #[test]
+    fn test_fhe_float_safe_serialization() {
+        let client_key = setup_default_cpu();
+
+        for value in [-1.5f32, f32::INFINITY, f32::NAN] {
+            let a = FheFloat32::encrypt(value, &client_key);
+
+            let mut serialized = vec![];
+            crate::safe_serialize(&a, &mut serialized, 1 << 20).unwrap();
+            let a: FheFloat32 =
+                crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20)
+                    .unwrap();
+            let decrypted: f32 = a.decrypt(&client_key);
+            assert_same_float(decrypted, value);
+        }
+    }
+}
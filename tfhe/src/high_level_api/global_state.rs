@@ -1,19 +1,101 @@
 //! In this module, we store the hidden (to the end-user) internal state/keys that are needed to
 //! perform operations.
+//!
+//! Keys can be set for the current thread only ([set_server_key]), or for the whole process
+//! ([set_global_server_key]). When both are set, the keys of the current thread take precedence
+//! over the keys of the process.
 #[cfg(feature = "gpu")]
 use crate::core_crypto::gpu::{CudaDevice, CudaStream};
 use crate::high_level_api::errors::{UninitializedServerKey, UnwrapResultExt};
 use crate::high_level_api::keys::{IntegerServerKey, InternalServerKey, ServerKey};
+use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 /// We store the internal keys as thread local, meaning each thread has its own set of keys.
 ///
 /// This means that the user can do computations in multiple threads
 /// (eg a web server that processes multiple requests in multiple threads).
-/// The user however, has to initialize the internal keys each time it starts a thread.
+/// The user however, has to initialize the internal keys each time it starts a thread,
+/// unless keys were set for the whole process.
 thread_local! {
     static INTERNAL_KEYS: RefCell<Option<InternalServerKey>> = const { RefCell::new(None) };
 }
 
+/// The keys used by threads which do not have keys of their own.
+///
+/// The keys are reference counted, so each thread shares them instead of holding a copy.
+static GLOBAL_INTERNAL_KEYS: RwLock<Option<InternalServerKey>> = RwLock::new(None);
+
+/// The maximum number of thread pools kept by [with_server_key_in_rayon_scope]
+const MAX_RAYON_SCOPE_POOLS: usize = 4;
+
+/// The thread pools of [with_server_key_in_rayon_scope], so that calls with the same keys do not
+/// create new threads.
+///
+/// A pool is kept until [release_rayon_scope_pool] is called with its keys, or until it is the
+/// least recently used one and a pool is needed for other keys.
+static RAYON_SCOPE_POOLS: OnceLock<Mutex<RayonScopePools>> = OnceLock::new();
+
+#[derive(Default)]
+struct RayonScopePools {
+    /// The pools, with the keys their threads use, by [keys_id] of the keys
+    pools: HashMap<usize, RayonScopePool>,
+    num_uses: u64,
+}
+
+struct RayonScopePool {
+    // Kept so that the allocation of the keys, identifying the pool, is not reused
+    _keys: InternalServerKey,
+    pool: Arc<ThreadPool>,
+    last_use: u64,
+}
+
+impl RayonScopePools {
+    /// Returns the pool for the given keys, with the given number of threads, creating it if
+    /// needed
+    fn get_or_create(&mut self, keys: InternalServerKey, num_threads: usize) -> Arc<ThreadPool> {
+        self.num_uses += 1;
+        let id = keys_id(&keys);
+        if let Some(entry) = self.pools.get_mut(&id) {
+            if entry.pool.current_num_threads() == num_threads {
+                entry.last_use = self.num_uses;
+                return Arc::clone(&entry.pool);
+            }
+        }
+
+        if !self.pools.contains_key(&id) && self.pools.len() >= MAX_RAYON_SCOPE_POOLS {
+            let least_recently_used = self
+                .pools
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_use)
+                .map(|(id, _)| *id)
+                .unwrap();
+            self.pools.remove(&least_recently_used);
+        }
+
+        let pool = Arc::new(
+            thread_pool_builder_with_keys(keys.clone())
+                .num_threads(num_threads)
+                .build()
+                .expect("Failed to build the thread pool"),
+        );
+        self.pools.insert(
+            id,
+            RayonScopePool {
+                _keys: keys,
+                pool: Arc::clone(&pool),
+                last_use: self.num_uses,
+            },
+        );
+        pool
+    }
+}
+
+fn rayon_scope_pools() -> &'static Mutex<RayonScopePools> {
+    RAYON_SCOPE_POOLS.get_or_init(Mutex::default)
+}
+
 /// The function used to initialize internal keys.
 ///
 /// As each thread has its own set of keys,
@@ -69,6 +151,50 @@ pub fn unset_server_key() {
     })
 }
 
+/// Sets the keys used by all the threads of the process which do not have keys of their own.
+///
+/// Keys set on a thread, with [set_server_key] for example, take precedence over the keys set
+/// with this function.
+///
+/// # Example
+///
+/// ```rust
+/// use std::thread;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// tfhe::set_global_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+///
+/// // No key was set on the spawned thread, it uses the keys of the process
+/// let c = thread::spawn(move || a + b).join().unwrap();
+///
+/// let decrypted: u8 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 127);
+///
+/// tfhe::unset_global_server_key();
+/// ```
+pub fn set_global_server_key<T: Into<InternalServerKey>>(keys: T) {
+    *GLOBAL_INTERNAL_KEYS.write().unwrap() = Some(keys.into());
+}
+
+/// Unsets the keys set with [set_global_server_key].
+///
+/// Threads which do not have keys of their own can no longer do operations on homomorphic
+/// types, the keys set on a thread are not affected.
+pub fn unset_global_server_key() {
+    *GLOBAL_INTERNAL_KEYS.write().unwrap() = None;
+}
+
+fn global_internal_keys() -> Option<InternalServerKey> {
+    GLOBAL_INTERNAL_KEYS.read().unwrap().clone()
+}
+
 pub fn with_server_key_as_context<T, F>(keys: ServerKey, f: F) -> T
 where
     F: FnOnce() -> T,
@@ -79,6 +205,92 @@ where
     result
 }
 
+/// Runs the function in a rayon thread pool in which every thread uses the given keys.
+///
+/// Contrary to [with_server_key_as_context], the keys are also used by the rayon tasks spawned
+/// inside the function (e.g. with `rayon::join` or parallel iterators), without having to set
+/// them on each thread of the pool. As the keys are set on each thread of the pool, they take
+/// precedence over the keys of the process.
+///
+/// The thread pool has as many threads as the current one. It is kept for the next calls with
+/// the same keys (i.e. clones of the same key), until [release_rayon_scope_pool] is called.
+/// At most 4 pools are kept: when keys which have no pool are used, the pool which has been
+/// used the least recently is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use rayon::prelude::*;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, with_server_key_in_rayon_scope, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::default().build();
+/// let (client_key, server_key) = generate_keys(config);
+///
+/// let values = [1u8, 2, 3, 4];
+/// let encrypted = values
+///     .iter()
+///     .map(|v| FheUint8::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let doubled = with_server_key_in_rayon_scope(server_key.clone(), || {
+///     encrypted.par_iter().map(|v| v + v).collect::<Vec<_>>()
+/// });
+///
+/// for (clear, encrypted) in values.iter().zip(doubled.iter()) {
+///     let decrypted: u8 = encrypted.decrypt(&client_key);
+///     assert_eq!(decrypted, clear * 2);
+/// }
+///
+/// tfhe::release_rayon_scope_pool(server_key);
+/// ```
+pub fn with_server_key_in_rayon_scope<T, R, F>(keys: T, func: F) -> R
+where
+    T: Into<InternalServerKey>,
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let keys = keys.into();
+    let num_threads = rayon::current_num_threads();
+
+    let pool = rayon_scope_pools()
+        .lock()
+        .unwrap()
+        .get_or_create(keys, num_threads);
+    // The lock is released, so that the function can itself create a rayon scope
+    pool.install(func)
+}
+
+/// Drops the thread pool kept by [with_server_key_in_rayon_scope] for the given keys.
+///
+/// The threads of the pool stop once the calls which are still using it return.
+pub fn release_rayon_scope_pool<T: Into<InternalServerKey>>(keys: T) {
+    let keys = keys.into();
+    rayon_scope_pools()
+        .lock()
+        .unwrap()
+        .pools
+        .remove(&keys_id(&keys));
+}
+
+/// Identifies the keys, clones of the same key having the same id
+fn keys_id(keys: &InternalServerKey) -> usize {
+    match keys {
+        InternalServerKey::Cpu(key) => Arc::as_ptr(key).cast::<()>() as usize,
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(key) => Arc::as_ptr(&key.key).cast::<()>() as usize,
+    }
+}
+
+/// Returns a builder of thread pools whose threads use the given keys
+pub(in crate::high_level_api) fn thread_pool_builder_with_keys(
+    keys: InternalServerKey,
+) -> ThreadPoolBuilder {
+    ThreadPoolBuilder::new()
+        .start_handler(move |_| set_server_key(keys.clone()))
+        .exit_handler(|_| unset_server_key())
+}
+
 /// Convenience function that allows to write functions that needs to access the internal keys
 ///
 /// # Panics
@@ -104,18 +316,18 @@ where
     // Should use `with_borrow` when its stabilized
     INTERNAL_KEYS.with(|keys| {
         let maybe_key = &*keys.borrow();
-        let key = maybe_key.as_ref();
-        func(key)
+        match maybe_key.as_ref() {
+            Some(key) => func(Some(key)),
+            None => func(global_internal_keys().as_ref()),
+        }
     })
 }
 
 #[cfg(feature = "gpu")]
 #[inline]
 pub(in crate::high_level_api) fn device_of_internal_keys() -> Option<crate::Device> {
-    // Should use `with_borrow` when its stabilized
-    INTERNAL_KEYS.with(|keys| {
-        let cell = keys.borrow();
-        Some(match cell.as_ref()? {
+    try_with_internal_keys(|maybe_key| {
+        Some(match maybe_key? {
             InternalServerKey::Cpu(_) => crate::Device::Cpu,
             #[cfg(feature = "gpu")]
             InternalServerKey::Cuda(_) => crate::Device::CudaGpu,
@@ -128,13 +340,8 @@ pub(crate) fn with_cpu_internal_keys<T, F>(func: F) -> T
 where
     F: FnOnce(&IntegerServerKey) -> T,
 {
-    // Should use `with_borrow` when its stabilized
-    INTERNAL_KEYS.with(|keys| {
-        let maybe_key = &*keys.borrow();
-        let key = maybe_key
-            .as_ref()
-            .ok_or(UninitializedServerKey)
-            .unwrap_display();
+    try_with_internal_keys(|maybe_key| {
+        let key = maybe_key.ok_or(UninitializedServerKey).unwrap_display();
         match key {
             InternalServerKey::Cpu(key) => func(key),
             #[cfg(feature = "gpu")]
@@ -0,0 +1,1028 @@
+//! This module defines [FheUintDyn] and [FheIntDyn], encrypted integers whose number of bits is
+//! chosen when they are encrypted instead of at compile time.
+//!
+//! As the width is only known at runtime, the operations taking two ciphertexts return an
+//! error when the widths of the operands differ, instead of panicking.
+use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId, IntegerId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::traits::{
+    DivRem, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateRight,
+};
+use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
+use crate::integer::ciphertext::IntegerCiphertext;
+use crate::integer::client_key::RecomposableSignedInteger;
+use crate::integer::{
+    IntegerRadixCiphertext, RadixCiphertext, ServerKey, SignedRadixCiphertext, I2048, I256, U2048,
+    U256,
+};
+use crate::named::Named;
+use crate::shortint::MessageModulus;
+use crate::{ClientKey, FheBool, FheInt, FheUint, FheUint32, FheUint32Id};
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+
+/// An encrypted unsigned integer whose number of bits is chosen at encryption time
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUintDyn};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUintDyn::try_encrypt(200u32, 24, &client_key).unwrap();
+/// let b = FheUintDyn::try_encrypt(100u32, 24, &client_key).unwrap();
+/// assert_eq!(a.num_bits(), 24);
+///
+/// let c = (&a + &b).unwrap();
+/// let decrypted: u32 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 300);
+///
+/// // Operands of different widths are rejected
+/// let d = FheUintDyn::try_encrypt(100u32, 16, &client_key).unwrap();
+/// assert!((&a + &d).is_err());
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "DynIntegerParts<RadixCiphertext>")]
+pub struct FheUintDyn {
+    ciphertext: RadixCiphertext,
+    num_bits: u32,
+}
+
+/// An encrypted signed integer whose number of bits is chosen at encryption time
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheIntDyn};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheIntDyn::try_encrypt(-200i32, 24, &client_key).unwrap();
+/// let b = FheIntDyn::try_encrypt(50i32, 24, &client_key).unwrap();
+///
+/// let c = (&a * &b).unwrap();
+/// let decrypted: i32 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, -10_000);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "DynIntegerParts<SignedRadixCiphertext>")]
+pub struct FheIntDyn {
+    ciphertext: SignedRadixCiphertext,
+    num_bits: u32,
+}
+
+/// What a runtime-width integer is deserialized from, before its width gets checked
+#[derive(Deserialize)]
+struct DynIntegerParts<C> {
+    ciphertext: C,
+    num_bits: u32,
+}
+
+/// The maximum number of bits of a runtime-width integer, the width of [crate::FheUint2048]
+const MAX_NUM_BITS: u32 = 2048;
+
+/// Returns the number of blocks needed to store `num_bits` bits
+pub(crate) fn num_blocks_for_bits(
+    num_bits: u32,
+    message_modulus: MessageModulus,
+) -> crate::Result<usize> {
+    if num_bits > MAX_NUM_BITS {
+        return Err(crate::Error::new(format!(
+            "The number of bits ({num_bits}) must be at most {MAX_NUM_BITS}"
+        )));
+    }
+    let bits_per_block = message_modulus.0.ilog2();
+    if num_bits == 0 || num_bits % bits_per_block != 0 {
+        return Err(crate::Error::new(format!(
+            "The number of bits ({num_bits}) must be a non-zero multiple of the number of \
+            bits per block ({bits_per_block})"
+        )));
+    }
+    Ok((num_bits / bits_per_block) as usize)
+}
+
+fn check_same_width(lhs: u32, rhs: u32) -> crate::Result<()> {
+    if lhs != rhs {
+        return Err(crate::Error::new(format!(
+            "The operands must have the same number of bits, got {lhs} and {rhs} bits"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `num_bits` matches the blocks of the ciphertext
+fn check_ciphertext_width<C: IntegerRadixCiphertext>(
+    ciphertext: &C,
+    num_bits: u32,
+) -> crate::Result<()> {
+    let blocks = ciphertext.blocks();
+    let Some(first_block) = blocks.first() else {
+        return Err(crate::Error::new(
+            "A runtime-width integer must have at least one block".to_string(),
+        ));
+    };
+    let actual_num_bits = blocks.len() as u32 * first_block.message_modulus.0.ilog2();
+    check_same_width(actual_num_bits, num_bits)
+}
+
+fn with_cpu_server_key<R>(func: impl FnOnce(&ServerKey) -> R) -> R {
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(cpu_key.pbs_key()),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support runtime-width integers yet")
+        }
+    })
+}
+
+/// Implements everything that does not depend on the signedness of the integer
+macro_rules! impl_dyn_integer {
+    (
+        name: $name:ident,
+        ciphertext: $ciphertext:ty,
+        static_type: $static_type:ident,
+        static_id: $static_id:ident,
+        cast_fn: $cast_fn:ident,
+        scalar_types: [$($scalar_type:ty),* $(,)?],
+        shift_types: [$($shift_type:ty),* $(,)?],
+        scalar_div: $scalar_div:ident,
+        scalar_rem: $scalar_rem:ident $(,)?
+    ) => {
+        impl Named for $name {
+            const NAME: &'static str = concat!("high_level_api::", stringify!($name));
+        }
+
+        impl TryFrom<DynIntegerParts<$ciphertext>> for $name {
+            type Error = crate::Error;
+
+            fn try_from(parts: DynIntegerParts<$ciphertext>) -> Result<Self, Self::Error> {
+                check_ciphertext_width(&parts.ciphertext, parts.num_bits)?;
+                Ok(Self {
+                    ciphertext: parts.ciphertext,
+                    num_bits: parts.num_bits,
+                })
+            }
+        }
+
+        impl $name {
+            /// The maximum number of bits of the integer
+            pub const MAX_NUM_BITS: u32 = MAX_NUM_BITS;
+
+            /// Returns the number of bits of the integer
+            pub fn num_bits(&self) -> u32 {
+                self.num_bits
+            }
+
+            /// Returns a copy of the integer with `num_bits` bits
+            ///
+            /// The value is truncated when the width shrinks, and extended (with its sign if it
+            /// is signed) when it grows.
+            ///
+            /// Returns an error if the number of bits is not a multiple of the number of bits
+            /// per block, or if it is greater than [Self::MAX_NUM_BITS].
+            pub fn cast_to_width(&self, num_bits: u32) -> crate::Result<Self> {
+                with_cpu_server_key(|sks| {
+                    let num_blocks = num_blocks_for_bits(num_bits, sks.message_modulus())?;
+                    let ciphertext = sks.$cast_fn(self.ciphertext.clone(), num_blocks);
+                    Ok(Self {
+                        ciphertext,
+                        num_bits,
+                    })
+                })
+            }
+
+            /// Selects `ct_then` if `condition` is true, `ct_else` otherwise
+            ///
+            /// Returns an error if the two branches do not have the same width.
+            pub fn if_then_else(
+                condition: &FheBool,
+                ct_then: &Self,
+                ct_else: &Self,
+            ) -> crate::Result<Self> {
+                check_same_width(ct_then.num_bits, ct_else.num_bits)?;
+                let ciphertext = with_cpu_server_key(|sks| {
+                    sks.if_then_else_parallelized(
+                        &condition.ciphertext.on_cpu(),
+                        &ct_then.ciphertext,
+                        &ct_else.ciphertext,
+                    )
+                });
+                Ok(Self {
+                    ciphertext,
+                    num_bits: ct_then.num_bits,
+                })
+            }
+
+            /// Returns the number of leading zeros in the binary representation
+            pub fn leading_zeros(&self) -> FheUint32 {
+                self.count_to_fhe_uint32(|sks, ct| sks.leading_zeros_parallelized(ct))
+            }
+
+            /// Returns the number of trailing zeros in the binary representation
+            pub fn trailing_zeros(&self) -> FheUint32 {
+                self.count_to_fhe_uint32(|sks, ct| sks.trailing_zeros_parallelized(ct))
+            }
+
+            /// Returns the number of ones in the binary representation
+            pub fn count_ones(&self) -> FheUint32 {
+                self.count_to_fhe_uint32(|sks, ct| sks.count_ones_parallelized(ct))
+            }
+
+            /// Returns the base 2 logarithm of the number, rounded down
+            ///
+            /// The result is meaningless for values smaller than or equal to 0.
+            pub fn ilog2(&self) -> FheUint32 {
+                self.count_to_fhe_uint32(|sks, ct| sks.ilog2_parallelized(ct))
+            }
+
+            fn count_to_fhe_uint32(
+                &self,
+                func: impl FnOnce(&ServerKey, &$ciphertext) -> RadixCiphertext,
+            ) -> FheUint32 {
+                with_cpu_server_key(|sks| {
+                    let result = func(sks, &self.ciphertext);
+                    let result = sks.cast_to_unsigned(
+                        result,
+                        FheUint32Id::num_blocks(sks.message_modulus()),
+                    );
+                    FheUint32::new(result)
+                })
+            }
+
+            fn binary_op(
+                &self,
+                rhs: &Self,
+                func: impl FnOnce(&ServerKey, &$ciphertext, &$ciphertext) -> $ciphertext,
+            ) -> crate::Result<Self> {
+                check_same_width(self.num_bits, rhs.num_bits)?;
+                let ciphertext =
+                    with_cpu_server_key(|sks| func(sks, &self.ciphertext, &rhs.ciphertext));
+                Ok(Self {
+                    ciphertext,
+                    num_bits: self.num_bits,
+                })
+            }
+
+            fn comparison(
+                &self,
+                rhs: &Self,
+                func: impl FnOnce(
+                    &ServerKey,
+                    &$ciphertext,
+                    &$ciphertext,
+                ) -> crate::integer::BooleanBlock,
+            ) -> crate::Result<FheBool> {
+                check_same_width(self.num_bits, rhs.num_bits)?;
+                let result =
+                    with_cpu_server_key(|sks| func(sks, &self.ciphertext, &rhs.ciphertext));
+                Ok(FheBool::new(result))
+            }
+
+            fn map(&self, func: impl FnOnce(&ServerKey, &$ciphertext) -> $ciphertext) -> Self {
+                let ciphertext = with_cpu_server_key(|sks| func(sks, &self.ciphertext));
+                Self {
+                    ciphertext,
+                    num_bits: self.num_bits,
+                }
+            }
+
+            /// Tests for equality, returns an error if the widths differ
+            pub fn try_eq(&self, rhs: &Self) -> crate::Result<FheBool> {
+                self.comparison(rhs, |sks, lhs, rhs| sks.eq_parallelized(lhs, rhs))
+            }
+
+            /// Tests for difference, returns an error if the widths differ
+            pub fn try_ne(&self, rhs: &Self) -> crate::Result<FheBool> {
+                self.comparison(rhs, |sks, lhs, rhs| sks.ne_parallelized(lhs, rhs))
+            }
+
+            /// Tests for less than, returns an error if the widths differ
+            pub fn try_lt(&self, rhs: &Self) -> crate::Result<FheBool> {
+                self.comparison(rhs, |sks, lhs, rhs| sks.lt_parallelized(lhs, rhs))
+            }
+
+            /// Tests for less than or equal, returns an error if the widths differ
+            pub fn try_le(&self, rhs: &Self) -> crate::Result<FheBool> {
+                self.comparison(rhs, |sks, lhs, rhs| sks.le_parallelized(lhs, rhs))
+            }
+
+            /// Tests for greater than, returns an error if the widths differ
+            pub fn try_gt(&self, rhs: &Self) -> crate::Result<FheBool> {
+                self.comparison(rhs, |sks, lhs, rhs| sks.gt_parallelized(lhs, rhs))
+            }
+
+            /// Tests for greater than or equal, returns an error if the widths differ
+            pub fn try_ge(&self, rhs: &Self) -> crate::Result<FheBool> {
+                self.comparison(rhs, |sks, lhs, rhs| sks.ge_parallelized(lhs, rhs))
+            }
+        }
+
+        impl<Id: $static_id> From<$static_type<Id>> for $name {
+            fn from(value: $static_type<Id>) -> Self {
+                Self {
+                    ciphertext: value.ciphertext.into_cpu(),
+                    num_bits: Id::num_bits() as u32,
+                }
+            }
+        }
+
+        impl<Id: $static_id> TryFrom<$name> for $static_type<Id> {
+            type Error = crate::Error;
+
+            /// Converts into the static type of the same width, `cast_to_width` has to be
+            /// used first to convert between widths
+            fn try_from(value: $name) -> Result<Self, Self::Error> {
+                check_same_width(value.num_bits, Id::num_bits() as u32)?;
+                Ok(Self::new(value.ciphertext))
+            }
+        }
+
+        impl FheMin<&Self> for $name {
+            type Output = crate::Result<Self>;
+
+            fn min(&self, other: &Self) -> Self::Output {
+                self.binary_op(other, |sks, lhs, rhs| sks.min_parallelized(lhs, rhs))
+            }
+        }
+
+        impl FheMax<&Self> for $name {
+            type Output = crate::Result<Self>;
+
+            fn max(&self, other: &Self) -> Self::Output {
+                self.binary_op(other, |sks, lhs, rhs| sks.max_parallelized(lhs, rhs))
+            }
+        }
+
+        impl<Clear: DecomposableInto<u64>> FheEq<Clear> for $name {
+            fn eq(&self, other: Clear) -> FheBool {
+                with_cpu_server_key(|sks| {
+                    FheBool::new(sks.scalar_eq_parallelized(&self.ciphertext, other))
+                })
+            }
+
+            fn ne(&self, other: Clear) -> FheBool {
+                with_cpu_server_key(|sks| {
+                    FheBool::new(sks.scalar_ne_parallelized(&self.ciphertext, other))
+                })
+            }
+        }
+
+        impl<Clear: DecomposableInto<u64>> FheOrd<Clear> for $name {
+            fn lt(&self, other: Clear) -> FheBool {
+                with_cpu_server_key(|sks| {
+                    FheBool::new(sks.scalar_lt_parallelized(&self.ciphertext, other))
+                })
+            }
+
+            fn le(&self, other: Clear) -> FheBool {
+                with_cpu_server_key(|sks| {
+                    FheBool::new(sks.scalar_le_parallelized(&self.ciphertext, other))
+                })
+            }
+
+            fn gt(&self, other: Clear) -> FheBool {
+                with_cpu_server_key(|sks| {
+                    FheBool::new(sks.scalar_gt_parallelized(&self.ciphertext, other))
+                })
+            }
+
+            fn ge(&self, other: Clear) -> FheBool {
+                with_cpu_server_key(|sks| {
+                    FheBool::new(sks.scalar_ge_parallelized(&self.ciphertext, other))
+                })
+            }
+        }
+
+        impl<Clear: DecomposableInto<u64>> FheMin<Clear> for $name {
+            type Output = Self;
+
+            fn min(&self, other: Clear) -> Self::Output {
+                self.map(|sks, ct| sks.scalar_min_parallelized(ct, other))
+            }
+        }
+
+        impl<Clear: DecomposableInto<u64>> FheMax<Clear> for $name {
+            type Output = Self;
+
+            fn max(&self, other: Clear) -> Self::Output {
+                self.map(|sks, ct| sks.scalar_max_parallelized(ct, other))
+            }
+        }
+
+        impl_dyn_binary_op!($name, Add, add, add_parallelized);
+        impl_dyn_binary_op!($name, Sub, sub, sub_parallelized);
+        impl_dyn_binary_op!($name, Mul, mul, mul_parallelized);
+        impl_dyn_binary_op!($name, BitAnd, bitand, bitand_parallelized);
+        impl_dyn_binary_op!($name, BitOr, bitor, bitor_parallelized);
+        impl_dyn_binary_op!($name, BitXor, bitxor, bitxor_parallelized);
+        impl_dyn_binary_op!($name, Div, div, div_parallelized);
+        impl_dyn_binary_op!($name, Rem, rem, rem_parallelized);
+
+        impl DivRem<&$name> for &$name {
+            type Output = crate::Result<($name, $name)>;
+
+            fn div_rem(self, rhs: &$name) -> Self::Output {
+                check_same_width(self.num_bits, rhs.num_bits)?;
+                let (quotient, remainder) = with_cpu_server_key(|sks| {
+                    sks.div_rem_parallelized(&self.ciphertext, &rhs.ciphertext)
+                });
+                Ok((
+                    $name {
+                        ciphertext: quotient,
+                        num_bits: self.num_bits,
+                    },
+                    $name {
+                        ciphertext: remainder,
+                        num_bits: self.num_bits,
+                    },
+                ))
+            }
+        }
+
+        // The amount of a shift or rotation is unsigned and can have any width
+        impl_dyn_shift_op!($name, Shl, shl, left_shift_parallelized);
+        impl_dyn_shift_op!($name, Shr, shr, right_shift_parallelized);
+        impl_dyn_shift_op!($name, RotateLeft, rotate_left, rotate_left_parallelized);
+        impl_dyn_shift_op!($name, RotateRight, rotate_right, rotate_right_parallelized);
+
+        impl Neg for &$name {
+            type Output = $name;
+
+            fn neg(self) -> Self::Output {
+                self.map(|sks, ct| sks.neg_parallelized(ct))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                -&self
+            }
+        }
+
+        impl Not for &$name {
+            type Output = $name;
+
+            fn not(self) -> Self::Output {
+                self.map(|sks, ct| sks.bitnot(ct))
+            }
+        }
+
+        impl Not for $name {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                !&self
+            }
+        }
+
+        $(
+            impl_dyn_scalar_op!($name, $scalar_type, Add, add, scalar_add_parallelized);
+            impl_dyn_scalar_op!($name, $scalar_type, Sub, sub, scalar_sub_parallelized);
+            impl_dyn_scalar_op!($name, $scalar_type, Mul, mul, scalar_mul_parallelized);
+            impl_dyn_scalar_op!($name, $scalar_type, BitAnd, bitand, scalar_bitand_parallelized);
+            impl_dyn_scalar_op!($name, $scalar_type, BitOr, bitor, scalar_bitor_parallelized);
+            impl_dyn_scalar_op!($name, $scalar_type, BitXor, bitxor, scalar_bitxor_parallelized);
+            impl_dyn_scalar_div_op!($name, $scalar_type, Div, div, $scalar_div);
+            impl_dyn_scalar_div_op!($name, $scalar_type, Rem, rem, $scalar_rem);
+        )*
+
+        $(
+            impl_dyn_scalar_op!($name, $shift_type, Shl, shl, scalar_left_shift_parallelized);
+            impl_dyn_scalar_op!($name, $shift_type, Shr, shr, scalar_right_shift_parallelized);
+            impl_dyn_scalar_op!(
+                $name,
+                $shift_type,
+                RotateLeft,
+                rotate_left,
+                scalar_rotate_left_parallelized
+            );
+            impl_dyn_scalar_op!(
+                $name,
+                $shift_type,
+                RotateRight,
+                rotate_right,
+                scalar_rotate_right_parallelized
+            );
+        )*
+    };
+}
+
+/// Implements an operation between two runtime-width integers, which fails if their widths
+/// differ
+macro_rules! impl_dyn_binary_op {
+    ($name:ident, $trait_name:ident, $trait_method:ident, $key_method:ident) => {
+        impl $trait_name<&$name> for &$name {
+            type Output = crate::Result<$name>;
+
+            fn $trait_method(self, rhs: &$name) -> Self::Output {
+                self.binary_op(rhs, |sks, lhs, rhs| sks.$key_method(lhs, rhs))
+            }
+        }
+
+        impl $trait_name<$name> for $name {
+            type Output = crate::Result<$name>;
+
+            fn $trait_method(self, rhs: $name) -> Self::Output {
+                <&$name as $trait_name<&$name>>::$trait_method(&self, &rhs)
+            }
+        }
+    };
+}
+
+/// Implements an operation whose right hand side is an unsigned runtime-width integer of any
+/// width
+macro_rules! impl_dyn_shift_op {
+    ($name:ident, $trait_name:ident, $trait_method:ident, $key_method:ident) => {
+        impl $trait_name<&FheUintDyn> for &$name {
+            type Output = $name;
+
+            fn $trait_method(self, amount: &FheUintDyn) -> Self::Output {
+                self.map(|sks, ct| {
+                    // The shifts of the server key expect the amount to have as many blocks as
+                    // the shifted value, only its low bits matter
+                    let amount = sks.cast_to_unsigned(amount.ciphertext.clone(), ct.blocks().len());
+                    sks.$key_method(ct, &amount)
+                })
+            }
+        }
+
+        impl $trait_name<FheUintDyn> for $name {
+            type Output = $name;
+
+            fn $trait_method(self, amount: FheUintDyn) -> Self::Output {
+                <&$name as $trait_name<&FheUintDyn>>::$trait_method(&self, &amount)
+            }
+        }
+    };
+}
+
+/// Implements an operation between a runtime-width integer and a clear value
+macro_rules! impl_dyn_scalar_op {
+    ($name:ident, $scalar_type:ty, $trait_name:ident, $trait_method:ident, $key_method:ident) => {
+        impl $trait_name<$scalar_type> for &$name {
+            type Output = $name;
+
+            fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                self.map(|sks, ct| sks.$key_method(ct, rhs))
+            }
+        }
+
+        impl $trait_name<$scalar_type> for $name {
+            type Output = $name;
+
+            fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                <&$name as $trait_name<$scalar_type>>::$trait_method(&self, rhs)
+            }
+        }
+    };
+}
+
+/// Implements a division by a scalar, the scalar is widened to [WidenScalar::Wide] when the
+/// ciphertext has more bits than the scalar type, as the division requires the scalar type to be
+/// at least as wide as the ciphertext
+macro_rules! impl_dyn_scalar_div_op {
+    ($name:ident, $scalar_type:ty, $trait_name:ident, $trait_method:ident, $key_method:ident) => {
+        impl $trait_name<$scalar_type> for &$name {
+            type Output = $name;
+
+            fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                self.map(|sks, ct| {
+                    let num_bits = sks.message_modulus().0.ilog2() * ct.blocks().len() as u32;
+                    if num_bits > <$scalar_type>::BITS {
+                        sks.$key_method(ct, rhs.widen_scalar())
+                    } else {
+                        sks.$key_method(ct, rhs)
+                    }
+                })
+            }
+        }
+
+        impl $trait_name<$scalar_type> for $name {
+            type Output = $name;
+
+            fn $trait_method(self, rhs: $scalar_type) -> Self::Output {
+                <&$name as $trait_name<$scalar_type>>::$trait_method(&self, rhs)
+            }
+        }
+    };
+}
+
+/// Conversion of a clear scalar to the widest scalar type, whose width is [MAX_NUM_BITS]
+trait WidenScalar {
+    type Wide;
+
+    fn widen_scalar(self) -> Self::Wide;
+}
+
+macro_rules! impl_widen_scalar {
+    ($wide:ty: $($scalar_type:ty),*) => {
+        $(
+            impl WidenScalar for $scalar_type {
+                type Wide = $wide;
+
+                fn widen_scalar(self) -> Self::Wide {
+                    <$wide>::from(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_widen_scalar!(U2048: u8, u16, u32, u64, u128, U2048);
+impl_widen_scalar!(I2048: i32, i64, i128, I2048);
+
+impl WidenScalar for i8 {
+    type Wide = I2048;
+
+    fn widen_scalar(self) -> Self::Wide {
+        i32::from(self).widen_scalar()
+    }
+}
+
+impl WidenScalar for i16 {
+    type Wide = I2048;
+
+    fn widen_scalar(self) -> Self::Wide {
+        i32::from(self).widen_scalar()
+    }
+}
+
+impl WidenScalar for U256 {
+    type Wide = U2048;
+
+    fn widen_scalar(self) -> Self::Wide {
+        let mut words = [0u64; 32];
+        words[..self.0.len()].copy_from_slice(&self.0);
+        U2048::from(words)
+    }
+}
+
+impl WidenScalar for I256 {
+    type Wide = I2048;
+
+    fn widen_scalar(self) -> Self::Wide {
+        // Sign extension
+        let is_negative = self.0.last().is_some_and(|word| (word >> 63) == 1);
+        let mut words = [if is_negative { u64::MAX } else { 0 }; 32];
+        words[..self.0.len()].copy_from_slice(&self.0);
+        I2048::from(words)
+    }
+}
+
+impl_dyn_integer!(
+    name: FheUintDyn,
+    ciphertext: RadixCiphertext,
+    static_type: FheUint,
+    static_id: FheUintId,
+    cast_fn: cast_to_unsigned,
+    scalar_types: [u8, u16, u32, u64, u128, U256, U2048],
+    shift_types: [u8, u16, u32, u64],
+    scalar_div: scalar_div_parallelized,
+    scalar_rem: scalar_rem_parallelized,
+);
+
+impl_dyn_integer!(
+    name: FheIntDyn,
+    ciphertext: SignedRadixCiphertext,
+    static_type: FheInt,
+    static_id: FheIntId,
+    cast_fn: cast_to_signed,
+    scalar_types: [i8, i16, i32, i64, i128, I256, I2048],
+    shift_types: [u8, u16, u32, u64],
+    scalar_div: signed_scalar_div_parallelized,
+    scalar_rem: signed_scalar_rem_parallelized,
+);
+
+impl FheUintDyn {
+    /// Encrypts `value` in an integer of `num_bits` bits
+    ///
+    /// The value is truncated if it does not fit in `num_bits` bits.
+    ///
+    /// Returns an error if the number of bits is not a multiple of the number of bits
+    /// per block, or if it is greater than [Self::MAX_NUM_BITS].
+    pub fn try_encrypt<T>(value: T, num_bits: u32, key: &ClientKey) -> crate::Result<Self>
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        let num_blocks = num_blocks_for_bits(num_bits, key.message_modulus())?;
+        let ciphertext = key.key.key.encrypt_radix(value, num_blocks);
+        Ok(Self {
+            ciphertext,
+            num_bits,
+        })
+    }
+
+    /// Creates a trivial encryption of `value` in an integer of `num_bits` bits
+    ///
+    /// Returns an error if the number of bits is not a multiple of the number of bits
+    /// per block, or if it is greater than [Self::MAX_NUM_BITS].
+    pub fn try_encrypt_trivial<T>(value: T, num_bits: u32) -> crate::Result<Self>
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        with_cpu_server_key(|sks| {
+            let num_blocks = num_blocks_for_bits(num_bits, sks.message_modulus())?;
+            Ok(Self {
+                ciphertext: sks.create_trivial_radix(value, num_blocks),
+                num_bits,
+            })
+        })
+    }
+
+    /// Converts into a signed integer of the same width, reinterpreting the bits
+    pub fn into_signed(self) -> FheIntDyn {
+        FheIntDyn {
+            ciphertext: SignedRadixCiphertext::from_blocks(self.ciphertext.into_blocks()),
+            num_bits: self.num_bits,
+        }
+    }
+}
+
+impl FheIntDyn {
+    /// Encrypts `value` in an integer of `num_bits` bits
+    ///
+    /// The value is truncated if it does not fit in `num_bits` bits.
+    ///
+    /// Returns an error if the number of bits is not a multiple of the number of bits
+    /// per block, or if it is greater than [Self::MAX_NUM_BITS].
+    pub fn try_encrypt<T>(value: T, num_bits: u32, key: &ClientKey) -> crate::Result<Self>
+    where
+        T: DecomposableInto<u64> + SignedNumeric,
+    {
+        let num_blocks = num_blocks_for_bits(num_bits, key.message_modulus())?;
+        let ciphertext = key.key.key.encrypt_signed_radix(value, num_blocks);
+        Ok(Self {
+            ciphertext,
+            num_bits,
+        })
+    }
+
+    /// Creates a trivial encryption of `value` in an integer of `num_bits` bits
+    ///
+    /// Returns an error if the number of bits is not a multiple of the number of bits
+    /// per block, or if it is greater than [Self::MAX_NUM_BITS].
+    pub fn try_encrypt_trivial<T>(value: T, num_bits: u32) -> crate::Result<Self>
+    where
+        T: DecomposableInto<u64> + SignedNumeric,
+    {
+        with_cpu_server_key(|sks| {
+            let num_blocks = num_blocks_for_bits(num_bits, sks.message_modulus())?;
+            Ok(Self {
+                ciphertext: sks.create_trivial_radix(value, num_blocks),
+                num_bits,
+            })
+        })
+    }
+
+    /// Returns the absolute value
+    pub fn abs(&self) -> Self {
+        self.map(ServerKey::abs_parallelized)
+    }
+
+    /// Converts into an unsigned integer of the same width, reinterpreting the bits
+    pub fn into_unsigned(self) -> FheUintDyn {
+        FheUintDyn {
+            ciphertext: RadixCiphertext::from_blocks(self.ciphertext.into_blocks()),
+            num_bits: self.num_bits,
+        }
+    }
+}
+
+impl<ClearType> FheDecrypt<ClearType> for FheUintDyn
+where
+    ClearType: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        key.key.key.decrypt_radix(&self.ciphertext)
+    }
+}
+
+impl<ClearType> FheDecrypt<ClearType> for FheIntDyn
+where
+    ClearType: RecomposableSignedInteger,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        key.key.key.decrypt_signed_radix(&self.ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high_level_api::integers::setup_default_cpu;
+    use crate::prelude::*;
+    use crate::{FheInt16, FheUint16, FheUint8};
+
+    #[test]
+    fn test_uint_dyn_operations() {
+        let client_key = setup_default_cpu();
+
+        let a = FheUintDyn::try_encrypt(1000u32, 12, &client_key).unwrap();
+        let b = FheUintDyn::try_encrypt(300u32, 12, &client_key).unwrap();
+        assert_eq!(a.num_bits(), 12);
+
+        let c = (&a + &b).unwrap();
+        let decrypted: u32 = c.decrypt(&client_key);
+        assert_eq!(decrypted, 1300);
+
+        let c = (&b - &a).unwrap();
+        let decrypted: u32 = c.decrypt(&client_key);
+        assert_eq!(decrypted, 300u32.wrapping_sub(1000) % 4096);
+
+        let (q, r) = (&a).div_rem(&b).unwrap();
+        let q: u32 = q.decrypt(&client_key);
+        let r: u32 = r.decrypt(&client_key);
+        assert_eq!((q, r), (3, 100));
+
+        let c = &a * 3u32;
+        let decrypted: u32 = c.decrypt(&client_key);
+        assert_eq!(decrypted, 3000);
+
+        let c = &a >> 2u32;
+        let decrypted: u32 = c.decrypt(&client_key);
+        assert_eq!(decrypted, 250);
+
+        let gt = a.try_gt(&b).unwrap();
+        assert!(gt.decrypt(&client_key));
+        let eq = a.eq(1000u32);
+        assert!(eq.decrypt(&client_key));
+
+        let max = a.max(&b).unwrap();
+        let decrypted: u32 = max.decrypt(&client_key);
+        assert_eq!(decrypted, 1000);
+    }
+
+    #[test]
+    fn test_dyn_scalar_div_wider_than_the_scalar() {
+        let client_key = setup_default_cpu();
+
+        let clear_a = (1u128 << 70) + 123_456_789;
+        let a = FheUintDyn::try_encrypt(clear_a, 72, &client_key).unwrap();
+        let divisor = 1_000_000_007u64;
+
+        let q: u128 = (&a / divisor).decrypt(&client_key);
+        assert_eq!(q, clear_a / u128::from(divisor));
+        let r: u128 = (&a % divisor).decrypt(&client_key);
+        assert_eq!(r, clear_a % u128::from(divisor));
+
+        let clear_b = -(clear_a as i128);
+        let b = FheIntDyn::try_encrypt(clear_b, 72, &client_key).unwrap();
+        let divisor = -1_000_000_007i64;
+
+        let q: i128 = (&b / divisor).decrypt(&client_key);
+        assert_eq!(q, clear_b / i128::from(divisor));
+        let r: i128 = (&b % divisor).decrypt(&client_key);
+        assert_eq!(r, clear_b % i128::from(divisor));
+    }
+
+    #[test]
+    fn test_dyn_scalar_ops_with_the_widest_scalars() {
+        let client_key = setup_default_cpu();
+
+        let a = FheUintDyn::try_encrypt(1000u32, 32, &client_key).unwrap();
+        let sum: u32 = (&a + U2048::from(24u64)).decrypt(&client_key);
+        assert_eq!(sum, 1024);
+        let q: u32 = (&a / U2048::from(7u64)).decrypt(&client_key);
+        assert_eq!(q, 1000 / 7);
+
+        let b = FheIntDyn::try_encrypt(-1000i32, 32, &client_key).unwrap();
+        let product: i32 = (&b * I2048::from(-3i64)).decrypt(&client_key);
+        assert_eq!(product, 3000);
+    }
+
+    #[test]
+    fn test_dyn_shifts_by_encrypted_amount() {
+        let client_key = setup_default_cpu();
+
+        let clear_a = 0xB6A5u16;
+        let a = FheUintDyn::try_encrypt(u32::from(clear_a), 16, &client_key).unwrap();
+        let b = FheIntDyn::try_encrypt(i32::from(clear_a as i16), 16, &client_key).unwrap();
+
+        // The amount is narrower, then wider, than the shifted value
+        for amount_bits in [8, 32] {
+            let amount = FheUintDyn::try_encrypt(3u32, amount_bits, &client_key).unwrap();
+
+            let c: u32 = (&a << &amount).decrypt(&client_key);
+            assert_eq!(c, u32::from(clear_a << 3));
+            let c: u32 = (&a >> &amount).decrypt(&client_key);
+            assert_eq!(c, u32::from(clear_a >> 3));
+            let c: u32 = (&a).rotate_left(&amount).decrypt(&client_key);
+            assert_eq!(c, u32::from(clear_a.rotate_left(3)));
+            let c: u32 = (&a).rotate_right(&amount).decrypt(&client_key);
+            assert_eq!(c, u32::from(clear_a.rotate_right(3)));
+
+            // The shift of a signed integer is arithmetic
+            let c: i32 = (&b >> &amount).decrypt(&client_key);
+            assert_eq!(c, i32::from(clear_a as i16 >> 3));
+        }
+    }
+
+    #[test]
+    fn test_dyn_width_mismatch_is_an_error() {
+        let client_key = setup_default_cpu();
+
+        let a = FheUintDyn::try_encrypt(1u32, 8, &client_key).unwrap();
+        let b = FheUintDyn::try_encrypt(1u32, 16, &client_key).unwrap();
+
+        assert!((&a + &b).is_err());
+        assert!((&a).div_rem(&b).is_err());
+        assert!(a.try_lt(&b).is_err());
+        assert!(a.min(&b).is_err());
+
+        let condition = FheBool::encrypt(true, &client_key);
+        assert!(FheUintDyn::if_then_else(&condition, &a, &b).is_err());
+
+        // The width must be a multiple of the number of bits per block
+        assert!(FheUintDyn::try_encrypt(1u32, 7, &client_key).is_err());
+        assert!(FheIntDyn::try_encrypt(1i32, 0, &client_key).is_err());
+
+        // The static type must have the same width
+        assert!(FheUint16::try_from(a.clone()).is_err());
+        assert!(FheUint8::try_from(a).is_ok());
+    }
+
+    #[test]
+    fn test_int_dyn_casts() {
+        let client_key = setup_default_cpu();
+
+        let a = FheInt16::encrypt(-1234i16, &client_key);
+        let a = FheIntDyn::from(a);
+        assert_eq!(a.num_bits(), 16);
+
+        let b = a.cast_to_width(32).unwrap();
+        let decrypted: i32 = b.decrypt(&client_key);
+        assert_eq!(decrypted, -1234);
+
+        let c = -&b;
+        let decrypted: i32 = c.decrypt(&client_key);
+        assert_eq!(decrypted, 1234);
+
+        let d = a.cast_to_width(8).unwrap();
+        let decrypted: i8 = d.decrypt(&client_key);
+        assert_eq!(decrypted, -1234i16 as i8);
+
+        let e = FheInt16::try_from(a).unwrap();
+        let decrypted: i16 = e.decrypt(&client_key);
+        assert_eq!(decrypted, -1234);
+    }
+
+    #[test]
+    fn test_dyn_width_is_bounded() {
+        let client_key = setup_default_cpu();
+
+        assert!(FheUintDyn::try_encrypt(1u32, FheUintDyn::MAX_NUM_BITS + 2, &client_key).is_err());
+        assert!(FheIntDyn::try_encrypt_trivial(1i32, FheIntDyn::MAX_NUM_BITS + 2).is_err());
+
+        let a = FheUintDyn::try_encrypt_trivial(1u32, 16).unwrap();
+        assert!(a.cast_to_width(FheUintDyn::MAX_NUM_BITS + 2).is_err());
+    }
+
+    #[test]
+    fn test_dyn_safe_serialization() {
+        let client_key = setup_default_cpu();
+
+        let a = FheUintDyn::try_encrypt(42u32, 24, &client_key).unwrap();
+        let mut serialized = vec![];
+        crate::safe_serialize(&a, &mut serialized, 1 << 20).unwrap();
+        let a: FheUintDyn =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert_eq!(a.num_bits(), 24);
+        let decrypted: u32 = a.decrypt(&client_key);
+        assert_eq!(decrypted, 42);
+
+        let b = FheIntDyn::try_encrypt(-42i32, 24, &client_key).unwrap();
+        let mut serialized = vec![];
+        crate::safe_serialize(&b, &mut serialized, 1 << 20).unwrap();
+        let b: FheIntDyn =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert_eq!(b.num_bits(), 24);
+        let decrypted: i32 = b.decrypt(&client_key);
+        assert_eq!(decrypted, -42);
+    }
+
+    #[test]
+    fn test_dyn_serialization_checks_width() {
+        let client_key = setup_default_cpu();
+
+        let a = FheUintDyn::try_encrypt(42u32, 16, &client_key).unwrap();
+        let bytes = bincode::serialize(&a).unwrap();
+        let deserialized: FheUintDyn = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized.num_bits(), 16);
+        let decrypted: u32 = deserialized.decrypt(&client_key);
+        assert_eq!(decrypted, 42);
+
+        // The width metadata must match the number of blocks
+        let parts = (&a.ciphertext, 8u32);
+        let bytes = bincode::serialize(&parts).unwrap();
+        assert!(bincode::deserialize::<FheUintDyn>(&bytes).is_err());
+    }
+}
@@ -1,26 +1,39 @@
 expand_pub_use_fhe_type!(
     pub use unsigned{
         FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
-        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256
+        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256, FheUint512,
+        FheUint1024, FheUint2048
     };
 );
 
 expand_pub_use_fhe_type!(
     pub use signed{
         FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16,
-        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256, FheInt512,
+        FheInt1024, FheInt2048
     };
 );
 
 pub(in crate::high_level_api) use signed::FheIntId;
 pub(in crate::high_level_api) use unsigned::FheUintId;
+#[cfg(feature = "zk-pok-experimental")]
+pub(in crate::high_level_api) use {
+    signed::{ProvenCompactFheInt, ProvenCompactFheIntList},
+    unsigned::{ProvenCompactFheUint, ProvenCompactFheUintList},
+};
 // These are pub-exported so that their doc can appear in generated rust docs
 use crate::shortint::MessageModulus;
+pub(crate) use dynamic::num_blocks_for_bits;
+pub use dynamic::{FheIntDyn, FheUintDyn};
 pub use signed::{CompactFheInt, CompactFheIntList, CompressedFheInt, FheInt};
+#[cfg(test)]
+pub(crate) use unsigned::tests::cpu::setup_default_cpu;
 pub use unsigned::{CompactFheUint, CompactFheUintList, CompressedFheUint, FheUint};
 
+mod dynamic;
 pub mod oprf;
 mod signed;
+mod sort;
 mod unsigned;
 
 /// Trait to mark ID type for integers
@@ -2,7 +2,7 @@ use super::{FheIntId, FheUintId};
 use crate::high_level_api::global_state;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::oprf::SignedRandomizationSpec;
-use crate::{FheInt, FheUint, Seed};
+use crate::{FheBool, FheInt, FheUint, Seed};
 
 impl<Id: FheUintId> FheUint<Id> {
     /// Generates an encrypted `num_block` blocks unsigned integer
@@ -45,6 +45,102 @@ impl<Id: FheUintId> FheUint<Id> {
     }
 }
 
+impl<Id: FheUintId> FheUint<Id> {
+    /// Generates an encrypted unsigned integer
+    /// taken in `[0, excluded_upper_bound[` using the given seed
+    /// The encryted value is oblivious to the server
+    ///
+    /// The statistical distance to the uniform distribution is smaller than
+    /// `2^-(extra_random_bits + 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `excluded_upper_bound` is 0 or if the range does not fit in the type
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let ct_res = FheUint8::generate_oblivious_pseudo_random_bounded(Seed(0), 100, 32);
+    ///
+    /// let dec_result: u8 = ct_res.decrypt(&client_key);
+    /// assert!(dec_result < 100);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_bounded(
+        seed: Seed,
+        excluded_upper_bound: u64,
+        extra_random_bits: u64,
+    ) -> Self {
+        let ct = global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => key
+                .key
+                .par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+                    seed,
+                    excluded_upper_bound,
+                    extra_random_bits,
+                    Id::num_blocks(key.message_modulus()) as u64,
+                ),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support bounded oblivious pseudo random generation yet")
+            }
+        });
+
+        Self::new(ct)
+    }
+
+    /// Generates an encrypted unsigned integer
+    /// taken in `[0, excluded_upper_bound[` using the given seed
+    /// The encryted value is oblivious to the server
+    ///
+    /// The statistical distance to the uniform distribution is smaller than
+    /// `2^-(extra_random_bits + 1)`. If `excluded_upper_bound` encrypts 0, the result is 0.
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let bound = FheUint8::encrypt(52u8, &client_key);
+    ///
+    /// let ct_res =
+    ///     FheUint8::generate_oblivious_pseudo_random_encrypted_bounded(Seed(0), &bound, 32);
+    ///
+    /// let dec_result: u8 = ct_res.decrypt(&client_key);
+    /// assert!(dec_result < 52);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_encrypted_bounded(
+        seed: Seed,
+        excluded_upper_bound: &Self,
+        extra_random_bits: u64,
+    ) -> Self {
+        let ct = global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => key
+                .key
+                .par_generate_oblivious_pseudo_random_unsigned_integer_encrypted_bounded(
+                    seed,
+                    &*excluded_upper_bound.ciphertext.on_cpu(),
+                    extra_random_bits,
+                ),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support bounded oblivious pseudo random generation yet")
+            }
+        });
+
+        Self::new(ct)
+    }
+}
+
 impl<Id: FheIntId> FheInt<Id> {
     /// Generates an encrypted `num_block` blocks signed integer
     /// using the given seed following the randomizer spec
@@ -90,3 +186,52 @@ impl<Id: FheIntId> FheInt<Id> {
         Self::new(ct)
     }
 }
+
+impl FheBool {
+    /// Generates an encrypted boolean which is true with probability
+    /// `numerator / denominator` using the given seed
+    /// The encryted value is oblivious to the server
+    ///
+    /// The statistical distance to the requested distribution is smaller than
+    /// `2^-(extra_random_bits + 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0 or if `numerator` is greater than `denominator`
+    ///
+    /// ```rust
+    /// use tfhe::prelude::FheDecrypt;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, Seed};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// // true with probability 1/3
+    /// let ct_res = FheBool::generate_oblivious_pseudo_random(Seed(0), 1, 3, 32);
+    ///
+    /// let _dec_result: bool = ct_res.decrypt(&client_key);
+    /// ```
+    pub fn generate_oblivious_pseudo_random(
+        seed: Seed,
+        numerator: u64,
+        denominator: u64,
+        extra_random_bits: u64,
+    ) -> Self {
+        let ct = global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => key.key.par_generate_oblivious_pseudo_random_boolean(
+                seed,
+                numerator,
+                denominator,
+                extra_random_bits,
+            ),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support oblivious pseudo random booleans yet")
+            }
+        });
+
+        Self::new(ct)
+    }
+}
@@ -108,6 +108,33 @@ where
         self.ciphertext.move_to_device(device)
     }
 
+    /// Computes (in-place) the ciphertext if it is the result of operations recorded under
+    /// [with_lazy_evaluation](crate::high_level_api::with_lazy_evaluation)
+    ///
+    /// Does nothing if the ciphertext was not computed lazily.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, with_lazy_evaluation, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let b = FheInt16::encrypt(5i16, &client_key);
+    ///
+    /// let mut result = with_lazy_evaluation(|| &a * &b);
+    /// result.eval();
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -15);
+    /// ```
+    pub fn eval(&mut self) {
+        self.ciphertext.materialize();
+    }
+
     /// Returns the device where the ciphertext is currently on
     pub fn current_device(&self) -> Device {
         self.ciphertext.current_device()
@@ -377,6 +404,387 @@ where
         })
     }
 
+    /// Returns the number of ones in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-2i16, &client_key);
+    ///
+    /// let result = a.count_ones();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 15);
+    /// ```
+    pub fn count_ones(&self) -> crate::FheUint32 {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .count_ones_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                crate::FheUint32::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support count_ones yet");
+            }
+        })
+    }
+
+    /// Returns the number of zeros in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-2i16, &client_key);
+    ///
+    /// let result = a.count_zeros();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    /// ```
+    pub fn count_zeros(&self) -> crate::FheUint32 {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .count_zeros_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                crate::FheUint32::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support count_zeros yet");
+            }
+        })
+    }
+
+    /// Reverses the order of bits, the least significant bit becomes the most significant bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(1i16, &client_key);
+    ///
+    /// let result = a.reverse_bits();
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, i16::MIN);
+    /// ```
+    pub fn reverse_bits(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .reverse_bits_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support reverse_bits yet");
+            }
+        })
+    }
+
+    /// Reverses the byte order of self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters used have a number of bits per block that does not divide 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(0x12_34i16, &client_key);
+    ///
+    /// let result = a.swap_bytes();
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0x34_12i16);
+    /// ```
+    pub fn swap_bytes(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .swap_bytes_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support swap_bytes yet");
+            }
+        })
+    }
+
+    /// Returns the bits of self within `range`, moved to the least significant positions.
+    ///
+    /// All the other bits of the result are 0, the sign bit is not extended, i.e. the result
+    /// is `((self as unsigned) >> range.start) & ((1 << range.len()) - 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is reversed or exceeds the number of bits of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-2i16, &client_key);
+    ///
+    /// let result = a.extract_bits(13..16);
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b111i16);
+    /// ```
+    pub fn extract_bits(&self, range: std::ops::Range<u32>) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .extract_bits_parallelized(&*self.ciphertext.on_cpu(), range);
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support extract_bits yet");
+            }
+        })
+    }
+
+    /// Returns the integer square root of self, rounded down.
+    ///
+    /// Self is interpreted as unsigned, so the result is meaningless when self is negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(1000i16, &client_key);
+    ///
+    /// let result = a.isqrt();
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 31i16);
+    /// ```
+    pub fn isqrt(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .isqrt_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support isqrt yet");
+            }
+        })
+    }
+
+    /// Multiplies self by rhs and returns the high half of the full double-width product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt64};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt64::encrypt(i64::MIN + 3, &client_key);
+    /// let b = FheInt64::encrypt(-(1i64 << 40), &client_key);
+    ///
+    /// let result = a.mul_high(&b);
+    /// let decrypted: i64 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((i64::MIN + 3) as i128 * -(1i128 << 40) >> 64) as i64);
+    /// ```
+    pub fn mul_high(&self, rhs: &Self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mul_high_parallelized(&*self.ciphertext.on_cpu(), &rhs.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_high yet");
+            }
+        })
+    }
+
+    /// Multiplies self by rhs without wrapping, the result having twice as many bits.
+    ///
+    /// `OutId` must be the id of the type with twice the number of bits of self, which is
+    /// ensured by the typed `widening_mul` wrappers.
+    pub(in crate::high_level_api) fn widening_mul_impl<OutId: FheIntId>(
+        &self,
+        rhs: &Self,
+    ) -> FheInt<OutId> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let result = sks.widening_mul_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &rhs.ciphertext.on_cpu(),
+                );
+                assert_eq!(
+                    result.blocks.len(),
+                    OutId::num_blocks(sks.message_modulus()),
+                    "Invalid output type for widening_mul"
+                );
+                FheInt::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support widening_mul yet");
+            }
+        })
+    }
+
+    /// Raises self to the power of an encrypted exponent, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let b = FheUint8::encrypt(5u8, &client_key);
+    ///
+    /// let result = a.pow(&b);
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (-3i16).wrapping_pow(5));
+    /// ```
+    pub fn pow<E: FheUintId>(&self, exponent: &FheUint<E>) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .pow_parallelized(&*self.ciphertext.on_cpu(), &exponent.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+
+    /// Raises self to the power of a clear exponent, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// let result = a.scalar_pow(7);
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (-3i16).wrapping_pow(7));
+    /// ```
+    pub fn scalar_pow(&self, exponent: u32) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), exponent);
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support scalar_pow yet");
+            }
+        })
+    }
+
+    /// Raises self to the power of an encrypted exponent.
+    ///
+    /// Returns the wrapped result, and a boolean flag that is true if the computation
+    /// overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    /// let b = FheUint8::encrypt(11u8, &client_key);
+    ///
+    /// let (result, overflowed) = a.checked_pow(&b);
+    ///
+    /// let overflowed = overflowed.decrypt(&client_key);
+    /// assert!(overflowed);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (-3i16).wrapping_pow(11));
+    /// ```
+    pub fn checked_pow<E: FheUintId>(&self, exponent: &FheUint<E>) -> (Self, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, overflowed) = cpu_key.pbs_key().signed_checked_pow_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &exponent.ciphertext.on_cpu(),
+                );
+                (Self::new(result), FheBool::new(overflowed))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_pow yet");
+            }
+        })
+    }
+
     /// Tries to decrypt a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -0,0 +1,463 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::ciphertext::IntegerCiphertext;
+use crate::integer::{BooleanBlock, ServerKey, SignedRadixCiphertext};
+use crate::prelude::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedShl, CheckedShr, CheckedSub,
+};
+use crate::{FheBool, FheInt, FheUint};
+
+impl<Id> CheckedAdd<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Adds two [FheInt] and returns a boolean indicating whether the result is valid.
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    /// * On overflow the [FheBool] is false, otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(i16::MAX, &client_key);
+    /// let b = FheInt16::encrypt(1i16, &client_key);
+    ///
+    /// let (result, is_ok) = (&a).checked_add(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, i16::MAX.wrapping_add(1i16));
+    /// assert_eq!(is_ok.decrypt(&client_key), false);
+    /// ```
+    fn checked_add(self, other: Self) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let (result, overflowed) = sks.signed_overflowing_add_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                (
+                    FheInt::new(result),
+                    FheBool::new(sks.boolean_bitnot(&overflowed)),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_add yet");
+            }
+        })
+    }
+}
+
+impl<Id> CheckedAdd<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn checked_add(self, other: &Self) -> (Self::Output, FheBool) {
+        <&Self as CheckedAdd<&Self>>::checked_add(&self, other)
+    }
+}
+
+impl<Id> CheckedSub<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Subtracts two [FheInt] and returns a boolean indicating whether the result is valid.
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    /// * On overflow the [FheBool] is false, otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(i16::MIN, &client_key);
+    /// let b = FheInt16::encrypt(1i16, &client_key);
+    ///
+    /// let (result, is_ok) = (&a).checked_sub(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, i16::MIN.wrapping_sub(1i16));
+    /// assert_eq!(is_ok.decrypt(&client_key), false);
+    /// ```
+    fn checked_sub(self, other: Self) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let (result, overflowed) = sks.signed_overflowing_sub_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                (
+                    FheInt::new(result),
+                    FheBool::new(sks.boolean_bitnot(&overflowed)),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_sub yet");
+            }
+        })
+    }
+}
+
+impl<Id> CheckedSub<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn checked_sub(self, other: &Self) -> (Self::Output, FheBool) {
+        <&Self as CheckedSub<&Self>>::checked_sub(&self, other)
+    }
+}
+
+impl<Id> CheckedMul<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Multiplies two [FheInt] and returns a boolean indicating whether the result is valid.
+    ///
+    /// * The operation is modular, i.e on overflow the result wraps around.
+    /// * On overflow the [FheBool] is false, otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(3434i16, &client_key);
+    /// let b = FheInt16::encrypt(54i16, &client_key);
+    ///
+    /// let (result, is_ok) = (&a).checked_mul(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3434i16.wrapping_mul(54i16));
+    /// assert_eq!(is_ok.decrypt(&client_key), false);
+    /// ```
+    fn checked_mul(self, other: Self) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let (result, overflowed) = sks.signed_overflowing_mul_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                (
+                    FheInt::new(result),
+                    FheBool::new(sks.boolean_bitnot(&overflowed)),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_mul yet");
+            }
+        })
+    }
+}
+
+impl<Id> CheckedMul<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn checked_mul(self, other: &Self) -> (Self::Output, FheBool) {
+        <&Self as CheckedMul<&Self>>::checked_mul(&self, other)
+    }
+}
+
+impl<Id> CheckedDiv<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Divides two [FheInt] and returns a boolean indicating whether the result is valid.
+    ///
+    /// * The [FheBool] is false when dividing by zero or when the result overflows (MIN / -1),
+    ///   otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(3434i16, &client_key);
+    /// let b = FheInt16::encrypt(0i16, &client_key);
+    ///
+    /// let (_, is_ok) = (&a).checked_div(&b);
+    /// assert_eq!(is_ok.decrypt(&client_key), false);
+    /// ```
+    fn checked_div(self, other: Self) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let lhs = self.ciphertext.on_cpu();
+                let rhs = other.ciphertext.on_cpu();
+                let (result, is_ok) = rayon::join(
+                    || sks.div_parallelized(&*lhs, &*rhs),
+                    || signed_div_is_ok(sks, &lhs, &rhs),
+                );
+                (FheInt::new(result), FheBool::new(is_ok))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_div yet");
+            }
+        })
+    }
+}
+
+impl<Id> CheckedDiv<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn checked_div(self, other: &Self) -> (Self::Output, FheBool) {
+        <&Self as CheckedDiv<&Self>>::checked_div(&self, other)
+    }
+}
+
+impl<Id> CheckedRem<Self> for &FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = FheInt<Id>;
+
+    /// Computes the remainder of the division of two [FheInt]
+    /// and returns a boolean indicating whether the result is valid.
+    ///
+    /// * The [FheBool] is false when dividing by zero or when the result overflows (MIN / -1),
+    ///   otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(3434i16, &client_key);
+    /// let b = FheInt16::encrypt(54i16, &client_key);
+    ///
+    /// let (result, is_ok) = (&a).checked_rem(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3434i16 % 54i16);
+    /// assert_eq!(is_ok.decrypt(&client_key), true);
+    /// ```
+    fn checked_rem(self, other: Self) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let lhs = self.ciphertext.on_cpu();
+                let rhs = other.ciphertext.on_cpu();
+                let (result, is_ok) = rayon::join(
+                    || sks.rem_parallelized(&*lhs, &*rhs),
+                    || signed_div_is_ok(sks, &lhs, &rhs),
+                );
+                (FheInt::new(result), FheBool::new(is_ok))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_rem yet");
+            }
+        })
+    }
+}
+
+impl<Id> CheckedRem<&Self> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    type Output = Self;
+
+    fn checked_rem(self, other: &Self) -> (Self::Output, FheBool) {
+        <&Self as CheckedRem<&Self>>::checked_rem(&self, other)
+    }
+}
+
+impl<Id, Id2> CheckedShl<&FheUint<Id2>> for &FheInt<Id>
+where
+    Id: FheIntId,
+    Id2: FheUintId,
+{
+    type Output = FheInt<Id>;
+
+    /// Shifts a [FheInt] to the left and returns a boolean indicating whether the result is
+    /// valid.
+    ///
+    /// * The [FheBool] is false when the shift amount is greater or equal to the number of bits,
+    ///   otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(3434i16, &client_key);
+    /// let b = FheUint16::encrypt(16u16, &client_key);
+    ///
+    /// let (_, is_ok) = (&a).checked_shl(&b);
+    /// assert_eq!(is_ok.decrypt(&client_key), false);
+    /// ```
+    fn checked_shl(self, other: &FheUint<Id2>) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let lhs = self.ciphertext.on_cpu();
+                let rhs = other.ciphertext.on_cpu();
+                // The shifts of the server key expect the amount to have as many blocks as the
+                // shifted value, only its low bits matter
+                let amount = sks.cast_to_unsigned((*rhs).clone(), lhs.blocks().len());
+                let (result, is_ok) = rayon::join(
+                    || sks.left_shift_parallelized(&*lhs, &amount),
+                    || sks.scalar_lt_parallelized(&*rhs, Id::num_bits() as u64),
+                );
+                (FheInt::new(result), FheBool::new(is_ok))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_shl yet");
+            }
+        })
+    }
+}
+
+impl<Id, Id2> CheckedShl<&FheUint<Id2>> for FheInt<Id>
+where
+    Id: FheIntId,
+    Id2: FheUintId,
+{
+    type Output = Self;
+
+    fn checked_shl(self, other: &FheUint<Id2>) -> (Self::Output, FheBool) {
+        <&Self as CheckedShl<&FheUint<Id2>>>::checked_shl(&self, other)
+    }
+}
+
+impl<Id, Id2> CheckedShr<&FheUint<Id2>> for &FheInt<Id>
+where
+    Id: FheIntId,
+    Id2: FheUintId,
+{
+    type Output = FheInt<Id>;
+
+    /// Shifts a [FheInt] to the right and returns a boolean indicating whether the result is
+    /// valid.
+    ///
+    /// * The [FheBool] is false when the shift amount is greater or equal to the number of bits,
+    ///   otherwise true
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(3434i16, &client_key);
+    /// let b = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let (result, is_ok) = (&a).checked_shr(&b);
+    /// let result: i16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 3434i16 >> 3);
+    /// assert_eq!(is_ok.decrypt(&client_key), true);
+    /// ```
+    fn checked_shr(self, other: &FheUint<Id2>) -> (Self::Output, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let lhs = self.ciphertext.on_cpu();
+                let rhs = other.ciphertext.on_cpu();
+                // The shifts of the server key expect the amount to have as many blocks as the
+                // shifted value, only its low bits matter
+                let amount = sks.cast_to_unsigned((*rhs).clone(), lhs.blocks().len());
+                let (result, is_ok) = rayon::join(
+                    || sks.right_shift_parallelized(&*lhs, &amount),
+                    || sks.scalar_lt_parallelized(&*rhs, Id::num_bits() as u64),
+                );
+                (FheInt::new(result), FheBool::new(is_ok))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_shr yet");
+            }
+        })
+    }
+}
+
+impl<Id, Id2> CheckedShr<&FheUint<Id2>> for FheInt<Id>
+where
+    Id: FheIntId,
+    Id2: FheUintId,
+{
+    type Output = Self;
+
+    fn checked_shr(self, other: &FheUint<Id2>) -> (Self::Output, FheBool) {
+        <&Self as CheckedShr<&FheUint<Id2>>>::checked_shr(&self, other)
+    }
+}
+
+/// Returns whether the signed division of lhs by rhs is valid,
+/// that is rhs is not 0 and the division does not overflow (MIN / -1)
+fn signed_div_is_ok(
+    sks: &ServerKey,
+    lhs: &SignedRadixCiphertext,
+    rhs: &SignedRadixCiphertext,
+) -> BooleanBlock {
+    let num_blocks = lhs.blocks.len();
+    let ((rhs_is_zero, rhs_is_minus_one), lhs_is_min) = rayon::join(
+        || {
+            rayon::join(
+                || sks.scalar_eq_parallelized(rhs, 0u64),
+                || {
+                    let minus_one: SignedRadixCiphertext =
+                        sks.create_trivial_radix(-1i64, num_blocks);
+                    sks.eq_parallelized(rhs, &minus_one)
+                },
+            )
+        },
+        || {
+            let min: SignedRadixCiphertext = sks.create_trivial_min_radix(num_blocks);
+            sks.eq_parallelized(lhs, &min)
+        },
+    );
+    let overflows = sks.boolean_bitand(&lhs_is_min, &rhs_is_minus_one);
+    let is_invalid = sks.boolean_bitor(&rhs_is_zero, &overflows);
+    sks.boolean_bitnot(&is_invalid)
+}
@@ -1,7 +1,7 @@
 use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
 use crate::high_level_api::integers::signed::base::FheIntConformanceParams;
 use crate::high_level_api::integers::{FheInt, FheIntId};
-use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::ciphertext::{CompactCiphertextList, DataKind};
 use crate::integer::parameters::RadixCompactCiphertextListConformanceParams;
 use crate::named::Named;
 use crate::prelude::FheTryEncrypt;
@@ -35,8 +35,8 @@ use std::marker::PhantomData;
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct CompactFheInt<Id: FheIntId> {
-    pub(in crate::high_level_api::integers) list: CompactCiphertextList,
-    pub(in crate::high_level_api::integers) id: Id,
+    pub(in crate::high_level_api) list: CompactCiphertextList,
+    pub(in crate::high_level_api) id: Id,
 }
 
 impl<Id> CompactFheInt<Id>
@@ -46,10 +46,16 @@ where
     /// Expand to a [FheInt]
     ///
     /// See [CompactFheInt] example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying list is empty, which can only happen with a value that was
+    /// deserialized without checking its conformance.
     pub fn expand(&self) -> FheInt<Id> {
         let ct = self
             .list
-            .expand_one::<crate::integer::SignedRadixCiphertext>();
+            .try_expand_one::<crate::integer::SignedRadixCiphertext>()
+            .expect("A CompactFheInt holds one value");
         FheInt::new(ct)
     }
 
@@ -74,7 +80,7 @@ where
         let id = Id::default();
         let ciphertext = key
             .key
-            .try_encrypt_compact(&[value], Id::num_blocks(key.message_modulus()));
+            .try_encrypt_signed_compact(&[value], Id::num_blocks(key.message_modulus()));
         Ok(Self {
             list: ciphertext,
             id,
@@ -93,7 +99,9 @@ impl<Id: FheIntId> ParameterSetConformant for CompactFheInt<Id> {
         let params = params
             .params
             .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(1));
-        self.list.is_conformant(&params)
+        self.list
+            .holds_only(DataKind::Signed(params.num_blocks_per_integer))
+            && self.list.is_conformant(&params)
     }
 }
 
@@ -128,8 +136,8 @@ impl<Id: FheIntId> ParameterSetConformant for CompactFheInt<Id> {
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct CompactFheIntList<Id: FheIntId> {
-    pub(in crate::high_level_api::integers) list: CompactCiphertextList,
-    pub(in crate::high_level_api::integers) id: Id,
+    pub(in crate::high_level_api) list: CompactCiphertextList,
+    pub(in crate::high_level_api) id: Id,
 }
 
 impl<Id> CompactFheIntList<Id>
@@ -177,7 +185,7 @@ where
         let id = Id::default();
         let ciphertext = key
             .key
-            .try_encrypt_compact(values, Id::num_blocks(key.message_modulus()));
+            .try_encrypt_signed_compact(values, Id::num_blocks(key.message_modulus()));
         Ok(Self {
             list: ciphertext,
             id,
@@ -229,6 +237,8 @@ impl<Id: FheIntId> ParameterSetConformant for CompactFheIntList<Id> {
     type ParameterSet = CompactFheIntListConformanceParams<Id>;
 
     fn is_conformant(&self, params: &CompactFheIntListConformanceParams<Id>) -> bool {
-        self.list.is_conformant(&params.params)
+        self.list
+            .holds_only(DataKind::Signed(params.params.num_blocks_per_integer))
+            && self.list.is_conformant(&params.params)
     }
 }
@@ -1,17 +1,23 @@
 use crate::high_level_api::details::MaybeCloned;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::{self, with_thread_local_cuda_stream};
+use crate::high_level_api::lazy::{LazyNode, LazyOp, LazyOperand};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaSignedRadixCiphertext;
 use crate::Device;
 use serde::{Deserializer, Serializer};
+use std::sync::Arc;
 
 pub(crate) enum RadixCiphertext {
     Cpu(crate::integer::SignedRadixCiphertext),
     #[cfg(feature = "gpu")]
     Cuda(CudaSignedRadixCiphertext),
+    /// Result of operations recorded under
+    /// [with_lazy_evaluation](crate::high_level_api::with_lazy_evaluation), computed on the CPU
+    /// when needed
+    Lazy(Arc<LazyNode>),
 }
 
 impl From<crate::integer::SignedRadixCiphertext> for RadixCiphertext {
@@ -36,6 +42,7 @@ impl Clone for RadixCiphertext {
                 let inner = inner.duplicate(stream);
                 Self::Cuda(inner)
             }),
+            Self::Lazy(node) => Self::Lazy(node.clone()),
         }
     }
 }
@@ -65,7 +72,7 @@ impl<'de> serde::Deserialize<'de> for RadixCiphertext {
 impl RadixCiphertext {
     pub(crate) fn current_device(&self) -> Device {
         match self {
-            Self::Cpu(_) => Device::Cpu,
+            Self::Cpu(_) | Self::Lazy(_) => Device::Cpu,
             #[cfg(feature = "gpu")]
             Self::Cuda(_) => Device::CudaGpu,
         }
@@ -76,6 +83,7 @@ impl RadixCiphertext {
     pub(crate) fn on_cpu(&self) -> MaybeCloned<'_, crate::integer::SignedRadixCiphertext> {
         match self {
             Self::Cpu(ct) => MaybeCloned::Borrowed(ct),
+            Self::Lazy(node) => MaybeCloned::Borrowed(node.evaluate().as_signed()),
             #[cfg(feature = "gpu")]
             Self::Cuda(ct) => with_thread_local_cuda_stream(|stream| {
                 let cpu_ct = ct.to_signed_radix_ciphertext(stream);
@@ -95,17 +103,22 @@ impl RadixCiphertext {
             }),
             #[cfg(feature = "gpu")]
             Self::Cuda(ct) => MaybeCloned::Borrowed(ct),
+            Self::Lazy(node) => with_thread_local_cuda_stream(|stream| {
+                let ct = CudaSignedRadixCiphertext::from_signed_radix_ciphertext(
+                    node.evaluate().as_signed(),
+                    stream,
+                );
+                MaybeCloned::Cloned(ct)
+            }),
         }
     }
 
     pub(crate) fn as_cpu_mut(&mut self) -> &mut crate::integer::SignedRadixCiphertext {
-        match self {
-            Self::Cpu(radix_ct) => radix_ct,
-            #[cfg(feature = "gpu")]
-            _ => {
-                self.move_to_device(Device::Cpu);
-                self.as_cpu_mut()
-            }
+        if let Self::Cpu(radix_ct) = self {
+            radix_ct
+        } else {
+            self.move_to_device(Device::Cpu);
+            self.as_cpu_mut()
         }
     }
 
@@ -126,6 +139,7 @@ impl RadixCiphertext {
             Self::Cuda(ct) => {
                 with_thread_local_cuda_stream(|stream| ct.to_signed_radix_ciphertext(stream))
             }
+            Self::Lazy(node) => node.evaluate().as_signed().clone(),
         }
     }
 
@@ -137,6 +151,12 @@ impl RadixCiphertext {
                 CudaSignedRadixCiphertext::from_signed_radix_ciphertext(&cpu_ct, stream)
             }),
             Self::Cuda(ct) => ct,
+            Self::Lazy(node) => with_thread_local_cuda_stream(|stream| {
+                CudaSignedRadixCiphertext::from_signed_radix_ciphertext(
+                    node.evaluate().as_signed(),
+                    stream,
+                )
+            }),
         }
     }
 
@@ -162,6 +182,40 @@ impl RadixCiphertext {
                     with_thread_local_cuda_stream(|stream| ct.to_signed_radix_ciphertext(stream));
                 *self = Self::Cpu(new_inner);
             }
+            (Self::Lazy(node), Device::Cpu) => {
+                let new_inner = node.evaluate().as_signed().clone();
+                *self = Self::Cpu(new_inner);
+            }
+            #[cfg(feature = "gpu")]
+            (Self::Lazy(node), Device::CudaGpu) => {
+                let new_inner = with_thread_local_cuda_stream(|stream| {
+                    CudaSignedRadixCiphertext::from_signed_radix_ciphertext(
+                        node.evaluate().as_signed(),
+                        stream,
+                    )
+                });
+                *self = Self::Cuda(new_inner);
+            }
+        }
+    }
+
+    /// Records `op` on the operands, to be computed when the result is needed
+    pub(crate) fn lazy(op: LazyOp, operands: Vec<LazyOperand>) -> Self {
+        Self::Lazy(Arc::new(LazyNode::new(op, operands)))
+    }
+
+    pub(crate) fn to_lazy_operand(&self) -> LazyOperand {
+        if let Self::Lazy(node) = self {
+            LazyOperand::Node(node.clone())
+        } else {
+            LazyOperand::from(self.on_cpu().into_owned())
+        }
+    }
+
+    /// Computes the ciphertext if it is the result of lazy operations
+    pub(crate) fn materialize(&mut self) {
+        if matches!(self, Self::Lazy(_)) {
+            self.move_to_device(Device::Cpu);
         }
     }
 
@@ -2,10 +2,12 @@ mod base;
 mod compact;
 mod compressed;
 
+mod checked_ops;
 mod encrypt;
 mod inner;
 mod ops;
 mod overflowing_ops;
+mod saturating_ops;
 mod scalar_ops;
 mod static_;
 #[cfg(test)]
@@ -16,10 +18,13 @@ mod zk;
 pub use base::{FheInt, FheIntId};
 pub use compact::{CompactFheInt, CompactFheIntList};
 pub use compressed::CompressedFheInt;
+#[cfg(feature = "zk-pok-experimental")]
+pub use zk::{ProvenCompactFheInt, ProvenCompactFheIntList};
 
 expand_pub_use_fhe_type!(
     pub use static_{
         FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16,
-        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256, FheInt512,
+        FheInt1024, FheInt2048
     };
 );
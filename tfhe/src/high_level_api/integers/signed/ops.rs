@@ -1,6 +1,8 @@
+use super::inner::RadixCiphertext;
 use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheIntId, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::lazy::{is_lazy_evaluation_enabled, LazyOp};
 use crate::high_level_api::traits::{
     DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
@@ -205,6 +207,17 @@ where
     fn eq(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.eq_parallelized(values[0].as_signed(), values[1].as_signed())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .eq_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -244,6 +257,17 @@ where
     fn ne(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.ne_parallelized(values[0].as_signed(), values[1].as_signed())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .ne_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -309,6 +333,17 @@ where
     fn lt(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.lt_parallelized(values[0].as_signed(), values[1].as_signed())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .lt_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -348,6 +383,17 @@ where
     fn le(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.le_parallelized(values[0].as_signed(), values[1].as_signed())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .le_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -387,6 +433,17 @@ where
     fn gt(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.gt_parallelized(values[0].as_signed(), values[1].as_signed())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .gt_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -426,6 +483,17 @@ where
     fn ge(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.ge_parallelized(values[0].as_signed(), values[1].as_signed())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .ge_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -580,6 +648,12 @@ generic_integer_impl_operation!(
         |lhs: &FheInt<_>, rhs: &FheInt<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheInt::new(RadixCiphertext::lazy(
+                            LazyOp::Add,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .add_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -623,6 +697,12 @@ generic_integer_impl_operation!(
         |lhs: &FheInt<_>, rhs: &FheInt<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheInt::new(RadixCiphertext::lazy(
+                            LazyOp::Sub,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .sub_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -666,6 +746,12 @@ generic_integer_impl_operation!(
         |lhs: &FheInt<_>, rhs: &FheInt<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheInt::new(RadixCiphertext::lazy(
+                            LazyOp::Mul,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .mul_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -707,6 +793,12 @@ generic_integer_impl_operation!(
         |lhs: &FheInt<_>, rhs: &FheInt<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheInt::new(RadixCiphertext::lazy(
+                            LazyOp::BitAnd,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .bitand_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -748,6 +840,12 @@ generic_integer_impl_operation!(
         |lhs: &FheInt<_>, rhs: &FheInt<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheInt::new(RadixCiphertext::lazy(
+                            LazyOp::BitOr,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .bitor_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -789,6 +887,12 @@ generic_integer_impl_operation!(
         |lhs: &FheInt<_>, rhs: &FheInt<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheInt::new(RadixCiphertext::lazy(
+                            LazyOp::BitXor,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .bitxor_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
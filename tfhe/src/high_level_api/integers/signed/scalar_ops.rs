@@ -6,13 +6,14 @@ use crate::high_level_api::global_state::with_thread_local_cuda_stream;
 use crate::high_level_api::integers::signed::inner::RadixCiphertext;
 use crate::high_level_api::integers::FheIntId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::lazy::{is_lazy_evaluation_enabled, LazyOp};
 use crate::high_level_api::traits::{
     DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::IntegerCiphertext;
-use crate::integer::{I256, U256};
+use crate::integer::{I1024, I2048, I256, I512, U1024, U2048, U256, U512};
 use crate::{FheBool, FheInt};
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
@@ -140,6 +141,12 @@ where
     fn eq(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_eq_parallelized(values[0].as_signed(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_eq_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -180,6 +187,12 @@ where
     fn ne(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_ne_parallelized(values[0].as_signed(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_ne_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -225,6 +238,12 @@ where
     fn lt(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_lt_parallelized(values[0].as_signed(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_lt_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -264,6 +283,12 @@ where
     fn le(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_le_parallelized(values[0].as_signed(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_le_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -303,6 +328,12 @@ where
     fn gt(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_gt_parallelized(values[0].as_signed(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_gt_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -342,6 +373,12 @@ where
     fn ge(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|keys| match keys {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_ge_parallelized(values[0].as_signed(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_ge_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -425,6 +462,9 @@ generic_integer_impl_scalar_div_rem!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 
 use crate::high_level_api::integers::unsigned::scalar_ops::{
@@ -432,12 +472,32 @@ use crate::high_level_api::integers::unsigned::scalar_ops::{
     generic_integer_impl_scalar_operation_assign,
 };
 
+/// Records the addition or subtraction of a clear scalar, which is added as a trivial ciphertext
+/// so that chains of additions and subtractions can be fused
+fn lazy_scalar_sum<Id, Clear>(
+    sks: &crate::integer::ServerKey,
+    op: LazyOp,
+    lhs: &FheInt<Id>,
+    rhs: Clear,
+) -> RadixCiphertext
+where
+    Id: FheIntId,
+    Clear: DecomposableInto<u64>,
+{
+    let num_blocks = Id::num_blocks(sks.message_modulus());
+    let rhs: crate::integer::SignedRadixCiphertext = sks.create_trivial_radix(rhs, num_blocks);
+    RadixCiphertext::lazy(op, vec![lhs.ciphertext.to_lazy_operand(), rhs.into()])
+}
+
 generic_integer_impl_scalar_operation!(
     rust_trait: Add(add),
     implem: {
         |lhs: &FheInt<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return lazy_scalar_sum(cpu_key.pbs_key(), LazyOp::Add, lhs, rhs);
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_add_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -469,6 +529,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Sub(sub),
@@ -476,6 +539,9 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheInt<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return lazy_scalar_sum(cpu_key.pbs_key(), LazyOp::Sub, lhs, rhs);
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_sub_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -507,6 +573,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Mul(mul),
@@ -514,6 +583,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheInt<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_mul_parallelized(values[0].as_signed(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_mul_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -545,6 +622,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitAnd(bitand),
@@ -552,6 +632,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheInt<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_bitand_parallelized(values[0].as_signed(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_bitand_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -583,6 +671,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitOr(bitor),
@@ -590,6 +681,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheInt<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_bitor_parallelized(values[0].as_signed(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_bitor_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -621,6 +720,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitXor(bitxor),
@@ -628,6 +730,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheInt<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_bitxor_parallelized(values[0].as_signed(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_bitxor_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -660,6 +770,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Shl(shl),
@@ -698,6 +811,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Shr(shr),
@@ -736,6 +852,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: RotateLeft(rotate_left),
@@ -774,6 +893,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: RotateRight(rotate_right),
@@ -812,6 +934,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Div(div),
@@ -845,6 +970,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Rem(rem),
@@ -878,6 +1006,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 
 generic_integer_impl_scalar_left_operation!(
@@ -924,6 +1055,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: Sub(sub),
@@ -988,6 +1122,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: Mul(mul),
@@ -1033,6 +1170,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitAnd(bitand),
@@ -1076,6 +1216,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitOr(bitor),
@@ -1119,6 +1262,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitXor(bitxor),
@@ -1162,6 +1308,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 
 generic_integer_impl_scalar_operation_assign!(
@@ -1220,6 +1369,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: SubAssign(sub_assign),
@@ -1255,6 +1407,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: MulAssign(mul_assign),
@@ -1290,6 +1445,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitAndAssign(bitand_assign),
@@ -1325,6 +1483,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitOrAssign(bitor_assign),
@@ -1360,6 +1521,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitXorAssign(bitxor_assign),
@@ -1395,6 +1559,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShlAssign(shl_assign),
@@ -1430,6 +1597,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShrAssign(shr_assign),
@@ -1465,6 +1635,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateLeftAssign(rotate_left_assign),
@@ -1500,6 +1673,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateRightAssign(rotate_right_assign),
@@ -1535,6 +1711,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, u8, u16, u32, u64, u128),
         (super::FheInt160, u8, u16, u32, u64, u128, U256),
         (super::FheInt256, u8, u16, u32, u64, u128, U256),
+        (super::FheInt512, u8, u16, u32, u64, u128, U512),
+        (super::FheInt1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheInt2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: DivAssign(div_assign),
@@ -1567,6 +1746,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RemAssign(rem_assign),
@@ -1599,4 +1781,7 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheInt128, i128),
         (super::FheInt160, I256),
         (super::FheInt256, I256),
+        (super::FheInt512, I512),
+        (super::FheInt1024, I1024),
+        (super::FheInt2048, I2048),
 );
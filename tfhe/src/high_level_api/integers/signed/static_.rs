@@ -147,3 +147,56 @@ static_int_type! {
         num_bits: 256,
     }
 }
+
+static_int_type! {
+    Signed {
+        num_bits: 512,
+    }
+}
+
+static_int_type! {
+    Signed {
+        num_bits: 1024,
+    }
+}
+
+static_int_type! {
+    Signed {
+        num_bits: 2048,
+    }
+}
+
+macro_rules! impl_widening_mul {
+    (
+        $(
+            $num_bits:literal => $double_num_bits:literal
+        ),* $(,)?
+    ) => {
+        $(
+            ::paste::paste! {
+                impl [<FheInt $num_bits>] {
+                    /// Multiplies self by rhs without wrapping.
+                    ///
+                    #[doc = concat!("The full product is returned as a [", stringify!([<FheInt $double_num_bits>]), "].")]
+                    pub fn widening_mul(&self, rhs: &Self) -> [<FheInt $double_num_bits>] {
+                        self.widening_mul_impl(rhs)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_mul! {
+    2 => 4,
+    4 => 8,
+    6 => 12,
+    8 => 16,
+    16 => 32,
+    32 => 64,
+    64 => 128,
+    128 => 256,
+    256 => 512,
+    512 => 1024,
+    1024 => 2048,
+}
@@ -1,13 +1,13 @@
 use crate::conformance::ListSizeConstraint;
-use crate::integer::I256;
+use crate::integer::{I2048, I256};
 use crate::prelude::*;
 use crate::safe_deserialization::safe_deserialize_conformant;
 use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
 use crate::{
     generate_keys, set_server_key, ClientKey, CompactFheInt32, CompactFheInt32List,
     CompactFheInt32ListConformanceParams, CompactPublicKey, CompressedFheInt16, CompressedFheInt32,
-    Config, ConfigBuilder, FheInt16, FheInt256, FheInt32, FheInt32ConformanceParams, FheInt64,
-    FheInt8, FheUint64, FheUint8,
+    Config, ConfigBuilder, FheInt16, FheInt2048, FheInt256, FheInt32, FheInt32ConformanceParams,
+    FheInt64, FheInt8, FheUint16, FheUint2, FheUint64, FheUint8,
 };
 use rand::prelude::*;
 
@@ -543,6 +543,19 @@ fn test_trivial_fhe_int256_small() {
     let clear: I256 = a.decrypt(&client_key);
     assert_eq!(clear, clear_a);
 }
+
+#[test]
+fn test_trivial_fhe_int2048_small() {
+    let config = ConfigBuilder::default_with_small_encryption().build();
+    let (client_key, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let clear_a = I2048::MIN;
+    let a = FheInt2048::try_encrypt_trivial(clear_a).unwrap();
+    let clear: I2048 = a.decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+}
 #[test]
 fn test_compact_public_key_big() {
     let config = ConfigBuilder::default()
@@ -658,6 +671,32 @@ fn test_case_leading_trailing_zeros_ones(cks: &ClientKey) {
     }
 }
 
+fn test_case_bit_manipulation(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<i32>();
+        let a = FheInt32::try_encrypt(clear_a, cks).unwrap();
+
+        let count_ones: u32 = a.count_ones().decrypt(cks);
+        assert_eq!(count_ones, clear_a.count_ones());
+
+        let count_zeros: u32 = a.count_zeros().decrypt(cks);
+        assert_eq!(count_zeros, clear_a.count_zeros());
+
+        let reversed: i32 = a.reverse_bits().decrypt(cks);
+        assert_eq!(reversed, clear_a.reverse_bits());
+
+        let swapped: i32 = a.swap_bytes().decrypt(cks);
+        assert_eq!(swapped, clear_a.swap_bytes());
+
+        let start = rng.gen_range(0..32);
+        let end = rng.gen_range(start..=32);
+        let extracted: i32 = a.extract_bits(start..end).decrypt(cks);
+        let mask = 1u32.checked_shl(end - start).unwrap_or(0).wrapping_sub(1);
+        assert_eq!(extracted, ((clear_a as u32 >> start) & mask) as i32);
+    }
+}
+
 fn test_case_ilog2(cks: &ClientKey) {
     let mut rng = thread_rng();
     for _ in 0..5 {
@@ -690,6 +729,186 @@ fn test_ilog2() {
     test_case_ilog2(&client_key);
 }
 
+#[test]
+fn test_bit_manipulation() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    test_case_bit_manipulation(&client_key);
+}
+
+fn test_case_pow_isqrt(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    for _ in 0..2 {
+        let clear_a = rng.gen::<i8>();
+        let clear_b = rng.gen_range(0..16u8);
+        let a = FheInt8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+
+        // Negative values are interpreted as unsigned
+        let isqrt: i8 = a.isqrt().decrypt(cks);
+        assert_eq!(isqrt, (clear_a as u8 as f64).sqrt() as i8);
+
+        let pow: i8 = a.pow(&b).decrypt(cks);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b as u32));
+
+        let scalar_pow: i8 = a.scalar_pow(clear_b as u32).decrypt(cks);
+        assert_eq!(scalar_pow, clear_a.wrapping_pow(clear_b as u32));
+
+        let (pow, overflowed) = a.checked_pow(&b);
+        let pow: i8 = pow.decrypt(cks);
+        let overflowed = overflowed.decrypt(cks);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b as u32));
+        assert_eq!(overflowed, clear_a.checked_pow(clear_b as u32).is_none());
+    }
+}
+
+#[test]
+fn test_pow_isqrt() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    test_case_pow_isqrt(&client_key);
+}
+
+fn test_case_sort_and_argmax(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    let clears: Vec<i8> = (0..5).map(|_| rng.gen_range(-8..8)).collect();
+    let mut values: Vec<_> = clears
+        .iter()
+        .map(|&clear| FheInt8::encrypt(clear, cks))
+        .collect();
+
+    let (max, max_index) = FheInt8::argmax(&values).unwrap();
+    let (min, min_index) = FheInt8::argmin(&values).unwrap();
+    let top: Vec<i8> = FheInt8::top_k(&values, 2)
+        .iter()
+        .map(|value| value.decrypt(cks))
+        .collect();
+    FheInt8::sort(&mut values);
+
+    let mut expected = clears.clone();
+    expected.sort_unstable();
+    let sorted: Vec<i8> = values.iter().map(|value| value.decrypt(cks)).collect();
+    assert_eq!(sorted, expected);
+    assert_eq!(
+        top,
+        expected.iter().rev().take(2).copied().collect::<Vec<_>>()
+    );
+
+    let max: i8 = max.decrypt(cks);
+    let max_index: u32 = max_index.decrypt(cks);
+    let min: i8 = min.decrypt(cks);
+    let min_index: u32 = min_index.decrypt(cks);
+    assert_eq!(max, *expected.last().unwrap());
+    assert_eq!(clears[max_index as usize], max);
+    assert!(clears[..max_index as usize].iter().all(|&c| c < max));
+    assert_eq!(min, expected[0]);
+    assert_eq!(clears[min_index as usize], min);
+    assert!(clears[..min_index as usize].iter().all(|&c| c > min));
+}
+
+#[test]
+fn test_sort_and_argmax() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    test_case_sort_and_argmax(&client_key);
+}
+
+fn test_case_saturating_and_checked_ops(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    for _ in 0..2 {
+        let clear_a = rng.gen::<i8>();
+        let clear_b = rng.gen::<i8>();
+        let a = FheInt8::encrypt(clear_a, cks);
+        let b = FheInt8::encrypt(clear_b, cks);
+
+        let result: i8 = (&a).saturating_add(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+        let result: i8 = (&a).saturating_sub(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+        let result: i8 = (&a).saturating_mul(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+
+        let (result, is_ok) = (&a).checked_add(&b);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_add(clear_b));
+        assert_eq!(is_ok.decrypt(cks), clear_a.checked_add(clear_b).is_some());
+
+        let (result, is_ok) = (&a).checked_sub(&b);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_sub(clear_b));
+        assert_eq!(is_ok.decrypt(cks), clear_a.checked_sub(clear_b).is_some());
+
+        let (result, is_ok) = (&a).checked_mul(&b);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_mul(clear_b));
+        assert_eq!(is_ok.decrypt(cks), clear_a.checked_mul(clear_b).is_some());
+
+        // Also check the invalid divisions
+        let (clear_a, clear_b) = match rng.gen_range(0..3) {
+            0 => (clear_a, 0),
+            1 => (i8::MIN, -1),
+            _ => (clear_a, clear_b),
+        };
+        let a = FheInt8::encrypt(clear_a, cks);
+        let b = FheInt8::encrypt(clear_b, cks);
+        let (result, is_ok) = (&a).checked_div(&b);
+        let expected = clear_a.checked_div(clear_b);
+        assert_eq!(is_ok.decrypt(cks), expected.is_some());
+        if let Some(expected) = expected {
+            let result: i8 = result.decrypt(cks);
+            assert_eq!(result, expected);
+        }
+        let (result, is_ok) = (&a).checked_rem(&b);
+        let expected = clear_a.checked_rem(clear_b);
+        assert_eq!(is_ok.decrypt(cks), expected.is_some());
+        if let Some(expected) = expected {
+            let result: i8 = result.decrypt(cks);
+            assert_eq!(result, expected);
+        }
+
+        let clear_shift = rng.gen_range(0..16u8);
+        let shift = FheUint8::encrypt(clear_shift, cks);
+        let (result, is_ok) = (&a).checked_shl(&shift);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shl(clear_shift as u32));
+        assert_eq!(
+            is_ok.decrypt(cks),
+            clear_a.checked_shl(clear_shift as u32).is_some()
+        );
+        let (result, is_ok) = (&a).checked_shr(&shift);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shr(clear_shift as u32));
+        assert_eq!(
+            is_ok.decrypt(cks),
+            clear_a.checked_shr(clear_shift as u32).is_some()
+        );
+
+        // The shift amount may be wider or narrower than the shifted value
+        let clear_shift = rng.gen_range(0..16u16);
+        let shift = FheUint16::encrypt(clear_shift, cks);
+        let (result, is_ok) = (&a).checked_shl(&shift);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shl(clear_shift as u32));
+        assert_eq!(
+            is_ok.decrypt(cks),
+            clear_a.checked_shl(clear_shift as u32).is_some()
+        );
+        let clear_shift = rng.gen_range(0..4u8);
+        let shift = FheUint2::encrypt(clear_shift, cks);
+        let (result, is_ok) = (&a).checked_shr(&shift);
+        let result: i8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shr(clear_shift as u32));
+        assert!(is_ok.decrypt(cks));
+    }
+}
+
+#[test]
+fn test_saturating_and_checked_ops() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+    test_case_saturating_and_checked_ops(&client_key);
+}
+
 #[test]
 fn test_leading_trailing_zeros_ones() {
     let (client_key, server_key) = generate_keys(ConfigBuilder::default());
@@ -697,6 +916,30 @@ fn test_leading_trailing_zeros_ones() {
     test_case_leading_trailing_zeros_ones(&client_key);
 }
 
+#[test]
+fn test_int32_widening_mul() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<i32>();
+    let clear_b = rng.gen::<i32>();
+
+    let a = FheInt32::encrypt(clear_a, &client_key);
+    let b = FheInt32::encrypt(clear_b, &client_key);
+
+    let c: FheInt64 = a.widening_mul(&b);
+    let decrypted: i64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, i64::from(clear_a) * i64::from(clear_b));
+
+    let c = a.mul_high(&b);
+    let decrypted: i32 = c.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        ((i64::from(clear_a) * i64::from(clear_b)) >> 32) as i32
+    );
+}
+
 #[test]
 fn test_safe_deserialize_conformant_fhe_int32() {
     let block_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
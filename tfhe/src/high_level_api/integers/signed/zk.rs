@@ -13,8 +13,8 @@ use crate::{CompactPublicKey, FheInt};
 /// encrypted.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactFheInt<Id: FheIntId> {
-    inner: ProvenCompactCiphertextList,
-    _id: Id,
+    pub(in crate::high_level_api) inner: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) _id: Id,
 }
 
 impl<Id: FheIntId> Named for ProvenCompactFheInt<Id> {
@@ -77,8 +77,8 @@ where
 /// encrypted.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactFheIntList<Id: FheIntId> {
-    inner: ProvenCompactCiphertextList,
-    _id: Id,
+    pub(in crate::high_level_api) inner: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) _id: Id,
 }
 
 impl<Id: FheIntId> Named for ProvenCompactFheIntList<Id> {
@@ -0,0 +1,372 @@
+use super::{FheIntId, FheUintId, IntegerId};
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::{IntegerServerKey, InternalServerKey};
+use crate::{FheInt, FheUint};
+
+/// Casts an encrypted index to a [crate::FheUint32]
+fn index_to_fhe_uint32(
+    cpu_key: &IntegerServerKey,
+    index: crate::integer::RadixCiphertext,
+) -> crate::FheUint32 {
+    let index = cpu_key.pbs_key().cast_to_unsigned(
+        index,
+        crate::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+    );
+    crate::FheUint32::new(index)
+}
+
+impl<Id: FheUintId> FheUint<Id> {
+    /// Sorts the values in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut values: Vec<_> = [43u16, 2, 1000, 17, 2]
+    ///     .iter()
+    ///     .map(|&v| FheUint16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// FheUint16::sort(&mut values);
+    ///
+    /// let decrypted: Vec<u16> = values.iter().map(|v| v.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, [2, 2, 17, 43, 1000]);
+    /// ```
+    pub fn sort(values: &mut [Self]) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let mut cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key.pbs_key().sort_parallelized(&mut cts);
+                for (value, ct) in values.iter_mut().zip(cts) {
+                    *value = Self::new(ct);
+                }
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support sort yet");
+            }
+        })
+    }
+
+    /// Returns the `k` greatest values, in descending order
+    ///
+    /// If `k` is greater than the number of values, all the values are returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values: Vec<_> = [43u16, 2, 1000, 17, 2]
+    ///     .iter()
+    ///     .map(|&v| FheUint16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// let top = FheUint16::top_k(&values, 2);
+    ///
+    /// let decrypted: Vec<u16> = top.iter().map(|v| v.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, [1000, 43]);
+    /// ```
+    pub fn top_k(values: &[Self], k: usize) -> Vec<Self> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key
+                    .pbs_key()
+                    .top_k_parallelized(&cts, k)
+                    .into_iter()
+                    .map(Self::new)
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support top_k yet");
+            }
+        })
+    }
+
+    /// Returns the greatest value alongside its index
+    ///
+    /// If the greatest value appears several times, the smallest index is returned.
+    ///
+    /// Returns `None` if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values: Vec<_> = [43u16, 2, 1000, 17, 2]
+    ///     .iter()
+    ///     .map(|&v| FheUint16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// let (max, index) = FheUint16::argmax(&values).unwrap();
+    ///
+    /// let max: u16 = max.decrypt(&client_key);
+    /// let index: u32 = index.decrypt(&client_key);
+    /// assert_eq!(max, 1000);
+    /// assert_eq!(index, 2);
+    /// ```
+    pub fn argmax(values: &[Self]) -> Option<(Self, crate::FheUint32)> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key
+                    .pbs_key()
+                    .argmax_parallelized(&cts)
+                    .map(|(max, index)| (Self::new(max), index_to_fhe_uint32(cpu_key, index)))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support argmax yet");
+            }
+        })
+    }
+
+    /// Returns the smallest value alongside its index
+    ///
+    /// If the smallest value appears several times, the smallest index is returned.
+    ///
+    /// Returns `None` if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values: Vec<_> = [43u16, 2, 1000, 17, 2]
+    ///     .iter()
+    ///     .map(|&v| FheUint16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// let (min, index) = FheUint16::argmin(&values).unwrap();
+    ///
+    /// let min: u16 = min.decrypt(&client_key);
+    /// let index: u32 = index.decrypt(&client_key);
+    /// assert_eq!(min, 2);
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn argmin(values: &[Self]) -> Option<(Self, crate::FheUint32)> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key
+                    .pbs_key()
+                    .argmin_parallelized(&cts)
+                    .map(|(min, index)| (Self::new(min), index_to_fhe_uint32(cpu_key, index)))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support argmin yet");
+            }
+        })
+    }
+}
+
+impl<Id: FheIntId> FheInt<Id> {
+    /// Sorts the values in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let mut values: Vec<_> = [43i16, -2, 1000, 17, -2]
+    ///     .iter()
+    ///     .map(|&v| FheInt16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// FheInt16::sort(&mut values);
+    ///
+    /// let decrypted: Vec<i16> = values.iter().map(|v| v.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, [-2, -2, 17, 43, 1000]);
+    /// ```
+    pub fn sort(values: &mut [Self]) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let mut cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key.pbs_key().sort_parallelized(&mut cts);
+                for (value, ct) in values.iter_mut().zip(cts) {
+                    *value = Self::new(ct);
+                }
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support sort yet");
+            }
+        })
+    }
+
+    /// Returns the `k` greatest values, in descending order
+    ///
+    /// If `k` is greater than the number of values, all the values are returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values: Vec<_> = [43i16, -2, 1000, 17, -2]
+    ///     .iter()
+    ///     .map(|&v| FheInt16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// let top = FheInt16::top_k(&values, 2);
+    ///
+    /// let decrypted: Vec<i16> = top.iter().map(|v| v.decrypt(&client_key)).collect();
+    /// assert_eq!(decrypted, [1000, 43]);
+    /// ```
+    pub fn top_k(values: &[Self], k: usize) -> Vec<Self> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key
+                    .pbs_key()
+                    .top_k_parallelized(&cts, k)
+                    .into_iter()
+                    .map(Self::new)
+                    .collect()
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support top_k yet");
+            }
+        })
+    }
+
+    /// Returns the greatest value alongside its index
+    ///
+    /// If the greatest value appears several times, the smallest index is returned.
+    ///
+    /// Returns `None` if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values: Vec<_> = [43i16, -2, 1000, 17, -2]
+    ///     .iter()
+    ///     .map(|&v| FheInt16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// let (max, index) = FheInt16::argmax(&values).unwrap();
+    ///
+    /// let max: i16 = max.decrypt(&client_key);
+    /// let index: u32 = index.decrypt(&client_key);
+    /// assert_eq!(max, 1000);
+    /// assert_eq!(index, 2);
+    /// ```
+    pub fn argmax(values: &[Self]) -> Option<(Self, crate::FheUint32)> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key
+                    .pbs_key()
+                    .argmax_parallelized(&cts)
+                    .map(|(max, index)| (Self::new(max), index_to_fhe_uint32(cpu_key, index)))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support argmax yet");
+            }
+        })
+    }
+
+    /// Returns the smallest value alongside its index
+    ///
+    /// If the smallest value appears several times, the smallest index is returned.
+    ///
+    /// Returns `None` if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let values: Vec<_> = [43i16, -2, 1000, 17, -2]
+    ///     .iter()
+    ///     .map(|&v| FheInt16::encrypt(v, &client_key))
+    ///     .collect();
+    ///
+    /// let (min, index) = FheInt16::argmin(&values).unwrap();
+    ///
+    /// let min: i16 = min.decrypt(&client_key);
+    /// let index: u32 = index.decrypt(&client_key);
+    /// assert_eq!(min, -2);
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn argmin(values: &[Self]) -> Option<(Self, crate::FheUint32)> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let cts: Vec<_> = values
+                    .iter()
+                    .map(|value| value.ciphertext.on_cpu().to_owned())
+                    .collect();
+                cpu_key
+                    .pbs_key()
+                    .argmin_parallelized(&cts)
+                    .map(|(min, index)| (Self::new(min), index_to_fhe_uint32(cpu_key, index)))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support argmin yet");
+            }
+        })
+    }
+}
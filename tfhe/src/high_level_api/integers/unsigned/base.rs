@@ -1,14 +1,17 @@
 use super::inner::RadixCiphertext;
 use crate::conformance::ParameterSetConformant;
-use crate::core_crypto::prelude::{CastFrom, UnsignedNumeric};
+use crate::core_crypto::prelude::{CastFrom, UnsignedInteger, UnsignedNumeric};
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::with_thread_local_cuda_stream;
 use crate::high_level_api::integers::signed::{FheInt, FheIntId};
 use crate::high_level_api::integers::IntegerId;
 use crate::high_level_api::keys::InternalServerKey;
 use crate::high_level_api::{global_state, Device};
-use crate::integer::block_decomposition::RecomposableFrom;
+use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
+use crate::integer::server_key::{
+    MatchValues, MiniUnsignedInteger, Reciprocable, ScalarMultiplier,
+};
 use crate::named::Named;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
@@ -161,6 +164,33 @@ where
         self.ciphertext.move_to_device(device)
     }
 
+    /// Computes (in-place) the ciphertext if it is the result of operations recorded under
+    /// [with_lazy_evaluation](crate::high_level_api::with_lazy_evaluation)
+    ///
+    /// Does nothing if the ciphertext was not computed lazily.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, with_lazy_evaluation, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint16::encrypt(5u16, &client_key);
+    ///
+    /// let mut result = with_lazy_evaluation(|| &a * &b);
+    /// result.eval();
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 15);
+    /// ```
+    pub fn eval(&mut self) {
+        self.ciphertext.materialize();
+    }
+
     /// Tries to decrypt a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -477,6 +507,758 @@ where
             }
         })
     }
+
+    /// Returns the number of ones in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b01100000_00010111u16, &client_key);
+    ///
+    /// let result = a.count_ones();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 6);
+    /// ```
+    pub fn count_ones(&self) -> super::FheUint32 {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .count_ones_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                super::FheUint32::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support count_ones yet");
+            }
+        })
+    }
+
+    /// Returns the number of zeros in the binary representation of self.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b01100000_00010111u16, &client_key);
+    ///
+    /// let result = a.count_zeros();
+    /// let decrypted: u32 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 10);
+    /// ```
+    pub fn count_zeros(&self) -> super::FheUint32 {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .count_zeros_parallelized(&*self.ciphertext.on_cpu());
+                let result = cpu_key.pbs_key().cast_to_unsigned(
+                    result,
+                    super::FheUint32Id::num_blocks(cpu_key.pbs_key().message_modulus()),
+                );
+                super::FheUint32::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support count_zeros yet");
+            }
+        })
+    }
+
+    /// Reverses the order of bits, the least significant bit becomes the most significant bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b00000000_00010111u16, &client_key);
+    ///
+    /// let result = a.reverse_bits();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b11101000_00000000u16);
+    /// ```
+    pub fn reverse_bits(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .reverse_bits_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support reverse_bits yet");
+            }
+        })
+    }
+
+    /// Reverses the byte order of self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters used have a number of bits per block that does not divide 8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0x12_34u16, &client_key);
+    ///
+    /// let result = a.swap_bytes();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0x34_12u16);
+    /// ```
+    pub fn swap_bytes(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .swap_bytes_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support swap_bytes yet");
+            }
+        })
+    }
+
+    /// Returns the bits of self within `range`, moved to the least significant positions.
+    ///
+    /// All the other bits of the result are 0, i.e. the result is
+    /// `(self >> range.start) & ((1 << range.len()) - 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is reversed or exceeds the number of bits of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(0b01100000_10110110u16, &client_key);
+    ///
+    /// let result = a.extract_bits(1..6);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b1_1011u16);
+    /// ```
+    pub fn extract_bits(&self, range: std::ops::Range<u32>) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .extract_bits_parallelized(&*self.ciphertext.on_cpu(), range);
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support extract_bits yet");
+            }
+        })
+    }
+
+    /// Returns the integer square root of self, rounded down.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    ///
+    /// let result = a.isqrt();
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 31u16);
+    /// ```
+    pub fn isqrt(&self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .isqrt_parallelized(&*self.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support isqrt yet");
+            }
+        })
+    }
+
+    /// Multiplies self by rhs and returns the high half of the full double-width product.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint64};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint64::encrypt(u64::MAX - 2, &client_key);
+    /// let b = FheUint64::encrypt(1u64 << 40, &client_key);
+    ///
+    /// let result = a.mul_high(&b);
+    /// let decrypted: u64 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((u64::MAX - 2) as u128 * (1u128 << 40) >> 64) as u64);
+    /// ```
+    pub fn mul_high(&self, rhs: &Self) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mul_high_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mul_high yet");
+            }
+        })
+    }
+
+    /// Multiplies self by rhs without wrapping, the result having twice as many bits.
+    ///
+    /// `OutId` must be the id of the type with twice the number of bits of self, which is
+    /// ensured by the typed `widening_mul` wrappers.
+    pub(in crate::high_level_api) fn widening_mul_impl<OutId: FheUintId>(
+        &self,
+        rhs: &Self,
+    ) -> FheUint<OutId> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let sks = cpu_key.pbs_key();
+                let result = sks.widening_mul_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                );
+                assert_eq!(
+                    result.blocks.len(),
+                    OutId::num_blocks(sks.message_modulus()),
+                    "Invalid output type for widening_mul"
+                );
+                FheUint::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support widening_mul yet");
+            }
+        })
+    }
+
+    /// Computes `(self + rhs) mod modulus`.
+    ///
+    /// Both operands must be smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2 and fit in the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let modulus = 65521u16;
+    ///
+    /// let a = FheUint16::encrypt(60000u16, &client_key);
+    /// let b = FheUint16::encrypt(50000u16, &client_key);
+    ///
+    /// let result = a.mod_add(&b, modulus);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((60000u32 + 50000) % 65521) as u16);
+    /// ```
+    pub fn mod_add<Scalar>(&self, rhs: &Self, modulus: Scalar) -> Self
+    where
+        Scalar: Reciprocable + UnsignedNumeric + DecomposableInto<u8>,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_add_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_add yet");
+            }
+        })
+    }
+
+    /// Computes `(self - rhs) mod modulus`.
+    ///
+    /// Both operands must be smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2 and fit in the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let modulus = 65521u16;
+    ///
+    /// let a = FheUint16::encrypt(60000u16, &client_key);
+    /// let b = FheUint16::encrypt(50000u16, &client_key);
+    ///
+    /// let result = b.mod_sub(&a, modulus);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (50000u32 + 65521 - 60000) as u16);
+    /// ```
+    pub fn mod_sub<Scalar>(&self, rhs: &Self, modulus: Scalar) -> Self
+    where
+        Scalar: Reciprocable + UnsignedNumeric + DecomposableInto<u8>,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_sub_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_sub yet");
+            }
+        })
+    }
+
+    /// Computes `(self * rhs) mod modulus`.
+    ///
+    /// Both operands must be smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2 and fit in the type.
+    ///
+    /// The product is reduced using Barrett reduction, which is cheaper than a division.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let modulus = 65521u16;
+    ///
+    /// let a = FheUint16::encrypt(60000u16, &client_key);
+    /// let b = FheUint16::encrypt(50000u16, &client_key);
+    ///
+    /// let result = a.mod_mul(&b, modulus);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((60000u64 * 50000) % 65521) as u16);
+    /// ```
+    pub fn mod_mul<Scalar>(&self, rhs: &Self, modulus: Scalar) -> Self
+    where
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_mul_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*rhs.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_mul yet");
+            }
+        })
+    }
+
+    /// Computes `self^exponent mod modulus` for an encrypted exponent.
+    ///
+    /// Self must be smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2 and fit in the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let modulus = 65521u16;
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint8::encrypt(20u8, &client_key);
+    ///
+    /// let result = a.mod_pow(&b, modulus);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (3u64.pow(20) % 65521) as u16);
+    /// ```
+    pub fn mod_pow<E, Scalar>(&self, exponent: &FheUint<E>, modulus: Scalar) -> Self
+    where
+        E: FheUintId,
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().mod_pow_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*exponent.ciphertext.on_cpu(),
+                    modulus,
+                );
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_pow yet");
+            }
+        })
+    }
+
+    /// Computes `self^exponent mod modulus` for a clear exponent.
+    ///
+    /// Self must be smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2 and fit in the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let modulus = 65521u16;
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let result = a.scalar_mod_pow(20u32, modulus);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, (3u64.pow(20) % 65521) as u16);
+    /// ```
+    pub fn scalar_mod_pow<E, Scalar>(&self, exponent: E, modulus: Scalar) -> Self
+    where
+        E: MiniUnsignedInteger,
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().scalar_mod_pow_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    exponent,
+                    modulus,
+                );
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support scalar_mod_pow yet");
+            }
+        })
+    }
+
+    /// Computes the inverse of self modulo a prime `modulus`.
+    ///
+    /// The inverse is computed as `self^(modulus - 2) mod modulus`, so the modulus must be
+    /// prime, and self must be smaller than `modulus`. The inverse of 0 is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let modulus = 65521u16;
+    ///
+    /// let a = FheUint16::encrypt(1234u16, &client_key);
+    ///
+    /// let result = a.mod_inverse(modulus);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!((decrypted as u32 * 1234) % 65521, 1);
+    /// ```
+    pub fn mod_inverse<Scalar>(&self, modulus: Scalar) -> Self
+    where
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .mod_inverse_parallelized(&*self.ciphertext.on_cpu(), modulus);
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support mod_inverse yet");
+            }
+        })
+    }
+
+    /// Raises self to the power of an encrypted exponent, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint8::encrypt(5u8, &client_key);
+    ///
+    /// let result = a.pow(&b);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u16.wrapping_pow(5));
+    /// ```
+    pub fn pow<E: FheUintId>(&self, exponent: &FheUint<E>) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .pow_parallelized(&*self.ciphertext.on_cpu(), &*exponent.ciphertext.on_cpu());
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support pow yet");
+            }
+        })
+    }
+
+    /// Raises self to the power of a clear exponent, wrapping around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    ///
+    /// let result = a.scalar_pow(7);
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u16.wrapping_pow(7));
+    /// ```
+    pub fn scalar_pow(&self, exponent: u32) -> Self {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key
+                    .pbs_key()
+                    .scalar_pow_parallelized(&*self.ciphertext.on_cpu(), exponent);
+                Self::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support scalar_pow yet");
+            }
+        })
+    }
+
+    /// Raises self to the power of an encrypted exponent.
+    ///
+    /// Returns the wrapped result, and a boolean flag that is true if the computation
+    /// overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3u16, &client_key);
+    /// let b = FheUint8::encrypt(11u8, &client_key);
+    ///
+    /// let (result, overflowed) = a.checked_pow(&b);
+    ///
+    /// let overflowed = overflowed.decrypt(&client_key);
+    /// assert!(overflowed);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u16.wrapping_pow(11));
+    /// ```
+    pub fn checked_pow<E: FheUintId>(&self, exponent: &FheUint<E>) -> (Self, FheBool) {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, overflowed) = cpu_key.pbs_key().unsigned_checked_pow_parallelized(
+                    &*self.ciphertext.on_cpu(),
+                    &*exponent.ciphertext.on_cpu(),
+                );
+                (Self::new(result), FheBool::new(overflowed))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support checked_pow yet");
+            }
+        })
+    }
+
+    /// Maps self to the output of the matching entry of the clear table
+    ///
+    /// Returns the mapped value and a boolean which is true if an entry matched.
+    /// If no entry matched, the mapped value is 0.
+    ///
+    /// Returns an error if the greatest output of the table does not fit in the output type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8, MatchValues};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(9u8, &client_key);
+    ///
+    /// let matches = MatchValues::new(vec![(3u16, 17u16), (9, 4), (200, 1000)]).unwrap();
+    ///
+    /// let (result, is_match): (FheUint16, _) = a.match_value(&matches).unwrap();
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 4);
+    /// assert!(is_match.decrypt(&client_key));
+    /// ```
+    pub fn match_value<Clear, OutId>(
+        &self,
+        matches: &MatchValues<Clear>,
+    ) -> crate::Result<(FheUint<OutId>, FheBool)>
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64>,
+        OutId: FheUintId,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let (result, is_match) = cpu_key
+                    .pbs_key()
+                    .match_value_parallelized(&self.ciphertext.on_cpu(), matches);
+                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+                if result.blocks.len() > target_num_blocks {
+                    return Err(crate::Error::new(
+                        "The outputs of the table do not fit in the output type".to_string(),
+                    ));
+                }
+                let result = cpu_key
+                    .pbs_key()
+                    .cast_to_unsigned(result, target_num_blocks);
+                Ok((FheUint::new(result), FheBool::new(is_match)))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support match_value yet");
+            }
+        })
+    }
+
+    /// Maps self to the output of the matching entry of the clear table,
+    /// or to `default` if no entry matched
+    ///
+    /// Returns an error if the greatest output of the table or `default` does not fit in the
+    /// output type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8, MatchValues};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(10u8, &client_key);
+    ///
+    /// let matches = MatchValues::new(vec![(3u16, 17u16), (9, 4), (200, 1000)]).unwrap();
+    ///
+    /// let result: FheUint16 = a.map_with_default(&matches, 42).unwrap();
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42);
+    /// ```
+    pub fn map_with_default<Clear, OutId>(
+        &self,
+        matches: &MatchValues<Clear>,
+        default: Clear,
+    ) -> crate::Result<FheUint<OutId>>
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64>,
+        OutId: FheUintId,
+    {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().map_with_default_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    matches,
+                    default,
+                );
+                let target_num_blocks = OutId::num_blocks(cpu_key.message_modulus());
+                if result.blocks.len() > target_num_blocks {
+                    return Err(crate::Error::new(
+                        "The outputs of the table do not fit in the output type".to_string(),
+                    ));
+                }
+                let result = cpu_key
+                    .pbs_key()
+                    .cast_to_unsigned(result, target_num_blocks);
+                Ok(FheUint::new(result))
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support map_with_default yet");
+            }
+        })
+    }
 }
 
 impl<Id> TryFrom<crate::integer::RadixCiphertext> for FheUint<Id>
@@ -3,7 +3,7 @@ use crate::high_level_api::integers::unsigned::base::{
     FheUint, FheUintConformanceParams, FheUintId,
 };
 use crate::high_level_api::traits::FheTryEncrypt;
-use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::ciphertext::{CompactCiphertextList, DataKind};
 use crate::integer::parameters::RadixCompactCiphertextListConformanceParams;
 use crate::named::Named;
 use crate::shortint::PBSParameters;
@@ -36,8 +36,8 @@ use std::marker::PhantomData;
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct CompactFheUint<Id: FheUintId> {
-    pub(in crate::high_level_api::integers) list: CompactCiphertextList,
-    pub(in crate::high_level_api::integers) id: Id,
+    pub(in crate::high_level_api) list: CompactCiphertextList,
+    pub(in crate::high_level_api) id: Id,
 }
 
 impl<Id> CompactFheUint<Id>
@@ -47,8 +47,16 @@ where
     /// Expand to a [FheUint]
     ///
     /// See [CompactFheUint] example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying list is empty, which can only happen with a value that was
+    /// deserialized without checking its conformance.
     pub fn expand(&self) -> FheUint<Id> {
-        let ct: crate::integer::RadixCiphertext = self.list.expand_one();
+        let ct: crate::integer::RadixCiphertext = self
+            .list
+            .try_expand_one()
+            .expect("A CompactFheUint holds one value");
         let mut ct = FheUint::new(ct);
         ct.move_to_device_of_server_key_if_set();
         ct
@@ -93,7 +101,9 @@ impl<Id: FheUintId> ParameterSetConformant for CompactFheUint<Id> {
         let params = params
             .params
             .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(1));
-        self.list.is_conformant(&params)
+        self.list
+            .holds_only(DataKind::Unsigned(params.num_blocks_per_integer))
+            && self.list.is_conformant(&params)
     }
 }
 
@@ -128,8 +138,8 @@ impl<Id: FheUintId> ParameterSetConformant for CompactFheUint<Id> {
 #[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
 #[derive(Clone, serde::Deserialize, serde::Serialize)]
 pub struct CompactFheUintList<Id: FheUintId> {
-    pub(in crate::high_level_api::integers) list: CompactCiphertextList,
-    pub(in crate::high_level_api::integers) id: Id,
+    pub(in crate::high_level_api) list: CompactCiphertextList,
+    pub(in crate::high_level_api) id: Id,
 }
 
 impl<Id> CompactFheUintList<Id>
@@ -234,7 +244,9 @@ impl<Id: FheUintId> ParameterSetConformant for CompactFheUintList<Id> {
     type ParameterSet = CompactFheUintListConformanceParams<Id>;
 
     fn is_conformant(&self, params: &CompactFheUintListConformanceParams<Id>) -> bool {
-        self.list.is_conformant(&params.params)
+        self.list
+            .holds_only(DataKind::Unsigned(params.params.num_blocks_per_integer))
+            && self.list.is_conformant(&params.params)
     }
 }
 
@@ -242,6 +254,7 @@ impl<Id: FheUintId> ParameterSetConformant for CompactFheUintList<Id> {
 mod test {
     use super::*;
     use crate::core_crypto::prelude::UnsignedInteger;
+    use crate::integer::ciphertext::DataKind;
     use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
     use crate::shortint::{CiphertextModulus, PBSOrder};
     use crate::{
@@ -280,7 +293,6 @@ mod test {
         )));
 
         let breaker_lists = [
-            change_parameters(&|ct: &mut Ct| &mut ct.list.num_blocks_per_integer),
             change_parameters(&|ct: &mut Ct| &mut ct.list.ct_list.message_modulus.0),
             change_parameters(&|ct: &mut Ct| &mut ct.list.ct_list.carry_modulus.0),
             change_parameters(&|ct: &mut Ct| ct.list.ct_list.degree.as_mut()),
@@ -303,6 +315,12 @@ mod test {
         }
 
         let breakers2: Vec<&ParameterModifier<'_, Ct>> = vec![
+            &|ct: &mut Ct| ct.list.info[0] = DataKind::Unsigned(ct.list.info[0].num_blocks() + 1),
+            &|ct: &mut Ct| ct.list.info[0] = DataKind::Unsigned(ct.list.info[0].num_blocks() - 1),
+            &|ct: &mut Ct| ct.list.info.push(ct.list.info[0]),
+            &|ct: &mut Ct| {
+                ct.list.info.pop();
+            },
             &|ct: &mut Ct| ct.list.ct_list.pbs_order = PBSOrder::BootstrapKeyswitch,
             &|ct: &mut Ct| {
                 *ct.list.ct_list.ct_list.get_mut_ciphertext_modulus() =
@@ -349,7 +367,6 @@ mod test {
         assert!(ct.is_conformant(&params));
 
         let breaker_lists = [
-            change_parameters(&|ct: &mut Ct| &mut ct.list.num_blocks_per_integer),
             change_parameters(&|ct: &mut Ct| &mut ct.list.ct_list.message_modulus.0),
             change_parameters(&|ct: &mut Ct| &mut ct.list.ct_list.carry_modulus.0),
             change_parameters(&|ct: &mut Ct| ct.list.ct_list.degree.as_mut()),
@@ -370,6 +387,12 @@ mod test {
         }
 
         let breakers2: Vec<&ParameterModifier<'_, Ct>> = vec![
+            &|ct: &mut Ct| ct.list.info[0] = DataKind::Unsigned(ct.list.info[0].num_blocks() + 1),
+            &|ct: &mut Ct| ct.list.info[0] = DataKind::Unsigned(ct.list.info[0].num_blocks() - 1),
+            &|ct: &mut Ct| ct.list.info.push(ct.list.info[0]),
+            &|ct: &mut Ct| {
+                ct.list.info.pop();
+            },
             &|ct: &mut Ct| ct.list.ct_list.pbs_order = PBSOrder::BootstrapKeyswitch,
             &|ct: &mut Ct| {
                 *ct.list.ct_list.ct_list.get_mut_ciphertext_modulus() =
@@ -1,15 +1,21 @@
 use crate::high_level_api::details::MaybeCloned;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::{self, with_thread_local_cuda_stream};
+use crate::high_level_api::lazy::{LazyNode, LazyOp, LazyOperand};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::Device;
 use serde::{Deserializer, Serializer};
+use std::sync::Arc;
 
 pub(crate) enum RadixCiphertext {
     Cpu(crate::integer::RadixCiphertext),
     #[cfg(feature = "gpu")]
     Cuda(crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext),
+    /// Result of operations recorded under
+    /// [with_lazy_evaluation](crate::high_level_api::with_lazy_evaluation), computed on the CPU
+    /// when needed
+    Lazy(Arc<LazyNode>),
 }
 
 impl From<crate::integer::RadixCiphertext> for RadixCiphertext {
@@ -33,6 +39,7 @@ impl Clone for RadixCiphertext {
             Self::Cuda(inner) => {
                 with_thread_local_cuda_stream(|stream| Self::Cuda(inner.duplicate(stream)))
             }
+            Self::Lazy(node) => Self::Lazy(node.clone()),
         }
     }
 }
@@ -61,7 +68,7 @@ impl<'de> serde::Deserialize<'de> for RadixCiphertext {
 impl RadixCiphertext {
     pub(crate) fn current_device(&self) -> Device {
         match self {
-            Self::Cpu(_) => Device::Cpu,
+            Self::Cpu(_) | Self::Lazy(_) => Device::Cpu,
             #[cfg(feature = "gpu")]
             Self::Cuda(_) => Device::CudaGpu,
         }
@@ -72,6 +79,7 @@ impl RadixCiphertext {
     pub(crate) fn on_cpu(&self) -> MaybeCloned<'_, crate::integer::RadixCiphertext> {
         match self {
             Self::Cpu(ct) => MaybeCloned::Borrowed(ct),
+            Self::Lazy(node) => MaybeCloned::Borrowed(node.evaluate().as_unsigned()),
             #[cfg(feature = "gpu")]
             Self::Cuda(ct) => with_thread_local_cuda_stream(|stream| {
                 let cpu_ct = ct.to_radix_ciphertext(stream);
@@ -96,17 +104,22 @@ impl RadixCiphertext {
             }),
             #[cfg(feature = "gpu")]
             Self::Cuda(ct) => MaybeCloned::Borrowed(ct),
+            Self::Lazy(node) => with_thread_local_cuda_stream(|stream| {
+                let ct =
+                    crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                        node.evaluate().as_unsigned(), stream,
+                    );
+                MaybeCloned::Cloned(ct)
+            }),
         }
     }
 
     pub(crate) fn as_cpu_mut(&mut self) -> &mut crate::integer::RadixCiphertext {
-        match self {
-            Self::Cpu(radix_ct) => radix_ct,
-            #[cfg(feature = "gpu")]
-            _ => {
-                self.move_to_device(Device::Cpu);
-                self.as_cpu_mut()
-            }
+        if let Self::Cpu(radix_ct) = self {
+            radix_ct
+        } else {
+            self.move_to_device(Device::Cpu);
+            self.as_cpu_mut()
         }
     }
 
@@ -129,6 +142,7 @@ impl RadixCiphertext {
             Self::Cuda(ct) => {
                 with_thread_local_cuda_stream(|stream| ct.to_radix_ciphertext(stream))
             }
+            Self::Lazy(node) => node.evaluate().as_unsigned().clone(),
         }
     }
 
@@ -141,6 +155,12 @@ impl RadixCiphertext {
                 )
             }),
             Self::Cuda(ct) => ct,
+            Self::Lazy(node) => with_thread_local_cuda_stream(|stream| {
+                crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                    node.evaluate().as_unsigned(),
+                    stream,
+                )
+            }),
         }
     }
 
@@ -168,6 +188,39 @@ impl RadixCiphertext {
                     with_thread_local_cuda_stream(|stream| ct.to_radix_ciphertext(stream));
                 *self = Self::Cpu(new_inner);
             }
+            (Self::Lazy(node), Device::Cpu) => {
+                let new_inner = node.evaluate().as_unsigned().clone();
+                *self = Self::Cpu(new_inner);
+            }
+            #[cfg(feature = "gpu")]
+            (Self::Lazy(node), Device::CudaGpu) => {
+                let new_inner = with_thread_local_cuda_stream(|stream| {
+                    crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                        node.evaluate().as_unsigned(), stream,
+                    )
+                });
+                *self = Self::Cuda(new_inner);
+            }
+        }
+    }
+
+    /// Records `op` on the operands, to be computed when the result is needed
+    pub(crate) fn lazy(op: LazyOp, operands: Vec<LazyOperand>) -> Self {
+        Self::Lazy(Arc::new(LazyNode::new(op, operands)))
+    }
+
+    pub(crate) fn to_lazy_operand(&self) -> LazyOperand {
+        if let Self::Lazy(node) = self {
+            LazyOperand::Node(node.clone())
+        } else {
+            LazyOperand::from(self.on_cpu().into_owned())
+        }
+    }
+
+    /// Computes the ciphertext if it is the result of lazy operations
+    pub(crate) fn materialize(&mut self) {
+        if matches!(self, Self::Lazy(_)) {
+            self.move_to_device(Device::Cpu);
         }
     }
 
@@ -9,6 +9,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_stream;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::lazy::{is_lazy_evaluation_enabled, LazyOp};
 use crate::high_level_api::traits::{
     DivRem, FheBootstrap, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
@@ -311,6 +312,17 @@ where
     fn eq(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.eq_parallelized(values[0].as_unsigned(), values[1].as_unsigned())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .eq_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -349,6 +361,17 @@ where
     fn ne(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.ne_parallelized(values[0].as_unsigned(), values[1].as_unsigned())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .ne_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -413,6 +436,17 @@ where
     fn lt(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.lt_parallelized(values[0].as_unsigned(), values[1].as_unsigned())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .lt_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -451,6 +485,17 @@ where
     fn le(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.le_parallelized(values[0].as_unsigned(), values[1].as_unsigned())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .le_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -489,6 +534,17 @@ where
     fn gt(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.gt_parallelized(values[0].as_unsigned(), values[1].as_unsigned())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .gt_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -527,6 +583,17 @@ where
     fn ge(&self, rhs: &Self) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        |sks, values| {
+                            sks.ge_parallelized(values[0].as_unsigned(), values[1].as_unsigned())
+                        },
+                        vec![
+                            self.ciphertext.to_lazy_operand(),
+                            rhs.ciphertext.to_lazy_operand(),
+                        ],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .ge_parallelized(&*self.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -747,6 +814,12 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheUint::new(RadixCiphertext::lazy(
+                            LazyOp::Add,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .add_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -790,6 +863,12 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheUint::new(RadixCiphertext::lazy(
+                            LazyOp::Sub,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .sub_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -833,6 +912,12 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheUint::new(RadixCiphertext::lazy(
+                            LazyOp::Mul,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .mul_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -874,6 +959,12 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheUint::new(RadixCiphertext::lazy(
+                            LazyOp::BitAnd,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .bitand_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -915,6 +1006,12 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheUint::new(RadixCiphertext::lazy(
+                            LazyOp::BitOr,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .bitor_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -956,6 +1053,12 @@ generic_integer_impl_operation!(
         |lhs: &FheUint<_>, rhs: &FheUint<_>| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return FheUint::new(RadixCiphertext::lazy(
+                            LazyOp::BitXor,
+                            vec![lhs.ciphertext.to_lazy_operand(), rhs.ciphertext.to_lazy_operand()],
+                        ));
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .bitxor_parallelized(&*lhs.ciphertext.on_cpu(), &*rhs.ciphertext.on_cpu());
@@ -0,0 +1,215 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::prelude::{SaturatingAdd, SaturatingMul, SaturatingSub};
+use crate::FheUint;
+
+impl<Id> SaturatingAdd<Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Adds two [FheUint], saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(u16::MAX - 10, &client_key);
+    /// let b = FheUint16::encrypt(20u16, &client_key);
+    ///
+    /// let result = (&a).saturating_add(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, u16::MAX);
+    /// ```
+    fn saturating_add(self, other: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().unsigned_saturating_add_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                FheUint::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating_add yet");
+            }
+        })
+    }
+}
+
+impl<Id> SaturatingAdd<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Adds two [FheUint], saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    /// let b = FheUint16::encrypt(20u16, &client_key);
+    ///
+    /// let result = a.saturating_add(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 1020u16);
+    /// ```
+    fn saturating_add(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingAdd<&Self>>::saturating_add(&self, other)
+    }
+}
+
+impl<Id> SaturatingSub<Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Subtracts two [FheUint], saturating at 0 instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(10u16, &client_key);
+    /// let b = FheUint16::encrypt(20u16, &client_key);
+    ///
+    /// let result = (&a).saturating_sub(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 0u16);
+    /// ```
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().unsigned_saturating_sub_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                FheUint::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating_sub yet");
+            }
+        })
+    }
+}
+
+impl<Id> SaturatingSub<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Subtracts two [FheUint], saturating at 0 instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(30u16, &client_key);
+    /// let b = FheUint16::encrypt(20u16, &client_key);
+    ///
+    /// let result = a.saturating_sub(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 10u16);
+    /// ```
+    fn saturating_sub(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingSub<&Self>>::saturating_sub(&self, other)
+    }
+}
+
+impl<Id> SaturatingMul<Self> for &FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = FheUint<Id>;
+
+    /// Multiplies two [FheUint], saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(3000u16, &client_key);
+    /// let b = FheUint16::encrypt(30u16, &client_key);
+    ///
+    /// let result = (&a).saturating_mul(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, u16::MAX);
+    /// ```
+    fn saturating_mul(self, other: Self) -> Self::Output {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let result = cpu_key.pbs_key().unsigned_saturating_mul_parallelized(
+                    &self.ciphertext.on_cpu(),
+                    &other.ciphertext.on_cpu(),
+                );
+                FheUint::new(result)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support saturating_mul yet");
+            }
+        })
+    }
+}
+
+impl<Id> SaturatingMul<&Self> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    type Output = Self;
+
+    /// Multiplies two [FheUint], saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    /// let b = FheUint16::encrypt(30u16, &client_key);
+    ///
+    /// let result = a.saturating_mul(&b);
+    /// let result: u16 = result.decrypt(&client_key);
+    /// assert_eq!(result, 9000u16);
+    /// ```
+    fn saturating_mul(self, other: &Self) -> Self::Output {
+        <&Self as SaturatingMul<&Self>>::saturating_mul(&self, other)
+    }
+}
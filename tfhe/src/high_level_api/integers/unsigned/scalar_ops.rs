@@ -11,6 +11,7 @@ use crate::high_level_api::global_state;
 use crate::high_level_api::global_state::with_thread_local_cuda_stream;
 use crate::high_level_api::integers::FheUintId;
 use crate::high_level_api::keys::InternalServerKey;
+use crate::high_level_api::lazy::{is_lazy_evaluation_enabled, LazyOp};
 use crate::high_level_api::traits::{
     DivRem, FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
@@ -19,7 +20,7 @@ use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::IntegerCiphertext;
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
-use crate::integer::U256;
+use crate::integer::{U1024, U2048, U256, U512};
 use crate::FheBool;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
@@ -53,6 +54,12 @@ where
     fn eq(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_eq_parallelized(values[0].as_unsigned(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_eq_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -90,6 +97,12 @@ where
     fn ne(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_ne_parallelized(values[0].as_unsigned(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_ne_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -133,6 +146,12 @@ where
     fn lt(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_lt_parallelized(values[0].as_unsigned(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_lt_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -170,6 +189,12 @@ where
     fn le(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_le_parallelized(values[0].as_unsigned(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_le_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -207,6 +232,12 @@ where
     fn gt(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_gt_parallelized(values[0].as_unsigned(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_gt_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -244,6 +275,12 @@ where
     fn ge(&self, rhs: Clear) -> FheBool {
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
+                if is_lazy_evaluation_enabled() {
+                    return FheBool::lazy_comparison(
+                        move |sks, values| sks.scalar_ge_parallelized(values[0].as_unsigned(), rhs),
+                        vec![self.ciphertext.to_lazy_operand()],
+                    );
+                }
                 let inner_result = cpu_key
                     .pbs_key()
                     .scalar_ge_parallelized(&*self.ciphertext.on_cpu(), rhs);
@@ -416,6 +453,9 @@ generic_integer_impl_scalar_div_rem!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 
 // Ciphertext/Scalar ops
@@ -460,12 +500,32 @@ macro_rules! generic_integer_impl_scalar_operation {
 
 pub(in crate::high_level_api::integers) use generic_integer_impl_scalar_operation;
 
+/// Records the addition or subtraction of a clear scalar, which is added as a trivial ciphertext
+/// so that chains of additions and subtractions can be fused
+fn lazy_scalar_sum<Id, Clear>(
+    sks: &crate::integer::ServerKey,
+    op: LazyOp,
+    lhs: &FheUint<Id>,
+    rhs: Clear,
+) -> RadixCiphertext
+where
+    Id: FheUintId,
+    Clear: DecomposableInto<u64>,
+{
+    let num_blocks = Id::num_blocks(sks.message_modulus());
+    let rhs: crate::integer::RadixCiphertext = sks.create_trivial_radix(rhs, num_blocks);
+    RadixCiphertext::lazy(op, vec![lhs.ciphertext.to_lazy_operand(), rhs.into()])
+}
+
 generic_integer_impl_scalar_operation!(
     rust_trait: Add(add),
     implem: {
         |lhs: &FheUint<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return lazy_scalar_sum(cpu_key.pbs_key(), LazyOp::Add, lhs, rhs);
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_add_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -497,6 +557,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Sub(sub),
@@ -504,6 +567,9 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheUint<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return lazy_scalar_sum(cpu_key.pbs_key(), LazyOp::Sub, lhs, rhs);
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_sub_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -535,6 +601,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Mul(mul),
@@ -542,6 +611,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheUint<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_mul_parallelized(values[0].as_unsigned(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_mul_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -573,6 +650,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitAnd(bitand),
@@ -580,6 +660,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheUint<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_bitand_parallelized(values[0].as_unsigned(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_bitand_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -611,6 +699,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitOr(bitor),
@@ -618,6 +709,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheUint<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_bitor_parallelized(values[0].as_unsigned(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_bitor_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -649,6 +748,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: BitXor(bitxor),
@@ -656,6 +758,14 @@ generic_integer_impl_scalar_operation!(
         |lhs: &FheUint<_>, rhs| {
             global_state::with_internal_keys(|key| match key {
                 InternalServerKey::Cpu(cpu_key) => {
+                    if is_lazy_evaluation_enabled() {
+                        return RadixCiphertext::lazy(
+                            LazyOp::other(move |sks, values| {
+                                sks.scalar_bitxor_parallelized(values[0].as_unsigned(), rhs).into()
+                            }),
+                            vec![lhs.ciphertext.to_lazy_operand()],
+                        );
+                    }
                     let inner_result = cpu_key
                         .pbs_key()
                         .scalar_bitxor_parallelized(&*lhs.ciphertext.on_cpu(), rhs);
@@ -688,6 +798,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Shl(shl),
@@ -726,6 +839,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Shr(shr),
@@ -764,6 +880,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: RotateLeft(rotate_left),
@@ -802,6 +921,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: RotateRight(rotate_right),
@@ -840,6 +962,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Div(div),
@@ -873,6 +998,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation!(
     rust_trait: Rem(rem),
@@ -906,6 +1034,9 @@ generic_integer_impl_scalar_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 
 // Scalar / Ciphertext ops
@@ -1018,6 +1149,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: Sub(sub),
@@ -1082,6 +1216,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: Mul(mul),
@@ -1127,6 +1264,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitAnd(bitand),
@@ -1170,6 +1310,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitOr(bitor),
@@ -1213,6 +1356,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_left_operation!(
     rust_trait: BitXor(bitxor),
@@ -1256,6 +1402,9 @@ generic_integer_impl_scalar_left_operation!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 
 // Scalar assign ops
@@ -1344,6 +1493,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: SubAssign(sub_assign),
@@ -1379,6 +1531,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: MulAssign(mul_assign),
@@ -1414,6 +1569,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitAndAssign(bitand_assign),
@@ -1449,6 +1607,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitOrAssign(bitor_assign),
@@ -1484,6 +1645,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: BitXorAssign(bitxor_assign),
@@ -1519,6 +1683,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShlAssign(shl_assign),
@@ -1554,6 +1721,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: ShrAssign(shr_assign),
@@ -1589,6 +1759,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateLeftAssign(rotate_left_assign),
@@ -1624,6 +1797,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RotateRightAssign(rotate_right_assign),
@@ -1659,6 +1835,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u8, u16, u32, u64, u128),
         (super::FheUint160, u8, u16, u32, u64, u128, U256),
         (super::FheUint256, u8, u16, u32, u64, u128, U256),
+        (super::FheUint512, u8, u16, u32, u64, u128, U512),
+        (super::FheUint1024, u8, u16, u32, u64, u128, U1024),
+        (super::FheUint2048, u8, u16, u32, u64, u128, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: DivAssign(div_assign),
@@ -1691,6 +1870,9 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: RemAssign(rem_assign),
@@ -1723,4 +1905,7 @@ generic_integer_impl_scalar_operation_assign!(
         (super::FheUint128, u128),
         (super::FheUint160, U256),
         (super::FheUint256, U256),
+        (super::FheUint512, U512),
+        (super::FheUint1024, U1024),
+        (super::FheUint2048, U2048),
 );
@@ -1,15 +1,15 @@
 use crate::conformance::ListSizeConstraint;
 use crate::high_level_api::prelude::*;
 use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
-use crate::integer::U256;
+use crate::integer::{U1024, U256, U512};
 use crate::safe_deserialization::safe_deserialize_conformant;
 use crate::shortint::parameters::classic::compact_pk::*;
 use crate::shortint::parameters::*;
 use crate::{
     ClientKey, CompactFheUint32, CompactFheUint32List, CompactFheUint32ListConformanceParams,
     CompactPublicKey, CompressedFheUint16, CompressedFheUint256, CompressedFheUint32,
-    CompressedPublicKey, Config, FheInt16, FheInt32, FheInt8, FheUint128, FheUint16, FheUint256,
-    FheUint32, FheUint32ConformanceParams,
+    CompressedFheUint512, CompressedPublicKey, Config, FheInt16, FheInt32, FheInt8, FheUint1024,
+    FheUint128, FheUint16, FheUint256, FheUint32, FheUint32ConformanceParams, FheUint64,
 };
 use rand::prelude::*;
 
@@ -29,7 +29,7 @@ fn setup_cpu(params: Option<impl Into<PBSParameters>>) -> ClientKey {
     client_key
 }
 
-fn setup_default_cpu() -> ClientKey {
+pub(crate) fn setup_default_cpu() -> ClientKey {
     setup_cpu(Option::<ClassicPBSParameters>::None)
 }
 
@@ -49,6 +49,37 @@ fn test_integer_compressed_can_be_serialized() {
     assert_eq!(clear_decompressed, clear);
 }
 
+#[test]
+fn test_uint512_compressed_scalar_ops_and_casting() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+    let clear = rng.gen::<U512>();
+    let clear_scalar = rng.gen::<U512>();
+
+    let compressed = CompressedFheUint512::try_encrypt(clear, &client_key).unwrap();
+    let a = compressed.decompress();
+    let decrypted: U512 = a.decrypt(&client_key);
+    assert_eq!(decrypted, clear);
+
+    let c = &a + clear_scalar;
+    let decrypted: U512 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear + clear_scalar);
+
+    let c = a.eq(clear);
+    assert!(c.decrypt(&client_key));
+
+    // Upcasting keeps the value, downcasting truncates it
+    let c: FheUint1024 = a.clone().cast_into();
+    let decrypted: U1024 = c.decrypt(&client_key);
+    assert_eq!(decrypted.0[..8], clear.0);
+    assert_eq!(decrypted.0[8..], [0u64; 8]);
+
+    let c: FheUint256 = a.cast_into();
+    let decrypted: U256 = c.decrypt(&client_key);
+    assert_eq!(decrypted.0, clear.0[..4]);
+}
+
 #[test]
 fn test_integer_compressed() {
     let config = ConfigBuilder::default().build();
@@ -166,6 +197,76 @@ fn test_small_uint128() {
     assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
 }
 
+#[test]
+fn test_uint32_widening_mul() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u32>();
+    let clear_b = rng.gen::<u32>();
+
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+
+    let c: FheUint64 = a.widening_mul(&b);
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, u64::from(clear_a) * u64::from(clear_b));
+
+    let c = a.mul_high(&b);
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        ((u64::from(clear_a) * u64::from(clear_b)) >> 32) as u32
+    );
+}
+
+#[test]
+fn test_uint16_modular_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let mut rng = rand::thread_rng();
+    // Largest prime below 2^16
+    let modulus = 65521u16;
+    let clear_a = rng.gen_range(0..modulus);
+    let clear_b = rng.gen_range(0..modulus);
+    let clear_exponent = rng.gen::<u8>();
+
+    let a = FheUint16::encrypt(clear_a, &client_key);
+    let b = FheUint16::encrypt(clear_b, &client_key);
+    let exponent = FheUint8::encrypt(clear_exponent, &client_key);
+
+    let (clear_a, clear_b, clear_modulus) =
+        (u64::from(clear_a), u64::from(clear_b), u64::from(modulus));
+    let clear_pow = (0..clear_exponent).fold(1, |acc, _| (acc * clear_a) % clear_modulus);
+
+    let decrypted: u16 = a.mod_add(&b, modulus).decrypt(&client_key);
+    assert_eq!(u64::from(decrypted), (clear_a + clear_b) % clear_modulus);
+
+    let decrypted: u16 = a.mod_sub(&b, modulus).decrypt(&client_key);
+    assert_eq!(
+        u64::from(decrypted),
+        (clear_a + clear_modulus - clear_b) % clear_modulus
+    );
+
+    let decrypted: u16 = a.mod_mul(&b, modulus).decrypt(&client_key);
+    assert_eq!(u64::from(decrypted), (clear_a * clear_b) % clear_modulus);
+
+    let decrypted: u16 = a.mod_pow(&exponent, modulus).decrypt(&client_key);
+    assert_eq!(u64::from(decrypted), clear_pow);
+
+    let decrypted: u16 = a
+        .scalar_mod_pow(clear_exponent, modulus)
+        .decrypt(&client_key);
+    assert_eq!(u64::from(decrypted), clear_pow);
+
+    let decrypted: u16 = a.mod_inverse(modulus).decrypt(&client_key);
+    if clear_a == 0 {
+        assert_eq!(decrypted, 0);
+    } else {
+        assert_eq!((u64::from(decrypted) * clear_a) % clear_modulus, 1);
+    }
+}
+
 #[test]
 fn test_integer_compressed_public_key() {
     let config = ConfigBuilder::default().build();
@@ -433,6 +534,36 @@ fn test_leading_trailing_zeros_ones() {
     super::test_case_leading_trailing_zeros_ones(&client_key);
 }
 
+#[test]
+fn test_bit_manipulation() {
+    let client_key = setup_default_cpu();
+    super::test_case_bit_manipulation(&client_key);
+}
+
+#[test]
+fn test_pow_isqrt() {
+    let client_key = setup_default_cpu();
+    super::test_case_pow_isqrt(&client_key);
+}
+
+#[test]
+fn test_match_value() {
+    let client_key = setup_default_cpu();
+    super::test_case_match_value(&client_key);
+}
+
+#[test]
+fn test_sort_and_argmax() {
+    let client_key = setup_default_cpu();
+    super::test_case_sort_and_argmax(&client_key);
+}
+
+#[test]
+fn test_saturating_and_checked_ops() {
+    let client_key = setup_default_cpu();
+    super::test_case_saturating_and_checked_ops(&client_key);
+}
+
 #[test]
 fn test_sum() {
     let client_key = setup_default_cpu();
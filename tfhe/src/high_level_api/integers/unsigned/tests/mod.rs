@@ -1,9 +1,11 @@
 use crate::integer::U256;
 use crate::prelude::*;
-use crate::{ClientKey, FheUint256, FheUint32, FheUint64, FheUint8};
+use crate::{
+    ClientKey, FheUint16, FheUint2, FheUint256, FheUint32, FheUint64, FheUint8, MatchValues,
+};
 use rand::{thread_rng, Rng};
 
-mod cpu;
+pub(crate) mod cpu;
 #[cfg(feature = "gpu")]
 mod gpu;
 
@@ -442,6 +444,215 @@ fn test_case_leading_trailing_zeros_ones(cks: &ClientKey) {
     }
 }
 
+fn test_case_bit_manipulation(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u32>();
+        let a = FheUint32::try_encrypt(clear_a, cks).unwrap();
+
+        let count_ones: u32 = a.count_ones().decrypt(cks);
+        assert_eq!(count_ones, clear_a.count_ones());
+
+        let count_zeros: u32 = a.count_zeros().decrypt(cks);
+        assert_eq!(count_zeros, clear_a.count_zeros());
+
+        let reversed: u32 = a.reverse_bits().decrypt(cks);
+        assert_eq!(reversed, clear_a.reverse_bits());
+
+        let swapped: u32 = a.swap_bytes().decrypt(cks);
+        assert_eq!(swapped, clear_a.swap_bytes());
+
+        let start = rng.gen_range(0..32);
+        let end = rng.gen_range(start..=32);
+        let extracted: u32 = a.extract_bits(start..end).decrypt(cks);
+        let mask = 1u32.checked_shl(end - start).unwrap_or(0).wrapping_sub(1);
+        assert_eq!(extracted, (clear_a >> start) & mask);
+    }
+}
+
+fn test_case_pow_isqrt(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..2 {
+        let clear_a = rng.gen::<u8>();
+        let clear_b = rng.gen_range(0..16u8);
+        let a = FheUint8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+
+        let isqrt: u8 = a.isqrt().decrypt(cks);
+        assert_eq!(isqrt, (clear_a as f64).sqrt() as u8);
+
+        let pow: u8 = a.pow(&b).decrypt(cks);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b as u32));
+
+        let scalar_pow: u8 = a.scalar_pow(clear_b as u32).decrypt(cks);
+        assert_eq!(scalar_pow, clear_a.wrapping_pow(clear_b as u32));
+
+        let (pow, overflowed) = a.checked_pow(&b);
+        let pow: u8 = pow.decrypt(cks);
+        let overflowed = overflowed.decrypt(cks);
+        assert_eq!(pow, clear_a.wrapping_pow(clear_b as u32));
+        assert_eq!(overflowed, clear_a.checked_pow(clear_b as u32).is_none());
+    }
+}
+
+fn test_case_match_value(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    let table: Vec<(u16, u16)> = (0..20u16)
+        .map(|i| ((i * 13) % 256, rng.gen::<u16>()))
+        .collect();
+    let matches = MatchValues::new(table.clone()).unwrap();
+
+    for _ in 0..3 {
+        let clear = rng.gen::<u8>();
+        let a = FheUint8::encrypt(clear, cks);
+        let expected = table
+            .iter()
+            .find(|(input, _)| *input == clear as u16)
+            .map(|(_, output)| *output);
+
+        let (result, is_match): (FheUint16, _) = a.match_value(&matches).unwrap();
+        let decrypted: u16 = result.decrypt(cks);
+        assert_eq!(decrypted, expected.unwrap_or(0));
+        assert_eq!(is_match.decrypt(cks), expected.is_some());
+
+        let result: FheUint16 = a.map_with_default(&matches, 1234).unwrap();
+        let decrypted: u16 = result.decrypt(cks);
+        assert_eq!(decrypted, expected.unwrap_or(1234));
+    }
+
+    // The outputs do not fit in the output type
+    let matches = MatchValues::new(vec![(1u16, 1000u16)]).unwrap();
+    let a = FheUint8::encrypt(1u8, cks);
+    assert!(a.match_value::<_, crate::FheUint8Id>(&matches).is_err());
+}
+
+fn test_case_sort_and_argmax(cks: &ClientKey) {
+    let mut rng = thread_rng();
+    let clears: Vec<u8> = (0..5).map(|_| rng.gen_range(0..16)).collect();
+    let mut values: Vec<_> = clears
+        .iter()
+        .map(|&clear| FheUint8::encrypt(clear, cks))
+        .collect();
+
+    let (max, max_index) = FheUint8::argmax(&values).unwrap();
+    let (min, min_index) = FheUint8::argmin(&values).unwrap();
+    let top: Vec<u8> = FheUint8::top_k(&values, 3)
+        .iter()
+        .map(|value| value.decrypt(cks))
+        .collect();
+    FheUint8::sort(&mut values);
+
+    let mut expected = clears.clone();
+    expected.sort_unstable();
+    let sorted: Vec<u8> = values.iter().map(|value| value.decrypt(cks)).collect();
+    assert_eq!(sorted, expected);
+    assert_eq!(
+        top,
+        expected.iter().rev().take(3).copied().collect::<Vec<_>>()
+    );
+
+    let expected_max = *expected.last().unwrap();
+    let expected_min = expected[0];
+    let max: u8 = max.decrypt(cks);
+    let max_index: u32 = max_index.decrypt(cks);
+    let min: u8 = min.decrypt(cks);
+    let min_index: u32 = min_index.decrypt(cks);
+    assert_eq!(max, expected_max);
+    assert_eq!(clears[max_index as usize], expected_max);
+    assert!(clears[..max_index as usize]
+        .iter()
+        .all(|&c| c < expected_max));
+    assert_eq!(min, expected_min);
+    assert_eq!(clears[min_index as usize], expected_min);
+    assert!(clears[..min_index as usize]
+        .iter()
+        .all(|&c| c > expected_min));
+
+    assert!(FheUint8::argmax(&[]).is_none());
+}
+
+fn test_case_saturating_and_checked_ops(cks: &ClientKey) {
+    let mut rng = rand::thread_rng();
+    for _ in 0..2 {
+        let clear_a = rng.gen::<u8>();
+        let clear_b = rng.gen::<u8>();
+        let a = FheUint8::encrypt(clear_a, cks);
+        let b = FheUint8::encrypt(clear_b, cks);
+
+        let result: u8 = (&a).saturating_add(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+        let result: u8 = (&a).saturating_sub(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+        let result: u8 = (&a).saturating_mul(&b).decrypt(cks);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+
+        let (result, is_ok) = (&a).checked_add(&b);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_add(clear_b));
+        assert_eq!(is_ok.decrypt(cks), clear_a.checked_add(clear_b).is_some());
+
+        let (result, is_ok) = (&a).checked_sub(&b);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_sub(clear_b));
+        assert_eq!(is_ok.decrypt(cks), clear_a.checked_sub(clear_b).is_some());
+
+        let (result, is_ok) = (&a).checked_mul(&b);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_mul(clear_b));
+        assert_eq!(is_ok.decrypt(cks), clear_a.checked_mul(clear_b).is_some());
+
+        // Also check the division by zero
+        let clear_b = if rng.gen_bool(0.5) { 0 } else { clear_b };
+        let b = FheUint8::encrypt(clear_b, cks);
+        let (result, is_ok) = (&a).checked_div(&b);
+        assert_eq!(is_ok.decrypt(cks), clear_b != 0);
+        if clear_b != 0 {
+            let result: u8 = result.decrypt(cks);
+            assert_eq!(result, clear_a / clear_b);
+        }
+        let (result, is_ok) = (&a).checked_rem(&b);
+        assert_eq!(is_ok.decrypt(cks), clear_b != 0);
+        if clear_b != 0 {
+            let result: u8 = result.decrypt(cks);
+            assert_eq!(result, clear_a % clear_b);
+        }
+
+        let clear_shift = rng.gen_range(0..16u8);
+        let shift = FheUint8::encrypt(clear_shift, cks);
+        let (result, is_ok) = (&a).checked_shl(&shift);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shl(clear_shift as u32));
+        assert_eq!(
+            is_ok.decrypt(cks),
+            clear_a.checked_shl(clear_shift as u32).is_some()
+        );
+        let (result, is_ok) = (&a).checked_shr(&shift);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shr(clear_shift as u32));
+        assert_eq!(
+            is_ok.decrypt(cks),
+            clear_a.checked_shr(clear_shift as u32).is_some()
+        );
+
+        // The shift amount may be wider or narrower than the shifted value
+        let clear_shift = rng.gen_range(0..16u16);
+        let shift = FheUint16::encrypt(clear_shift, cks);
+        let (result, is_ok) = (&a).checked_shl(&shift);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shl(clear_shift as u32));
+        assert_eq!(
+            is_ok.decrypt(cks),
+            clear_a.checked_shl(clear_shift as u32).is_some()
+        );
+        let clear_shift = rng.gen_range(0..4u8);
+        let shift = FheUint2::encrypt(clear_shift, cks);
+        let (result, is_ok) = (&a).checked_shr(&shift);
+        let result: u8 = result.decrypt(cks);
+        assert_eq!(result, clear_a.wrapping_shr(clear_shift as u32));
+        assert!(is_ok.decrypt(cks));
+    }
+}
+
 fn test_case_ilog2(cks: &ClientKey) {
     let mut rng = rand::thread_rng();
     for _ in 0..5 {
@@ -13,8 +13,8 @@ use crate::{CompactPublicKey, FheUint};
 /// encrypted.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactFheUint<Id: FheUintId> {
-    inner: ProvenCompactCiphertextList,
-    _id: Id,
+    pub(in crate::high_level_api) inner: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) _id: Id,
 }
 
 impl<Id: FheUintId> Named for ProvenCompactFheUint<Id> {
@@ -77,8 +77,8 @@ where
 /// encrypted.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactFheUintList<Id: FheUintId> {
-    inner: ProvenCompactCiphertextList,
-    _id: Id,
+    pub(in crate::high_level_api) inner: ProvenCompactCiphertextList,
+    pub(in crate::high_level_api) _id: Id,
 }
 
 impl<Id: FheUintId> Named for ProvenCompactFheUintList<Id> {
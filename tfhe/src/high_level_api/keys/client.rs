@@ -19,6 +19,10 @@ pub struct ClientKey {
     pub(crate) key: IntegerClientKey,
 }
 
+impl crate::named::Named for ClientKey {
+    const NAME: &'static str = "high_level_api::ClientKey";
+}
+
 impl ClientKey {
     /// Generates a new key from the given config.
     pub fn generate<C: Into<Config>>(config: C) -> Self {
@@ -66,6 +70,7 @@ impl ClientKey {
     ) -> (
         crate::integer::ClientKey,
         Option<crate::shortint::WopbsParameters>,
+        Option<crate::integer::compression_keys::CompressionPrivateKeys>,
     ) {
         self.key.into_raw_parts()
     }
@@ -73,9 +78,10 @@ impl ClientKey {
     pub fn from_raw_parts(
         key: crate::integer::ClientKey,
         wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+        compression_key: Option<crate::integer::compression_keys::CompressionPrivateKeys>,
     ) -> Self {
         Self {
-            key: IntegerClientKey::from_raw_parts(key, wopbs_block_parameters),
+            key: IntegerClientKey::from_raw_parts(key, wopbs_block_parameters, compression_key),
         }
     }
 
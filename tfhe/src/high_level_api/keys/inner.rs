@@ -1,16 +1,20 @@
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
-use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::ciphertext::{CompactCiphertextList, DataKind};
+use crate::integer::compression_keys::{CompressionKey, CompressionPrivateKeys, DecompressionKey};
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::{EncryptionKeyChoice, MessageModulus};
 use concrete_csprng::seeders::Seed;
 use serde::{Deserialize, Serialize};
 
+#[allow(clippy::struct_field_names)]
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: crate::shortint::PBSParameters,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) compression_parameters: Option<CompressionParameters>,
 }
 
 impl IntegerConfig {
@@ -21,6 +25,7 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            compression_parameters: None,
         }
     }
 
@@ -28,6 +33,7 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
             wopbs_block_parameters: None,
+            compression_parameters: None,
         }
     }
 
@@ -35,6 +41,7 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into(),
             wopbs_block_parameters: None,
+            compression_parameters: None,
         }
     }
 
@@ -46,12 +53,23 @@ impl IntegerConfig {
 
         self.wopbs_block_parameters = Some(wopbs_block_parameters);
     }
+
+    pub fn enable_compression(&mut self, compression_parameters: CompressionParameters) {
+        assert_eq!(
+            self.block_parameters.encryption_key_choice(),
+            EncryptionKeyChoice::Big,
+            "Compression is only compatible with KS_PBS parameters"
+        );
+
+        self.compression_parameters = Some(compression_parameters);
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) compression_key: Option<CompressionPrivateKeys>,
 }
 
 impl IntegerClientKey {
@@ -61,12 +79,18 @@ impl IntegerClientKey {
             "This API only supports parameters for which the MessageModulus is 2 or 4 (1 or 2 bits per block)",
         );
         let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
-        let cks = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder)
-            .new_client_key(config.block_parameters.into());
+        let mut engine = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder);
+        let cks = engine.new_client_key(config.block_parameters.into());
+
+        let compression_key = config.compression_parameters.map(|params| {
+            CompressionPrivateKeys::from_raw_parts(engine.new_compression_private_key(&cks, params))
+        });
+
         let key = crate::integer::ClientKey::from(cks);
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            compression_key,
         }
     }
 
@@ -76,12 +100,14 @@ impl IntegerClientKey {
     ) -> (
         crate::integer::ClientKey,
         Option<crate::shortint::WopbsParameters>,
+        Option<CompressionPrivateKeys>,
     ) {
         let Self {
             key,
             wopbs_block_parameters,
+            compression_key,
         } = self;
-        (key, wopbs_block_parameters)
+        (key, wopbs_block_parameters, compression_key)
     }
 
     /// Construct a, [`IntegerClientKey`] from its constituents.
@@ -92,6 +118,7 @@ impl IntegerClientKey {
     pub fn from_raw_parts(
         key: crate::integer::ClientKey,
         wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+        compression_key: Option<CompressionPrivateKeys>,
     ) -> Self {
         let shortint_cks: &crate::shortint::ClientKey = key.as_ref();
         if let Some(wop_params) = wopbs_block_parameters.as_ref() {
@@ -105,9 +132,18 @@ impl IntegerClientKey {
             );
         }
 
+        if compression_key.is_some() {
+            assert_eq!(
+                shortint_cks.parameters.encryption_key_choice(),
+                EncryptionKeyChoice::Big,
+                "Compression is only compatible with KS_PBS parameters"
+            );
+        }
+
         Self {
             key,
             wopbs_block_parameters,
+            compression_key,
         }
     }
 
@@ -123,9 +159,15 @@ impl From<IntegerConfig> for IntegerClientKey {
             "This API only supports parameters for which the MessageModulus is 2 or 4 (1 or 2 bits per block)",
         );
         let key = crate::integer::ClientKey::new(config.block_parameters);
+
+        let compression_key = config
+            .compression_parameters
+            .map(|params| key.new_compression_private_key(params));
+
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            compression_key,
         }
     }
 }
@@ -134,6 +176,8 @@ impl From<IntegerConfig> for IntegerClientKey {
 pub struct IntegerServerKey {
     pub(crate) key: crate::integer::ServerKey,
     pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+    pub(crate) compression_key: Option<CompressionKey>,
+    pub(crate) decompression_key: Option<DecompressionKey>,
 }
 
 impl IntegerServerKey {
@@ -146,9 +190,22 @@ impl IntegerServerKey {
             .map(|wopbs_params| {
                 crate::integer::wopbs::WopbsKey::new_wopbs_key(cks, &base_integer_key, wopbs_params)
             });
+
+        let (compression_key, decompression_key) =
+            client_key
+                .compression_key
+                .as_ref()
+                .map_or((None, None), |private_compression_key| {
+                    let (compression_key, decompression_key) =
+                        cks.new_compression_decompression_keys(private_compression_key);
+                    (Some(compression_key), Some(decompression_key))
+                });
+
         Self {
             key: base_integer_key,
             wopbs_key,
+            compression_key,
+            decompression_key,
         }
     }
 
@@ -164,6 +221,8 @@ impl IntegerServerKey {
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct IntegerCompressedServerKey {
     pub(crate) key: crate::integer::CompressedServerKey,
+    pub(crate) compression_key: Option<CompressionKey>,
+    pub(crate) decompression_key: Option<DecompressionKey>,
 }
 
 impl IntegerCompressedServerKey {
@@ -179,21 +238,53 @@ impl IntegerCompressedServerKey {
                    "
         );
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(integer_key);
-        Self { key }
+
+        // The compression keys do not have a seeded version yet, they are stored as is
+        let (compression_key, decompression_key) =
+            client_key
+                .compression_key
+                .as_ref()
+                .map_or((None, None), |private_compression_key| {
+                    let (compression_key, decompression_key) =
+                        integer_key.new_compression_decompression_keys(private_compression_key);
+                    (Some(compression_key), Some(decompression_key))
+                });
+
+        Self {
+            key,
+            compression_key,
+            decompression_key,
+        }
     }
 
-    pub fn into_raw_parts(self) -> crate::integer::CompressedServerKey {
-        self.key
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::CompressedServerKey,
+        Option<CompressionKey>,
+        Option<DecompressionKey>,
+    ) {
+        (self.key, self.compression_key, self.decompression_key)
     }
 
-    pub fn from_raw_parts(key: crate::integer::CompressedServerKey) -> Self {
-        Self { key }
+    pub fn from_raw_parts(
+        key: crate::integer::CompressedServerKey,
+        compression_key: Option<CompressionKey>,
+        decompression_key: Option<DecompressionKey>,
+    ) -> Self {
+        Self {
+            key,
+            compression_key,
+            decompression_key,
+        }
     }
 
     pub(in crate::high_level_api) fn decompress(&self) -> IntegerServerKey {
         IntegerServerKey {
             key: self.key.decompress(),
             wopbs_key: None,
+            compression_key: self.compression_key.clone(),
+            decompression_key: self.decompression_key.clone(),
         }
     }
 }
@@ -227,6 +318,19 @@ impl IntegerCompactPublicKey {
         self.key.encrypt_slice_radix_compact(values, num_blocks)
     }
 
+    pub(in crate::high_level_api) fn try_encrypt_signed_compact<T>(
+        &self,
+        values: &[T],
+        num_blocks: usize,
+    ) -> CompactCiphertextList
+    where
+        T: crate::integer::block_decomposition::DecomposableInto<u64>,
+    {
+        let mut list = self.key.encrypt_slice_radix_compact(values, num_blocks);
+        list.info.fill(DataKind::Signed(num_blocks));
+        list
+    }
+
     pub fn into_raw_parts(self) -> CompactPublicKey {
         self.key
     }
@@ -26,6 +26,10 @@ pub struct PublicKey {
     pub(in crate::high_level_api) key: crate::integer::PublicKey,
 }
 
+impl crate::named::Named for PublicKey {
+    const NAME: &'static str = "high_level_api::PublicKey";
+}
+
 impl PublicKey {
     /// Creates a PublicKey, derived from the given client key
     pub fn new(client_key: &ClientKey) -> Self {
@@ -54,6 +58,10 @@ pub struct CompressedPublicKey {
     pub(in crate::high_level_api) key: crate::integer::CompressedPublicKey,
 }
 
+impl crate::named::Named for CompressedPublicKey {
+    const NAME: &'static str = "high_level_api::CompressedPublicKey";
+}
+
 impl CompressedPublicKey {
     pub fn new(client_key: &ClientKey) -> Self {
         let base_integer_key = crate::integer::CompressedPublicKey::new(&client_key.key.key);
@@ -90,6 +98,10 @@ pub struct CompactPublicKey {
     pub(in crate::high_level_api) key: IntegerCompactPublicKey,
 }
 
+impl crate::named::Named for CompactPublicKey {
+    const NAME: &'static str = "high_level_api::CompactPublicKey";
+}
+
 impl CompactPublicKey {
     /// Creates a CompactPublicKey, derived from the given client key
     ///
@@ -130,6 +142,10 @@ pub struct CompressedCompactPublicKey {
     pub(in crate::high_level_api) key: IntegerCompressedCompactPublicKey,
 }
 
+impl crate::named::Named for CompressedCompactPublicKey {
+    const NAME: &'static str = "high_level_api::CompressedCompactPublicKey";
+}
+
 impl CompressedCompactPublicKey {
     /// Creates a CompressedCompactPublicKey
     ///
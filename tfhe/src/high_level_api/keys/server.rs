@@ -1,4 +1,6 @@
 use crate::high_level_api::keys::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::integer::compression_keys::{CompressionKey, DecompressionKey};
+use crate::integer::server_key::{IntegerOperation, PbsCost};
 
 use std::sync::Arc;
 
@@ -19,6 +21,10 @@ pub struct ServerKey {
     pub(crate) key: Arc<IntegerServerKey>,
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "high_level_api::ServerKey";
+}
+
 impl ServerKey {
     pub fn new(keys: &ClientKey) -> Self {
         Self {
@@ -31,20 +37,74 @@ impl ServerKey {
     ) -> (
         crate::integer::ServerKey,
         Option<crate::integer::wopbs::WopbsKey>,
+        Option<CompressionKey>,
+        Option<DecompressionKey>,
     ) {
-        let IntegerServerKey { key, wopbs_key } = (*self.key).clone();
+        let IntegerServerKey {
+            key,
+            wopbs_key,
+            compression_key,
+            decompression_key,
+        } = (*self.key).clone();
 
-        (key, wopbs_key)
+        (key, wopbs_key, compression_key, decompression_key)
     }
 
     pub fn from_raw_parts(
         key: crate::integer::ServerKey,
         wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+        compression_key: Option<CompressionKey>,
+        decompression_key: Option<DecompressionKey>,
     ) -> Self {
         Self {
-            key: Arc::new(IntegerServerKey { key, wopbs_key }),
+            key: Arc::new(IntegerServerKey {
+                key,
+                wopbs_key,
+                compression_key,
+                decompression_key,
+            }),
         }
     }
+
+    /// Estimates the cost of an operation on encrypted integers of `num_bits` bits, without
+    /// executing it
+    ///
+    /// See [crate::integer::ServerKey::estimate_pbs_cost], the integers of this API always
+    /// have clean carries, unless unchecked operations of the integer API are used on them.
+    ///
+    /// Returns an error if `num_bits` is not a multiple of the number of bits per block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::{generate_keys, ConfigBuilder, IntegerOperation};
+    ///
+    /// let (_client_key, server_key) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let cost = server_key
+    ///     .estimate_pbs_cost(IntegerOperation::Eq, 32, true)
+    ///     .unwrap();
+    /// assert!(cost.pbs_count > 0);
+    /// assert!(cost.pbs_depth <= cost.pbs_count);
+    /// ```
+    pub fn estimate_pbs_cost(
+        &self,
+        operation: IntegerOperation,
+        num_bits: u32,
+        operands_are_clean: bool,
+    ) -> crate::Result<PbsCost> {
+        let bits_per_block = self.key.message_modulus().0.ilog2();
+        if num_bits % bits_per_block != 0 {
+            return Err(crate::Error::new(format!(
+                "The number of bits ({num_bits}) must be a multiple of the number of bits per \
+                block ({bits_per_block})"
+            )));
+        }
+        let num_blocks = (num_bits / bits_per_block) as usize;
+        self.key
+            .pbs_key()
+            .estimate_pbs_cost(operation, num_blocks, operands_are_clean)
+    }
 }
 
 impl AsRef<crate::integer::ServerKey> for ServerKey {
@@ -112,6 +172,10 @@ pub struct CompressedServerKey {
     pub(crate) integer_key: IntegerCompressedServerKey,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "high_level_api::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     pub fn new(keys: &ClientKey) -> Self {
         Self {
@@ -119,13 +183,27 @@ impl CompressedServerKey {
         }
     }
 
-    pub fn into_raw_parts(self) -> crate::integer::CompressedServerKey {
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::CompressedServerKey,
+        Option<CompressionKey>,
+        Option<DecompressionKey>,
+    ) {
         self.integer_key.into_raw_parts()
     }
 
-    pub fn from_raw_parts(integer_key: crate::integer::CompressedServerKey) -> Self {
+    pub fn from_raw_parts(
+        integer_key: crate::integer::CompressedServerKey,
+        compression_key: Option<CompressionKey>,
+        decompression_key: Option<DecompressionKey>,
+    ) -> Self {
         Self {
-            integer_key: IntegerCompressedServerKey::from_raw_parts(integer_key),
+            integer_key: IntegerCompressedServerKey::from_raw_parts(
+                integer_key,
+                compression_key,
+                decompression_key,
+            ),
         }
     }
 
@@ -158,6 +236,7 @@ impl CudaServerKey {
     }
 }
 
+#[derive(Clone)]
 pub enum InternalServerKey {
     Cpu(Arc<IntegerServerKey>),
     #[cfg(feature = "gpu")]
@@ -0,0 +1,669 @@
+//! Opt-in lazy evaluation of [FheUint](crate::FheUint) and [FheInt](crate::FheInt) operations.
+//!
+//! Inside [with_lazy_evaluation], the arithmetic and bitwise operators, the operators with a
+//! clear scalar and the comparisons do not compute anything: they record a node in a graph of
+//! operations and return a ciphertext which refers to this node.
+//!
+//! The graph is materialized when the value is needed: on decryption, serialization, any
+//! operation which is not recorded, or an explicit call to
+//! [FheUint::eval](crate::FheUint::eval). Independent nodes are then computed in parallel.
+//!
+//! Each node is computed with the server key which was set when it was recorded, so a value can
+//! be materialized on a thread without a server key, e.g. by a client which decrypts it.
+//!
+//! Chains of additions and subtractions, including with clear scalars, are fused into a single
+//! multi-operand sum, which only propagates the carries once: `a - b` is computed as
+//! `a + !b + 1`, the bitwise not of a ciphertext with empty carries not requiring any PBS, and
+//! the scalars are added as trivial ciphertexts.
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::{IntegerServerKey, InternalServerKey};
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use rayon::prelude::*;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+thread_local! {
+    static LAZY_EVALUATION_ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `func` with lazy evaluation of [FheUint](crate::FheUint) and [FheInt](crate::FheInt)
+/// operations enabled on the current thread
+///
+/// The values created lazily stay lazy after this function returns, until they are materialized.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, with_lazy_evaluation, ConfigBuilder, FheUint16};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint16::encrypt(3u16, &client_key);
+/// let b = FheUint16::encrypt(5u16, &client_key);
+/// let c = FheUint16::encrypt(7u16, &client_key);
+///
+/// let (result, is_greater) = with_lazy_evaluation(|| {
+///     // These two products are independent, they will be computed in parallel,
+///     // and the additions and the subtraction are fused in a single sum
+///     let result = &(&(&(&a * &b) + &(&b * &c)) + &a) - 1u16;
+///     let is_greater = result.gt(&c);
+///     (result, is_greater)
+/// });
+///
+/// // Decryption materializes the result
+/// let decrypted: u16 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 3 * 5 + 5 * 7 + 3 - 1);
+/// assert!(is_greater.decrypt(&client_key));
+/// ```
+pub fn with_lazy_evaluation<R>(func: impl FnOnce() -> R) -> R {
+    struct RestoreOnDrop(bool);
+
+    impl Drop for RestoreOnDrop {
+        fn drop(&mut self) {
+            LAZY_EVALUATION_ENABLED.with(|enabled| enabled.set(self.0));
+        }
+    }
+
+    let _restore = RestoreOnDrop(LAZY_EVALUATION_ENABLED.with(|enabled| enabled.replace(true)));
+    func()
+}
+
+pub(in crate::high_level_api) fn is_lazy_evaluation_enabled() -> bool {
+    LAZY_EVALUATION_ENABLED.with(Cell::get)
+}
+
+/// The value of an operand or of the result of a node
+pub(in crate::high_level_api) enum LazyValue {
+    Unsigned(RadixCiphertext),
+    Signed(SignedRadixCiphertext),
+    Boolean(BooleanBlock),
+}
+
+impl LazyValue {
+    pub(in crate::high_level_api) fn as_unsigned(&self) -> &RadixCiphertext {
+        match self {
+            Self::Unsigned(ct) => ct,
+            _ => panic!("Expected an unsigned ciphertext"),
+        }
+    }
+
+    pub(in crate::high_level_api) fn as_signed(&self) -> &SignedRadixCiphertext {
+        match self {
+            Self::Signed(ct) => ct,
+            _ => panic!("Expected a signed ciphertext"),
+        }
+    }
+
+    pub(in crate::high_level_api) fn as_boolean(&self) -> &BooleanBlock {
+        match self {
+            Self::Boolean(block) => block,
+            _ => panic!("Expected a boolean"),
+        }
+    }
+}
+
+impl From<RadixCiphertext> for LazyValue {
+    fn from(value: RadixCiphertext) -> Self {
+        Self::Unsigned(value)
+    }
+}
+
+impl From<SignedRadixCiphertext> for LazyValue {
+    fn from(value: SignedRadixCiphertext) -> Self {
+        Self::Signed(value)
+    }
+}
+
+impl From<BooleanBlock> for LazyValue {
+    fn from(value: BooleanBlock) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+type LazyFunction = dyn Fn(&ServerKey, &[&LazyValue]) -> LazyValue + Send + Sync;
+
+/// The operations which can be recorded
+///
+/// All of them but [LazyOp::Other] take two radix ciphertexts of the same kind.
+pub(in crate::high_level_api) enum LazyOp {
+    Add,
+    Sub,
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+    /// Any other operation, computed by the function from the values of the operands
+    Other(Box<LazyFunction>),
+}
+
+impl LazyOp {
+    pub(in crate::high_level_api) fn other<F>(func: F) -> Self
+    where
+        F: Fn(&ServerKey, &[&LazyValue]) -> LazyValue + Send + Sync + 'static,
+    {
+        Self::Other(Box::new(func))
+    }
+
+    fn is_sum(&self) -> bool {
+        matches!(self, Self::Add | Self::Sub)
+    }
+}
+
+#[derive(Clone)]
+pub(in crate::high_level_api) enum LazyOperand {
+    Value(Arc<LazyValue>),
+    Node(Arc<LazyNode>),
+}
+
+impl<T: Into<LazyValue>> From<T> for LazyOperand {
+    fn from(value: T) -> Self {
+        Self::Value(Arc::new(value.into()))
+    }
+}
+
+impl LazyOperand {
+    /// Returns the operand value, which must have been computed
+    fn value(&self) -> &LazyValue {
+        match self {
+            Self::Value(value) => value,
+            Self::Node(node) => node
+                .result
+                .get()
+                .expect("Operands are computed before the nodes using them"),
+        }
+    }
+
+    /// Returns the node this operand refers to, if it still has to be computed
+    fn pending_node(&self) -> Option<&Arc<LazyNode>> {
+        match self {
+            Self::Node(node) if node.result.get().is_none() => Some(node),
+            _ => None,
+        }
+    }
+}
+
+/// An operand a node is computed from, once the sums are flattened
+struct Term {
+    operand: LazyOperand,
+    /// Whether the operand is subtracted from the sum
+    negated: bool,
+}
+
+/// A recorded operation, whose result is computed at most once
+pub(in crate::high_level_api) struct LazyNode {
+    op: LazyOp,
+    /// Cleared once the result is computed, so that the operands are not kept alive by the
+    /// values computed from them
+    operands: Mutex<Vec<LazyOperand>>,
+    /// The key set when the operation was recorded, which computes it
+    server_key: Arc<IntegerServerKey>,
+    result: OnceLock<LazyValue>,
+}
+
+type Level = Vec<(Arc<LazyNode>, Vec<Term>)>;
+
+impl LazyNode {
+    /// Records an operation, which will be computed with the server key set for the current
+    /// thread, which must be a CPU key
+    pub(in crate::high_level_api) fn new(op: LazyOp, operands: Vec<LazyOperand>) -> Self {
+        match op {
+            LazyOp::Other(_) => assert!(!operands.is_empty(), "Operations need operands"),
+            _ => assert_eq!(operands.len(), 2, "Binary operations need two operands"),
+        }
+        let server_key = global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => Arc::clone(cpu_key),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support lazy evaluation yet")
+            }
+        });
+        Self {
+            op,
+            operands: Mutex::new(operands),
+            server_key,
+            result: OnceLock::new(),
+        }
+    }
+
+    /// Returns the value of the node, computing the pending part of the graph if needed
+    pub(in crate::high_level_api) fn evaluate(self: &Arc<Self>) -> &LazyValue {
+        if self.result.get().is_none() {
+            self.evaluate_graph();
+        }
+        self.result.get().unwrap()
+    }
+
+    /// Computes the pending nodes of the graph rooted at `self`, level by level, the nodes of a
+    /// level being independent from each other
+    fn evaluate_graph(self: &Arc<Self>) {
+        for level in self.plan() {
+            level.par_iter().for_each(|(node, terms)| {
+                let result = node.compute(node.server_key.pbs_key(), terms);
+                // If another thread evaluated a shared node concurrently, the results are the
+                // same and the first one is kept
+                let _ = node.result.set(result);
+                // The operands are not needed anymore, they are dropped once the lock is released
+                let _operands = std::mem::take(&mut *node.lock_operands());
+            });
+        }
+    }
+
+    fn lock_operands(&self) -> std::sync::MutexGuard<'_, Vec<LazyOperand>> {
+        // The operands are only read or cleared, a panic cannot leave them half updated
+        self.operands.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Groups the pending nodes needed to compute `self` by their depth in the graph, with the
+    /// terms they will be computed from
+    ///
+    /// The graph is walked with an explicit stack, as chains of operations can be deeper than
+    /// what recursion allows.
+    fn plan(self: &Arc<Self>) -> Vec<Level> {
+        enum Step {
+            Visit(Arc<LazyNode>),
+            Finish(Arc<LazyNode>, Vec<Term>),
+        }
+
+        let mut depths = HashMap::new();
+        let mut levels: Vec<Level> = Vec::new();
+        let mut stack = vec![Step::Visit(Arc::clone(self))];
+
+        while let Some(step) = stack.pop() {
+            match step {
+                Step::Visit(node) => {
+                    if depths.contains_key(&Arc::as_ptr(&node)) {
+                        continue;
+                    }
+                    let Some(terms) = node.collect_terms() else {
+                        // Computed by another thread in the meantime
+                        continue;
+                    };
+                    let children = terms
+                        .iter()
+                        .filter_map(|term| term.operand.pending_node())
+                        .filter(|child| !depths.contains_key(&Arc::as_ptr(child)))
+                        .map(|child| Step::Visit(Arc::clone(child)))
+                        .collect::<Vec<_>>();
+                    stack.push(Step::Finish(node, terms));
+                    stack.extend(children);
+                }
+                Step::Finish(node, terms) => {
+                    // All the children were finished before their parent
+                    let depth = 1 + terms
+                        .iter()
+                        .filter_map(|term| term.operand.pending_node())
+                        .map(|child| depths[&Arc::as_ptr(child)])
+                        .max()
+                        .unwrap_or(0);
+                    depths.insert(Arc::as_ptr(&node), depth);
+
+                    if levels.len() < depth {
+                        levels.resize_with(depth, Vec::new);
+                    }
+                    levels[depth - 1].push((node, terms));
+                }
+            }
+        }
+
+        levels
+    }
+
+    /// Returns the terms the node is computed from, or `None` if the node was computed since it
+    /// was found pending
+    ///
+    /// The operands of additions and subtractions which are pending additions or subtractions
+    /// used nowhere else are replaced by their own operands, so that the carries are propagated
+    /// only once for the whole sum.
+    fn collect_terms(&self) -> Option<Vec<Term>> {
+        let operands = self.lock_operands();
+        // The result is set before the operands are cleared
+        if self.result.get().is_some() {
+            return None;
+        }
+
+        if !self.op.is_sum() {
+            let terms = operands
+                .iter()
+                .map(|operand| Term {
+                    operand: operand.clone(),
+                    negated: false,
+                })
+                .collect();
+            return Some(terms);
+        }
+
+        let mut terms = Vec::with_capacity(operands.len());
+        // The flattened nodes are only referenced by the node being flattened, so they are
+        // neither computed nor cleared by another thread
+        let mut flattened = Vec::new();
+        Self::push_sum_terms(&self.op, &operands, false, &mut terms, &mut flattened);
+        while let Some((node, negated)) = flattened.pop() {
+            let operands = node.lock_operands();
+            Self::push_sum_terms(&node.op, &operands, negated, &mut terms, &mut flattened);
+        }
+        Some(terms)
+    }
+
+    /// Pushes the terms of the operands of a sum, and the operands to flatten
+    fn push_sum_terms(
+        op: &LazyOp,
+        operands: &[LazyOperand],
+        negated: bool,
+        terms: &mut Vec<Term>,
+        flattened: &mut Vec<(Arc<Self>, bool)>,
+    ) {
+        for (i, operand) in operands.iter().enumerate() {
+            // The right hand side of a subtraction is subtracted
+            let negated = negated ^ (matches!(op, LazyOp::Sub) && i == 1);
+            match operand.pending_node() {
+                Some(child) if child.op.is_sum() && Arc::strong_count(child) == 1 => {
+                    flattened.push((Arc::clone(child), negated));
+                }
+                _ => terms.push(Term {
+                    operand: operand.clone(),
+                    negated,
+                }),
+            }
+        }
+    }
+
+    fn compute(&self, sks: &ServerKey, terms: &[Term]) -> LazyValue {
+        if let LazyOp::Other(func) = &self.op {
+            let values = terms
+                .iter()
+                .map(|term| term.operand.value())
+                .collect::<Vec<_>>();
+            return func(sks, &values);
+        }
+
+        match terms[0].operand.value() {
+            LazyValue::Unsigned(_) => self
+                .compute_radix(sks, terms, LazyValue::as_unsigned)
+                .into(),
+            LazyValue::Signed(_) => self.compute_radix(sks, terms, LazyValue::as_signed).into(),
+            LazyValue::Boolean(_) => panic!("Booleans are only operands of LazyOp::Other"),
+        }
+    }
+
+    fn compute_radix<T>(
+        &self,
+        sks: &ServerKey,
+        terms: &[Term],
+        as_radix: impl Fn(&LazyValue) -> &T + Sync,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if terms.len() > 2 {
+            // A flattened sum
+            return Self::compute_sum(sks, terms, as_radix);
+        }
+
+        let (lhs, rhs) = (
+            as_radix(terms[0].operand.value()),
+            as_radix(terms[1].operand.value()),
+        );
+        match self.op {
+            LazyOp::Add => sks.add_parallelized(lhs, rhs),
+            LazyOp::Sub => sks.sub_parallelized(lhs, rhs),
+            LazyOp::Mul => sks.mul_parallelized(lhs, rhs),
+            LazyOp::BitAnd => sks.bitand_parallelized(lhs, rhs),
+            LazyOp::BitOr => sks.bitor_parallelized(lhs, rhs),
+            LazyOp::BitXor => sks.bitxor_parallelized(lhs, rhs),
+            LazyOp::Other(_) => unreachable!(),
+        }
+    }
+
+    /// Computes the sum of the terms, with a single carry propagation
+    fn compute_sum<T>(
+        sks: &ServerKey,
+        terms: &[Term],
+        as_radix: impl Fn(&LazyValue) -> &T + Sync,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut operands = terms
+            .par_iter()
+            .map(|term| {
+                let value = as_radix(term.operand.value());
+                if term.negated {
+                    sks.bitnot(value)
+                } else {
+                    value.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // -x == !x + 1
+        let num_negated = terms.iter().filter(|term| term.negated).count() as u64;
+        if num_negated != 0 {
+            let num_blocks = operands[0].blocks().len();
+            operands.push(sks.create_trivial_radix(num_negated, num_blocks));
+        }
+
+        // The operands computed by the server key and the bitwise not of such ciphertexts
+        // have empty carries
+        sks.unchecked_sum_ciphertexts_vec_parallelized(operands)
+            .expect("A sum has operands")
+    }
+}
+
+impl Drop for LazyNode {
+    // The default drop would recurse along chains of operations, and overflow the stack on long
+    // ones, so the nodes owned only by this one are unlinked first
+    fn drop(&mut self) {
+        let mut operands = std::mem::take(
+            self.operands
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        while let Some(operand) = operands.pop() {
+            if let LazyOperand::Node(node) = operand {
+                if let Some(mut node) = Arc::into_inner(node) {
+                    operands.append(
+                        node.operands
+                            .get_mut()
+                            .unwrap_or_else(PoisonError::into_inner),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::high_level_api::integers::setup_default_cpu;
+    use crate::prelude::*;
+    use crate::{FheInt8, FheUint8};
+
+    #[test]
+    fn test_lazy_evaluation_matches_eager() {
+        let client_key = setup_default_cpu();
+
+        let clear_a = 13u8;
+        let clear_b = 71u8;
+        let clear_c = 200u8;
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+        let c = FheUint8::encrypt(clear_c, &client_key);
+
+        let (sum, shared, mut product) = with_lazy_evaluation(|| {
+            let sum = &(&(&(&a + &b) - &c) + &a) - 5u8;
+            // Used twice, it is computed once and is not fused
+            let shared = &a ^ &b;
+            let product = &(&(&shared * &c) - &(&shared | &a)) * 3u8;
+            (sum, shared, product)
+        });
+        assert!(!is_lazy_evaluation_enabled());
+
+        let decrypted: u8 = sum.decrypt(&client_key);
+        assert_eq!(
+            decrypted,
+            clear_a
+                .wrapping_add(clear_b)
+                .wrapping_sub(clear_c)
+                .wrapping_add(clear_a)
+                .wrapping_sub(5)
+        );
+
+        product.eval();
+        let decrypted: u8 = product.decrypt(&client_key);
+        let clear_shared = clear_a ^ clear_b;
+        assert_eq!(
+            decrypted,
+            clear_shared
+                .wrapping_mul(clear_c)
+                .wrapping_sub(clear_shared | clear_a)
+                .wrapping_mul(3)
+        );
+
+        let decrypted: u8 = shared.decrypt(&client_key);
+        assert_eq!(decrypted, clear_shared);
+
+        // Outside of the scope, operations are eager again, and accept lazy operands
+        let d = &sum & &b;
+        let decrypted: u8 = d.decrypt(&client_key);
+        assert_eq!(
+            decrypted,
+            clear_a
+                .wrapping_add(clear_b)
+                .wrapping_sub(clear_c)
+                .wrapping_add(clear_a)
+                .wrapping_sub(5)
+                & clear_b
+        );
+    }
+
+    #[test]
+    fn test_lazy_evaluation_independent_branches() {
+        let client_key = setup_default_cpu();
+
+        let clears = [3u8, 250, 17, 42];
+        let values = clears
+            .iter()
+            .map(|clear| FheUint8::encrypt(*clear, &client_key))
+            .collect::<Vec<_>>();
+
+        let (left, right, both) = with_lazy_evaluation(|| {
+            let left = &(&values[0] * &values[1]) | &values[2];
+            let right = &(&values[2] * &values[3]) ^ &values[0];
+            let both = &left + &right;
+            (left, right, both)
+        });
+
+        // Materializing one branch only, then the rest of the graph, which reuses it
+        let clear_left = clears[0].wrapping_mul(clears[1]) | clears[2];
+        let clear_right = clears[2].wrapping_mul(clears[3]) ^ clears[0];
+        let decrypted: u8 = right.decrypt(&client_key);
+        assert_eq!(decrypted, clear_right);
+
+        let decrypted: u8 = both.decrypt(&client_key);
+        assert_eq!(decrypted, clear_left.wrapping_add(clear_right));
+        let decrypted: u8 = left.decrypt(&client_key);
+        assert_eq!(decrypted, clear_left);
+    }
+
+    #[test]
+    fn test_lazy_evaluation_releases_operands() {
+        let client_key = setup_default_cpu();
+        let encrypt = |value: u64| client_key.key.key.encrypt_radix(value, 4);
+
+        let product = Arc::new(LazyNode::new(
+            LazyOp::Mul,
+            vec![encrypt(3).into(), encrypt(5).into()],
+        ));
+        let weak_product = Arc::downgrade(&product);
+        let result = Arc::new(LazyNode::new(
+            LazyOp::BitXor,
+            vec![LazyOperand::Node(product), encrypt(6).into()],
+        ));
+
+        let decrypted: u64 = client_key
+            .key
+            .key
+            .decrypt_radix(result.evaluate().as_unsigned());
+        assert_eq!(decrypted, (3 * 5) ^ 6);
+
+        // Once computed, the result does not hold its operands anymore
+        assert!(result.lock_operands().is_empty());
+        assert!(weak_product.upgrade().is_none());
+    }
+
+    #[test]
+    fn test_lazy_evaluation_deep_chain() {
+        let client_key = setup_default_cpu();
+
+        let clear_x = 7u8;
+        let x = FheUint8::encrypt(clear_x, &client_key);
+        let mut acc = FheUint8::encrypt(0u8, &client_key);
+
+        let num_iterations = 2000;
+        with_lazy_evaluation(|| {
+            for _ in 0..num_iterations {
+                acc = &acc + &x;
+            }
+        });
+
+        let decrypted: u8 = acc.decrypt(&client_key);
+        assert_eq!(decrypted, clear_x.wrapping_mul(num_iterations as u8));
+
+        // Dropping a long chain which was never computed
+        let mut acc = x.clone();
+        with_lazy_evaluation(|| {
+            for _ in 0..num_iterations {
+                acc = &acc ^ &x;
+            }
+        });
+        drop(acc);
+    }
+
+    #[test]
+    fn test_lazy_evaluation_materializes_on_decrypt() {
+        let client_key = setup_default_cpu();
+
+        let a = FheInt8::encrypt(-13i8, &client_key);
+        let b = FheInt8::encrypt(71i8, &client_key);
+
+        let (difference, is_lower, is_equal) = with_lazy_evaluation(|| {
+            let difference = &(&a - &b) + 2i8;
+            let is_lower = difference.lt(&a);
+            let is_equal = (&a + 84i8).eq(&b);
+            (difference, is_lower, is_equal)
+        });
+
+        let clear_difference = (-13i8).wrapping_sub(71).wrapping_add(2);
+        let decrypted: i8 = difference.decrypt(&client_key);
+        assert_eq!(decrypted, clear_difference);
+        assert_eq!(is_lower.decrypt(&client_key), clear_difference < -13);
+        assert!(is_equal.decrypt(&client_key));
+    }
+
+    #[test]
+    fn test_lazy_evaluation_uses_the_recording_key() {
+        let _lock = crate::high_level_api::tests::GLOBAL_SERVER_KEY_LOCK
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let client_key = setup_default_cpu();
+
+        let a = FheUint8::encrypt(100u8, &client_key);
+        let b = FheUint8::encrypt(27u8, &client_key);
+
+        let (sum, product) = with_lazy_evaluation(|| (&a + &b, &a * &b));
+
+        // Without a server key, e.g. on the client, the values can still be materialized
+        crate::unset_server_key();
+        let serialized = bincode::serialize(&sum).unwrap();
+        let sum: FheUint8 = bincode::deserialize(&serialized).unwrap();
+        let decrypted: u8 = sum.decrypt(&client_key);
+        assert_eq!(decrypted, 127);
+        let decrypted: u8 = product.decrypt(&client_key);
+        assert_eq!(decrypted, 100u8.wrapping_mul(27));
+    }
+}
@@ -36,12 +36,27 @@ macro_rules! expand_pub_use_fhe_type(
 
 pub use crate::core_crypto::commons::math::random::Seed;
 pub use crate::integer::oprf::SignedRandomizationSpec;
+pub use crate::integer::server_key::{IntegerOperation, MatchValues, PbsCost};
+pub use array::{FheArray, FheArrayElement};
+#[cfg(feature = "zk-pok-experimental")]
+pub use compact_list::ProvenCompactCiphertextList;
+pub use compact_list::{
+    CompactCiphertextList, CompactCiphertextListBuilder, CompactCiphertextListExpander,
+};
+pub use compressed_ciphertext_list::{CompressedCiphertextList, CompressedCiphertextListBuilder};
 pub use config::{Config, ConfigBuilder};
-pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
+pub use executor::{FheExecutor, FheTask, TaskContext};
+pub use fixed::{FheFixed, FheFixedInteger, RoundingMode};
+pub use float::FheFloat32;
+pub use global_state::{
+    release_rayon_scope_pool, set_global_server_key, set_server_key, unset_global_server_key,
+    unset_server_key, with_server_key_as_context, with_server_key_in_rayon_scope,
+};
+pub use lazy::with_lazy_evaluation;
 
 pub use integers::{
     CompactFheInt, CompactFheIntList, CompactFheUint, CompactFheUintList, CompressedFheInt,
-    CompressedFheUint, FheInt, FheUint, IntegerId,
+    CompressedFheUint, FheInt, FheIntDyn, FheUint, FheUintDyn, IntegerId,
 };
 #[cfg(feature = "gpu")]
 pub use keys::CudaServerKey;
@@ -62,27 +77,42 @@ pub use crate::high_level_api::booleans::{ProvenCompactFheBool, ProvenCompactFhe
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
         FheUint2, FheUint4, FheUint6, FheUint8, FheUint10, FheUint12, FheUint14, FheUint16,
-        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256,
+        FheUint32, FheUint64, FheUint128, FheUint160, FheUint256, FheUint512,
+        FheUint1024, FheUint2048,
 
         FheInt2, FheInt4, FheInt6, FheInt8, FheInt10, FheInt12, FheInt14, FheInt16,
-        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256
+        FheInt32, FheInt64, FheInt128, FheInt160, FheInt256, FheInt512,
+        FheInt1024, FheInt2048
     };
 );
 
 pub use safe_serialize::safe_serialize;
+#[cfg(feature = "strings")]
+pub use strings::{FheAsciiString, FheStringIsEmpty, FheStringLen, FheStringPattern};
 
 mod config;
 mod global_state;
 mod keys;
 mod traits;
 
+mod array;
+pub mod backward_compatibility;
 mod booleans;
+mod compact_list;
+mod compressed_ciphertext_list;
 mod errors;
+mod executor;
+mod fixed;
+mod float;
 mod integers;
+mod lazy;
+#[cfg(feature = "strings")]
+mod strings;
 
 pub(in crate::high_level_api) mod details;
 /// The tfhe prelude.
 pub mod prelude;
+pub mod program;
 #[cfg(feature = "zk-pok-experimental")]
 mod zk;
 
@@ -96,7 +126,7 @@ pub enum Device {
 
 pub mod safe_serialize {
     use crate::named::Named;
-    use serde::Serialize;
+    use crate::versionable::Versionize;
 
     pub fn safe_serialize<T>(
         a: &T,
@@ -104,7 +134,7 @@ pub mod safe_serialize {
         serialized_size_limit: u64,
     ) -> Result<(), String>
     where
-        T: Named + Serialize,
+        T: Named + Versionize,
     {
         crate::safe_deserialization::safe_serialize(a, writer, serialized_size_limit)
             .map_err(|err| err.to_string())
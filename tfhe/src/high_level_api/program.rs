@@ -0,0 +1,1035 @@
+//! A small intermediate representation of encrypted computations, and its interpreter.
+//!
+//! A [Program] describes a computation as data: the types of its inputs, a list of operations
+//! and the values it outputs. It can be serialized, audited and versioned independently of the
+//! binary which executes it, and run against any [ServerKey](crate::ServerKey).
+//!
+//! Values are identified by their [ValueId]: the inputs come first, in their declaration order,
+//! then the result of each operation. An operation can only use values defined before it, which
+//! makes every program acyclic.
+//!
+//! Programs are type checked when they are built with a [ProgramBuilder] and when they are
+//! deserialized, so a [Program] is always well typed. The integer widths also get checked against
+//! the parameters of the key before a program runs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::program::{BinaryOp, ComparisonOp, ProgramBuilder, Value, ValueType};
+//! use tfhe::{generate_keys, ConfigBuilder, FheUintDyn};
+//!
+//! let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+//!
+//! // Computes max(a, b) * 3 and whether a < b
+//! let mut builder = ProgramBuilder::new();
+//! let a = builder.input(ValueType::Uint(16)).unwrap();
+//! let b = builder.input(ValueType::Uint(16)).unwrap();
+//! let max = builder.binary(BinaryOp::Max, a, b).unwrap();
+//! let result = builder.scalar_binary(BinaryOp::Mul, max, 3u16).unwrap();
+//! let is_lower = builder.comparison(ComparisonOp::Lt, a, b).unwrap();
+//! builder.output(result).unwrap();
+//! builder.output(is_lower).unwrap();
+//! let program = builder.build();
+//!
+//! // The program can be shipped to the server
+//! let serialized = bincode::serialize(&program).unwrap();
+//! let program: tfhe::program::Program = bincode::deserialize(&serialized).unwrap();
+//!
+//! let inputs = vec![
+//!     Value::Uint(FheUintDyn::try_encrypt(1000u16, 16, &client_key).unwrap()),
+//!     Value::Uint(FheUintDyn::try_encrypt(400u16, 16, &client_key).unwrap()),
+//! ];
+//! let outputs = program.run(inputs, &server_key).unwrap();
+//!
+//! let Value::Uint(result) = &outputs[0] else {
+//!     panic!("Expected an integer");
+//! };
+//! let decrypted: u16 = result.decrypt(&client_key);
+//! assert_eq!(decrypted, 3000);
+//!
+//! let Value::Bool(is_lower) = &outputs[1] else {
+//!     panic!("Expected a boolean");
+//! };
+//! assert!(!is_lower.decrypt(&client_key));
+//! ```
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::num_blocks_for_bits;
+use crate::high_level_api::traits::{FheEq, FheMax, FheMin, FheOrd, RotateLeft, RotateRight};
+use crate::integer::U2048;
+use crate::named::Named;
+use crate::shortint::MessageModulus;
+use crate::{FheBool, FheUintDyn, ServerKey};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The type of a value of a [Program]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ValueType {
+    /// An encrypted boolean, see [FheBool]
+    Bool,
+    /// An encrypted unsigned integer with the given number of bits, see [FheUintDyn]
+    ///
+    /// The number of bits is at most [FheUintDyn::MAX_NUM_BITS], and must be a multiple of the
+    /// number of bits per block of the key the program runs with.
+    Uint(u32),
+}
+
+impl Display for ValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "FheBool"),
+            Self::Uint(num_bits) => write!(f, "FheUint{num_bits}"),
+        }
+    }
+}
+
+/// Identifies a value of a [Program]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ValueId(u32);
+
+impl ValueId {
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Display for ValueId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+/// A clear unsigned integer operand of a [Program]
+///
+/// It must fit in the integer it is used with, so it has at most [FheUintDyn::MAX_NUM_BITS] bits.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "Vec<u64>", into = "Vec<u64>")]
+pub struct ClearUint {
+    /// The 64 bits words of the value, least significant first, without the leading zero words
+    words: Vec<u64>,
+}
+
+impl ClearUint {
+    /// Creates a clear integer from its 64 bits words, least significant first
+    pub fn from_words(words: impl Into<Vec<u64>>) -> Self {
+        let mut words = words.into();
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+        Self { words }
+    }
+
+    /// Returns the 64 bits words of the value, least significant first
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the number of bits needed to represent the value, 0 for 0
+    pub fn num_bits(&self) -> u32 {
+        self.words.last().map_or(0, |last| {
+            (self.words.len() as u32 - 1) * u64::BITS + (u64::BITS - last.leading_zeros())
+        })
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Converts the value, which must fit in [FheUintDyn::MAX_NUM_BITS] bits
+    fn to_u2048(&self) -> U2048 {
+        let mut words = [0u64; 32];
+        words[..self.words.len()].copy_from_slice(&self.words);
+        U2048::from(words)
+    }
+
+    /// Returns the value modulo `modulus`
+    fn rem_u32(&self, modulus: u32) -> u32 {
+        let modulus = u128::from(modulus);
+        self.words.iter().rev().fold(0, |rem, &word| {
+            (((u128::from(rem) << u64::BITS) | u128::from(word)) % modulus) as u32
+        })
+    }
+}
+
+impl From<Vec<u64>> for ClearUint {
+    fn from(words: Vec<u64>) -> Self {
+        Self::from_words(words)
+    }
+}
+
+impl From<ClearUint> for Vec<u64> {
+    fn from(value: ClearUint) -> Self {
+        value.words
+    }
+}
+
+macro_rules! impl_clear_uint_from {
+    ($($clear_type:ty),*) => {
+        $(
+            impl From<$clear_type> for ClearUint {
+                fn from(value: $clear_type) -> Self {
+                    Self::from_words([u64::from(value)])
+                }
+            }
+        )*
+    };
+}
+
+impl_clear_uint_from!(u8, u16, u32, u64);
+
+impl From<u128> for ClearUint {
+    fn from(value: u128) -> Self {
+        Self::from_words([value as u64, (value >> u64::BITS) as u64])
+    }
+}
+
+impl Display for ClearUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.words.as_slice() {
+            [] => write!(f, "0"),
+            [word] => write!(f, "{word}"),
+            words => {
+                write!(f, "0x")?;
+                for (i, word) in words.iter().rev().enumerate() {
+                    if i == 0 {
+                        write!(f, "{word:x}")?;
+                    } else {
+                        write!(f, "{word:016x}")?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Operations taking two operands
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Min,
+    Max,
+    /// Also accepts booleans, when both operands are encrypted
+    BitAnd,
+    /// Also accepts booleans, when both operands are encrypted
+    BitOr,
+    /// Also accepts booleans, when both operands are encrypted
+    BitXor,
+    /// The amount can be an integer of any width
+    Shl,
+    /// The amount can be an integer of any width
+    Shr,
+    /// The amount can be an integer of any width
+    RotateLeft,
+    /// The amount can be an integer of any width
+    RotateRight,
+}
+
+/// Comparisons, whose result is a boolean
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ComparisonOp {
+    /// Also accepts booleans, when both operands are encrypted
+    Eq,
+    /// Also accepts booleans, when both operands are encrypted
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Operations taking a single operand
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UnaryOp {
+    /// Two's complement negation of an integer
+    Neg,
+    /// Bitwise not of an integer, or logical not of a boolean
+    Not,
+}
+
+/// An operation of a [Program], whose result is a new value
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Operation {
+    /// Operation between two encrypted operands
+    Binary {
+        op: BinaryOp,
+        lhs: ValueId,
+        rhs: ValueId,
+    },
+    /// Operation between an encrypted integer and a clear value, which must fit in the
+    /// integer
+    ScalarBinary {
+        op: BinaryOp,
+        lhs: ValueId,
+        rhs: ClearUint,
+    },
+    /// Comparison of two encrypted operands
+    Comparison {
+        op: ComparisonOp,
+        lhs: ValueId,
+        rhs: ValueId,
+    },
+    /// Comparison of an encrypted integer with a clear value, which must fit in the integer
+    ScalarComparison {
+        op: ComparisonOp,
+        lhs: ValueId,
+        rhs: ClearUint,
+    },
+    Unary {
+        op: UnaryOp,
+        operand: ValueId,
+    },
+    /// Selects `then_value` if `condition` is true, `else_value` otherwise
+    IfThenElse {
+        condition: ValueId,
+        then_value: ValueId,
+        else_value: ValueId,
+    },
+    /// Converts a value to another type
+    ///
+    /// Integers are truncated or zero extended, booleans become 0 or 1, and integers become
+    /// true when they are not 0.
+    Cast {
+        operand: ValueId,
+        to: ValueType,
+    },
+}
+
+/// A value given to, or returned by, a [Program]
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Value {
+    Bool(FheBool),
+    Uint(FheUintDyn),
+}
+
+impl Value {
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Bool(_) => ValueType::Bool,
+            Self::Uint(value) => ValueType::Uint(value.num_bits()),
+        }
+    }
+
+    fn as_bool(&self) -> crate::Result<&FheBool> {
+        match self {
+            Self::Bool(value) => Ok(value),
+            Self::Uint(_) => Err(crate::Error::new(format!(
+                "Expected a FheBool, got a {}",
+                self.value_type()
+            ))),
+        }
+    }
+
+    fn as_uint(&self) -> crate::Result<&FheUintDyn> {
+        match self {
+            Self::Uint(value) => Ok(value),
+            Self::Bool(_) => Err(crate::Error::new(
+                "Expected an unsigned integer, got a FheBool".to_string(),
+            )),
+        }
+    }
+}
+
+impl From<FheBool> for Value {
+    fn from(value: FheBool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<FheUintDyn> for Value {
+    fn from(value: FheUintDyn) -> Self {
+        Self::Uint(value)
+    }
+}
+
+/// A well typed encrypted computation
+///
+/// Use a [ProgramBuilder] to create one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "ProgramParts", try_from = "ProgramParts")]
+pub struct Program {
+    inputs: Vec<ValueType>,
+    operations: Vec<Operation>,
+    outputs: Vec<ValueId>,
+    /// Type of each value, inputs first
+    value_types: Vec<ValueType>,
+}
+
+impl Named for Program {
+    const NAME: &'static str = "high_level_api::program::Program";
+}
+
+/// What a program is serialized as, it gets type checked when deserialized
+#[derive(Clone, Serialize, Deserialize)]
+struct ProgramParts {
+    inputs: Vec<ValueType>,
+    operations: Vec<Operation>,
+    outputs: Vec<ValueId>,
+}
+
+impl From<Program> for ProgramParts {
+    fn from(program: Program) -> Self {
+        Self {
+            inputs: program.inputs,
+            operations: program.operations,
+            outputs: program.outputs,
+        }
+    }
+}
+
+impl TryFrom<ProgramParts> for Program {
+    type Error = crate::Error;
+
+    fn try_from(parts: ProgramParts) -> Result<Self, Self::Error> {
+        let mut builder = ProgramBuilder::new();
+        for input in parts.inputs {
+            builder.input(input)?;
+        }
+        for operation in parts.operations {
+            builder.push(operation)?;
+        }
+        for output in parts.outputs {
+            builder.output(output)?;
+        }
+        Ok(builder.build())
+    }
+}
+
+impl Program {
+    pub fn input_types(&self) -> &[ValueType] {
+        &self.inputs
+    }
+
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    pub fn outputs(&self) -> &[ValueId] {
+        &self.outputs
+    }
+
+    /// Returns the type of each output, in order
+    pub fn output_types(&self) -> Vec<ValueType> {
+        self.outputs
+            .iter()
+            .map(|output| self.value_types[output.index()])
+            .collect()
+    }
+
+    /// Returns the type of a value of the program, or `None` if it does not exist
+    pub fn value_type(&self, value: ValueId) -> Option<ValueType> {
+        self.value_types.get(value.index()).copied()
+    }
+
+    /// Runs the program on `inputs` using `server_key`, and returns its outputs
+    ///
+    /// The server key is set for the current thread during the run, like
+    /// [with_server_key_as_context](crate::with_server_key_as_context) does.
+    ///
+    /// Returns an error if the inputs do not match the types the program expects, or if an
+    /// integer width is not supported by the parameters of the key. Widths are checked
+    /// before any operation runs.
+    pub fn run(&self, inputs: Vec<Value>, server_key: &ServerKey) -> crate::Result<Vec<Value>> {
+        self.check_widths(server_key.key.pbs_key().message_modulus())?;
+        global_state::with_server_key_as_context(server_key.clone(), || self.execute(inputs))
+    }
+
+    /// Checks that every integer of the program can be made of blocks of `message_modulus`
+    fn check_widths(&self, message_modulus: MessageModulus) -> crate::Result<()> {
+        for value_type in &self.value_types {
+            if let ValueType::Uint(num_bits) = *value_type {
+                num_blocks_for_bits(num_bits, message_modulus)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn execute(&self, inputs: Vec<Value>) -> crate::Result<Vec<Value>> {
+        if inputs.len() != self.inputs.len() {
+            return Err(crate::Error::new(format!(
+                "The program expects {} inputs, got {}",
+                self.inputs.len(),
+                inputs.len()
+            )));
+        }
+        for (i, (input, expected)) in inputs.iter().zip(self.inputs.iter()).enumerate() {
+            if input.value_type() != *expected {
+                return Err(crate::Error::new(format!(
+                    "Input {i} must be a {expected}, got a {}",
+                    input.value_type()
+                )));
+            }
+        }
+
+        let mut values = inputs;
+        values.reserve(self.operations.len());
+        for operation in &self.operations {
+            let result = execute_operation(operation, &values)?;
+            values.push(result);
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .map(|output| values[output.index()].clone())
+            .collect())
+    }
+}
+
+fn execute_operation(operation: &Operation, values: &[Value]) -> crate::Result<Value> {
+    let value = |id: ValueId| &values[id.index()];
+
+    let result = match *operation {
+        Operation::Binary { op, lhs, rhs } => match (value(lhs), value(rhs)) {
+            (Value::Bool(lhs), Value::Bool(rhs)) => Value::Bool(match op {
+                BinaryOp::BitAnd => lhs & rhs,
+                BinaryOp::BitOr => lhs | rhs,
+                BinaryOp::BitXor => lhs ^ rhs,
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "{op:?} is not supported on booleans"
+                    )))
+                }
+            }),
+            (lhs, rhs) => {
+                let (lhs, rhs) = (lhs.as_uint()?, rhs.as_uint()?);
+                Value::Uint(match op {
+                    BinaryOp::Add => (lhs + rhs)?,
+                    BinaryOp::Sub => (lhs - rhs)?,
+                    BinaryOp::Mul => (lhs * rhs)?,
+                    BinaryOp::Div => (lhs / rhs)?,
+                    BinaryOp::Rem => (lhs % rhs)?,
+                    BinaryOp::Min => FheMin::min(lhs, rhs)?,
+                    BinaryOp::Max => FheMax::max(lhs, rhs)?,
+                    BinaryOp::BitAnd => (lhs & rhs)?,
+                    BinaryOp::BitOr => (lhs | rhs)?,
+                    BinaryOp::BitXor => (lhs ^ rhs)?,
+                    BinaryOp::Shl => lhs << rhs,
+                    BinaryOp::Shr => lhs >> rhs,
+                    BinaryOp::RotateLeft => lhs.rotate_left(rhs),
+                    BinaryOp::RotateRight => lhs.rotate_right(rhs),
+                })
+            }
+        },
+        Operation::ScalarBinary { op, lhs, ref rhs } => {
+            let lhs = value(lhs).as_uint()?;
+            // Shifting by the amount modulo the number of bits is what the shifts by a clear
+            // amount do
+            let amount = || u64::from(rhs.rem_u32(lhs.num_bits()));
+            let rhs = rhs.to_u2048();
+            Value::Uint(match op {
+                BinaryOp::Add => lhs + rhs,
+                BinaryOp::Sub => lhs - rhs,
+                BinaryOp::Mul => lhs * rhs,
+                BinaryOp::Div => lhs / rhs,
+                BinaryOp::Rem => lhs % rhs,
+                BinaryOp::Min => FheMin::min(lhs, rhs),
+                BinaryOp::Max => FheMax::max(lhs, rhs),
+                BinaryOp::BitAnd => lhs & rhs,
+                BinaryOp::BitOr => lhs | rhs,
+                BinaryOp::BitXor => lhs ^ rhs,
+                BinaryOp::Shl => lhs << amount(),
+                BinaryOp::Shr => lhs >> amount(),
+                BinaryOp::RotateLeft => lhs.rotate_left(amount()),
+                BinaryOp::RotateRight => lhs.rotate_right(amount()),
+            })
+        }
+        Operation::Comparison { op, lhs, rhs } => match (value(lhs), value(rhs)) {
+            (Value::Bool(lhs), Value::Bool(rhs)) => Value::Bool(match op {
+                ComparisonOp::Eq => lhs.eq(rhs),
+                ComparisonOp::Ne => lhs.ne(rhs),
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "{op:?} is not supported on booleans"
+                    )))
+                }
+            }),
+            (lhs, rhs) => {
+                let (lhs, rhs) = (lhs.as_uint()?, rhs.as_uint()?);
+                Value::Bool(match op {
+                    ComparisonOp::Eq => lhs.try_eq(rhs)?,
+                    ComparisonOp::Ne => lhs.try_ne(rhs)?,
+                    ComparisonOp::Lt => lhs.try_lt(rhs)?,
+                    ComparisonOp::Le => lhs.try_le(rhs)?,
+                    ComparisonOp::Gt => lhs.try_gt(rhs)?,
+                    ComparisonOp::Ge => lhs.try_ge(rhs)?,
+                })
+            }
+        },
+        Operation::ScalarComparison { op, lhs, ref rhs } => {
+            let lhs = value(lhs).as_uint()?;
+            let rhs = rhs.to_u2048();
+            Value::Bool(match op {
+                ComparisonOp::Eq => FheEq::eq(lhs, rhs),
+                ComparisonOp::Ne => FheEq::ne(lhs, rhs),
+                ComparisonOp::Lt => FheOrd::lt(lhs, rhs),
+                ComparisonOp::Le => FheOrd::le(lhs, rhs),
+                ComparisonOp::Gt => FheOrd::gt(lhs, rhs),
+                ComparisonOp::Ge => FheOrd::ge(lhs, rhs),
+            })
+        }
+        Operation::Unary { op, operand } => match (op, value(operand)) {
+            (UnaryOp::Neg, operand) => {
+                let operand = operand.as_uint()?;
+                Value::Uint(-operand)
+            }
+            (UnaryOp::Not, Value::Bool(operand)) => Value::Bool(!operand),
+            (UnaryOp::Not, Value::Uint(operand)) => Value::Uint(!operand),
+        },
+        Operation::IfThenElse {
+            condition,
+            then_value,
+            else_value,
+        } => {
+            let condition = value(condition).as_bool()?;
+            match (value(then_value), value(else_value)) {
+                (Value::Bool(then_value), Value::Bool(else_value)) => {
+                    Value::Bool((condition & then_value) | (!condition & else_value))
+                }
+                (then_value, else_value) => Value::Uint(FheUintDyn::if_then_else(
+                    condition,
+                    then_value.as_uint()?,
+                    else_value.as_uint()?,
+                )?),
+            }
+        }
+        Operation::Cast { operand, to } => match (value(operand), to) {
+            (Value::Bool(operand), ValueType::Bool) => Value::Bool(operand.clone()),
+            (Value::Uint(operand), ValueType::Bool) => Value::Bool(FheEq::ne(operand, 0u64)),
+            (Value::Bool(operand), ValueType::Uint(num_bits)) => {
+                let one = FheUintDyn::try_encrypt_trivial(1u64, num_bits)?;
+                let zero = FheUintDyn::try_encrypt_trivial(0u64, num_bits)?;
+                Value::Uint(FheUintDyn::if_then_else(operand, &one, &zero)?)
+            }
+            (Value::Uint(operand), ValueType::Uint(num_bits)) => {
+                Value::Uint(operand.cast_to_width(num_bits)?)
+            }
+        },
+    };
+    Ok(result)
+}
+
+/// Builds a [Program], type checking each operation as it is added
+///
+/// Each method adding a value returns its [ValueId], or an error if the operation is not
+/// well typed.
+#[derive(Clone, Debug, Default)]
+pub struct ProgramBuilder {
+    inputs: Vec<ValueType>,
+    operations: Vec<Operation>,
+    outputs: Vec<ValueId>,
+    value_types: Vec<ValueType>,
+}
+
+impl ProgramBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a new input, inputs must be declared before any operation
+    pub fn input(&mut self, value_type: ValueType) -> crate::Result<ValueId> {
+        if !self.operations.is_empty() {
+            return Err(crate::Error::new(
+                "Inputs must be declared before the operations".to_string(),
+            ));
+        }
+        check_value_type(value_type)?;
+        self.inputs.push(value_type);
+        Ok(self.push_value(value_type))
+    }
+
+    /// Adds an operation to the program
+    pub fn push(&mut self, operation: Operation) -> crate::Result<ValueId> {
+        let value_type = self.check_operation(&operation)?;
+        self.operations.push(operation);
+        Ok(self.push_value(value_type))
+    }
+
+    pub fn binary(&mut self, op: BinaryOp, lhs: ValueId, rhs: ValueId) -> crate::Result<ValueId> {
+        self.push(Operation::Binary { op, lhs, rhs })
+    }
+
+    pub fn scalar_binary(
+        &mut self,
+        op: BinaryOp,
+        lhs: ValueId,
+        rhs: impl Into<ClearUint>,
+    ) -> crate::Result<ValueId> {
+        let rhs = rhs.into();
+        self.push(Operation::ScalarBinary { op, lhs, rhs })
+    }
+
+    pub fn comparison(
+        &mut self,
+        op: ComparisonOp,
+        lhs: ValueId,
+        rhs: ValueId,
+    ) -> crate::Result<ValueId> {
+        self.push(Operation::Comparison { op, lhs, rhs })
+    }
+
+    pub fn scalar_comparison(
+        &mut self,
+        op: ComparisonOp,
+        lhs: ValueId,
+        rhs: impl Into<ClearUint>,
+    ) -> crate::Result<ValueId> {
+        let rhs = rhs.into();
+        self.push(Operation::ScalarComparison { op, lhs, rhs })
+    }
+
+    pub fn unary(&mut self, op: UnaryOp, operand: ValueId) -> crate::Result<ValueId> {
+        self.push(Operation::Unary { op, operand })
+    }
+
+    pub fn if_then_else(
+        &mut self,
+        condition: ValueId,
+        then_value: ValueId,
+        else_value: ValueId,
+    ) -> crate::Result<ValueId> {
+        self.push(Operation::IfThenElse {
+            condition,
+            then_value,
+            else_value,
+        })
+    }
+
+    pub fn cast(&mut self, operand: ValueId, to: ValueType) -> crate::Result<ValueId> {
+        self.push(Operation::Cast { operand, to })
+    }
+
+    /// Marks a value as an output of the program, outputs are returned in the order they
+    /// are marked
+    pub fn output(&mut self, value: ValueId) -> crate::Result<()> {
+        self.value_type(value)?;
+        self.outputs.push(value);
+        Ok(())
+    }
+
+    pub fn build(self) -> Program {
+        Program {
+            inputs: self.inputs,
+            operations: self.operations,
+            outputs: self.outputs,
+            value_types: self.value_types,
+        }
+    }
+
+    fn push_value(&mut self, value_type: ValueType) -> ValueId {
+        let id = ValueId(self.value_types.len() as u32);
+        self.value_types.push(value_type);
+        id
+    }
+
+    fn value_type(&self, value: ValueId) -> crate::Result<ValueType> {
+        self.value_types
+            .get(value.index())
+            .copied()
+            .ok_or_else(|| crate::Error::new(format!("The value {value} is not defined")))
+    }
+
+    fn uint_width(&self, value: ValueId) -> crate::Result<u32> {
+        match self.value_type(value)? {
+            ValueType::Uint(num_bits) => Ok(num_bits),
+            ValueType::Bool => Err(crate::Error::new(format!(
+                "The value {value} must be an unsigned integer, it is a FheBool"
+            ))),
+        }
+    }
+
+    /// Returns the width of `lhs`, which must be an integer `rhs` fits in
+    fn scalar_lhs_width(&self, lhs: ValueId, rhs: &ClearUint) -> crate::Result<u32> {
+        let num_bits = self.uint_width(lhs)?;
+        if rhs.num_bits() > num_bits {
+            return Err(crate::Error::new(format!(
+                "The clear value {rhs} does not fit in the {num_bits} bits of {lhs}"
+            )));
+        }
+        Ok(num_bits)
+    }
+
+    fn same_type(&self, lhs: ValueId, rhs: ValueId) -> crate::Result<ValueType> {
+        let (lhs_type, rhs_type) = (self.value_type(lhs)?, self.value_type(rhs)?);
+        if lhs_type != rhs_type {
+            return Err(crate::Error::new(format!(
+                "The values {lhs} and {rhs} must have the same type, got {lhs_type} and {rhs_type}"
+            )));
+        }
+        Ok(lhs_type)
+    }
+
+    /// Returns the type of the result of the operation, if it is well typed
+    fn check_operation(&self, operation: &Operation) -> crate::Result<ValueType> {
+        match *operation {
+            Operation::Binary { op, lhs, rhs } => match op {
+                BinaryOp::Shl | BinaryOp::Shr | BinaryOp::RotateLeft | BinaryOp::RotateRight => {
+                    self.uint_width(rhs)?;
+                    Ok(ValueType::Uint(self.uint_width(lhs)?))
+                }
+                BinaryOp::BitAnd | BinaryOp::BitOr | BinaryOp::BitXor => self.same_type(lhs, rhs),
+                _ => {
+                    self.uint_width(lhs)?;
+                    self.same_type(lhs, rhs)
+                }
+            },
+            Operation::ScalarBinary { op, lhs, ref rhs } => {
+                if matches!(op, BinaryOp::Div | BinaryOp::Rem) && rhs.is_zero() {
+                    return Err(crate::Error::new(format!(
+                        "The clear divisor of {op:?} must not be 0"
+                    )));
+                }
+                Ok(ValueType::Uint(self.scalar_lhs_width(lhs, rhs)?))
+            }
+            Operation::Comparison { op, lhs, rhs } => {
+                if !matches!(op, ComparisonOp::Eq | ComparisonOp::Ne) {
+                    self.uint_width(lhs)?;
+                }
+                self.same_type(lhs, rhs)?;
+                Ok(ValueType::Bool)
+            }
+            Operation::ScalarComparison { lhs, ref rhs, .. } => {
+                self.scalar_lhs_width(lhs, rhs)?;
+                Ok(ValueType::Bool)
+            }
+            Operation::Unary { op, operand } => match op {
+                UnaryOp::Neg => Ok(ValueType::Uint(self.uint_width(operand)?)),
+                UnaryOp::Not => self.value_type(operand),
+            },
+            Operation::IfThenElse {
+                condition,
+                then_value,
+                else_value,
+            } => {
+                let condition_type = self.value_type(condition)?;
+                if condition_type != ValueType::Bool {
+                    return Err(crate::Error::new(format!(
+                        "The condition {condition} must be a FheBool, it is a {condition_type}"
+                    )));
+                }
+                self.same_type(then_value, else_value)
+            }
+            Operation::Cast { operand, to } => {
+                self.value_type(operand)?;
+                check_value_type(to)?;
+                Ok(to)
+            }
+        }
+    }
+}
+
+fn check_value_type(value_type: ValueType) -> crate::Result<()> {
+    match value_type {
+        ValueType::Uint(0) => Err(crate::Error::new(
+            "An unsigned integer must have at least one bit".to_string(),
+        )),
+        ValueType::Uint(num_bits) if num_bits > FheUintDyn::MAX_NUM_BITS => {
+            Err(crate::Error::new(format!(
+                "An unsigned integer must have at most {} bits, got {num_bits}",
+                FheUintDyn::MAX_NUM_BITS
+            )))
+        }
+        ValueType::Uint(_) | ValueType::Bool => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{ClientKey, ConfigBuilder};
+
+    #[test]
+    fn test_program_type_checking() {
+        let mut builder = ProgramBuilder::new();
+        let a = builder.input(ValueType::Uint(8)).unwrap();
+        let b = builder.input(ValueType::Uint(16)).unwrap();
+        let c = builder.input(ValueType::Bool).unwrap();
+
+        assert!(builder.binary(BinaryOp::Add, a, b).is_err());
+        assert!(builder.binary(BinaryOp::Add, a, c).is_err());
+        assert!(builder.binary(BinaryOp::Shl, a, b).is_ok());
+        assert!(builder.binary(BinaryOp::BitXor, c, c).is_ok());
+        assert!(builder.comparison(ComparisonOp::Lt, c, c).is_err());
+        assert!(builder.comparison(ComparisonOp::Eq, c, c).is_ok());
+        assert!(builder.scalar_binary(BinaryOp::Div, a, 0u8).is_err());
+        assert!(builder.scalar_binary(BinaryOp::Add, a, 256u16).is_err());
+        assert!(builder
+            .scalar_comparison(ComparisonOp::Lt, a, 256u16)
+            .is_err());
+        assert!(builder.scalar_binary(BinaryOp::Add, b, 65535u16).is_ok());
+        assert!(builder.if_then_else(a, a, a).is_err());
+        assert!(builder.if_then_else(c, a, b).is_err());
+        assert!(builder.cast(a, ValueType::Uint(0)).is_err());
+        assert!(builder.cast(a, ValueType::Uint(u32::MAX - 1)).is_err());
+        assert!(builder.cast(a, ValueType::Uint(2048)).is_ok());
+        assert!(builder.unary(UnaryOp::Neg, c).is_err());
+        assert!(builder.output(ValueId(100)).is_err());
+        assert!(builder.input(ValueType::Bool).is_err());
+
+        // Operations can only use values defined before them
+        let parts = ProgramParts {
+            inputs: vec![ValueType::Uint(8)],
+            operations: vec![Operation::Binary {
+                op: BinaryOp::Add,
+                lhs: ValueId(0),
+                rhs: ValueId(1),
+            }],
+            outputs: vec![ValueId(1)],
+        };
+        let bytes = bincode::serialize(&parts).unwrap();
+        assert!(bincode::deserialize::<Program>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_program_run() {
+        let config = ConfigBuilder::default().build();
+        let client_key = ClientKey::generate(config);
+        let server_key = ServerKey::new(&client_key);
+
+        // if a > b { (a - b) << c } else { !(b ^ 5) } with a and b of 16 bits and c of 8 bits,
+        // then the 8 low bits of the result
+        let mut builder = ProgramBuilder::new();
+        let a = builder.input(ValueType::Uint(16)).unwrap();
+        let b = builder.input(ValueType::Uint(16)).unwrap();
+        let c = builder.input(ValueType::Uint(8)).unwrap();
+        let condition = builder.comparison(ComparisonOp::Gt, a, b).unwrap();
+        let diff = builder.binary(BinaryOp::Sub, a, b).unwrap();
+        let then_value = builder.binary(BinaryOp::Shl, diff, c).unwrap();
+        let xored = builder.scalar_binary(BinaryOp::BitXor, b, 5u16).unwrap();
+        let else_value = builder.unary(UnaryOp::Not, xored).unwrap();
+        let result = builder
+            .if_then_else(condition, then_value, else_value)
+            .unwrap();
+        let low_bits = builder.cast(result, ValueType::Uint(8)).unwrap();
+        let is_non_zero = builder.cast(low_bits, ValueType::Bool).unwrap();
+        builder.output(result).unwrap();
+        builder.output(low_bits).unwrap();
+        builder.output(is_non_zero).unwrap();
+        let program = builder.build();
+        assert_eq!(
+            program.output_types(),
+            vec![ValueType::Uint(16), ValueType::Uint(8), ValueType::Bool]
+        );
+
+        let bytes = bincode::serialize(&program).unwrap();
+        let deserialized: Program = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, program);
+
+        for (clear_a, clear_b, clear_c) in [(1000u16, 300u16, 3u8), (300, 1000, 2)] {
+            let inputs = vec![
+                Value::Uint(FheUintDyn::try_encrypt(clear_a, 16, &client_key).unwrap()),
+                Value::Uint(FheUintDyn::try_encrypt(clear_b, 16, &client_key).unwrap()),
+                Value::Uint(FheUintDyn::try_encrypt(clear_c, 8, &client_key).unwrap()),
+            ];
+            let outputs = deserialized.run(inputs, &server_key).unwrap();
+
+            let expected = if clear_a > clear_b {
+                (clear_a - clear_b) << clear_c
+            } else {
+                !(clear_b ^ 5)
+            };
+            let result: u16 = outputs[0].as_uint().unwrap().decrypt(&client_key);
+            assert_eq!(result, expected);
+            let low_bits: u8 = outputs[1].as_uint().unwrap().decrypt(&client_key);
+            assert_eq!(low_bits, expected as u8);
+            let is_non_zero = outputs[2].as_bool().unwrap().decrypt(&client_key);
+            assert_eq!(is_non_zero, expected as u8 != 0);
+        }
+
+        // The inputs must match the declared types
+        let inputs = vec![Value::Bool(FheBool::encrypt(true, &client_key))];
+        assert!(deserialized.run(inputs, &server_key).is_err());
+
+        // Widths which are not a multiple of the bits per block are rejected before running
+        let mut builder = ProgramBuilder::new();
+        let a = builder.input(ValueType::Uint(16)).unwrap();
+        let odd = builder.cast(a, ValueType::Uint(7)).unwrap();
+        builder.output(odd).unwrap();
+        let program = builder.build();
+        let inputs = vec![Value::Uint(
+            FheUintDyn::try_encrypt(1u16, 16, &client_key).unwrap(),
+        )];
+        assert!(program.run(inputs, &server_key).is_err());
+    }
+
+    #[test]
+    fn test_program_scalar_div_of_values_wider_than_the_scalar() {
+        let config = ConfigBuilder::default().build();
+        let client_key = ClientKey::generate(config);
+        let server_key = ServerKey::new(&client_key);
+
+        let divisor = 1_000_000_007u64;
+        let mut builder = ProgramBuilder::new();
+        let a = builder.input(ValueType::Uint(128)).unwrap();
+        let quotient = builder.scalar_binary(BinaryOp::Div, a, divisor).unwrap();
+        let remainder = builder.scalar_binary(BinaryOp::Rem, a, divisor).unwrap();
+        builder.output(quotient).unwrap();
+        builder.output(remainder).unwrap();
+        let program = builder.build();
+
+        let clear_a = u128::MAX - 12345;
+        let inputs = vec![Value::Uint(
+            FheUintDyn::try_encrypt(clear_a, 128, &client_key).unwrap(),
+        )];
+        let outputs = program.run(inputs, &server_key).unwrap();
+
+        let quotient: u128 = outputs[0].as_uint().unwrap().decrypt(&client_key);
+        assert_eq!(quotient, clear_a / u128::from(divisor));
+        let remainder: u128 = outputs[1].as_uint().unwrap().decrypt(&client_key);
+        assert_eq!(remainder, clear_a % u128::from(divisor));
+    }
+
+    #[test]
+    fn test_program_scalars_wider_than_64_bits() {
+        let config = ConfigBuilder::default().build();
+        let client_key = ClientKey::generate(config);
+        let server_key = ServerKey::new(&client_key);
+
+        let scalar = (1u128 << 100) + 7;
+        let mut builder = ProgramBuilder::new();
+        let a = builder.input(ValueType::Uint(128)).unwrap();
+        let sum = builder.scalar_binary(BinaryOp::Add, a, scalar).unwrap();
+        let is_greater = builder
+            .scalar_comparison(ComparisonOp::Gt, sum, scalar)
+            .unwrap();
+        builder.output(sum).unwrap();
+        builder.output(is_greater).unwrap();
+        let program = builder.build();
+
+        let clear_a = 3u128 << 64;
+        let inputs = vec![Value::Uint(
+            FheUintDyn::try_encrypt(clear_a, 128, &client_key).unwrap(),
+        )];
+        let outputs = program.run(inputs, &server_key).unwrap();
+
+        let sum: u128 = outputs[0].as_uint().unwrap().decrypt(&client_key);
+        assert_eq!(sum, clear_a + scalar);
+        let is_greater = outputs[1].as_bool().unwrap().decrypt(&client_key);
+        assert!(is_greater);
+    }
+
+    #[test]
+    fn test_program_safe_serialization() {
+        let mut builder = ProgramBuilder::new();
+        let a = builder.input(ValueType::Uint(128)).unwrap();
+        let b = builder.input(ValueType::Uint(128)).unwrap();
+        let product = builder.binary(BinaryOp::Mul, a, b).unwrap();
+        let sum = builder
+            .scalar_binary(BinaryOp::Add, product, u128::MAX)
+            .unwrap();
+        builder.output(sum).unwrap();
+        let program = builder.build();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&program, &mut serialized, 1 << 20).unwrap();
+        let deserialized: Program =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+        assert_eq!(deserialized, program);
+    }
+}
@@ -0,0 +1,180 @@
+use super::{trivial_chars, with_cpu_server_key, FheAsciiString};
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext, ServerKey};
+use crate::prelude::{FheEq, FheOrd};
+use crate::FheBool;
+
+#[derive(Copy, Clone)]
+enum ComparisonKind {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// Converts the characters into a radix in which the first character is the most significant,
+/// so that comparing radixes gives the lexicographic order of the strings.
+///
+/// The radix is padded with null characters in its least significant blocks so that
+/// it holds `num_chars` characters. As the null character is smaller than all the others,
+/// this gives the expected ordering between a string and its prefixes.
+fn lexicographic_radix(
+    sks: &ServerKey,
+    chars: &[RadixCiphertext],
+    num_chars: usize,
+) -> RadixCiphertext {
+    let mut radix = RadixCiphertext::from_blocks(
+        chars
+            .iter()
+            .rev()
+            .flat_map(|c| c.blocks().iter().cloned())
+            .collect(),
+    );
+    let num_blocks_per_char = super::num_blocks_per_char(sks.message_modulus());
+    sks.extend_radix_with_trivial_zero_blocks_lsb_assign(
+        &mut radix,
+        (num_chars - chars.len()) * num_blocks_per_char,
+    );
+    radix
+}
+
+fn compare(
+    sks: &ServerKey,
+    lhs: &[RadixCiphertext],
+    rhs: &[RadixCiphertext],
+    kind: ComparisonKind,
+) -> BooleanBlock {
+    // Empty strings are compared as a single null character
+    let num_chars = lhs.len().max(rhs.len()).max(1);
+
+    let (lhs, rhs) = rayon::join(
+        || lexicographic_radix(sks, lhs, num_chars),
+        || lexicographic_radix(sks, rhs, num_chars),
+    );
+
+    match kind {
+        ComparisonKind::Eq => sks.eq_parallelized(&lhs, &rhs),
+        ComparisonKind::Ne => sks.ne_parallelized(&lhs, &rhs),
+        ComparisonKind::Lt => sks.lt_parallelized(&lhs, &rhs),
+        ComparisonKind::Le => sks.le_parallelized(&lhs, &rhs),
+        ComparisonKind::Gt => sks.gt_parallelized(&lhs, &rhs),
+        ComparisonKind::Ge => sks.ge_parallelized(&lhs, &rhs),
+    }
+}
+
+impl FheAsciiString {
+    fn compare_to(&self, other: &Self, kind: ComparisonKind) -> FheBool {
+        with_cpu_server_key(|sks| FheBool::new(compare(sks, &self.chars, &other.chars, kind)))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the clear string is not ASCII or contains a null character
+    fn compare_to_clear(&self, other: &str, kind: ComparisonKind) -> FheBool {
+        with_cpu_server_key(|sks| {
+            let other = trivial_chars(sks, other);
+            FheBool::new(compare(sks, &self.chars, &other, kind))
+        })
+    }
+}
+
+impl FheEq<&Self> for FheAsciiString {
+    /// Tests for equality between two strings, padding excluded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("tfhe", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt_with_padding("tfhe", 2, &client_key).unwrap();
+    ///
+    /// let result = a.eq(&b);
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    fn eq(&self, other: &Self) -> FheBool {
+        self.compare_to(other, ComparisonKind::Eq)
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        self.compare_to(other, ComparisonKind::Ne)
+    }
+}
+
+impl FheEq<&str> for FheAsciiString {
+    /// Tests for equality between an encrypted string and a clear string
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clear string is not ASCII or contains a null character
+    fn eq(&self, other: &str) -> FheBool {
+        self.compare_to_clear(other, ComparisonKind::Eq)
+    }
+
+    fn ne(&self, other: &str) -> FheBool {
+        self.compare_to_clear(other, ComparisonKind::Ne)
+    }
+}
+
+impl FheOrd<&Self> for FheAsciiString {
+    /// Compares two strings in lexicographic order, like [str] does
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("apple", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt_with_padding("app", 3, &client_key).unwrap();
+    ///
+    /// let result = b.lt(&a);
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    fn lt(&self, other: &Self) -> FheBool {
+        self.compare_to(other, ComparisonKind::Lt)
+    }
+
+    fn le(&self, other: &Self) -> FheBool {
+        self.compare_to(other, ComparisonKind::Le)
+    }
+
+    fn gt(&self, other: &Self) -> FheBool {
+        self.compare_to(other, ComparisonKind::Gt)
+    }
+
+    fn ge(&self, other: &Self) -> FheBool {
+        self.compare_to(other, ComparisonKind::Ge)
+    }
+}
+
+impl FheOrd<&str> for FheAsciiString {
+    /// Compares an encrypted string with a clear string in lexicographic order
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clear string is not ASCII or contains a null character
+    fn lt(&self, other: &str) -> FheBool {
+        self.compare_to_clear(other, ComparisonKind::Lt)
+    }
+
+    fn le(&self, other: &str) -> FheBool {
+        self.compare_to_clear(other, ComparisonKind::Le)
+    }
+
+    fn gt(&self, other: &str) -> FheBool {
+        self.compare_to_clear(other, ComparisonKind::Gt)
+    }
+
+    fn ge(&self, other: &str) -> FheBool {
+        self.compare_to_clear(other, ComparisonKind::Ge)
+    }
+}
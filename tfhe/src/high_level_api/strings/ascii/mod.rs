@@ -0,0 +1,316 @@
+mod comparison;
+mod pattern;
+#[cfg(test)]
+mod tests;
+mod transform;
+
+pub use pattern::FheStringPattern;
+
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::IntegerId;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::{
+    BooleanBlock, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, ServerKey,
+};
+use crate::named::Named;
+use crate::prelude::{FheDecrypt, FheTryEncrypt};
+use crate::shortint::MessageModulus;
+use crate::{ClientKey, FheBool, FheUint16, FheUint16Id};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Number of bits used to encrypt one character
+const BITS_PER_CHAR: usize = 8;
+
+/// An encrypted ASCII string
+///
+/// Each character is encrypted separately, the string may be padded with encrypted null
+/// characters at its end to hide its actual length.
+///
+/// Operations on strings use the server key set in the current thread,
+/// see [set_server_key](crate::set_server_key).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheAsciiString::try_encrypt("Hello", &client_key).unwrap();
+/// // The length of `b` is hidden by 3 padding characters
+/// let b = FheAsciiString::try_encrypt_with_padding("hello", 3, &client_key).unwrap();
+///
+/// let a_lower = a.to_lowercase();
+/// let are_equal = a_lower.eq(&b);
+///
+/// let decrypted: String = a_lower.decrypt(&client_key);
+/// assert_eq!(decrypted, "hello");
+/// assert!(are_equal.decrypt(&client_key));
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheAsciiString {
+    pub(in crate::high_level_api) chars: Vec<RadixCiphertext>,
+    pub(in crate::high_level_api) padded: bool,
+}
+
+impl Named for FheAsciiString {
+    const NAME: &'static str = "high_level_api::FheAsciiString";
+}
+
+/// The length of a [FheAsciiString]
+///
+/// The length of a string that is not padded is known in clear,
+/// otherwise it has to be computed homomorphically.
+pub enum FheStringLen {
+    NoPadding(u16),
+    Padding(FheUint16),
+}
+
+/// Whether a [FheAsciiString] is empty
+///
+/// This is known in clear for strings that are not padded.
+pub enum FheStringIsEmpty {
+    NoPadding(bool),
+    Padding(FheBool),
+}
+
+impl FheAsciiString {
+    /// Encrypts a string, adding `padding` encrypted null characters at its end
+    ///
+    /// Returns an error if the string is not ASCII, contains a null character,
+    /// or if its length (including padding) does not fit in a `u16`.
+    pub fn try_encrypt_with_padding(
+        value: &str,
+        padding: u32,
+        key: &ClientKey,
+    ) -> crate::Result<Self> {
+        check_is_valid_str(value)?;
+
+        let total_len = value.len() + padding as usize;
+        if total_len > u16::MAX as usize {
+            return Err(crate::Error::new(format!(
+                "The length of the string with its padding ({total_len}) \
+                exceeds the maximum length ({})",
+                u16::MAX
+            )));
+        }
+
+        let num_blocks = try_num_blocks_per_char(key.message_modulus())?;
+        let chars = value
+            .bytes()
+            .chain(std::iter::repeat(0u8).take(padding as usize))
+            .map(|byte| key.key.key.encrypt_radix(byte, num_blocks))
+            .collect();
+
+        Ok(Self {
+            chars,
+            padded: padding != 0,
+        })
+    }
+
+    /// Returns whether the string may contain padding null characters
+    pub fn is_padded(&self) -> bool {
+        self.padded
+    }
+
+    /// Returns the length of the string, padding excluded
+    ///
+    /// Returns an error if the string has more characters than what a `u16` can count,
+    /// which can happen for strings that grew with [replace](Self::replace).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString, FheStringLen};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheAsciiString::try_encrypt("tfhe", &client_key).unwrap();
+    /// let b = FheAsciiString::try_encrypt_with_padding("tfhe", 2, &client_key).unwrap();
+    ///
+    /// match a.len().unwrap() {
+    ///     FheStringLen::NoPadding(len) => assert_eq!(len, 4),
+    ///     FheStringLen::Padding(_) => panic!("a is not padded"),
+    /// }
+    ///
+    /// match b.len().unwrap() {
+    ///     FheStringLen::NoPadding(_) => panic!("b is padded"),
+    ///     FheStringLen::Padding(len) => {
+    ///         let len: u16 = len.decrypt(&client_key);
+    ///         assert_eq!(len, 4);
+    ///     }
+    /// }
+    /// ```
+    pub fn len(&self) -> crate::Result<FheStringLen> {
+        let num_chars = u16::try_from(self.chars.len()).map_err(|_| {
+            crate::Error::new(format!(
+                "The string has {} characters, its length does not fit in a u16",
+                self.chars.len()
+            ))
+        })?;
+        if !self.padded {
+            return Ok(FheStringLen::NoPadding(num_chars));
+        }
+
+        Ok(with_cpu_server_key(|sks| {
+            let non_null_chars = self
+                .chars
+                .par_iter()
+                .map(|c| sks.scalar_ne_parallelized(c, 0u8))
+                .collect();
+            let num_blocks = FheUint16Id::num_blocks(sks.message_modulus());
+            FheStringLen::Padding(FheUint16::new(count_true(sks, non_null_chars, num_blocks)))
+        }))
+    }
+
+    /// Returns whether the string is empty, padding excluded
+    pub fn is_empty(&self) -> FheStringIsEmpty {
+        match self.chars.first() {
+            Some(first_char) if self.padded => with_cpu_server_key(|sks| {
+                FheStringIsEmpty::Padding(FheBool::new(sks.scalar_eq_parallelized(first_char, 0u8)))
+            }),
+            _ => FheStringIsEmpty::NoPadding(self.chars.is_empty()),
+        }
+    }
+}
+
+impl<'a> FheTryEncrypt<&'a str, ClientKey> for FheAsciiString {
+    type Error = crate::Error;
+
+    /// Encrypts a string without padding
+    ///
+    /// Returns an error if the string is not ASCII or contains a null character.
+    fn try_encrypt(value: &'a str, key: &ClientKey) -> crate::Result<Self> {
+        Self::try_encrypt_with_padding(value, 0, key)
+    }
+}
+
+impl FheDecrypt<String> for FheAsciiString {
+    /// Decrypts the string, padding characters are removed
+    fn decrypt(&self, key: &ClientKey) -> String {
+        self.chars
+            .iter()
+            .map(|c| key.key.key.decrypt_radix::<u8>(c))
+            .take_while(|byte| *byte != 0)
+            .map(char::from)
+            .collect()
+    }
+}
+
+fn check_is_valid_str(value: &str) -> crate::Result<()> {
+    if !value.is_ascii() {
+        return Err(crate::Error::new(format!(
+            "The string '{value}' is not an ASCII string"
+        )));
+    }
+    if value.contains('\0') {
+        return Err(crate::Error::new(
+            "The string must not contain null characters".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the number of blocks encrypting a character
+///
+/// Returns an error if a character cannot be split evenly into blocks (e.g. blocks of 3 bits),
+/// as the operations on strings rely on characters being exactly [BITS_PER_CHAR] bits.
+fn try_num_blocks_per_char(message_modulus: MessageModulus) -> crate::Result<usize> {
+    let bits_per_block = message_modulus.0.ilog2() as usize;
+    if bits_per_block == 0 || BITS_PER_CHAR % bits_per_block != 0 {
+        return Err(crate::Error::new(format!(
+            "Strings are not supported with blocks of {bits_per_block} bits, \
+            the number of bits per block must divide {BITS_PER_CHAR}"
+        )));
+    }
+    Ok(BITS_PER_CHAR / bits_per_block)
+}
+
+/// # Panics
+///
+/// Panics if a character cannot be split evenly into blocks, see [try_num_blocks_per_char]
+fn num_blocks_per_char(message_modulus: MessageModulus) -> usize {
+    try_num_blocks_per_char(message_modulus).unwrap()
+}
+
+fn with_cpu_server_key<R>(func: impl FnOnce(&ServerKey) -> R) -> R {
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(&cpu_key.key),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support strings yet")
+        }
+    })
+}
+
+/// Trivially encrypts a clear string into a list of characters
+///
+/// # Panics
+///
+/// Panics if the string is not ASCII or contains a null character
+fn trivial_chars(sks: &ServerKey, value: &str) -> Vec<RadixCiphertext> {
+    check_is_valid_str(value).unwrap();
+
+    let num_blocks = num_blocks_per_char(sks.message_modulus());
+    value
+        .bytes()
+        .map(|byte| sks.create_trivial_radix(byte, num_blocks))
+        .collect()
+}
+
+/// Concatenates the blocks of the characters, the first character being in the least
+/// significant blocks
+fn concat_chars(chars: &[RadixCiphertext]) -> RadixCiphertext {
+    RadixCiphertext::from_blocks(
+        chars
+            .iter()
+            .flat_map(|c| c.blocks().iter().cloned())
+            .collect(),
+    )
+}
+
+/// Splits a radix built by [concat_chars] back into characters
+fn split_chars(radix: RadixCiphertext, num_blocks_per_char: usize) -> Vec<RadixCiphertext> {
+    radix
+        .into_blocks()
+        .chunks(num_blocks_per_char)
+        .map(|blocks| RadixCiphertext::from_blocks(blocks.to_vec()))
+        .collect()
+}
+
+/// Returns a block encrypting whether all the booleans are true
+///
+/// Returns a trivial true if the list is empty
+fn all_true(sks: &ServerKey, booleans: Vec<BooleanBlock>) -> BooleanBlock {
+    if booleans.is_empty() {
+        return sks.create_trivial_boolean_block(true);
+    }
+    let blocks = booleans.into_iter().map(BooleanBlock::into_inner).collect();
+    BooleanBlock::new_unchecked(sks.are_all_comparisons_block_true(blocks))
+}
+
+/// Returns a block encrypting whether at least one of the booleans is true
+///
+/// Returns a trivial false if the list is empty
+fn any_true(sks: &ServerKey, booleans: Vec<BooleanBlock>) -> BooleanBlock {
+    if booleans.is_empty() {
+        return sks.create_trivial_boolean_block(false);
+    }
+    let blocks = booleans.into_iter().map(BooleanBlock::into_inner).collect();
+    BooleanBlock::new_unchecked(sks.is_at_least_one_comparisons_block_true(blocks))
+}
+
+/// Counts the number of true booleans into a radix of `num_blocks` blocks
+fn count_true(sks: &ServerKey, booleans: Vec<BooleanBlock>, num_blocks: usize) -> RadixCiphertext {
+    let radixes = booleans
+        .into_iter()
+        .map(|boolean| boolean.into_radix::<RadixCiphertext>(num_blocks, sks))
+        .collect::<Vec<_>>();
+    sks.sum_ciphertexts_parallelized(&radixes)
+        .unwrap_or_else(|| sks.create_trivial_zero_radix(num_blocks))
+}
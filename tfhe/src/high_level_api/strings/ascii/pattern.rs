@@ -0,0 +1,584 @@
+use super::{
+    all_true, any_true, concat_chars, count_true, num_blocks_per_char, trivial_chars,
+    with_cpu_server_key, FheAsciiString,
+};
+use crate::high_level_api::integers::IntegerId;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use crate::{FheBool, FheUint16, FheUint16Id};
+use rayon::prelude::*;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// A pattern to look for in a [FheAsciiString]
+///
+/// It can either be a clear string, or an encrypted string (possibly padded).
+#[derive(Copy, Clone)]
+pub enum FheStringPattern<'a> {
+    Clear(&'a str),
+    Encrypted(&'a FheAsciiString),
+}
+
+impl<'a> From<&'a str> for FheStringPattern<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Clear(value)
+    }
+}
+
+impl<'a> From<&'a String> for FheStringPattern<'a> {
+    fn from(value: &'a String) -> Self {
+        Self::Clear(value.as_str())
+    }
+}
+
+impl<'a> From<&'a FheAsciiString> for FheStringPattern<'a> {
+    fn from(value: &'a FheAsciiString) -> Self {
+        Self::Encrypted(value)
+    }
+}
+
+/// The characters of a pattern, clear patterns being trivially encrypted
+struct PatternChars<'a> {
+    chars: Cow<'a, [RadixCiphertext]>,
+    padded: bool,
+}
+
+impl<'a> FheStringPattern<'a> {
+    /// # Panics
+    ///
+    /// Panics if the pattern is a clear string that is not ASCII or that contains a null character
+    fn to_chars(self, sks: &ServerKey) -> PatternChars<'a> {
+        match self {
+            Self::Clear(value) => PatternChars {
+                chars: Cow::Owned(trivial_chars(sks, value)),
+                padded: false,
+            },
+            Self::Encrypted(string) => PatternChars {
+                chars: Cow::Borrowed(&string.chars),
+                padded: string.padded,
+            },
+        }
+    }
+}
+
+impl PatternChars<'_> {
+    /// The maximum number of positions at which the pattern can be found in a string of
+    /// `num_chars` characters
+    ///
+    /// The pattern is always checked at position 0, so that an empty pattern matches an empty
+    /// string.
+    fn match_positions(&self, num_chars: usize) -> Range<usize> {
+        if self.padded {
+            0..num_chars.max(1)
+        } else if self.chars.len() <= num_chars {
+            0..num_chars - self.chars.len() + 1
+        } else {
+            0..0
+        }
+    }
+}
+
+/// Returns, for each position, whether the pattern matches the string starting at that
+/// position.
+///
+/// The string is considered to be followed by null characters, so that the padding of the
+/// pattern can match past the end of the string.
+fn pattern_matches(
+    sks: &ServerKey,
+    string: &[RadixCiphertext],
+    pattern: &PatternChars<'_>,
+    positions: Range<usize>,
+) -> Vec<BooleanBlock> {
+    let num_chars = string.len();
+
+    if !pattern.padded {
+        let pattern_len = pattern.chars.len();
+        if pattern_len == 0 {
+            return positions
+                .map(|_| sks.create_trivial_boolean_block(true))
+                .collect();
+        }
+
+        let pattern_radix = concat_chars(&pattern.chars);
+        return positions
+            .into_par_iter()
+            .map(|i| {
+                if i + pattern_len > num_chars {
+                    sks.create_trivial_boolean_block(false)
+                } else {
+                    let window = concat_chars(&string[i..i + pattern_len]);
+                    sks.eq_parallelized(&window, &pattern_radix)
+                }
+            })
+            .collect();
+    }
+
+    // With padding, the length of the pattern is not known,
+    // each one of its chars must either match or be a null char
+    let pattern_nulls = pattern
+        .chars
+        .par_iter()
+        .map(|c| sks.scalar_eq_parallelized(c, 0u8))
+        .collect::<Vec<_>>();
+
+    positions
+        .into_par_iter()
+        .map(|i| {
+            let char_matches = pattern
+                .chars
+                .par_iter()
+                .zip(pattern_nulls.par_iter())
+                .enumerate()
+                .map(|(j, (pattern_char, pattern_char_is_null))| {
+                    string.get(i + j).map_or_else(
+                        || pattern_char_is_null.clone(),
+                        |string_char| {
+                            let are_equal = sks.eq_parallelized(string_char, pattern_char);
+                            sks.boolean_bitor(&are_equal, pattern_char_is_null)
+                        },
+                    )
+                })
+                .collect();
+            all_true(sks, char_matches)
+        })
+        .collect()
+}
+
+/// Returns whether each position in `0..=string.len()` is within the string,
+/// i.e. is not after its padding start
+fn positions_within_string(
+    sks: &ServerKey,
+    string: &[RadixCiphertext],
+    padded: bool,
+) -> Vec<Option<BooleanBlock>> {
+    if !padded {
+        return vec![None; string.len() + 1];
+    }
+
+    std::iter::once(None)
+        .chain(
+            string
+                .par_iter()
+                .map(|c| Some(sks.scalar_ne_parallelized(c, 0u8)))
+                .collect::<Vec<_>>(),
+        )
+        .collect()
+}
+
+fn zero_out_if_false(
+    sks: &ServerKey,
+    value: &RadixCiphertext,
+    condition: &BooleanBlock,
+) -> RadixCiphertext {
+    let mut result = value.clone();
+    sks.zero_out_if_condition_is_false(&mut result, condition.as_ref());
+    result
+}
+
+/// Replaces all the non-overlapping occurrences of `from` by `to`, occurrences being
+/// selected from left to right like [str::replace] does.
+///
+/// Each output character is gathered from the input characters and from the characters of `to`
+/// using the encrypted output position of each of them. The output is sized for the worst case,
+/// and is thus padded.
+fn replace_chars(
+    sks: &ServerKey,
+    string: &[RadixCiphertext],
+    string_padded: bool,
+    from: &PatternChars<'_>,
+    to: &PatternChars<'_>,
+) -> Vec<RadixCiphertext> {
+    let num_chars = string.len();
+    let from_max_len = from.chars.len();
+    let to_max_len = to.chars.len();
+    let from_min_len = if from.padded { 0 } else { from_max_len };
+
+    // Upper bound of the number of replacements and of the output length
+    let max_replacements = num_chars.checked_div(from_min_len).unwrap_or(num_chars + 1);
+    let output_len = num_chars + max_replacements * to_max_len.saturating_sub(from_min_len);
+
+    let message_modulus = sks.message_modulus();
+    let bits_per_block = message_modulus.0.ilog2() as usize;
+    let num_blocks_per_position = ((output_len + 1).next_power_of_two().ilog2() as usize)
+        .div_ceil(bits_per_block)
+        .max(1);
+
+    // 1. Find all the positions where `from` matches, including the end of the string,
+    // as an empty pattern also matches there
+    let (mut matches, (within_string, from_len_greater_than)) = rayon::join(
+        || pattern_matches(sks, string, from, 0..num_chars + 1),
+        || {
+            rayon::join(
+                || positions_within_string(sks, string, string_padded),
+                || {
+                    // from_len_greater_than[d] encrypts from.len() > d
+                    from.padded.then(|| {
+                        from.chars
+                            .par_iter()
+                            .map(|c| sks.scalar_ne_parallelized(c, 0u8))
+                            .collect::<Vec<_>>()
+                    })
+                },
+            )
+        },
+    );
+
+    // An empty pattern would also match in the padding of the string
+    if from_min_len == 0 {
+        matches
+            .par_iter_mut()
+            .zip(within_string.par_iter())
+            .for_each(|(is_match, is_within_string)| {
+                if let Some(is_within_string) = is_within_string {
+                    *is_match = sks.boolean_bitand(is_match, is_within_string);
+                }
+            });
+    }
+
+    // 2. Select the non-overlapping matches, from left to right.
+    // A match at position i is covered by a selected match at position i - d
+    // if the length of `from` is greater than d
+    let mut selected: Vec<BooleanBlock> = Vec::with_capacity(num_chars + 1);
+    let mut covered: Vec<Option<BooleanBlock>> = Vec::with_capacity(num_chars + 1);
+    for (i, is_match) in matches.into_iter().enumerate() {
+        let coverings = (1..from_max_len.min(i + 1))
+            .into_par_iter()
+            .map(|d| {
+                from_len_greater_than.as_ref().map_or_else(
+                    || selected[i - d].clone(),
+                    |greater_than| sks.boolean_bitand(&selected[i - d], &greater_than[d]),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if coverings.is_empty() {
+            selected.push(is_match);
+            covered.push(None);
+        } else {
+            let is_covered = any_true(sks, coverings);
+            let is_not_covered = sks.boolean_bitnot(&is_covered);
+            selected.push(sks.boolean_bitand(&is_match, &is_not_covered));
+            covered.push(Some(is_covered));
+        }
+    }
+
+    // 3. A char of the string is kept if it is not part of a selected match
+    let from_is_not_empty = from_len_greater_than.as_ref().map_or_else(
+        || (from_max_len > 0).then(|| sks.create_trivial_boolean_block(true)),
+        |greater_than| greater_than.first().cloned(),
+    );
+    let kept_chars = string
+        .par_iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let starts_consumed_match = from_is_not_empty
+                .as_ref()
+                .map(|not_empty| sks.boolean_bitand(&selected[i], not_empty));
+            let is_consumed = match (&covered[i], starts_consumed_match) {
+                (None, None) => return (c.clone(), sks.create_trivial_boolean_block(true)),
+                (Some(is_covered), None) => is_covered.clone(),
+                (None, Some(starts_match)) => starts_match,
+                (Some(is_covered), Some(starts_match)) => {
+                    sks.boolean_bitor(is_covered, &starts_match)
+                }
+            };
+            let is_kept = sks.boolean_bitnot(&is_consumed);
+            (zero_out_if_false(sks, c, &is_kept), is_kept)
+        })
+        .collect::<Vec<_>>();
+
+    // 4. Compute where each segment starts in the output: the segment of position i is made of
+    // `to` if there is a selected match at i, followed by the char at i if it is kept
+    let to_len = if to.padded {
+        let non_null_chars = to
+            .chars
+            .par_iter()
+            .map(|c| sks.scalar_ne_parallelized(c, 0u8))
+            .collect();
+        count_true(sks, non_null_chars, num_blocks_per_position)
+    } else {
+        sks.create_trivial_radix(to_max_len as u64, num_blocks_per_position)
+    };
+    let replacement_lens = selected
+        .par_iter()
+        .map(|is_selected| zero_out_if_false(sks, &to_len, is_selected))
+        .collect::<Vec<_>>();
+
+    let mut segment_starts = Vec::with_capacity(num_chars + 1);
+    let mut current_start: RadixCiphertext = sks.create_trivial_zero_radix(num_blocks_per_position);
+    for (i, replacement_len) in replacement_lens.iter().enumerate() {
+        segment_starts.push(current_start.clone());
+        if i < num_chars {
+            let is_kept = kept_chars[i]
+                .1
+                .clone()
+                .into_radix(num_blocks_per_position, sks);
+            let segment_len = sks.add_parallelized(replacement_len, &is_kept);
+            sks.add_assign_parallelized(&mut current_start, &segment_len);
+        }
+    }
+
+    // Upper bound of the start of the segment i, each segment being at most
+    // `to_max_len + 1` chars long
+    let max_segment_start = |i: usize| (i * (to_max_len + 1)).min(output_len);
+
+    // One-hot encodings of the positions
+    // - of the replacements: is_replacement_at[i][p] is true if `to` is written starting at p
+    // - of the kept chars: is_char_at[i][p] is true if the i-th char is written at p
+    let (is_replacement_at, is_char_at) = rayon::join(
+        || {
+            segment_starts
+                .par_iter()
+                .zip(selected.par_iter())
+                .enumerate()
+                .map(|(i, (start, is_selected))| {
+                    (0..=max_segment_start(i).min(output_len.saturating_sub(1)))
+                        .into_par_iter()
+                        .map(|p| {
+                            let is_at_p = sks.scalar_eq_parallelized(start, p as u64);
+                            sks.boolean_bitand(&is_at_p, is_selected)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        },
+        || {
+            segment_starts[..num_chars]
+                .par_iter()
+                .zip(replacement_lens.par_iter())
+                .enumerate()
+                .map(|(i, (start, replacement_len))| {
+                    let char_position = sks.add_parallelized(start, replacement_len);
+                    (0..(max_segment_start(i) + to_max_len + 1).min(output_len))
+                        .into_par_iter()
+                        .map(|p| sks.scalar_eq_parallelized(&char_position, p as u64))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        },
+    );
+
+    // 5. Gather the output chars, each output char has at most one source
+    let num_blocks_per_char = num_blocks_per_char(message_modulus);
+    (0..output_len)
+        .into_par_iter()
+        .map(|p| {
+            let from_string = is_char_at.par_iter().zip(kept_chars.par_iter()).filter_map(
+                |(is_char_at, (kept_char, _))| {
+                    is_char_at
+                        .get(p)
+                        .map(|is_at_p| zero_out_if_false(sks, kept_char, is_at_p))
+                },
+            );
+
+            let from_replacements = is_replacement_at.par_iter().flat_map(|is_replacement_at| {
+                to.chars
+                    .par_iter()
+                    .enumerate()
+                    .filter_map(move |(r, to_char)| {
+                        let start = p.checked_sub(r)?;
+                        is_replacement_at
+                            .get(start)
+                            .map(|is_at_start| zero_out_if_false(sks, to_char, is_at_start))
+                    })
+            });
+
+            let sources = from_string.chain(from_replacements).collect::<Vec<_>>();
+            sks.sum_ciphertexts_parallelized(&sources)
+                .unwrap_or_else(|| sks.create_trivial_zero_radix(num_blocks_per_char))
+        })
+        .collect()
+}
+
+impl FheAsciiString {
+    /// Returns whether the pattern is a substring of the string
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is a clear string that is not ASCII or that contains a null character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    /// let pattern = FheAsciiString::try_encrypt_with_padding("he", 1, &client_key).unwrap();
+    ///
+    /// let result = string.contains(&pattern);
+    /// assert!(result.decrypt(&client_key));
+    ///
+    /// let result = string.contains("rust");
+    /// assert!(!result.decrypt(&client_key));
+    /// ```
+    pub fn contains<'a>(&self, pattern: impl Into<FheStringPattern<'a>>) -> FheBool {
+        with_cpu_server_key(|sks| {
+            let pattern = pattern.into().to_chars(sks);
+            let positions = pattern.match_positions(self.chars.len());
+            let matches = pattern_matches(sks, &self.chars, &pattern, positions);
+            FheBool::new(any_true(sks, matches))
+        })
+    }
+
+    /// Returns whether the string starts with the pattern
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is a clear string that is not ASCII or that contains a null character
+    pub fn starts_with<'a>(&self, pattern: impl Into<FheStringPattern<'a>>) -> FheBool {
+        with_cpu_server_key(|sks| {
+            let pattern = pattern.into().to_chars(sks);
+            let positions = pattern.match_positions(self.chars.len());
+            let result = if positions.is_empty() {
+                sks.create_trivial_boolean_block(false)
+            } else {
+                pattern_matches(sks, &self.chars, &pattern, 0..1)
+                    .pop()
+                    .expect("one position was checked")
+            };
+            FheBool::new(result)
+        })
+    }
+
+    /// Returns the index of the first occurrence of the pattern in the string,
+    /// and whether the pattern was found
+    ///
+    /// The returned index is 0 if the pattern was not found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern is a clear string that is not ASCII or that contains a null character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("banana", &client_key).unwrap();
+    ///
+    /// let (index, found) = string.find("na");
+    /// let index: u16 = index.decrypt(&client_key);
+    /// assert!(found.decrypt(&client_key));
+    /// assert_eq!(index, 2);
+    /// ```
+    pub fn find<'a>(&self, pattern: impl Into<FheStringPattern<'a>>) -> (FheUint16, FheBool) {
+        with_cpu_server_key(|sks| {
+            let pattern = pattern.into().to_chars(sks);
+            let positions = pattern.match_positions(self.chars.len());
+            let matches = pattern_matches(sks, &self.chars, &pattern, positions.clone());
+
+            let num_blocks = FheUint16Id::num_blocks(sks.message_modulus());
+
+            // Tree reduction keeping the leftmost match
+            let (found, mut index) = matches
+                .into_par_iter()
+                .zip(positions.into_par_iter())
+                .map(|(is_match, i)| {
+                    (
+                        is_match,
+                        sks.create_trivial_radix::<u64, RadixCiphertext>(i as u64, num_blocks),
+                    )
+                })
+                .reduce_with(|(left_found, left_index), (right_found, right_index)| {
+                    rayon::join(
+                        || sks.boolean_bitor(&left_found, &right_found),
+                        || sks.if_then_else_parallelized(&left_found, &left_index, &right_index),
+                    )
+                })
+                .unwrap_or_else(|| {
+                    (
+                        sks.create_trivial_boolean_block(false),
+                        sks.create_trivial_zero_radix(num_blocks),
+                    )
+                });
+
+            sks.zero_out_if_condition_is_false(&mut index, found.as_ref());
+
+            (FheUint16::new(index), FheBool::new(found))
+        })
+    }
+
+    /// Replaces all the non-overlapping occurrences of `from` by `to`
+    ///
+    /// As the number of replacements is not known, the result is padded so that it can hold
+    /// the longest possible result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pattern is a clear string that is not ASCII or that contains a null character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt("aXbX", &client_key).unwrap();
+    /// let to = FheAsciiString::try_encrypt("--", &client_key).unwrap();
+    ///
+    /// let replaced = string.replace("X", &to);
+    /// let replaced: String = replaced.decrypt(&client_key);
+    /// assert_eq!(replaced, "a--b--");
+    /// ```
+    pub fn replace<'a, 'b>(
+        &self,
+        from: impl Into<FheStringPattern<'a>>,
+        to: impl Into<FheStringPattern<'b>>,
+    ) -> Self {
+        with_cpu_server_key(|sks| {
+            let from = from.into().to_chars(sks);
+            let to = to.into().to_chars(sks);
+
+            if from.match_positions(self.chars.len()).is_empty() {
+                // `from` cannot be in the string
+                return self.clone();
+            }
+
+            let chars = replace_chars(sks, &self.chars, self.padded, &from, &to);
+            Self {
+                chars,
+                padded: true,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::IntegerCiphertext;
+
+    #[test]
+    fn test_match_positions() {
+        let pattern = PatternChars {
+            chars: Cow::Owned(vec![RadixCiphertext::from_blocks(vec![]); 3]),
+            padded: false,
+        };
+        assert_eq!(pattern.match_positions(5), 0..3);
+        assert_eq!(pattern.match_positions(3), 0..1);
+        assert_eq!(pattern.match_positions(2), 0..0);
+
+        let pattern = PatternChars {
+            padded: true,
+            ..pattern
+        };
+        assert_eq!(pattern.match_positions(5), 0..5);
+        assert_eq!(pattern.match_positions(0), 0..1);
+
+        let empty_pattern = PatternChars {
+            chars: Cow::Owned(vec![]),
+            padded: false,
+        };
+        assert_eq!(empty_pattern.match_positions(0), 0..1);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::high_level_api::integers::setup_default_cpu;
+use crate::integer::{IntegerCiphertext, RadixCiphertext};
+use crate::prelude::*;
+use crate::{ClientKey, FheAsciiString, FheStringIsEmpty, FheStringLen};
+
+fn decrypt_len(len: FheStringLen, client_key: &ClientKey) -> u16 {
+    match len {
+        FheStringLen::NoPadding(len) => len,
+        FheStringLen::Padding(len) => len.decrypt(client_key),
+    }
+}
+
+#[test]
+fn test_ascii_string_encrypt_decrypt() {
+    let client_key = setup_default_cpu();
+
+    let string = FheAsciiString::try_encrypt("tfhe", &client_key).unwrap();
+    assert!(!string.is_padded());
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe");
+
+    let string = FheAsciiString::try_encrypt_with_padding("tfhe", 3, &client_key).unwrap();
+    assert!(string.is_padded());
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe");
+
+    assert!(FheAsciiString::try_encrypt("é", &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("a\0b", &client_key).is_err());
+    assert!(
+        FheAsciiString::try_encrypt_with_padding("a", u32::from(u16::MAX), &client_key).is_err()
+    );
+}
+
+#[test]
+fn test_ascii_string_safe_serialization() {
+    let client_key = setup_default_cpu();
+
+    for padding in [0, 2] {
+        let string =
+            FheAsciiString::try_encrypt_with_padding("tfhe", padding, &client_key).unwrap();
+
+        let mut serialized = vec![];
+        crate::safe_serialize(&string, &mut serialized, 1 << 20).unwrap();
+        let string: FheAsciiString =
+            crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+
+        assert_eq!(string.is_padded(), padding != 0);
+        let decrypted: String = string.decrypt(&client_key);
+        assert_eq!(decrypted, "tfhe");
+    }
+}
+
+#[test]
+fn test_ascii_string_unsupported_parameters() {
+    use crate::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS;
+    use crate::ConfigBuilder;
+
+    // Characters of 8 bits cannot be split evenly into blocks of 3 bits
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_3_CARRY_3_KS_PBS, None).build();
+    let client_key = ClientKey::generate(config);
+    assert!(FheAsciiString::try_encrypt("tfhe", &client_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_len() {
+    let client_key = setup_default_cpu();
+
+    for (value, padding) in [("", 0), ("", 2), ("ab", 0), ("ab", 2)] {
+        let string = FheAsciiString::try_encrypt_with_padding(value, padding, &client_key).unwrap();
+        assert_eq!(
+            decrypt_len(string.len().unwrap(), &client_key),
+            value.len() as u16
+        );
+
+        let is_empty = match string.is_empty() {
+            FheStringIsEmpty::NoPadding(is_empty) => is_empty,
+            FheStringIsEmpty::Padding(is_empty) => is_empty.decrypt(&client_key),
+        };
+        assert_eq!(is_empty, value.is_empty());
+    }
+}
+
+#[test]
+fn test_ascii_string_len_overflow() {
+    let string = FheAsciiString {
+        chars: vec![RadixCiphertext::from_blocks(vec![]); u16::MAX as usize + 1],
+        padded: false,
+    };
+    assert!(string.len().is_err());
+}
+
+#[test]
+fn test_ascii_string_comparisons() {
+    let client_key = setup_default_cpu();
+
+    let cases = [("ab", "ab"), ("ab", "abc"), ("b", "ab"), ("", "a")];
+    for (lhs, rhs) in cases {
+        let enc_lhs = FheAsciiString::try_encrypt_with_padding(lhs, 1, &client_key).unwrap();
+        let enc_rhs = FheAsciiString::try_encrypt(rhs, &client_key).unwrap();
+
+        assert_eq!(enc_lhs.eq(&enc_rhs).decrypt(&client_key), lhs == rhs);
+        assert_eq!(enc_lhs.ne(&enc_rhs).decrypt(&client_key), lhs != rhs);
+        assert_eq!(enc_lhs.lt(&enc_rhs).decrypt(&client_key), lhs < rhs);
+        assert_eq!(enc_lhs.ge(&enc_rhs).decrypt(&client_key), lhs >= rhs);
+
+        assert_eq!(enc_lhs.eq(rhs).decrypt(&client_key), lhs == rhs);
+        assert_eq!(enc_lhs.gt(rhs).decrypt(&client_key), lhs > rhs);
+    }
+}
+
+#[test]
+fn test_ascii_string_contains_starts_with_find() {
+    let client_key = setup_default_cpu();
+
+    let haystack = "abab";
+    let string = FheAsciiString::try_encrypt_with_padding(haystack, 1, &client_key).unwrap();
+
+    for pattern in ["ba", "b", "", "abc"] {
+        let encrypted_pattern =
+            FheAsciiString::try_encrypt_with_padding(pattern, 1, &client_key).unwrap();
+
+        let contains = string.contains(&encrypted_pattern).decrypt(&client_key);
+        assert_eq!(contains, haystack.contains(pattern), "pattern: {pattern:?}");
+
+        let starts_with = string.starts_with(pattern).decrypt(&client_key);
+        assert_eq!(
+            starts_with,
+            haystack.starts_with(pattern),
+            "pattern: {pattern:?}"
+        );
+
+        let (index, found) = string.find(pattern);
+        let index: u16 = index.decrypt(&client_key);
+        let found = found.decrypt(&client_key);
+        let expected = haystack.find(pattern);
+        assert_eq!(found, expected.is_some(), "pattern: {pattern:?}");
+        assert_eq!(
+            index as usize,
+            expected.unwrap_or(0),
+            "pattern: {pattern:?}"
+        );
+    }
+}
+
+#[test]
+fn test_ascii_string_replace() {
+    let client_key = setup_default_cpu();
+
+    let cases = [
+        ("aaa", "aa", "b"),
+        ("ab", "b", "xy"),
+        ("ab", "", "-"),
+        ("ab", "c", "d"),
+    ];
+    for (haystack, from, to) in cases {
+        let expected = haystack.replace(from, to);
+
+        let string = FheAsciiString::try_encrypt(haystack, &client_key).unwrap();
+        let replaced: String = string.replace(from, to).decrypt(&client_key);
+        assert_eq!(replaced, expected, "{haystack:?}.replace({from:?}, {to:?})");
+    }
+
+    // Padded haystack and patterns
+    let string = FheAsciiString::try_encrypt_with_padding("aba", 1, &client_key).unwrap();
+    let from = FheAsciiString::try_encrypt_with_padding("a", 1, &client_key).unwrap();
+    let to = FheAsciiString::try_encrypt_with_padding("c", 1, &client_key).unwrap();
+    let replaced: String = string.replace(&from, &to).decrypt(&client_key);
+    assert_eq!(replaced, "cbc");
+}
+
+#[test]
+fn test_ascii_string_case_conversion() {
+    let client_key = setup_default_cpu();
+
+    let value = "aZ@[`{";
+    let string = FheAsciiString::try_encrypt_with_padding(value, 1, &client_key).unwrap();
+
+    let lowercase: String = string.to_lowercase().decrypt(&client_key);
+    assert_eq!(lowercase, value.to_ascii_lowercase());
+
+    let uppercase: String = string.to_uppercase().decrypt(&client_key);
+    assert_eq!(uppercase, value.to_ascii_uppercase());
+}
+
+#[test]
+fn test_ascii_string_trim() {
+    let client_key = setup_default_cpu();
+
+    for (value, padding) in [(" \ta b\r", 1), ("ab", 0), ("  ", 1), ("", 0)] {
+        let string = FheAsciiString::try_encrypt_with_padding(value, padding, &client_key).unwrap();
+        let trimmed = string.trim();
+        let decrypted: String = trimmed.decrypt(&client_key);
+        assert_eq!(decrypted, value.trim(), "value: {value:?}");
+        assert_eq!(
+            decrypt_len(trimmed.len().unwrap(), &client_key),
+            value.trim().len() as u16
+        );
+    }
+}
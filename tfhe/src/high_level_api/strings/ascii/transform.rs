@@ -0,0 +1,162 @@
+use super::{concat_chars, split_chars, with_cpu_server_key, FheAsciiString, BITS_PER_CHAR};
+use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+/// Distance between an uppercase letter and its lowercase counterpart
+const CASE_OFFSET: u8 = b'a' - b'A';
+
+/// Returns whether the char is in the range `first..first + 26`
+fn is_letter_from(sks: &ServerKey, c: &RadixCiphertext, first: u8) -> BooleanBlock {
+    // Chars below `first` wrap around and are thus not in the range
+    let shifted = sks.scalar_sub_parallelized(c, first);
+    sks.scalar_lt_parallelized(&shifted, 26u8)
+}
+
+fn case_offset_if(sks: &ServerKey, condition: &BooleanBlock, num_blocks: usize) -> RadixCiphertext {
+    let mut offset: RadixCiphertext = sks.create_trivial_radix(CASE_OFFSET, num_blocks);
+    sks.zero_out_if_condition_is_false(&mut offset, condition.as_ref());
+    offset
+}
+
+/// Returns whether the char is an ASCII whitespace, as defined by [char::is_whitespace]
+fn is_whitespace(sks: &ServerKey, c: &RadixCiphertext) -> BooleanBlock {
+    let (is_space, is_control_whitespace) = rayon::join(
+        || sks.scalar_eq_parallelized(c, b' '),
+        || {
+            // '\t', '\n', '\x0B', '\x0C' and '\r'
+            let shifted = sks.scalar_sub_parallelized(c, b'\t');
+            sks.scalar_lt_parallelized(&shifted, 5u8)
+        },
+    );
+    sks.boolean_bitor(&is_space, &is_control_whitespace)
+}
+
+impl FheAsciiString {
+    /// Returns a copy of the string where each uppercase letter is converted to lowercase
+    pub fn to_lowercase(&self) -> Self {
+        with_cpu_server_key(|sks| {
+            let chars = self
+                .chars
+                .par_iter()
+                .map(|c| {
+                    let is_uppercase = is_letter_from(sks, c, b'A');
+                    let offset = case_offset_if(sks, &is_uppercase, c.blocks().len());
+                    sks.add_parallelized(c, &offset)
+                })
+                .collect();
+            Self {
+                chars,
+                padded: self.padded,
+            }
+        })
+    }
+
+    /// Returns a copy of the string where each lowercase letter is converted to uppercase
+    pub fn to_uppercase(&self) -> Self {
+        with_cpu_server_key(|sks| {
+            let chars = self
+                .chars
+                .par_iter()
+                .map(|c| {
+                    let is_lowercase = is_letter_from(sks, c, b'a');
+                    let offset = case_offset_if(sks, &is_lowercase, c.blocks().len());
+                    sks.sub_parallelized(c, &offset)
+                })
+                .collect();
+            Self {
+                chars,
+                padded: self.padded,
+            }
+        })
+    }
+
+    /// Returns a copy of the string with leading and trailing whitespaces removed
+    ///
+    /// As the number of removed chars is not known, the result is padded and has the same
+    /// number of encrypted chars as the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let string = FheAsciiString::try_encrypt(" tfhe\n", &client_key).unwrap();
+    ///
+    /// let trimmed = string.trim();
+    /// let trimmed: String = trimmed.decrypt(&client_key);
+    /// assert_eq!(trimmed, "tfhe");
+    /// ```
+    pub fn trim(&self) -> Self {
+        if self.chars.is_empty() {
+            return self.clone();
+        }
+
+        with_cpu_server_key(|sks| {
+            let (whitespaces, nulls): (Vec<_>, Vec<_>) = self
+                .chars
+                .par_iter()
+                .map(|c| {
+                    rayon::join(
+                        || is_whitespace(sks, c),
+                        || sks.scalar_eq_parallelized(c, 0u8),
+                    )
+                })
+                .unzip();
+
+            // A char is trailing if it and all the chars after it are whitespaces or nulls
+            let mut is_trailing = Vec::with_capacity(self.chars.len());
+            for (is_whitespace, is_null) in whitespaces.iter().zip(nulls.iter()).rev() {
+                let is_blank = sks.boolean_bitor(is_whitespace, is_null);
+                let value = match is_trailing.last() {
+                    None => is_blank,
+                    Some(next_is_trailing) => sks.boolean_bitand(&is_blank, next_is_trailing),
+                };
+                is_trailing.push(value);
+            }
+            is_trailing.reverse();
+
+            // A char is leading if it and all the chars before it are whitespaces
+            let mut is_leading: Vec<BooleanBlock> = Vec::with_capacity(self.chars.len());
+            for is_whitespace in whitespaces {
+                let value = match is_leading.last() {
+                    None => is_whitespace,
+                    Some(previous_is_leading) => {
+                        sks.boolean_bitand(&is_whitespace, previous_is_leading)
+                    }
+                };
+                is_leading.push(value);
+            }
+
+            let chars = self
+                .chars
+                .par_iter()
+                .zip(is_trailing.par_iter())
+                .map(|(c, is_trailing)| {
+                    let mut c = c.clone();
+                    let is_not_trailing = sks.boolean_bitnot(is_trailing);
+                    sks.zero_out_if_condition_is_false(&mut c, is_not_trailing.as_ref());
+                    c
+                })
+                .collect::<Vec<_>>();
+
+            // Move the chars to the front by shifting out the leading whitespaces,
+            // the shift amount must have as many blocks as the shifted value
+            let num_blocks_per_char = self.chars[0].blocks().len();
+            let mut string = concat_chars(&chars);
+            let num_blocks = string.blocks().len();
+            let mut num_leading = super::count_true(sks, is_leading, num_blocks);
+            let bits_per_char_log2 = BITS_PER_CHAR.ilog2();
+            sks.scalar_left_shift_assign_parallelized(&mut num_leading, bits_per_char_log2);
+            sks.right_shift_assign_parallelized(&mut string, &num_leading);
+
+            Self {
+                chars: split_chars(string, num_blocks_per_char),
+                padded: true,
+            }
+        })
+    }
+}
@@ -0,0 +1,11 @@
+//! Encrypted strings
+//!
+//! [FheAsciiString] stores an ASCII string as a list of encrypted characters,
+//! each character being encrypted like a [FheUint8](crate::FheUint8).
+//!
+//! As the length of a string may itself need to be hidden, a string can be padded with
+//! encrypted null characters (`'\0'`) at its end. Operations for which the length of the result
+//! depends on the encrypted content (e.g. [FheAsciiString::trim]) return padded strings.
+pub use ascii::{FheAsciiString, FheStringIsEmpty, FheStringLen, FheStringPattern};
+
+mod ascii;
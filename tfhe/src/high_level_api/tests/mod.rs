@@ -5,6 +5,11 @@ use crate::high_level_api::{
 use crate::integer::U256;
 use crate::{CompactPublicKey, CompressedPublicKey, CompressedServerKey};
 use std::fmt::Debug;
+use std::sync::{PoisonError, RwLock};
+
+/// Taken for writing by the tests which set keys for the whole process, and for reading by the
+/// tests which rely on a thread without keys having none, so that they do not run concurrently
+pub(crate) static GLOBAL_SERVER_KEY_LOCK: RwLock<()> = RwLock::new(());
 
 fn assert_that_public_key_encryption_is_decrypted_by_client_key<FheType, ClearType>(
     clear: ClearType,
@@ -145,6 +150,120 @@ fn test_with_context() {
     assert!(!d);
 }
 
+#[test]
+fn test_with_rayon_scope() {
+    use rayon::prelude::*;
+
+    let config = ConfigBuilder::default().build();
+
+    let (cks, sks) = generate_keys(config);
+
+    let a = FheBool::encrypt(false, &cks);
+    let b = FheBool::encrypt(true, &cks);
+
+    // The operations run in threads of the pool, on which the key was never explicitly set
+    let results = crate::high_level_api::with_server_key_in_rayon_scope(sks.clone(), || {
+        (0..4)
+            .into_par_iter()
+            .map(|i| if i % 2 == 0 { &a & &b } else { &a | &b })
+            .collect::<Vec<_>>()
+    });
+    let decrypted = results
+        .iter()
+        .map(|r| r.decrypt(&cks))
+        .collect::<Vec<bool>>();
+    assert_eq!(decrypted, vec![false, true, false, true]);
+
+    // The pool is kept for the same keys until it is released
+    let pool_threads = || {
+        crate::high_level_api::with_server_key_in_rayon_scope(sks.clone(), || {
+            rayon::broadcast(|_| std::thread::current().id())
+        })
+    };
+    let threads = pool_threads();
+    assert_eq!(pool_threads(), threads);
+    crate::release_rayon_scope_pool(sks.clone());
+    let new_threads = pool_threads();
+    assert!(new_threads.iter().all(|thread| !threads.contains(thread)));
+
+    // Only the pools of the most recently used keys are kept
+    let other_sks = (0..4)
+        .map(|_| crate::ServerKey::new(&cks))
+        .collect::<Vec<_>>();
+    for other in &other_sks {
+        crate::high_level_api::with_server_key_in_rayon_scope(other.clone(), || ());
+    }
+    assert!(pool_threads()
+        .iter()
+        .all(|thread| !new_threads.contains(thread)));
+    crate::release_rayon_scope_pool(sks);
+    for other in other_sks {
+        crate::release_rayon_scope_pool(other);
+    }
+}
+
+#[test]
+fn test_global_server_key() {
+    use crate::high_level_api::global_state::with_internal_keys;
+    use crate::high_level_api::keys::InternalServerKey;
+    use crate::ServerKey;
+
+    fn uses_keys(sks: &ServerKey) -> bool {
+        with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(key) => std::sync::Arc::ptr_eq(key, &sks.key),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => false,
+        })
+    }
+
+    /// Unsets the keys of the process even if the test fails, so that other tests do not use
+    /// them
+    struct GlobalKeysGuard;
+
+    impl Drop for GlobalKeysGuard {
+        fn drop(&mut self) {
+            crate::unset_global_server_key();
+        }
+    }
+
+    let config = ConfigBuilder::default().build();
+    let cks = ClientKey::generate(config);
+    let global_sks = ServerKey::new(&cks);
+    let thread_sks = ServerKey::new(&cks);
+
+    let _lock = GLOBAL_SERVER_KEY_LOCK
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    crate::set_global_server_key(global_sks.clone());
+    let _guard = GlobalKeysGuard;
+
+    let a = FheUint8::encrypt(27u8, &cks);
+    let b = FheUint8::encrypt(100u8, &cks);
+
+    // A thread without keys of its own uses the keys of the process
+    let sum = {
+        let (a, b, global_sks) = (a.clone(), b.clone(), global_sks.clone());
+        std::thread::spawn(move || {
+            assert!(uses_keys(&global_sks));
+            a + b
+        })
+        .join()
+        .unwrap()
+    };
+    let decrypted: u8 = sum.decrypt(&cks);
+    assert_eq!(decrypted, 127);
+
+    // The keys of the thread take precedence over the keys of the process
+    crate::set_server_key(thread_sks.clone());
+    assert!(uses_keys(&thread_sks));
+    let decrypted: u8 = (&a + &b).decrypt(&cks);
+    assert_eq!(decrypted, 127);
+
+    // Without keys of its own, the thread falls back to the keys of the process
+    crate::unset_server_key();
+    assert!(uses_keys(&global_sks));
+}
+
 /// The purpose of this test is to assert that
 /// the deserialize and serialize traits are implemented
 #[test]
@@ -179,3 +179,65 @@ pub trait OverflowingMul<Rhs> {
 
     fn overflowing_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
 }
+
+pub trait SaturatingAdd<Rhs> {
+    type Output;
+
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SaturatingSub<Rhs> {
+    type Output;
+
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+pub trait SaturatingMul<Rhs> {
+    type Output;
+
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// The [FheBool] returned by the checked operations is true when the result is valid,
+/// i.e. the operation did not overflow, divide by zero or shift by more than the number of bits.
+pub trait CheckedAdd<Rhs> {
+    type Output;
+
+    fn checked_add(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+pub trait CheckedSub<Rhs> {
+    type Output;
+
+    fn checked_sub(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+pub trait CheckedMul<Rhs> {
+    type Output;
+
+    fn checked_mul(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+pub trait CheckedDiv<Rhs> {
+    type Output;
+
+    fn checked_div(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+pub trait CheckedRem<Rhs> {
+    type Output;
+
+    fn checked_rem(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+pub trait CheckedShl<Rhs> {
+    type Output;
+
+    fn checked_shl(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
+
+pub trait CheckedShr<Rhs> {
+    type Output;
+
+    fn checked_shr(self, rhs: Rhs) -> (Self::Output, FheBool);
+}
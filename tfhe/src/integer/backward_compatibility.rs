@@ -0,0 +1,148 @@
+//! Versions of the serialized integer types, see [crate::versionable]
+use serde::{Deserialize, Serialize};
+
+use crate::integer::ciphertext::{CompactCiphertextList, DataKind};
+#[cfg(feature = "zk-pok-experimental")]
+use crate::integer::ProvenCompactCiphertextList;
+use crate::integer::{
+    BooleanBlock, ClientKey, CompressedServerKey, RadixCiphertext, ServerKey, SignedRadixCiphertext,
+};
+use crate::versionable::{versions_dispatch, Upgrade};
+
+versions_dispatch!(
+    pub enum RadixCiphertextVersions {
+        V0(RadixCiphertext),
+    }
+);
+
+versions_dispatch!(
+    pub enum SignedRadixCiphertextVersions {
+        V0(SignedRadixCiphertext),
+    }
+);
+
+versions_dispatch!(
+    pub enum BooleanBlockVersions {
+        V0(BooleanBlock),
+    }
+);
+
+versions_dispatch!(
+    pub enum ClientKeyVersions {
+        V0(ClientKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum ServerKeyVersions {
+        V0(ServerKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedServerKeyVersions {
+        V0(CompressedServerKey),
+    }
+);
+
+/// Returns the kinds of the `count` integers of `num_blocks_per_integer` blocks
+/// stored in a list serialized before the list could hold different kinds of values
+pub(crate) fn legacy_list_info(
+    num_blocks: usize,
+    num_blocks_per_integer: usize,
+    kind: fn(usize) -> DataKind,
+) -> crate::Result<Vec<DataKind>> {
+    if num_blocks_per_integer == 0 || num_blocks % num_blocks_per_integer != 0 {
+        return Err(crate::Error::new(format!(
+            "Invalid legacy list: {num_blocks} blocks cannot be split \
+            into integers of {num_blocks_per_integer} blocks"
+        )));
+    }
+
+    Ok(vec![
+        kind(num_blocks_per_integer);
+        num_blocks / num_blocks_per_integer
+    ])
+}
+
+/// [CompactCiphertextList] before it could hold values of different kinds,
+/// all its integers are unsigned and have the same number of blocks
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactCiphertextListV0 {
+    pub(crate) ct_list: crate::shortint::ciphertext::CompactCiphertextList,
+    pub(crate) num_blocks_per_integer: usize,
+}
+
+impl CompactCiphertextListV0 {
+    pub(crate) fn upgrade_with_kind(
+        self,
+        kind: fn(usize) -> DataKind,
+    ) -> crate::Result<CompactCiphertextList> {
+        let info = legacy_list_info(
+            self.ct_list.ct_list.lwe_ciphertext_count().0,
+            self.num_blocks_per_integer,
+            kind,
+        )?;
+
+        Ok(CompactCiphertextList {
+            ct_list: self.ct_list,
+            info,
+        })
+    }
+}
+
+impl Upgrade<CompactCiphertextList> for CompactCiphertextListV0 {
+    fn upgrade(self) -> crate::Result<CompactCiphertextList> {
+        self.upgrade_with_kind(DataKind::Unsigned)
+    }
+}
+
+versions_dispatch!(
+    pub enum CompactCiphertextListVersions {
+        V0(CompactCiphertextListV0) => V1,
+        V1(CompactCiphertextList),
+    }
+);
+
+/// [ProvenCompactCiphertextList] before it could hold values of different kinds,
+/// all its integers are unsigned and have the same number of blocks
+#[cfg(feature = "zk-pok-experimental")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProvenCompactCiphertextListV0 {
+    pub(crate) proved_list: crate::shortint::ciphertext::ProvenCompactCiphertextList,
+    pub(crate) num_blocks_per_integer: usize,
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl ProvenCompactCiphertextListV0 {
+    pub(crate) fn upgrade_with_kind(
+        self,
+        kind: fn(usize) -> DataKind,
+    ) -> crate::Result<ProvenCompactCiphertextList> {
+        let info = legacy_list_info(
+            self.proved_list.ciphertext_count(),
+            self.num_blocks_per_integer,
+            kind,
+        )?;
+
+        Ok(ProvenCompactCiphertextList {
+            proved_list: self.proved_list,
+            info,
+        })
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+impl Upgrade<ProvenCompactCiphertextList> for ProvenCompactCiphertextListV0 {
+    fn upgrade(self) -> crate::Result<ProvenCompactCiphertextList> {
+        self.upgrade_with_kind(DataKind::Unsigned)
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch!(
+    pub enum ProvenCompactCiphertextListVersions {
+        V0(ProvenCompactCiphertextListV0) => V1,
+        V1(ProvenCompactCiphertextList),
+    }
+);
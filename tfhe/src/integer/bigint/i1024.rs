@@ -0,0 +1,22 @@
+pub type I1024 = super::static_signed::StaticSignedBigInt<16>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const() {
+        assert_eq!(I1024::BITS, 1024);
+        assert_eq!(I1024::ZERO, I1024::from([0; 16]));
+        assert_eq!(I1024::ONE, I1024::from(1i64));
+        assert_eq!(I1024::from(-1i64), I1024::from([u64::MAX; 16]));
+
+        let mut max = [u64::MAX; 16];
+        max[15] >>= 1;
+        assert_eq!(I1024::MAX, I1024::from(max));
+
+        let mut min = [0u64; 16];
+        min[15] = 1u64 << 63;
+        assert_eq!(I1024::MIN, I1024::from(min));
+    }
+}
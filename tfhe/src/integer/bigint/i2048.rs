@@ -0,0 +1,25 @@
+pub type I2048 = super::static_signed::StaticSignedBigInt<32>;
+
+/// Only used as the double precision type of [I2048] in scalar divisions
+pub(crate) type I4096 = super::static_signed::StaticSignedBigInt<64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const() {
+        assert_eq!(I2048::BITS, 2048);
+        assert_eq!(I2048::ZERO, I2048::from([0; 32]));
+        assert_eq!(I2048::ONE, I2048::from(1i64));
+        assert_eq!(I2048::from(-1i64), I2048::from([u64::MAX; 32]));
+
+        let mut max = [u64::MAX; 32];
+        max[31] >>= 1;
+        assert_eq!(I2048::MAX, I2048::from(max));
+
+        let mut min = [0u64; 32];
+        min[31] = 1u64 << 63;
+        assert_eq!(I2048::MIN, I2048::from(min));
+    }
+}
@@ -1,8 +1,12 @@
 mod algorithms;
+pub mod i1024;
+pub mod i2048;
 pub mod i256;
 pub mod i512;
 pub mod static_signed;
 pub mod static_unsigned;
+pub mod u1024;
+pub mod u2048;
 pub mod u256;
 pub mod u512;
 
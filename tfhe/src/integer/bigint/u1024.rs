@@ -0,0 +1,43 @@
+use crate::core_crypto::prelude::CastFrom;
+
+pub type U1024 = super::static_unsigned::StaticUnsignedBigInt<16>;
+
+impl CastFrom<crate::integer::U512> for U1024 {
+    fn cast_from(input: crate::integer::U512) -> Self {
+        let mut converted = [0u64; 16];
+        converted[..8].copy_from_slice(&input.0);
+        Self(converted)
+    }
+}
+
+impl CastFrom<U1024> for crate::integer::U512 {
+    fn cast_from(input: U1024) -> Self {
+        let mut converted = [0u64; 8];
+        converted.copy_from_slice(&input.0[..8]);
+        Self(converted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::CastInto;
+    use crate::integer::U512;
+
+    #[test]
+    fn test_const() {
+        assert_eq!(U1024::BITS, 1024);
+        assert_eq!(U1024::ZERO, U1024::from([0; 16]));
+        assert_eq!(U1024::ONE, U1024::from(1u64));
+        assert_eq!(U1024::MAX, U1024::from([u64::MAX; 16]));
+    }
+
+    #[test]
+    fn test_cast() {
+        let value = U512::MAX - U512::from(12345u64);
+        let extended: U1024 = value.cast_into();
+        assert_eq!(extended.0[8..], [0u64; 8]);
+        assert_eq!(U512::cast_from(extended), value);
+        assert_eq!(U512::cast_from(U1024::MAX), U512::MAX);
+    }
+}
@@ -0,0 +1,67 @@
+use super::static_unsigned::StaticUnsignedBigInt;
+use crate::core_crypto::prelude::CastFrom;
+
+pub type U2048 = StaticUnsignedBigInt<32>;
+
+/// Only used as the double precision type of [U2048] in scalar divisions
+pub(crate) type U4096 = StaticUnsignedBigInt<64>;
+
+impl CastFrom<crate::integer::U1024> for U2048 {
+    fn cast_from(input: crate::integer::U1024) -> Self {
+        let mut converted = [0u64; 32];
+        converted[..16].copy_from_slice(&input.0);
+        Self(converted)
+    }
+}
+
+impl CastFrom<U2048> for crate::integer::U1024 {
+    fn cast_from(input: U2048) -> Self {
+        let mut converted = [0u64; 16];
+        converted.copy_from_slice(&input.0[..16]);
+        Self(converted)
+    }
+}
+
+impl CastFrom<U2048> for U4096 {
+    fn cast_from(input: U2048) -> Self {
+        let mut converted = [0u64; 64];
+        converted[..32].copy_from_slice(&input.0);
+        Self(converted)
+    }
+}
+
+impl CastFrom<U4096> for U2048 {
+    fn cast_from(input: U4096) -> Self {
+        let mut converted = [0u64; 32];
+        converted.copy_from_slice(&input.0[..32]);
+        Self(converted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::CastInto;
+    use crate::integer::U1024;
+
+    #[test]
+    fn test_const() {
+        assert_eq!(U2048::BITS, 2048);
+        assert_eq!(U2048::ZERO, U2048::from([0; 32]));
+        assert_eq!(U2048::ONE, U2048::from(1u64));
+        assert_eq!(U2048::MAX, U2048::from([u64::MAX; 32]));
+    }
+
+    #[test]
+    fn test_cast() {
+        let value = U1024::MAX - U1024::from(12345u64);
+        let extended: U2048 = value.cast_into();
+        assert_eq!(extended.0[16..], [0u64; 16]);
+        assert_eq!(U1024::cast_from(extended), value);
+        assert_eq!(U1024::cast_from(U2048::MAX), U1024::MAX);
+
+        let extended: U4096 = U2048::MAX.cast_into();
+        assert_eq!(extended.0[32..], [0u64; 32]);
+        assert_eq!(U2048::cast_from(extended), U2048::MAX);
+    }
+}
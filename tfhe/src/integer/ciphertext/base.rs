@@ -25,6 +25,10 @@ impl<Block> From<Vec<Block>> for BaseRadixCiphertext<Block> {
 // Type alias to save some typing in implementation parts
 pub type RadixCiphertext = BaseRadixCiphertext<Ciphertext>;
 
+impl crate::named::Named for RadixCiphertext {
+    const NAME: &'static str = "integer::RadixCiphertext";
+}
+
 impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> ParameterSetConformant
     for BaseRadixCiphertext<T>
 {
@@ -125,6 +129,10 @@ impl<Block> From<Vec<Block>> for BaseSignedRadixCiphertext<Block> {
 // Type alias to save some typing in implementation parts
 pub type SignedRadixCiphertext = BaseSignedRadixCiphertext<Ciphertext>;
 
+impl crate::named::Named for SignedRadixCiphertext {
+    const NAME: &'static str = "integer::SignedRadixCiphertext";
+}
+
 impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> ParameterSetConformant
     for BaseSignedRadixCiphertext<T>
 {
@@ -228,3 +236,9 @@ impl<Block> From<(Vec<Block>, Vec<u64>)> for BaseCrtCiphertext<Block> {
         Self { blocks, moduli }
     }
 }
+
+impl CrtCiphertext {
+    pub fn block_carries_are_empty(&self) -> bool {
+        self.blocks.iter().all(Ciphertext::carry_is_empty)
+    }
+}
@@ -45,6 +45,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct BooleanBlock(pub(crate) Ciphertext);
 
+impl crate::named::Named for BooleanBlock {
+    const NAME: &'static str = "integer::BooleanBlock";
+}
+
 impl BooleanBlock {
     /// Creates a new BooleanBlock without checks.
     ///
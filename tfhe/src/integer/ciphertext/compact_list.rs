@@ -1,42 +1,499 @@
 use super::super::parameters::RadixCompactCiphertextListConformanceParams;
 use super::IntegerRadixCiphertext;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+use crate::core_crypto::prelude::Numeric;
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::encryption::KnowsMessageModulus;
+use crate::integer::{
+    BooleanBlock, CompactPublicKey, RadixCiphertext, SignedRadixCiphertext, I1024, I2048, I256,
+    I512, U1024, U2048, U256, U512,
+};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::{Ciphertext, MessageModulus};
+#[cfg(feature = "zk-pok-experimental")]
+use crate::zk::{CompactPkePublicParams, ZkComputeLoad};
 use serde::{Deserialize, Serialize};
 
+/// Describes what kind of value is stored at a given index of a
+/// [CompactCiphertextList].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataKind {
+    /// An unsigned integer, the held value is its number of radix blocks.
+    Unsigned(usize),
+    /// A signed integer, the held value is its number of radix blocks.
+    Signed(usize),
+    Boolean,
+}
+
+impl DataKind {
+    pub fn num_blocks(self) -> usize {
+        match self {
+            Self::Unsigned(n) | Self::Signed(n) => n,
+            Self::Boolean => 1,
+        }
+    }
+}
+
+/// Trait for clear values that can be pushed in a [CompactCiphertextListBuilder]
+pub trait Compactable {
+    /// Decomposes `self` into `num_blocks` clear blocks that are appended to `messages`
+    /// and returns the [DataKind] that describes the pushed value.
+    ///
+    /// If `num_blocks` is `None`, the number of blocks is deduced from the
+    /// number of bits of the clear type.
+    ///
+    /// Returns an error, without modifying `messages`, if `num_blocks` is 0 or if the value
+    /// does not fit in `num_blocks` blocks.
+    fn compact_into(
+        self,
+        messages: &mut Vec<u64>,
+        message_modulus: MessageModulus,
+        num_blocks: Option<usize>,
+    ) -> crate::Result<DataKind>;
+}
+
+impl Compactable for bool {
+    fn compact_into(
+        self,
+        messages: &mut Vec<u64>,
+        _message_modulus: MessageModulus,
+        _num_blocks: Option<usize>,
+    ) -> crate::Result<DataKind> {
+        messages.push(u64::from(self));
+        Ok(DataKind::Boolean)
+    }
+}
+
+fn compact_numeric_into<T>(
+    value: T,
+    is_signed: bool,
+    messages: &mut Vec<u64>,
+    message_modulus: MessageModulus,
+    num_blocks: Option<usize>,
+) -> crate::Result<usize>
+where
+    T: Numeric + DecomposableInto<u64>,
+{
+    let bits_in_block = message_modulus.0.ilog2();
+    let num_blocks = match num_blocks {
+        Some(0) => {
+            return Err(crate::Error::new(
+                "The number of blocks must be greater than 0".to_string(),
+            ))
+        }
+        Some(num_blocks) => {
+            check_fits_in_num_bits(value, is_signed, num_blocks * bits_in_block as usize)?;
+            num_blocks
+        }
+        None => T::BITS / bits_in_block as usize,
+    };
+
+    // Values that need more blocks than what the clear type can fill
+    // are sign-extended (this is a no-op for unsigned values)
+    let padding_block = if value < T::ZERO {
+        message_modulus.0 as u64 - 1
+    } else {
+        0
+    };
+
+    let decomposer = BlockDecomposer::new(value, bits_in_block);
+    messages.extend(
+        decomposer
+            .iter_as::<u64>()
+            .chain(std::iter::repeat(padding_block))
+            .take(num_blocks),
+    );
+    Ok(num_blocks)
+}
+
+/// Checks that the bits of `value` above `num_bits` are a sign extension
+/// (i.e. zeros for positive values)
+fn check_fits_in_num_bits<T>(value: T, is_signed: bool, num_bits: usize) -> crate::Result<()>
+where
+    T: Numeric + DecomposableInto<u64>,
+{
+    if num_bits >= T::BITS {
+        return Ok(());
+    }
+
+    // For signed values, the bit at num_bits - 1 is the sign bit of the truncated value,
+    // so it is part of the sign extension
+    let mut high_bits = value;
+    high_bits >>= if is_signed { num_bits - 1 } else { num_bits } as u32;
+
+    // Shifts are arithmetic for signed types
+    let mut sign_extension = T::ZERO;
+    if value < T::ZERO {
+        sign_extension = value;
+        sign_extension >>= (T::BITS - 1) as u32;
+    }
+
+    if high_bits == sign_extension {
+        Ok(())
+    } else {
+        Err(crate::Error::new(format!(
+            "The value {value:?} does not fit in {num_bits} bits"
+        )))
+    }
+}
+
+macro_rules! impl_compactable {
+    (
+        Unsigned { $($unsigned:ty),* $(,)? }
+        Signed { $($signed:ty),* $(,)? }
+    ) => {
+        $(
+            impl Compactable for $unsigned {
+                fn compact_into(
+                    self,
+                    messages: &mut Vec<u64>,
+                    message_modulus: MessageModulus,
+                    num_blocks: Option<usize>,
+                ) -> crate::Result<DataKind> {
+                    let n =
+                        compact_numeric_into(self, false, messages, message_modulus, num_blocks)?;
+                    Ok(DataKind::Unsigned(n))
+                }
+            }
+        )*
+
+        $(
+            impl Compactable for $signed {
+                fn compact_into(
+                    self,
+                    messages: &mut Vec<u64>,
+                    message_modulus: MessageModulus,
+                    num_blocks: Option<usize>,
+                ) -> crate::Result<DataKind> {
+                    let n =
+                        compact_numeric_into(self, true, messages, message_modulus, num_blocks)?;
+                    Ok(DataKind::Signed(n))
+                }
+            }
+        )*
+    };
+}
+
+impl_compactable!(
+    Unsigned { u8, u16, u32, u64, u128, U256, U512, U1024, U2048 }
+    Signed { i8, i16, i32, i64, i128, I256, I512, I1024, I2048 }
+);
+
+/// Builder to create a [CompactCiphertextList] that stores values of heterogeneous types
+///
+/// All the values pushed are encrypted together, in a single compact list, when
+/// [build](Self::build) is called.
+pub struct CompactCiphertextListBuilder<'a> {
+    messages: Vec<u64>,
+    info: Vec<DataKind>,
+    pk: &'a CompactPublicKey,
+}
+
+impl<'a> CompactCiphertextListBuilder<'a> {
+    pub fn new(pk: &'a CompactPublicKey) -> Self {
+        Self {
+            messages: vec![],
+            info: vec![],
+            pk,
+        }
+    }
+
+    /// Pushes a value, the number of blocks used is deduced from the type of the value.
+    pub fn push<T>(&mut self, data: T) -> &mut Self
+    where
+        T: Compactable,
+    {
+        let kind = data
+            .compact_into(&mut self.messages, self.pk.key.message_modulus(), None)
+            .expect("A value always fits in the number of blocks deduced from its type");
+        self.info.push(kind);
+        self
+    }
+
+    /// Pushes a value that is to be encrypted on exactly `num_blocks`.
+    ///
+    /// This has no effect on booleans, which always use a single block.
+    ///
+    /// Returns an error, and pushes nothing, if `num_blocks` is 0 or if the value does not fit
+    /// in `num_blocks` blocks.
+    pub fn push_with_num_blocks<T>(
+        &mut self,
+        data: T,
+        num_blocks: usize,
+    ) -> crate::Result<&mut Self>
+    where
+        T: Compactable,
+    {
+        let kind = data.compact_into(
+            &mut self.messages,
+            self.pk.key.message_modulus(),
+            Some(num_blocks),
+        )?;
+        self.info.push(kind);
+        Ok(self)
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compactable,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    /// Pushes values that are to be encrypted on exactly `num_blocks` each.
+    ///
+    /// Returns an error on the first value which cannot be pushed, the values before it are
+    /// pushed.
+    pub fn extend_with_num_blocks<T>(
+        &mut self,
+        values: impl Iterator<Item = T>,
+        num_blocks: usize,
+    ) -> crate::Result<&mut Self>
+    where
+        T: Compactable,
+    {
+        for value in values {
+            self.push_with_num_blocks(value, num_blocks)?;
+        }
+        Ok(self)
+    }
+
+    /// Returns the number of values pushed so far
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Encrypts all the pushed values into a [CompactCiphertextList]
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value was pushed
+    pub fn build(&self) -> CompactCiphertextList {
+        let mut ct_list = self.pk.key.encrypt_slice(&self.messages);
+        if self.info.iter().all(|kind| *kind == DataKind::Boolean) {
+            ct_list.degree = Degree::new(1);
+        }
+        CompactCiphertextList {
+            ct_list,
+            info: self.info.clone(),
+        }
+    }
+
+    /// Encrypts all the pushed values into a [ProvenCompactCiphertextList]
+    /// with a single zero-knowledge proof covering all the values.
+    ///
+    /// [ProvenCompactCiphertextList]: crate::integer::ProvenCompactCiphertextList
+    #[cfg(feature = "zk-pok-experimental")]
+    pub fn build_with_proof(
+        &self,
+        public_params: &CompactPkePublicParams,
+        load: ZkComputeLoad,
+    ) -> crate::Result<crate::integer::ProvenCompactCiphertextList> {
+        let proved_list =
+            self.pk
+                .key
+                .encrypt_and_prove_slice(&self.messages, public_params, load)?;
+        Ok(crate::integer::ProvenCompactCiphertextList {
+            proved_list,
+            info: self.info.clone(),
+        })
+    }
+}
+
+/// Trait for types that can be extracted from a [CompactCiphertextListExpander]
+/// or from a [CompressedCiphertextList](super::CompressedCiphertextList)
+pub trait Expandable: Sized {
+    /// Creates the value from its blocks
+    ///
+    /// Returns an error if the kind of the stored data does not match `Self`
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self>;
+}
+
+fn data_kind_mismatch_error(expected: &str, kind: DataKind) -> crate::Error {
+    crate::Error::new(format!(
+        "Tried to expand a {expected}, but a {kind:?} is stored in this slot"
+    ))
+}
+
+impl Expandable for RadixCiphertext {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        match kind {
+            DataKind::Unsigned(_) => Ok(Self::from(blocks)),
+            DataKind::Signed(_) | DataKind::Boolean => {
+                Err(data_kind_mismatch_error("RadixCiphertext", kind))
+            }
+        }
+    }
+}
+
+impl Expandable for SignedRadixCiphertext {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        match kind {
+            DataKind::Signed(_) => Ok(Self::from(blocks)),
+            DataKind::Unsigned(_) | DataKind::Boolean => {
+                Err(data_kind_mismatch_error("SignedRadixCiphertext", kind))
+            }
+        }
+    }
+}
+
+impl Expandable for BooleanBlock {
+    fn from_expanded_blocks(mut blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        match kind {
+            DataKind::Boolean => {
+                let mut block = blocks.pop().ok_or_else(|| {
+                    crate::Error::new("No block to expand a BooleanBlock from".to_string())
+                })?;
+                block.degree = Degree::new(1);
+                Ok(Self::new_unchecked(block))
+            }
+            DataKind::Unsigned(_) | DataKind::Signed(_) => {
+                Err(data_kind_mismatch_error("BooleanBlock", kind))
+            }
+        }
+    }
+}
+
+/// The expanded content of a [CompactCiphertextList]
+///
+/// Values are extracted, using their index, with [get](Self::get).
+pub struct CompactCiphertextListExpander {
+    expanded_blocks: Vec<Ciphertext>,
+    info: Vec<DataKind>,
+}
+
+impl CompactCiphertextListExpander {
+    pub(crate) fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
+        Self {
+            expanded_blocks,
+            info,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Returns the kind of the value at the given index, or `None` if the index is out of bounds
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
+    }
+
+    fn blocks_of(&self, index: usize) -> Option<(&[Ciphertext], DataKind)> {
+        let kind = self.get_kind_of(index)?;
+        let start_block_index = self.info[..index]
+            .iter()
+            .map(|kind| kind.num_blocks())
+            .sum::<usize>();
+        let end_block_index = start_block_index + kind.num_blocks();
+
+        self.expanded_blocks
+            .get(start_block_index..end_block_index)
+            .map(|blocks| (blocks, kind))
+    }
+
+    /// Returns the value at the given index
+    ///
+    /// - Returns `None` if the index is out of bounds
+    /// - Returns `Some(Err(_))` if the value stored at the index is not of type `T`
+    pub fn get<T>(&self, index: usize) -> Option<crate::Result<T>>
+    where
+        T: Expandable,
+    {
+        self.blocks_of(index)
+            .map(|(blocks, kind)| T::from_expanded_blocks(blocks.to_owned(), kind))
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CompactCiphertextList {
     pub(crate) ct_list: crate::shortint::ciphertext::CompactCiphertextList,
-    // Keep track of the num_blocks, as we allow
-    // storing many integer that have the same num_blocks
-    // into ct_list
-    pub(crate) num_blocks_per_integer: usize,
+    // Keep track of the kind (and thus the num_blocks) of each integer
+    // stored into ct_list
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl crate::named::Named for CompactCiphertextList {
+    const NAME: &'static str = "integer::CompactCiphertextList";
 }
 
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = RadixCompactCiphertextListConformanceParams;
 
     fn is_conformant(&self, params: &RadixCompactCiphertextListConformanceParams) -> bool {
-        self.num_blocks_per_integer == params.num_blocks_per_integer
-            && self
-                .ct_list
-                .is_conformant(&params.to_shortint_ct_list_conformance_parameters())
+        // The values can be of different kinds, so each one is checked against its own kind
+        let kinds_are_valid = self.info.iter().all(|kind| match *kind {
+            DataKind::Unsigned(num_blocks) | DataKind::Signed(num_blocks) => {
+                (1..=params.num_blocks_per_integer).contains(&num_blocks)
+            }
+            DataKind::Boolean => true,
+        });
+        // The list must expand to exactly the blocks its values are made of
+        let num_blocks = self.info.iter().map(|kind| kind.num_blocks()).sum();
+
+        params.num_integers_constraint.is_valid(self.info.len())
+            && kinds_are_valid
+            && self.ct_list.is_conformant(
+                &params
+                    .shortint_params
+                    .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(num_blocks)),
+            )
     }
 }
 
 impl CompactCiphertextList {
+    /// Creates a builder to encrypt values of heterogeneous types into a single list
+    pub fn builder(pk: &CompactPublicKey) -> CompactCiphertextListBuilder<'_> {
+        CompactCiphertextListBuilder::new(pk)
+    }
+
+    /// Returns whether all the values of the list are of the given kind
+    pub(crate) fn holds_only(&self, kind: DataKind) -> bool {
+        self.info.iter().all(|value_kind| *value_kind == kind)
+    }
+
+    /// Expands the first value of the list
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is empty, see [Self::try_expand_one] for a fallible version.
     pub fn expand_one<T: IntegerRadixCiphertext>(&self) -> T {
+        self.try_expand_one().unwrap()
+    }
+
+    /// Expands the first value of the list
+    ///
+    /// Returns an error if the list is empty.
+    pub fn try_expand_one<T: IntegerRadixCiphertext>(&self) -> crate::Result<T> {
+        let first_kind = self
+            .info
+            .first()
+            .ok_or_else(|| crate::Error::new("The list is empty".to_string()))?;
         let mut blocks = self.ct_list.expand();
-        blocks.truncate(self.num_blocks_per_integer);
-        T::from(blocks)
+        blocks.truncate(first_kind.num_blocks());
+        Ok(T::from(blocks))
     }
 
     /// Deconstruct a [`CompactCiphertextList`] into its constituents.
-    pub fn into_raw_parts(self) -> (crate::shortint::ciphertext::CompactCiphertextList, usize) {
-        let Self {
-            ct_list,
-            num_blocks_per_integer,
-        } = self;
-        (ct_list, num_blocks_per_integer)
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::shortint::ciphertext::CompactCiphertextList,
+        Vec<DataKind>,
+    ) {
+        let Self { ct_list, info } = self;
+        (ct_list, info)
     }
 
     /// Construct a [`CompactCiphertextList`] from its constituents.
@@ -46,24 +503,28 @@ impl CompactCiphertextList {
     /// Panics if the constituents are not compatible with each others.
     pub fn from_raw_parts(
         ct_list: crate::shortint::ciphertext::CompactCiphertextList,
-        num_blocks_per_integer: usize,
+        info: Vec<DataKind>,
     ) -> Self {
+        let total_num_blocks = info.iter().map(|kind| kind.num_blocks()).sum::<usize>();
         assert_eq!(
-            ct_list.ct_list.lwe_ciphertext_count().0 % num_blocks_per_integer,
-            0,
+            ct_list.ct_list.lwe_ciphertext_count().0,
+            total_num_blocks,
             "CompactCiphertextList LweCiphertextCount is expected \
-            to be a multiple of {num_blocks_per_integer}, got {:?}",
+            to be equal to the sum of the number of blocks in the info ({total_num_blocks}), \
+            got {:?}",
             ct_list.ct_list.lwe_ciphertext_count()
         );
 
-        Self {
-            ct_list,
-            num_blocks_per_integer,
-        }
+        Self { ct_list, info }
     }
 
     pub fn ciphertext_count(&self) -> usize {
-        self.ct_list.ct_list.lwe_ciphertext_count().0 / self.num_blocks_per_integer
+        self.info.len()
+    }
+
+    /// Returns the kind of the value at the given index, or `None` if the index is out of bounds
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
     }
 
     pub fn expand<T: IntegerRadixCiphertext>(&self) -> Vec<T> {
@@ -71,12 +532,12 @@ impl CompactCiphertextList {
         let num_ct = self.ciphertext_count();
         let mut ciphertexts = Vec::with_capacity(num_ct);
 
-        for _ in 0..num_ct {
+        for kind in &self.info {
             let ct_blocks = all_block_iter
                 .by_ref()
-                .take(self.num_blocks_per_integer)
+                .take(kind.num_blocks())
                 .collect::<Vec<_>>();
-            if ct_blocks.len() < self.num_blocks_per_integer {
+            if ct_blocks.len() < kind.num_blocks() {
                 break;
             }
             let ct = T::from(ct_blocks);
@@ -86,6 +547,31 @@ impl CompactCiphertextList {
         ciphertexts
     }
 
+    /// Expands the list so that each of its values can be retrieved with
+    /// its own type.
+    ///
+    /// Returns an error if the number of blocks in the list does not match
+    /// what its metadata describes.
+    pub fn expand_heterogeneous(&self) -> crate::Result<CompactCiphertextListExpander> {
+        let expanded_blocks = self.ct_list.expand();
+        let expected_num_blocks = self
+            .info
+            .iter()
+            .map(|kind| kind.num_blocks())
+            .sum::<usize>();
+        if expanded_blocks.len() != expected_num_blocks {
+            return Err(crate::Error::new(format!(
+                "The list contains {} blocks, but its metadata describes {expected_num_blocks} blocks",
+                expanded_blocks.len()
+            )));
+        }
+
+        Ok(CompactCiphertextListExpander::new(
+            expanded_blocks,
+            self.info.clone(),
+        ))
+    }
+
     pub fn size_elements(&self) -> usize {
         self.ct_list.size_elements()
     }
@@ -94,3 +580,97 @@ impl CompactCiphertextList {
         self.ct_list.size_bytes()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::ClientKey;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::PBSParameters;
+
+    #[test]
+    fn test_heterogeneous_compact_ciphertext_list() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let pk = CompactPublicKey::new(&cks);
+
+        let mut builder = CompactCiphertextList::builder(&pk);
+        builder.push(u32::MAX).push(-17i8).push(true);
+        builder
+            .push_with_num_blocks(-3i8, 16)
+            .unwrap()
+            .push_with_num_blocks(5u8, 2)
+            .unwrap();
+        // 2 blocks only hold 4 bits
+        assert!(builder.push_with_num_blocks(16u8, 2).is_err());
+        assert_eq!(builder.len(), 5);
+
+        let list = builder.build();
+        assert_eq!(list.ciphertext_count(), 5);
+        assert_eq!(list.get_kind_of(0), Some(DataKind::Unsigned(16)));
+        assert_eq!(list.get_kind_of(1), Some(DataKind::Signed(4)));
+        assert_eq!(list.get_kind_of(2), Some(DataKind::Boolean));
+        assert_eq!(list.get_kind_of(3), Some(DataKind::Signed(16)));
+        assert_eq!(list.get_kind_of(4), Some(DataKind::Unsigned(2)));
+        assert_eq!(list.get_kind_of(5), None);
+
+        let expander = list.expand_heterogeneous().unwrap();
+
+        let a: RadixCiphertext = expander.get(0).unwrap().unwrap();
+        let b: SignedRadixCiphertext = expander.get(1).unwrap().unwrap();
+        let c: BooleanBlock = expander.get(2).unwrap().unwrap();
+        let d: SignedRadixCiphertext = expander.get(3).unwrap().unwrap();
+        let e: RadixCiphertext = expander.get(4).unwrap().unwrap();
+
+        assert_eq!(cks.decrypt_radix::<u32>(&a), u32::MAX);
+        assert_eq!(cks.decrypt_signed_radix::<i8>(&b), -17);
+        assert!(cks.decrypt_bool(&c));
+        assert_eq!(cks.decrypt_signed_radix::<i32>(&d), -3);
+        assert_eq!(cks.decrypt_radix::<u8>(&e), 5);
+
+        assert!(expander.get::<SignedRadixCiphertext>(0).unwrap().is_err());
+        assert!(expander.get::<BooleanBlock>(1).unwrap().is_err());
+        assert!(expander.get::<RadixCiphertext>(2).unwrap().is_err());
+        assert!(expander.get::<RadixCiphertext>(5).is_none());
+    }
+
+    #[test]
+    fn test_heterogeneous_compact_ciphertext_list_conformance() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let pk = CompactPublicKey::new(&cks);
+
+        let list = CompactCiphertextList::builder(&pk)
+            .push(u8::MAX)
+            .push(u64::MAX)
+            .push(true)
+            .build();
+
+        let params =
+            |num_blocks_per_integer, num_integers| RadixCompactCiphertextListConformanceParams {
+                shortint_params: PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+                    .to_shortint_conformance_param(),
+                num_blocks_per_integer,
+                num_integers_constraint: ListSizeConstraint::exact_size(num_integers),
+            };
+
+        assert!(list.is_conformant(&params(32, 3)));
+        assert!(list.is_conformant(&params(64, 3)));
+        // The u64 has more blocks than allowed
+        assert!(!list.is_conformant(&params(16, 3)));
+        assert!(!list.is_conformant(&params(32, 2)));
+
+        // The kinds must describe exactly the blocks of the list
+        let breakers: [fn(&mut Vec<DataKind>); 5] = [
+            |info| info[1] = DataKind::Unsigned(31),
+            |info| info[1] = DataKind::Signed(33),
+            |info| info[2] = DataKind::Unsigned(2),
+            |info| info[0] = DataKind::Unsigned(0),
+            |info| info.push(DataKind::Boolean),
+        ];
+        for breaker in breakers {
+            let mut broken = list.clone();
+            breaker(&mut broken.info);
+            let num_integers = broken.info.len();
+            assert!(!broken.is_conformant(&params(32, num_integers)));
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use super::{DataKind, Expandable};
+use crate::integer::compression_keys::{CompressionKey, DecompressionKey};
+use crate::integer::{BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Trait for already computed ciphertexts that can be pushed in a
+/// [CompressedCiphertextListBuilder]
+pub trait Compressible {
+    /// Moves the blocks of `self` into `messages` and returns the [DataKind] that describes them
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind;
+}
+
+impl Compressible for RadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        let num_blocks = self.blocks.len();
+        messages.extend(self.blocks);
+        DataKind::Unsigned(num_blocks)
+    }
+}
+
+impl Compressible for SignedRadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        let num_blocks = self.blocks.len();
+        messages.extend(self.blocks);
+        DataKind::Signed(num_blocks)
+    }
+}
+
+impl Compressible for BooleanBlock {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        messages.push(self.0);
+        DataKind::Boolean
+    }
+}
+
+/// Builder to create a [CompressedCiphertextList] from already computed ciphertexts
+///
+/// The blocks of all the pushed values must have empty carries, which is the case for the outputs
+/// of the default (parallelized) operations of the [ServerKey](crate::integer::ServerKey).
+#[derive(Clone, Default)]
+pub struct CompressedCiphertextListBuilder {
+    ciphertexts: Vec<Ciphertext>,
+    info: Vec<DataKind>,
+}
+
+impl CompressedCiphertextListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push<T>(&mut self, data: T) -> &mut Self
+    where
+        T: Compressible,
+    {
+        let kind = data.compress_into(&mut self.ciphertexts);
+        self.info.push(kind);
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compressible,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    /// Returns the number of values pushed so far
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Packs all the pushed values into a [CompressedCiphertextList]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if no value was pushed
+    /// - Panics if a block does not have an empty carry
+    pub fn build(&self, comp_key: &CompressionKey) -> CompressedCiphertextList {
+        let packed_list = comp_key
+            .key
+            .compress_ciphertexts_into_list(&self.ciphertexts);
+
+        CompressedCiphertextList {
+            packed_list,
+            info: self.info.clone(),
+        }
+    }
+}
+
+/// A list of values of heterogeneous types, compressed to be stored in little memory
+///
+/// Values are retrieved, using their index, with [get](Self::get), which requires a
+/// [DecompressionKey].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::ciphertext::{CompressedCiphertextList, CompressedCiphertextListBuilder};
+/// use tfhe::integer::{gen_keys_radix, BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
+/// use tfhe::shortint::parameters::{
+///     COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let private_compression_key = cks
+///     .as_ref()
+///     .new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+/// let (compression_key, decompression_key) = cks
+///     .as_ref()
+///     .new_compression_decompression_keys(&private_compression_key);
+///
+/// let a = sks.add_parallelized(&cks.encrypt(100u8), &cks.encrypt(20u8));
+/// let b = sks.neg_parallelized(&cks.encrypt_signed(3i8));
+/// let c = sks.scalar_gt_parallelized(&a, 110u8);
+///
+/// let compressed = CompressedCiphertextListBuilder::new()
+///     .push(a)
+///     .push(b)
+///     .push(c)
+///     .build(&compression_key);
+/// assert_eq!(compressed.len(), 3);
+///
+/// let a: RadixCiphertext = compressed.get(0, &decompression_key).unwrap().unwrap();
+/// let b: SignedRadixCiphertext = compressed.get(1, &decompression_key).unwrap().unwrap();
+/// let c: BooleanBlock = compressed.get(2, &decompression_key).unwrap().unwrap();
+///
+/// let a: u8 = cks.decrypt(&a);
+/// assert_eq!(a, 120);
+/// let b: i8 = cks.decrypt_signed(&b);
+/// assert_eq!(b, -3);
+/// assert!(cks.decrypt_bool(&c));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    pub(crate) packed_list: crate::shortint::ciphertext::CompressedCiphertextList,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl CompressedCiphertextList {
+    pub fn builder() -> CompressedCiphertextListBuilder {
+        CompressedCiphertextListBuilder::new()
+    }
+
+    /// Returns the number of values stored
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+    }
+
+    /// Returns the kind of the value at the given index, or `None` if the index is out of bounds
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
+    }
+
+    /// Decompresses the value at the given index
+    ///
+    /// This requires one PBS per block of the value.
+    ///
+    /// - Returns `None` if the index is out of bounds
+    /// - Returns `Some(Err(_))` if the value at the index is not of type `T`
+    pub fn get<T>(&self, index: usize, decomp_key: &DecompressionKey) -> Option<crate::Result<T>>
+    where
+        T: Expandable,
+    {
+        let kind = self.get_kind_of(index)?;
+
+        let start_block_index: usize = self.info[..index]
+            .iter()
+            .map(|kind| kind.num_blocks())
+            .sum();
+        let end_block_index = start_block_index + kind.num_blocks();
+
+        if end_block_index > self.packed_list.len() {
+            return Some(Err(crate::Error::new(format!(
+                "The list is malformed, it stores {} blocks but at least {end_block_index} \
+                are needed",
+                self.packed_list.len()
+            ))));
+        }
+
+        let blocks = (start_block_index..end_block_index)
+            .into_par_iter()
+            .map(|i| decomp_key.key.unpack(&self.packed_list, i))
+            .collect::<crate::Result<Vec<_>>>();
+
+        Some(blocks.and_then(|blocks| T::from_expanded_blocks(blocks, kind)))
+    }
+
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::shortint::ciphertext::CompressedCiphertextList,
+        Vec<DataKind>,
+    ) {
+        let Self { packed_list, info } = self;
+        (packed_list, info)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the number of blocks described by `info` does not match the number of blocks
+    /// stored in `packed_list`
+    pub fn from_raw_parts(
+        packed_list: crate::shortint::ciphertext::CompressedCiphertextList,
+        info: Vec<DataKind>,
+    ) -> Self {
+        let expected_num_blocks = info.iter().map(|kind| kind.num_blocks()).sum::<usize>();
+        assert_eq!(
+            expected_num_blocks,
+            packed_list.len(),
+            "CompressedCiphertextList::from_raw_parts: info expects {expected_num_blocks} blocks \
+            but the packed list stores {}",
+            packed_list.len()
+        );
+
+        Self { packed_list, info }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::IntegerKeyKind;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    #[test]
+    fn test_heterogeneous_compressed_ciphertext_list() {
+        let (cks, sks) =
+            KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+
+        let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+        let (compression_key, decompression_key) =
+            cks.new_compression_decompression_keys(&private_compression_key);
+
+        let a = cks.encrypt_radix(u32::MAX, 16);
+        let b = sks.scalar_add_parallelized(&a, 2u32);
+        let c = cks.encrypt_signed_radix(-17i8, 4);
+        let d = sks.scalar_eq_parallelized(&c, -17i8);
+
+        let mut builder = CompressedCiphertextListBuilder::new();
+        builder.push(b).push(c).push(d);
+        assert_eq!(builder.len(), 3);
+        let compressed = builder.build(&compression_key);
+
+        assert_eq!(compressed.len(), 3);
+        assert_eq!(compressed.get_kind_of(0), Some(DataKind::Unsigned(16)));
+        assert_eq!(compressed.get_kind_of(1), Some(DataKind::Signed(4)));
+        assert_eq!(compressed.get_kind_of(2), Some(DataKind::Boolean));
+        assert_eq!(compressed.get_kind_of(3), None);
+
+        let serialized = bincode::serialize(&compressed).unwrap();
+        let compressed: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        let b: RadixCiphertext = compressed.get(0, &decompression_key).unwrap().unwrap();
+        let c: SignedRadixCiphertext = compressed.get(1, &decompression_key).unwrap().unwrap();
+        let d: BooleanBlock = compressed.get(2, &decompression_key).unwrap().unwrap();
+
+        assert_eq!(cks.decrypt_radix::<u32>(&b), 1);
+        assert_eq!(cks.decrypt_signed_radix::<i8>(&c), -17);
+        assert!(cks.decrypt_bool(&d));
+
+        // Decompressed values can be used in computations
+        let e = sks.add_parallelized(&b, &b);
+        assert_eq!(cks.decrypt_radix::<u32>(&e), 2);
+
+        assert!(compressed
+            .get::<RadixCiphertext>(1, &decompression_key)
+            .unwrap()
+            .is_err());
+        assert!(compressed
+            .get::<BooleanBlock>(0, &decompression_key)
+            .unwrap()
+            .is_err());
+        assert!(compressed
+            .get::<RadixCiphertext>(3, &decompression_key)
+            .is_none());
+    }
+}
@@ -87,6 +87,10 @@ pub struct ClientKey {
     pub(crate) key: ShortintClientKey,
 }
 
+impl crate::named::Named for ClientKey {
+    const NAME: &'static str = "integer::ClientKey";
+}
+
 impl From<ShortintClientKey> for ClientKey {
     fn from(key: ShortintClientKey) -> Self {
         Self { key }
@@ -0,0 +1,87 @@
+use super::ClientKey;
+use crate::shortint::list_compression::{
+    CompressionKey as ShortintCompressionKey,
+    CompressionPrivateKeys as ShortintCompressionPrivateKeys,
+    DecompressionKey as ShortintDecompressionKey,
+};
+use crate::shortint::parameters::list_compression::CompressionParameters;
+use serde::{Deserialize, Serialize};
+
+/// The private key used to generate the [CompressionKey] and [DecompressionKey]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    pub(crate) key: ShortintCompressionPrivateKeys,
+}
+
+impl CompressionPrivateKeys {
+    pub fn into_raw_parts(self) -> ShortintCompressionPrivateKeys {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintCompressionPrivateKeys) -> Self {
+        Self { key }
+    }
+}
+
+/// Server key used to build a
+/// [CompressedCiphertextList](crate::integer::ciphertext::CompressedCiphertextList)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    pub(crate) key: ShortintCompressionKey,
+}
+
+impl CompressionKey {
+    pub fn into_raw_parts(self) -> ShortintCompressionKey {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintCompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+/// Server key used to get values back from a
+/// [CompressedCiphertextList](crate::integer::ciphertext::CompressedCiphertextList)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    pub(crate) key: ShortintDecompressionKey,
+}
+
+impl DecompressionKey {
+    pub fn into_raw_parts(self) -> ShortintDecompressionKey {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintDecompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+impl ClientKey {
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        CompressionPrivateKeys {
+            key: self.key.new_compression_private_key(params),
+        }
+    }
+
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let (compression_key, decompression_key) = self
+            .key
+            .new_compression_decompression_keys(&private_compression_key.key);
+
+        (
+            CompressionKey {
+                key: compression_key,
+            },
+            DecompressionKey {
+                key: decompression_key,
+            },
+        )
+    }
+}
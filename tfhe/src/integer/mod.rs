@@ -49,11 +49,13 @@
 pub mod block_decomposition;
 pub(crate) mod encryption;
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
+pub mod backward_compatibility;
 pub mod bigint;
 pub mod ciphertext;
 pub mod client_key;
+pub mod compression_keys;
 pub mod key_switching_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
@@ -71,8 +73,12 @@ mod zk;
 #[cfg(feature = "zk-pok-experimental")]
 pub use zk::ProvenCompactCiphertextList;
 
+pub use bigint::i1024::I1024;
+pub use bigint::i2048::I2048;
 pub use bigint::i256::I256;
 pub use bigint::i512::I512;
+pub use bigint::u1024::U1024;
+pub use bigint::u2048::U2048;
 pub use bigint::u256::U256;
 pub use bigint::u512::U512;
 pub use ciphertext::boolean_value::BooleanBlock;
@@ -1,4 +1,4 @@
-use super::{RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use super::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
@@ -86,6 +86,233 @@ impl ServerKey {
     }
 }
 
+impl ServerKey {
+    /// Generates an encrypted `num_block` blocks unsigned integer
+    /// taken in `[0, excluded_upper_bound[` using the given seed
+    /// The encryted value is oblivious to the server
+    ///
+    /// A uniform value `r` of `k` bits is generated, with `k` the number of bits of
+    /// `excluded_upper_bound` plus at least `extra_random_bits`, and `r * excluded_upper_bound /
+    /// 2^k` is returned. Each value of the range is taken with a probability which differs from
+    /// `1 / excluded_upper_bound` by less than `1 / 2^k`, so the statistical distance to the
+    /// uniform distribution is smaller than `2^-(extra_random_bits + 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `excluded_upper_bound` is 0 or if the range does not fit in `num_blocks` blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let excluded_upper_bound = 6;
+    /// let extra_random_bits = 16;
+    ///
+    /// let ct_res = sks.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+    ///     Seed(0),
+    ///     excluded_upper_bound,
+    ///     extra_random_bits,
+    ///     size as u64,
+    /// );
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < excluded_upper_bound);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+        &self,
+        seed: Seed,
+        excluded_upper_bound: u64,
+        extra_random_bits: u64,
+        num_blocks: u64,
+    ) -> RadixCiphertext {
+        assert!(
+            excluded_upper_bound > 0,
+            "The excluded upper bound of the range must be greater than 0"
+        );
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+        let range_log_size = message_bits_count * num_blocks;
+
+        let bound_bits_count = (u64::BITS - excluded_upper_bound.leading_zeros()) as u64;
+        let max_value_bits_count = (u64::BITS - (excluded_upper_bound - 1).leading_zeros()) as u64;
+        assert!(
+            max_value_bits_count <= range_log_size,
+            "The range asked for a random value (=[0, {excluded_upper_bound}[) does not fit in the available range [0, 2^{range_log_size}[",
+        );
+
+        let bound_blocks_count = bound_bits_count.div_ceil(message_bits_count);
+        let random_blocks_count =
+            (bound_bits_count + extra_random_bits).div_ceil(message_bits_count);
+
+        let mut random = self.par_generate_oblivious_pseudo_random_unsigned_integer(
+            seed,
+            random_blocks_count * message_bits_count,
+            random_blocks_count,
+        );
+        // r * excluded_upper_bound < 2^(k + bound_bits_count)
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(
+            &mut random,
+            bound_blocks_count as usize,
+        );
+
+        let product = self.scalar_mul_parallelized(&random, excluded_upper_bound);
+
+        self.resize_bounded_random(
+            self.trim_radix_blocks_lsb(&product, random_blocks_count as usize),
+            num_blocks,
+        )
+    }
+
+    /// Generates an encrypted unsigned integer taken in `[0, excluded_upper_bound[` using the
+    /// given seed, with the same number of blocks as `excluded_upper_bound`
+    /// The encryted value is oblivious to the server
+    ///
+    /// The statistical distance to the uniform distribution is smaller than
+    /// `2^-(extra_random_bits + 1)`, see
+    /// [Self::par_generate_oblivious_pseudo_random_unsigned_integer_bounded].
+    ///
+    /// If `excluded_upper_bound` encrypts 0, the result is an encryption of 0.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let excluded_upper_bound = 13u64;
+    /// let extra_random_bits = 16;
+    ///
+    /// let ct_bound = cks.encrypt(excluded_upper_bound);
+    ///
+    /// let ct_res = sks.par_generate_oblivious_pseudo_random_unsigned_integer_encrypted_bounded(
+    ///     Seed(0),
+    ///     &ct_bound,
+    ///     extra_random_bits,
+    /// );
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < excluded_upper_bound);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_unsigned_integer_encrypted_bounded(
+        &self,
+        seed: Seed,
+        excluded_upper_bound: &RadixCiphertext,
+        extra_random_bits: u64,
+    ) -> RadixCiphertext {
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+
+        let bound_blocks_count = excluded_upper_bound.blocks.len() as u64;
+        let random_blocks_count = (bound_blocks_count * message_bits_count + extra_random_bits)
+            .div_ceil(message_bits_count);
+        let total_blocks_count = (random_blocks_count + bound_blocks_count) as usize;
+
+        let mut random = self.par_generate_oblivious_pseudo_random_unsigned_integer(
+            seed,
+            random_blocks_count * message_bits_count,
+            random_blocks_count,
+        );
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(
+            &mut random,
+            bound_blocks_count as usize,
+        );
+
+        let mut bound = excluded_upper_bound.clone();
+        let num_bound_blocks = bound.blocks.len();
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(
+            &mut bound,
+            total_blocks_count - num_bound_blocks,
+        );
+
+        let product = self.mul_parallelized(&random, &bound);
+
+        self.trim_radix_blocks_lsb(&product, random_blocks_count as usize)
+    }
+
+    /// Generates an encrypted boolean which is true with probability
+    /// `numerator / denominator` using the given seed
+    /// The encryted value is oblivious to the server
+    ///
+    /// The statistical distance to the requested distribution is smaller than
+    /// `2^-(extra_random_bits + 1)`, see
+    /// [Self::par_generate_oblivious_pseudo_random_unsigned_integer_bounded].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is 0 or if `numerator` is greater than `denominator`
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::Seed;
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// // Always true
+    /// let ct_res = sks.par_generate_oblivious_pseudo_random_boolean(Seed(0), 3, 3, 16);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn par_generate_oblivious_pseudo_random_boolean(
+        &self,
+        seed: Seed,
+        numerator: u64,
+        denominator: u64,
+        extra_random_bits: u64,
+    ) -> BooleanBlock {
+        assert!(
+            numerator <= denominator,
+            "The probability (={numerator}/{denominator}) must not be greater than 1"
+        );
+        assert!(self.message_modulus().0.is_power_of_two());
+        let message_bits_count = self.message_modulus().0.ilog2() as u64;
+        let denominator_bits_count = (u64::BITS - denominator.leading_zeros()) as u64;
+        let num_blocks = denominator_bits_count.div_ceil(message_bits_count).max(1);
+
+        let random = self.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+            seed,
+            denominator,
+            extra_random_bits,
+            num_blocks,
+        );
+
+        self.scalar_lt_parallelized(&random, numerator)
+    }
+
+    /// Fits a random value known to be in the range to `num_blocks` blocks
+    fn resize_bounded_random(&self, mut ct: RadixCiphertext, num_blocks: u64) -> RadixCiphertext {
+        let num_blocks = num_blocks as usize;
+        let current_num_blocks = ct.blocks.len();
+        if current_num_blocks < num_blocks {
+            self.extend_radix_with_trivial_zero_blocks_msb_assign(
+                &mut ct,
+                num_blocks - current_num_blocks,
+            );
+        } else {
+            // The removed blocks encrypt 0 as the value is in the range
+            self.trim_radix_blocks_msb_assign(&mut ct, current_num_blocks - num_blocks);
+        }
+        ct
+    }
+}
+
 // Describes in which range a random signed integer should be generated
 #[derive(Copy, Clone)]
 pub enum SignedRandomizationSpec {
@@ -250,4 +477,79 @@ pub(crate) mod test {
             result as u64
         });
     }
+
+    #[test]
+    fn oprf_bounded_test_uniformity_ci_run_filter() {
+        let sample_count: usize = 2_000;
+
+        let p_value_limit: f64 = 0.001;
+
+        let extra_random_bits = 8;
+
+        let num_blocks = 2;
+
+        use crate::integer::gen_keys_radix;
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        let (ck, sk) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+
+        let test_uniformity = |distinct_values: u64, f: &(dyn Fn(usize) -> u64 + Sync)| {
+            test_uniformity(sample_count, p_value_limit, distinct_values, f)
+        };
+
+        for excluded_upper_bound in [3, 5, 16] {
+            test_uniformity(excluded_upper_bound, &|seed| {
+                let img = sk.par_generate_oblivious_pseudo_random_unsigned_integer_bounded(
+                    Seed(seed as u128),
+                    excluded_upper_bound,
+                    extra_random_bits,
+                    num_blocks as u64,
+                );
+                assert_eq!(img.blocks.len(), num_blocks);
+                ck.decrypt(&img)
+            });
+        }
+
+        let excluded_upper_bound = 11u64;
+        let ct_bound = ck.encrypt(excluded_upper_bound);
+        test_uniformity(excluded_upper_bound, &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_unsigned_integer_encrypted_bounded(
+                Seed(seed as u128),
+                &ct_bound,
+                extra_random_bits,
+            );
+            assert_eq!(img.blocks.len(), num_blocks);
+            ck.decrypt(&img)
+        });
+
+        let ct_zero = ck.encrypt(0u64);
+        let img = sk.par_generate_oblivious_pseudo_random_unsigned_integer_encrypted_bounded(
+            Seed(0),
+            &ct_zero,
+            extra_random_bits,
+        );
+        assert_eq!(ck.decrypt::<u64>(&img), 0);
+
+        test_uniformity(2, &|seed| {
+            let img = sk.par_generate_oblivious_pseudo_random_boolean(Seed(seed as u128), 3, 6, 8);
+            ck.decrypt_bool(&img) as u64
+        });
+
+        for seed in 0..10 {
+            let never = sk.par_generate_oblivious_pseudo_random_boolean(
+                Seed(seed),
+                0,
+                7,
+                extra_random_bits,
+            );
+            assert!(!ck.decrypt_bool(&never));
+
+            let always = sk.par_generate_oblivious_pseudo_random_boolean(
+                Seed(seed),
+                7,
+                7,
+                extra_random_bits,
+            );
+            assert!(ck.decrypt_bool(&always));
+        }
+    }
 }
@@ -188,6 +188,7 @@ impl RadixCiphertextConformanceParams {
 /// before running a computation on them
 pub struct RadixCompactCiphertextListConformanceParams {
     pub shortint_params: CiphertextConformanceParams,
+    /// Maximum number of blocks of each integer of the list, booleans always have one block
     pub num_blocks_per_integer: usize,
     pub num_integers_constraint: ListSizeConstraint,
 }
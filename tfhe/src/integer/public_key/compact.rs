@@ -1,6 +1,6 @@
 use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::{CompactCiphertextList, RadixCiphertext};
+use crate::integer::ciphertext::{CompactCiphertextList, DataKind, RadixCiphertext};
 use crate::integer::encryption::{create_clear_radix_block_iterator, encrypt_words_radix_impl};
 use crate::integer::{ClientKey, SignedRadixCiphertext};
 use crate::shortint::{
@@ -73,7 +73,7 @@ impl CompactPublicKey {
         let ct_list = self.key.encrypt_iter(clear_block_iter);
         CompactCiphertextList {
             ct_list,
-            num_blocks_per_integer,
+            info: vec![DataKind::Unsigned(num_blocks_per_integer)],
         }
     }
 
@@ -91,6 +91,7 @@ impl CompactPublicKey {
         num_blocks_per_integer: usize,
     ) -> CompactCiphertextList {
         let mut iterator_chain;
+        let mut num_integers = 2;
         match (message_iter.next(), message_iter.next()) {
             (None, None) => panic!("At least one message is required"),
             (None, Some(_)) => unreachable!(),
@@ -123,12 +124,13 @@ impl CompactPublicKey {
             );
 
             iterator_chain = Box::new(iterator_chain.chain(other_iter));
+            num_integers += 1;
         }
 
         let ct_list = self.key.encrypt_iter(iterator_chain);
         CompactCiphertextList {
             ct_list,
-            num_blocks_per_integer,
+            info: vec![DataKind::Unsigned(num_blocks_per_integer); num_integers],
         }
     }
 
@@ -0,0 +1,75 @@
+use crate::integer::{BooleanBlock, CrtCiphertext, ServerKey};
+
+impl ServerKey {
+    /// FHE "if then else" selection on CRT ciphertexts.
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. See [Self::crt_if_then_else_parallelized].
+    pub fn unchecked_crt_if_then_else_parallelized(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &CrtCiphertext,
+        false_ct: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (radix_true, radix_false) = self.crt_pair_to_radix_parallelized(true_ct, false_ct);
+        let result = self.unchecked_if_then_else_parallelized(condition, &radix_true, &radix_false);
+        self.radix_to_crt_parallelized(&result, &true_ct.moduli)
+    }
+
+    /// FHE "if then else" selection on CRT ciphertexts.
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// See [Self::crt_if_then_else_parallelized].
+    pub fn smart_crt_if_then_else_parallelized(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &mut CrtCiphertext,
+        false_ct: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_pair_clean_carries_assign_parallelized(true_ct, false_ct);
+        self.unchecked_crt_if_then_else_parallelized(condition, true_ct, false_ct)
+    }
+
+    /// FHE "if then else" selection on CRT ciphertexts.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// The CRT blocks do not leave room to pack the condition with them, so the selection is
+    /// done on the radix representation of the values. The server key must be a radix server
+    /// key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    ///
+    /// let ct1 = cks.encrypt_crt(14, basis.clone());
+    /// let ct2 = cks.encrypt_crt(86, basis);
+    ///
+    /// let condition = sks.crt_gt_parallelized(&ct1, &ct2);
+    /// let ct_res = sks.crt_if_then_else_parallelized(&condition, &ct1, &ct2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, 86);
+    /// ```
+    pub fn crt_if_then_else_parallelized(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &CrtCiphertext,
+        false_ct: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (true_ct, false_ct) = self.crt_pair_with_clean_carries_parallelized(true_ct, false_ct);
+        self.unchecked_crt_if_then_else_parallelized(condition, &true_ct, &false_ct)
+    }
+}
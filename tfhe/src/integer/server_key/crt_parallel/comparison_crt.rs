@@ -0,0 +1,419 @@
+use crate::integer::{BooleanBlock, CrtCiphertext, RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Converts both CRT ciphertexts to radix ciphertexts large enough to hold their values
+    ///
+    /// The carries of the CRT blocks are taken into account by the conversion, and the radix
+    /// ciphertexts have empty carries.
+    pub(crate) fn crt_pair_to_radix_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Both CRT ciphertexts must use the same basis"
+        );
+        let num_blocks = self.crt_to_radix_num_blocks(&lhs.moduli);
+        rayon::join(
+            || self.crt_to_radix_parallelized(lhs, num_blocks),
+            || self.crt_to_radix_parallelized(rhs, num_blocks),
+        )
+    }
+
+    /// Compares for equality two CRT ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_eq_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        self.unchecked_eq_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares for equality two CRT ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_eq_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> BooleanBlock {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_eq_parallelized(lhs, rhs)
+    }
+
+    /// Compares for equality two CRT ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// The server key must be a radix server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    ///
+    /// let ct1 = cks.encrypt_crt(86, basis.clone());
+    /// let ct2 = cks.encrypt_crt(86, basis);
+    ///
+    /// let ct_res = sks.crt_eq_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_bool(&ct_res);
+    /// assert!(res);
+    /// ```
+    pub fn crt_eq_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_eq_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares for inequality two CRT ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_ne_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        self.unchecked_ne_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares for inequality two CRT ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_ne_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> BooleanBlock {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_ne_parallelized(lhs, rhs)
+    }
+
+    /// Compares for inequality two CRT ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// The server key must be a radix server key.
+    pub fn crt_ne_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_ne_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// The values are compared as integers in `[0, M)`, where `M` is the product of the moduli.
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_lt_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        self.unchecked_lt_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_lt_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> BooleanBlock {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_lt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// The values are compared as integers in `[0, M)`, where `M` is the product of the moduli.
+    /// The server key must be a radix server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    ///
+    /// let ct1 = cks.encrypt_crt(14, basis.clone());
+    /// let ct2 = cks.encrypt_crt(86, basis);
+    ///
+    /// let ct_res = sks.crt_lt_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_bool(&ct_res);
+    /// assert!(res);
+    /// ```
+    pub fn crt_lt_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_lt_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_le_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        self.unchecked_le_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_le_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> BooleanBlock {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_le_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower or equal than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// The server key must be a radix server key.
+    pub fn crt_le_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_le_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_gt_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        self.unchecked_gt_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_gt_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> BooleanBlock {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_gt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// The server key must be a radix server key.
+    pub fn crt_gt_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_gt_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_ge_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        self.unchecked_ge_parallelized(&lhs, &rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_ge_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> BooleanBlock {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_ge_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater or equal than rhs
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// The server key must be a radix server key.
+    pub fn crt_ge_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> BooleanBlock {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_ge_parallelized(&lhs, &rhs)
+    }
+
+    /// Computes the minimum of two CRT ciphertexts
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_min_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (radix_lhs, radix_rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        let min = self.unchecked_min_parallelized(&radix_lhs, &radix_rhs);
+        self.radix_to_crt_parallelized(&min, &lhs.moduli)
+    }
+
+    /// Computes the minimum of two CRT ciphertexts
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_min_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_min_parallelized(lhs, rhs)
+    }
+
+    /// Computes the minimum of two CRT ciphertexts
+    ///
+    /// The server key must be a radix server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    ///
+    /// let ct1 = cks.encrypt_crt(14, basis.clone());
+    /// let ct2 = cks.encrypt_crt(86, basis);
+    ///
+    /// let ct_res = sks.crt_min_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, 14);
+    /// ```
+    pub fn crt_min_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_min_parallelized(&lhs, &rhs)
+    }
+
+    /// Computes the maximum of two CRT ciphertexts
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. The server key must be a radix server key.
+    pub fn unchecked_crt_max_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (radix_lhs, radix_rhs) = self.crt_pair_to_radix_parallelized(lhs, rhs);
+        let max = self.unchecked_max_parallelized(&radix_lhs, &radix_rhs);
+        self.radix_to_crt_parallelized(&max, &lhs.moduli)
+    }
+
+    /// Computes the maximum of two CRT ciphertexts
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// The server key must be a radix server key.
+    pub fn smart_crt_max_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_pair_clean_carries_assign_parallelized(lhs, rhs);
+        self.unchecked_crt_max_parallelized(lhs, rhs)
+    }
+
+    /// Computes the maximum of two CRT ciphertexts
+    ///
+    /// The server key must be a radix server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    ///
+    /// let ct1 = cks.encrypt_crt(14, basis.clone());
+    /// let ct2 = cks.encrypt_crt(86, basis);
+    ///
+    /// let ct_res = sks.crt_max_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, 86);
+    /// ```
+    pub fn crt_max_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        let (lhs, rhs) = self.crt_pair_with_clean_carries_parallelized(lhs, rhs);
+        self.unchecked_crt_max_parallelized(&lhs, &rhs)
+    }
+}
@@ -0,0 +1,255 @@
+use crate::integer::client_key::utils::extended_euclid;
+use crate::integer::{CrtCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::{CarryModulus, Ciphertext, MessageModulus};
+use rayon::prelude::*;
+use std::borrow::Cow;
+
+/// Returns the product `M` of the moduli
+///
+/// # Panics
+///
+/// Panics if the product does not fit in a u64.
+fn crt_modulus(moduli: &[u64]) -> u64 {
+    moduli
+        .iter()
+        .try_fold(1u64, |product, &modulus| product.checked_mul(modulus))
+        .expect("The product of the CRT moduli must fit in 64 bits")
+}
+
+/// Returns the number of bits needed to represent `value`
+fn num_bits(value: u128) -> u32 {
+    value.checked_ilog2().map_or(1, |log| log + 1)
+}
+
+/// Returns the coefficients `c_i` of the inverse CRT, such that `x = sum_i x_i * c_i mod M`
+/// where `x_i = x mod b_i` and `M` is the product of the moduli `b_i`.
+fn inverse_crt_coefficients(moduli: &[u64]) -> Vec<u64> {
+    let big_mod = u128::from(crt_modulus(moduli));
+
+    moduli
+        .iter()
+        .map(|&modulus| {
+            let tmp_mod = big_mod / u128::from(modulus);
+            let remainder = (tmp_mod % u128::from(modulus)) as i64;
+            let (l, _, s, _, _) = extended_euclid(remainder, modulus as i64);
+            let inverse = s[l].rem_euclid(modulus as i64) as u64;
+            // tmp_mod * inverse < tmp_mod * modulus == M
+            ((tmp_mod * u128::from(inverse)) % big_mod) as u64
+        })
+        .collect()
+}
+
+impl ServerKey {
+    /// Returns the number of radix blocks needed to represent any value encrypted by a CRT
+    /// ciphertext with the given moduli.
+    pub(crate) fn crt_to_radix_num_blocks(&self, moduli: &[u64]) -> usize {
+        let big_mod = crt_modulus(moduli);
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        num_bits(u128::from(big_mod - 1)).div_ceil(bits_per_block) as usize
+    }
+
+    /// Clears the carries of both CRT ciphertexts, if they hold some
+    pub(crate) fn crt_pair_clean_carries_assign_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) {
+        let clean_carries = |ct: &mut CrtCiphertext| {
+            if !ct.block_carries_are_empty() {
+                self.full_extract_message_assign_parallelized(ct);
+            }
+        };
+        rayon::join(|| clean_carries(lhs), || clean_carries(rhs));
+    }
+
+    /// Returns both CRT ciphertexts with empty carries, copying the ones which hold carries
+    pub(crate) fn crt_pair_with_clean_carries_parallelized<'a>(
+        &self,
+        lhs: &'a CrtCiphertext,
+        rhs: &'a CrtCiphertext,
+    ) -> (Cow<'a, CrtCiphertext>, Cow<'a, CrtCiphertext>) {
+        let clean_carries = |ct: &'a CrtCiphertext| {
+            if ct.block_carries_are_empty() {
+                Cow::Borrowed(ct)
+            } else {
+                let mut ct = ct.clone();
+                self.full_extract_message_assign_parallelized(&mut ct);
+                Cow::Owned(ct)
+            }
+        };
+        rayon::join(|| clean_carries(lhs), || clean_carries(rhs))
+    }
+
+    /// Converts a CRT ciphertext into a radix ciphertext of `num_blocks` blocks encrypting the
+    /// same value.
+    ///
+    /// If `num_blocks` is not enough to represent the value, it is reduced modulo the radix
+    /// modulus, as done by [Self::cast_to_unsigned].
+    ///
+    /// The server key must be a radix server key, as radix operations are used on the result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    /// let clear = 86u64;
+    ///
+    /// let ct = cks.encrypt_crt(clear, basis);
+    ///
+    /// // 4 blocks of 2 bits are enough to represent values up to 3 * 5 * 7 - 1
+    /// let ct_res = sks.crt_to_radix_parallelized(&ct, 4);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn crt_to_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let big_mod = crt_modulus(&ct.moduli);
+        let message_modulus = self.key.message_modulus.0 as u128;
+
+        // The terms are each below M, so their sum needs room for a few more bits
+        let term_num_blocks = self.crt_to_radix_num_blocks(&ct.moduli);
+        let sum_num_blocks = {
+            let max_sum = u128::from(big_mod - 1) * ct.moduli.len() as u128;
+            let num_bits = num_bits(max_sum);
+            num_bits.div_ceil(message_modulus.ilog2()) as usize
+        };
+
+        let coefficients = inverse_crt_coefficients(&ct.moduli);
+
+        // Each CRT block x_i is turned into the radix decomposition of x_i * c_i mod M
+        let terms: Vec<RadixCiphertext> = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter().zip(coefficients.par_iter()))
+            .map(|(block, (&modulus, &coefficient))| {
+                let mut blocks: Vec<Ciphertext> = (0..term_num_blocks)
+                    .into_par_iter()
+                    .map(|block_index| {
+                        let lut = self.key.generate_lookup_table(|x| {
+                            let term = (u128::from(x % modulus) * u128::from(coefficient))
+                                % u128::from(big_mod);
+                            ((term / message_modulus.pow(block_index as u32)) % message_modulus)
+                                as u64
+                        });
+                        let mut radix_block = self.key.apply_lookup_table(block, &lut);
+                        radix_block.message_modulus = self.key.message_modulus;
+                        radix_block.carry_modulus = self.key.carry_modulus;
+                        radix_block
+                    })
+                    .collect();
+                blocks.resize_with(sum_num_blocks, || self.key.create_trivial(0));
+                RadixCiphertext::from(blocks)
+            })
+            .collect();
+
+        let result = if terms.len() == 1 {
+            terms.into_iter().next().unwrap()
+        } else {
+            let sum = self
+                .unchecked_sum_ciphertexts_vec_parallelized(terms)
+                .expect("CRT ciphertexts have at least one block");
+            self.scalar_rem_parallelized(&sum, big_mod)
+        };
+
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Converts a radix ciphertext into a CRT ciphertext with the given basis.
+    ///
+    /// The value is reduced modulo the product of the moduli of the basis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum of two values below a modulus of the basis does not fit in a block, i.e.
+    /// if `2 * (modulus - 1)` is greater than the maximum degree of the blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = [3, 5, 7];
+    /// let clear = 200u64;
+    ///
+    /// let ct = cks.encrypt_radix(clear, 4);
+    ///
+    /// let ct_res = sks.radix_to_crt_parallelized(&ct, &basis);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear % (3 * 5 * 7));
+    /// ```
+    pub fn radix_to_crt_parallelized(&self, ct: &RadixCiphertext, basis: &[u64]) -> CrtCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        let max_degree = self.key.max_degree.get();
+        let max_noise_level = self.key.max_noise_level.get();
+
+        let blocks = basis
+            .par_iter()
+            .map(|&modulus| {
+                // The blocks are summed after being reduced, so the sum of two reduced blocks
+                // must fit in a block
+                assert!(
+                    2 * (modulus as usize - 1) <= max_degree,
+                    "CRT modulus {modulus} is too big for the sum of two values reduced modulo \
+                    {modulus} to fit in a block of {total_modulus} values"
+                );
+
+                // The weight of the block j is message_modulus^j mod b
+                let weights: Vec<u64> = std::iter::successors(Some(1 % modulus), |weight| {
+                    Some((weight * message_modulus) % modulus)
+                })
+                .take(ct.blocks.len())
+                .collect();
+
+                let terms: Vec<Ciphertext> = ct
+                    .blocks
+                    .par_iter()
+                    .zip(weights.par_iter())
+                    .map(|(block, &weight)| {
+                        // The carries are part of the value of the block, so x is used as a whole
+                        let lut = self.key.generate_lookup_table(|x| (x * weight) % modulus);
+                        self.key.apply_lookup_table(block, &lut)
+                    })
+                    .collect();
+
+                let reduce_lut = self.key.generate_lookup_table(|x| x % modulus);
+                let mut terms = terms.into_iter();
+                let mut sum = terms.next().unwrap_or_else(|| self.key.create_trivial(0));
+                for term in terms {
+                    if sum.degree.get() + term.degree.get() > max_degree
+                        || sum.noise_level().get() + term.noise_level().get() > max_noise_level
+                    {
+                        self.key.apply_lookup_table_assign(&mut sum, &reduce_lut);
+                    }
+                    self.key.unchecked_add_assign(&mut sum, &term);
+                }
+                if sum.degree.get() >= modulus as usize {
+                    self.key.apply_lookup_table_assign(&mut sum, &reduce_lut);
+                }
+
+                sum.message_modulus = MessageModulus(modulus as usize);
+                sum.carry_modulus = CarryModulus(total_modulus / modulus as usize);
+                sum
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, basis.to_vec()))
+    }
+}
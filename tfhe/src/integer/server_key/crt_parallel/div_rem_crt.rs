@@ -0,0 +1,158 @@
+use crate::integer::{CrtCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Computes homomorphically the quotient and remainder of the euclidean division of two
+    /// CRT ciphertexts.
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. See [Self::crt_div_rem_parallelized].
+    pub fn unchecked_crt_div_rem_parallelized(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        let (radix_numerator, radix_divisor) =
+            self.crt_pair_to_radix_parallelized(numerator, divisor);
+        let (quotient, remainder) =
+            self.unchecked_div_rem_parallelized(&radix_numerator, &radix_divisor);
+        rayon::join(
+            || self.radix_to_crt_parallelized(&quotient, &numerator.moduli),
+            || self.radix_to_crt_parallelized(&remainder, &numerator.moduli),
+        )
+    }
+
+    /// Computes homomorphically the quotient and remainder of the euclidean division of two
+    /// CRT ciphertexts.
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// See [Self::crt_div_rem_parallelized].
+    pub fn smart_crt_div_rem_parallelized(
+        &self,
+        numerator: &mut CrtCiphertext,
+        divisor: &mut CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        self.crt_pair_clean_carries_assign_parallelized(numerator, divisor);
+        self.unchecked_crt_div_rem_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the euclidean division of two
+    /// CRT ciphertexts.
+    ///
+    /// The values are divided as integers in `[0, M)`, where `M` is the product of the moduli.
+    /// The server key must be a radix server key.
+    ///
+    /// If the divisor is 0, the remainder is the numerator and the quotient is the greatest
+    /// value of the radix representation used for the division, reduced modulo `M`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    ///
+    /// let basis = vec![3, 5, 7];
+    ///
+    /// let ct1 = cks.encrypt_crt(86, basis.clone());
+    /// let ct2 = cks.encrypt_crt(9, basis);
+    ///
+    /// let (q, r) = sks.crt_div_rem_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(cks.decrypt_crt(&q), 86 / 9);
+    /// assert_eq!(cks.decrypt_crt(&r), 86 % 9);
+    /// ```
+    pub fn crt_div_rem_parallelized(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        let (numerator, divisor) =
+            self.crt_pair_with_clean_carries_parallelized(numerator, divisor);
+        self.unchecked_crt_div_rem_parallelized(&numerator, &divisor)
+    }
+
+    /// Computes homomorphically the quotient of the euclidean division of two CRT ciphertexts.
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. See [Self::crt_div_rem_parallelized].
+    pub fn unchecked_crt_div_parallelized(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (radix_numerator, radix_divisor) =
+            self.crt_pair_to_radix_parallelized(numerator, divisor);
+        let quotient = self.unchecked_div_parallelized(&radix_numerator, &radix_divisor);
+        self.radix_to_crt_parallelized(&quotient, &numerator.moduli)
+    }
+
+    /// Computes homomorphically the quotient of the euclidean division of two CRT ciphertexts.
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// See [Self::crt_div_rem_parallelized].
+    pub fn smart_crt_div_parallelized(
+        &self,
+        numerator: &mut CrtCiphertext,
+        divisor: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_pair_clean_carries_assign_parallelized(numerator, divisor);
+        self.unchecked_crt_div_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient of the euclidean division of two CRT ciphertexts.
+    ///
+    /// See [Self::crt_div_rem_parallelized].
+    pub fn crt_div_parallelized(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (numerator, divisor) =
+            self.crt_pair_with_clean_carries_parallelized(numerator, divisor);
+        self.unchecked_crt_div_parallelized(&numerator, &divisor)
+    }
+
+    /// Computes homomorphically the remainder of the euclidean division of two CRT ciphertexts.
+    ///
+    /// The carries of the blocks are not cleared, the values being converted to radix as they
+    /// are. See [Self::crt_div_rem_parallelized].
+    pub fn unchecked_crt_rem_parallelized(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (radix_numerator, radix_divisor) =
+            self.crt_pair_to_radix_parallelized(numerator, divisor);
+        let remainder = self.unchecked_rem_parallelized(&radix_numerator, &radix_divisor);
+        self.radix_to_crt_parallelized(&remainder, &numerator.moduli)
+    }
+
+    /// Computes homomorphically the remainder of the euclidean division of two CRT ciphertexts.
+    ///
+    /// The carries of the inputs are cleared first if they are not empty.
+    /// See [Self::crt_div_rem_parallelized].
+    pub fn smart_crt_rem_parallelized(
+        &self,
+        numerator: &mut CrtCiphertext,
+        divisor: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_pair_clean_carries_assign_parallelized(numerator, divisor);
+        self.unchecked_crt_rem_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the remainder of the euclidean division of two CRT ciphertexts.
+    ///
+    /// See [Self::crt_div_rem_parallelized].
+    pub fn crt_rem_parallelized(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (numerator, divisor) =
+            self.crt_pair_with_clean_carries_parallelized(numerator, divisor);
+        self.unchecked_crt_rem_parallelized(&numerator, &divisor)
+    }
+}
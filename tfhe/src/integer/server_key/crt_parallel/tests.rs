@@ -1,5 +1,6 @@
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::IntegerKeyKind;
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{BooleanBlock, ClientKey, CrtCiphertext, IntegerKeyKind, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::*;
@@ -12,6 +13,12 @@ const NB_TESTS: usize = 30;
 // instrumentation
 #[cfg(tarpaulin)]
 const NB_TESTS: usize = 1;
+/// Smaller number of loop iteration within randomized test,
+/// meant for test where the function tested is more expensive
+#[cfg(not(tarpaulin))]
+const NB_TESTS_SMALLER: usize = 10;
+#[cfg(tarpaulin)]
+const NB_TESTS_SMALLER: usize = 1;
 
 #[cfg(not(tarpaulin))]
 const PARAM: ClassicPBSParameters = PARAM_MESSAGE_5_CARRY_1_KS_PBS;
@@ -206,3 +213,535 @@ fn integer_unchecked_crt_scalar_sub_parallelized_32_bits() {
         );
     }
 }
+
+/// Returns the CRT bases used to test the parameters, whose moduli are small enough for the sum
+/// of two values below a modulus to fit in the blocks of the parameters
+///
+/// The bases have different numbers of moduli, as the conversion to radix sums a term per
+/// modulus.
+fn crt_bases(param: impl Into<PBSParameters>) -> Vec<Vec<u64>> {
+    let param = param.into();
+    let message_modulus = param.message_modulus().0 as u64;
+    if message_modulus == 2 {
+        // With 1 bit of carry, 1 + 1 is the biggest sum that fits in a block
+        vec![vec![2]]
+    } else {
+        vec![
+            vec![message_modulus - 1, message_modulus],
+            vec![message_modulus - 1, message_modulus, message_modulus + 1],
+        ]
+    }
+}
+
+/// Which variant of a CRT operation is tested
+#[derive(Copy, Clone)]
+enum Variant {
+    Unchecked,
+    Smart,
+    Default,
+}
+
+impl Variant {
+    /// Encrypts `clear`, in a ciphertext which holds carries for the variants which clear them
+    fn encrypt(self, cks: &ClientKey, sks: &ServerKey, clear: u64, basis: &[u64]) -> CrtCiphertext {
+        match self {
+            Self::Unchecked => cks.encrypt_crt(clear, basis.to_vec()),
+            Self::Smart | Self::Default => {
+                let modulus = basis.iter().product::<u64>();
+                let ct = cks.encrypt_crt((clear + modulus - 1) % modulus, basis.to_vec());
+                let ct = sks.unchecked_crt_scalar_add_parallelized(&ct, 1);
+                assert!(!ct.block_carries_are_empty());
+                ct
+            }
+        }
+    }
+}
+
+type CrtOp<R> = fn(&ServerKey, &CrtCiphertext, &CrtCiphertext) -> R;
+type SmartCrtOp<R> = fn(&ServerKey, &mut CrtCiphertext, &mut CrtCiphertext) -> R;
+/// The unchecked, smart and default variants of an operation
+type CrtOpVariants<R> = (CrtOp<R>, SmartCrtOp<R>, CrtOp<R>);
+/// The variants of a comparison, along with the clear comparison
+type CrtComparisonCase = (
+    CrtOp<BooleanBlock>,
+    SmartCrtOp<BooleanBlock>,
+    CrtOp<BooleanBlock>,
+    fn(&u64, &u64) -> bool,
+);
+/// The variants of a binary operation, along with the clear operation
+type CrtBinaryOpCase = (
+    CrtOp<CrtCiphertext>,
+    SmartCrtOp<CrtCiphertext>,
+    CrtOp<CrtCiphertext>,
+    fn(u64, u64) -> u64,
+);
+
+/// Runs a binary operation in the given variant
+fn run_binary_op<R>(
+    variant: Variant,
+    sks: &ServerKey,
+    lhs: &mut CrtCiphertext,
+    rhs: &mut CrtCiphertext,
+    ops: CrtOpVariants<R>,
+) -> R {
+    let (unchecked, smart, default) = ops;
+    match variant {
+        Variant::Unchecked => unchecked(sks, lhs, rhs),
+        Variant::Smart => {
+            let result = smart(sks, lhs, rhs);
+            assert!(lhs.block_carries_are_empty());
+            assert!(rhs.block_carries_are_empty());
+            result
+        }
+        Variant::Default => default(sks, lhs, rhs),
+    }
+}
+
+create_parametrized_test!(integer_crt_radix_conversion);
+// The radix comparisons, on which these operations rely, do not support 1 bit blocks
+create_parametrized_test!(integer_unchecked_crt_comparisons {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_smart_crt_comparisons {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_default_crt_comparisons {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_unchecked_crt_min_max {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_smart_crt_min_max {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_default_crt_min_max {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_unchecked_crt_div_rem {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_smart_crt_div_rem {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_default_crt_div_rem {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    #[cfg(tarpaulin)]
+    COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
+create_parametrized_test!(integer_unchecked_crt_if_then_else);
+create_parametrized_test!(integer_smart_crt_if_then_else);
+create_parametrized_test!(integer_default_crt_if_then_else);
+
+fn integer_crt_radix_conversion<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    for basis in crt_bases(param) {
+        let modulus = basis.iter().product::<u64>();
+        let num_blocks = sks.crt_to_radix_num_blocks(&basis);
+        let radix_modulus = (cks.parameters().message_modulus().0 as u64).pow(num_blocks as u32);
+
+        for _ in 0..NB_TESTS_SMALLER {
+            let clear = rng.gen::<u64>() % modulus;
+
+            let ct = cks.encrypt_crt(clear, basis.clone());
+            let ct_res = sks.crt_to_radix_parallelized(&ct, num_blocks);
+            assert_eq!(ct_res.blocks.len(), num_blocks);
+            let dec_res: u64 = cks.decrypt_radix(&ct_res);
+            assert_eq!(clear, dec_res);
+
+            // Only keep the least significant block
+            let ct_res = sks.crt_to_radix_parallelized(&ct, 1);
+            assert_eq!(ct_res.blocks.len(), 1);
+            let dec_res: u64 = cks.decrypt_radix(&ct_res);
+            assert_eq!(clear % cks.parameters().message_modulus().0 as u64, dec_res);
+
+            // The carries of the blocks are part of their value
+            let ct = Variant::Smart.encrypt(&cks, &sks, clear, &basis);
+            let ct_res = sks.crt_to_radix_parallelized(&ct, num_blocks);
+            let dec_res: u64 = cks.decrypt_radix(&ct_res);
+            assert_eq!(clear, dec_res);
+
+            let clear = rng.gen::<u64>() % radix_modulus;
+            let ct = cks.encrypt_radix(clear, num_blocks);
+            let ct_res = sks.radix_to_crt_parallelized(&ct, &basis);
+            assert_eq!(ct_res.moduli, basis);
+            let dec_res = cks.decrypt_crt(&ct_res);
+            assert_eq!(clear % modulus, dec_res);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "CRT modulus 11 is too big")]
+fn integer_radix_to_crt_modulus_too_big() {
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+
+    // 11 values fit in a block, but 10 + 10 does not
+    let ct = cks.encrypt_radix(200u64, 4);
+    let _ = sks.radix_to_crt_parallelized(&ct, &[3, 11]);
+}
+
+fn crt_comparisons_test<P>(param: P, variant: Variant)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    let comparisons: [CrtComparisonCase; 6] = [
+        (
+            ServerKey::unchecked_crt_eq_parallelized,
+            ServerKey::smart_crt_eq_parallelized,
+            ServerKey::crt_eq_parallelized,
+            u64::eq,
+        ),
+        (
+            ServerKey::unchecked_crt_ne_parallelized,
+            ServerKey::smart_crt_ne_parallelized,
+            ServerKey::crt_ne_parallelized,
+            u64::ne,
+        ),
+        (
+            ServerKey::unchecked_crt_lt_parallelized,
+            ServerKey::smart_crt_lt_parallelized,
+            ServerKey::crt_lt_parallelized,
+            u64::lt,
+        ),
+        (
+            ServerKey::unchecked_crt_le_parallelized,
+            ServerKey::smart_crt_le_parallelized,
+            ServerKey::crt_le_parallelized,
+            u64::le,
+        ),
+        (
+            ServerKey::unchecked_crt_gt_parallelized,
+            ServerKey::smart_crt_gt_parallelized,
+            ServerKey::crt_gt_parallelized,
+            u64::gt,
+        ),
+        (
+            ServerKey::unchecked_crt_ge_parallelized,
+            ServerKey::smart_crt_ge_parallelized,
+            ServerKey::crt_ge_parallelized,
+            u64::ge,
+        ),
+    ];
+
+    for basis in crt_bases(param) {
+        let modulus = basis.iter().product::<u64>();
+
+        for _ in 0..NB_TESTS_SMALLER {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = if rng.gen_bool(0.25) {
+                clear_0
+            } else {
+                rng.gen::<u64>() % modulus
+            };
+
+            for (unchecked, smart, default, clear_op) in comparisons {
+                let mut ct_0 = variant.encrypt(&cks, &sks, clear_0, &basis);
+                let mut ct_1 = variant.encrypt(&cks, &sks, clear_1, &basis);
+
+                let ct_res = run_binary_op(
+                    variant,
+                    &sks,
+                    &mut ct_0,
+                    &mut ct_1,
+                    (unchecked, smart, default),
+                );
+                assert_eq!(cks.decrypt_bool(&ct_res), clear_op(&clear_0, &clear_1));
+            }
+        }
+    }
+}
+
+fn integer_unchecked_crt_comparisons<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_comparisons_test(param, Variant::Unchecked);
+}
+
+fn integer_smart_crt_comparisons<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_comparisons_test(param, Variant::Smart);
+}
+
+fn integer_default_crt_comparisons<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_comparisons_test(param, Variant::Default);
+}
+
+/// Tests binary operations whose result is a CRT ciphertext, `clear_op` returning the expected
+/// result from clear values in `[0, M)`
+fn crt_binary_op_test<P>(param: P, variant: Variant, ops: &[CrtBinaryOpCase])
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    for basis in crt_bases(param) {
+        let modulus = basis.iter().product::<u64>();
+
+        for _ in 0..NB_TESTS_SMALLER {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = rng.gen::<u64>() % modulus;
+
+            for &(unchecked, smart, default, clear_op) in ops {
+                let mut ct_0 = variant.encrypt(&cks, &sks, clear_0, &basis);
+                let mut ct_1 = variant.encrypt(&cks, &sks, clear_1, &basis);
+
+                let ct_res = run_binary_op(
+                    variant,
+                    &sks,
+                    &mut ct_0,
+                    &mut ct_1,
+                    (unchecked, smart, default),
+                );
+                assert_eq!(ct_res.moduli, basis);
+                assert_eq!(
+                    cks.decrypt_crt(&ct_res),
+                    clear_op(clear_0, clear_1) % modulus
+                );
+            }
+        }
+    }
+}
+
+fn crt_min_max_test<P>(param: P, variant: Variant)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_binary_op_test(
+        param,
+        variant,
+        &[
+            (
+                ServerKey::unchecked_crt_min_parallelized,
+                ServerKey::smart_crt_min_parallelized,
+                ServerKey::crt_min_parallelized,
+                u64::min,
+            ),
+            (
+                ServerKey::unchecked_crt_max_parallelized,
+                ServerKey::smart_crt_max_parallelized,
+                ServerKey::crt_max_parallelized,
+                u64::max,
+            ),
+        ],
+    );
+}
+
+fn integer_unchecked_crt_min_max<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_min_max_test(param, Variant::Unchecked);
+}
+
+fn integer_smart_crt_min_max<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_min_max_test(param, Variant::Smart);
+}
+
+fn integer_default_crt_min_max<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_min_max_test(param, Variant::Default);
+}
+
+fn crt_div_rem_test<P>(param: P, variant: Variant)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    for basis in crt_bases(param) {
+        let modulus = basis.iter().product::<u64>();
+
+        // When dividing by 0, the quotient is the greatest value of the radix representation
+        let num_blocks = sks.crt_to_radix_num_blocks(&basis);
+        let radix_max = (cks.parameters().message_modulus().0 as u64).pow(num_blocks as u32) - 1;
+
+        for i in 0..NB_TESTS_SMALLER {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = if i == 0 { 0 } else { rng.gen_range(1..modulus) };
+            let (expected_q, expected_r) = if clear_1 == 0 {
+                (radix_max % modulus, clear_0)
+            } else {
+                (clear_0 / clear_1, clear_0 % clear_1)
+            };
+
+            let mut ct_0 = variant.encrypt(&cks, &sks, clear_0, &basis);
+            let mut ct_1 = variant.encrypt(&cks, &sks, clear_1, &basis);
+            let (q_res, r_res) = run_binary_op(
+                variant,
+                &sks,
+                &mut ct_0,
+                &mut ct_1,
+                (
+                    ServerKey::unchecked_crt_div_rem_parallelized,
+                    ServerKey::smart_crt_div_rem_parallelized,
+                    ServerKey::crt_div_rem_parallelized,
+                ),
+            );
+            assert_eq!(cks.decrypt_crt(&q_res), expected_q);
+            assert_eq!(cks.decrypt_crt(&r_res), expected_r);
+
+            let mut ct_0 = variant.encrypt(&cks, &sks, clear_0, &basis);
+            let mut ct_1 = variant.encrypt(&cks, &sks, clear_1, &basis);
+            let q_res = run_binary_op(
+                variant,
+                &sks,
+                &mut ct_0,
+                &mut ct_1,
+                (
+                    ServerKey::unchecked_crt_div_parallelized,
+                    ServerKey::smart_crt_div_parallelized,
+                    ServerKey::crt_div_parallelized,
+                ),
+            );
+            assert_eq!(cks.decrypt_crt(&q_res), expected_q);
+
+            let mut ct_0 = variant.encrypt(&cks, &sks, clear_0, &basis);
+            let mut ct_1 = variant.encrypt(&cks, &sks, clear_1, &basis);
+            let r_res = run_binary_op(
+                variant,
+                &sks,
+                &mut ct_0,
+                &mut ct_1,
+                (
+                    ServerKey::unchecked_crt_rem_parallelized,
+                    ServerKey::smart_crt_rem_parallelized,
+                    ServerKey::crt_rem_parallelized,
+                ),
+            );
+            assert_eq!(cks.decrypt_crt(&r_res), expected_r);
+        }
+    }
+}
+
+fn integer_unchecked_crt_div_rem<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_div_rem_test(param, Variant::Unchecked);
+}
+
+fn integer_smart_crt_div_rem<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_div_rem_test(param, Variant::Smart);
+}
+
+fn integer_default_crt_div_rem<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_div_rem_test(param, Variant::Default);
+}
+
+fn crt_if_then_else_test<P>(param: P, variant: Variant)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    for basis in crt_bases(param) {
+        let modulus = basis.iter().product::<u64>();
+
+        for _ in 0..NB_TESTS_SMALLER {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = rng.gen::<u64>() % modulus;
+            let clear_condition = rng.gen_bool(0.5);
+
+            let mut ct_0 = variant.encrypt(&cks, &sks, clear_0, &basis);
+            let mut ct_1 = variant.encrypt(&cks, &sks, clear_1, &basis);
+            let ct_condition = cks.encrypt_bool(clear_condition);
+
+            let ct_res = match variant {
+                Variant::Unchecked => {
+                    sks.unchecked_crt_if_then_else_parallelized(&ct_condition, &ct_0, &ct_1)
+                }
+                Variant::Smart => {
+                    sks.smart_crt_if_then_else_parallelized(&ct_condition, &mut ct_0, &mut ct_1)
+                }
+                Variant::Default => sks.crt_if_then_else_parallelized(&ct_condition, &ct_0, &ct_1),
+            };
+            assert_eq!(ct_res.moduli, basis);
+            let expected = if clear_condition { clear_0 } else { clear_1 };
+            assert_eq!(cks.decrypt_crt(&ct_res), expected);
+        }
+    }
+}
+
+fn integer_unchecked_crt_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_if_then_else_test(param, Variant::Unchecked);
+}
+
+fn integer_smart_crt_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_if_then_else_test(param, Variant::Smart);
+}
+
+fn integer_default_crt_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    crt_if_then_else_test(param, Variant::Default);
+}
@@ -17,7 +17,9 @@ pub use crate::shortint::CheckError;
 use crate::shortint::{CarryModulus, MessageModulus};
 pub use radix::scalar_mul::ScalarMultiplier;
 pub use radix::scalar_sub::TwosComplementNegation;
-pub use radix_parallel::{MiniUnsignedInteger, Reciprocable};
+pub use radix_parallel::{
+    IntegerOperation, MatchValues, MiniUnsignedInteger, PbsCost, Reciprocable,
+};
 
 /// A structure containing the server public key.
 ///
@@ -28,6 +30,10 @@ pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "integer::ServerKey";
+}
+
 impl From<ServerKey> for crate::shortint::ServerKey {
     fn from(key: ServerKey) -> Self {
         key.key
@@ -220,6 +226,10 @@ pub struct CompressedServerKey {
     pub(crate) key: crate::shortint::CompressedServerKey,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "integer::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     pub fn new_radix_compressed_server_key(client_key: &ClientKey) -> Self {
         let max_degree = MaxDegree::integer_radix_server_key(
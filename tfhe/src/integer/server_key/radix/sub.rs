@@ -518,6 +518,12 @@ impl ServerKey {
                 &input_carry,
             );
 
+            // In a subtraction, the carry into the first block is the 1 added to the bitwise
+            // negation of rhs, on a single block this is the carry into the last bit
+            if num_blocks == 1 && signed_operation == SignedOperation::Subtraction {
+                input_carry = self.key.create_trivial(1);
+            }
+
             // Encode the rule
             // "Overflow occurred if the carry into the last bit is different than the carry out
             // of the last bit"
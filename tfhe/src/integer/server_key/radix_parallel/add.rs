@@ -34,7 +34,7 @@ pub(crate) enum OutputCarry {
 ///
 /// Otherwise, msb either does not generate, or it does generate,
 /// but it means it won't propagate
-fn prefix_sum_carry_propagation(msb: u64, lsb: u64) -> u64 {
+pub(super) fn prefix_sum_carry_propagation(msb: u64, lsb: u64) -> u64 {
     if msb == OutputCarry::Propagated as u64 {
         lsb
     } else {
@@ -450,6 +450,13 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
+        self.is_eligible_for_parallel_single_carry_propagation_of_blocks(ct.blocks().len())
+    }
+
+    pub(crate) fn is_eligible_for_parallel_single_carry_propagation_of_blocks(
+        &self,
+        num_blocks: usize,
+    ) -> bool {
         // having 4-bits is a hard requirement
         // as the parallel implementation uses a bivariate BPS where individual values need
         // 2 bits
@@ -459,7 +466,7 @@ impl ServerKey {
             return false;
         }
 
-        should_hillis_steele_propagation_be_faster(ct.blocks().len(), rayon::current_num_threads())
+        should_hillis_steele_propagation_be_faster(num_blocks, rayon::current_num_threads())
     }
 
     /// This add_assign two numbers
@@ -1033,10 +1040,6 @@ impl ServerKey {
         &self,
         mut ciphertexts: Vec<RadixCiphertext>,
     ) -> Option<(RadixCiphertext, BooleanBlock)> {
-        if ciphertexts.is_empty() {
-            return None;
-        }
-
         if ciphertexts.len() == 1 {
             return Some((
                 ciphertexts.pop().unwrap(),
@@ -1044,7 +1047,55 @@ impl ServerKey {
             ));
         }
 
+        let (lhs, rhs, carries) = self
+            .unchecked_unsigned_overflowing_partial_sum_ciphertexts_vec_parallelized(ciphertexts)?;
+
+        if carries.is_empty() {
+            return Some(self.unsigned_overflowing_add_parallelized(&lhs, &rhs));
+        }
+
+        let ((result, overflowed), any_sum_overflowed) = rayon::join(
+            || {
+                let mut result = lhs;
+                let overflowed =
+                    self.unsigned_overflowing_add_assign_parallelized(&mut result, &rhs);
+                assert!(result.block_carries_are_empty());
+                (result, overflowed)
+            },
+            || {
+                let mut carries = RadixCiphertext::from(carries);
+                carries.blocks.retain(|block| block.degree.get() != 0);
+                self.scalar_ne_parallelized(&carries, 0)
+            },
+        );
+
+        let overflowed = self.boolean_bitor(&overflowed, &any_sum_overflowed);
+
+        Some((result, overflowed))
+    }
+
+    /// Reduces the ciphertexts to sum to two ciphertexts that are yet to be added
+    ///
+    /// Also returns the carries that went out of the last block while reducing, the
+    /// sum overflowed if any of these carries is not zero or if the addition
+    /// of the two ciphertexts overflows.
+    ///
+    /// - Expects all ciphertexts to have empty carries
+    /// - Expects all ciphertexts to have the same size
+    pub(crate) fn unchecked_unsigned_overflowing_partial_sum_ciphertexts_vec_parallelized(
+        &self,
+        mut ciphertexts: Vec<RadixCiphertext>,
+    ) -> Option<(RadixCiphertext, RadixCiphertext, Vec<Ciphertext>)> {
+        if ciphertexts.is_empty() {
+            return None;
+        }
+
         let num_blocks = ciphertexts[0].blocks().len();
+        if ciphertexts.len() == 1 {
+            let zero = self.create_trivial_zero_radix(num_blocks);
+            return Some((ciphertexts.pop().unwrap(), zero, vec![]));
+        }
+
         assert!(
             ciphertexts[1..]
                 .iter()
@@ -1053,9 +1104,9 @@ impl ServerKey {
         );
 
         if ciphertexts.len() == 2 {
-            return Some(
-                self.unsigned_overflowing_add_parallelized(&ciphertexts[0], &ciphertexts[1]),
-            );
+            let rhs = ciphertexts.pop().unwrap();
+            let lhs = ciphertexts.pop().unwrap();
+            return Some((lhs, rhs, vec![]));
         }
 
         assert!(
@@ -1187,25 +1238,11 @@ impl ServerKey {
             },
         );
 
-        let ((result, overflowed), any_sum_overflowed) = rayon::join(
-            || {
-                let mut result = RadixCiphertext::from(message_blocks);
-                let carry = RadixCiphertext::from(carry_blocks);
-                let overflowed =
-                    self.unsigned_overflowing_add_assign_parallelized(&mut result, &carry);
-                assert!(result.block_carries_are_empty());
-                (result, overflowed)
-            },
-            || {
-                let mut carries = RadixCiphertext::from(carries);
-                carries.blocks.retain(|block| block.degree.get() != 0);
-                self.scalar_ne_parallelized(&carries, 0)
-            },
-        );
-
-        let overflowed = self.boolean_bitor(&overflowed, &any_sum_overflowed);
-
-        Some((result, overflowed))
+        Some((
+            RadixCiphertext::from(message_blocks),
+            RadixCiphertext::from(carry_blocks),
+            carries,
+        ))
     }
 
     /// Computes the sum of the unsigned ciphertexts in parallel.
@@ -0,0 +1,383 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use std::ops::Range;
+
+impl ServerKey {
+    //==============================================================================================
+    //  Unchecked
+    //==============================================================================================
+
+    /// See [Self::reverse_bits_parallelized]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_reverse_bits_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_bits_in_block = self.message_modulus().0.ilog2();
+        if num_bits_in_block == 1 {
+            // Reversing the bits is the same as reversing the blocks
+            return T::from_blocks(ct.blocks().iter().rev().cloned().collect());
+        }
+
+        let lut = self.key.generate_lookup_table(|x| {
+            let x = x % self.message_modulus().0 as u64;
+            x.reverse_bits() >> (u64::BITS - num_bits_in_block)
+        });
+
+        // Reverse the bits within each block, then the order of the blocks
+        let blocks = ct
+            .blocks()
+            .par_iter()
+            .rev()
+            .map(|block| self.key.apply_lookup_table(block, &lut))
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
+
+    /// See [Self::swap_bytes_parallelized]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_swap_bytes_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_bits_in_block = self.message_modulus().0.ilog2();
+        assert_eq!(
+            8 % num_bits_in_block,
+            0,
+            "Swapping bytes requires blocks that split a byte evenly, got blocks of {num_bits_in_block} bits"
+        );
+        let blocks_per_byte = (8 / num_bits_in_block) as usize;
+        assert_eq!(
+            ct.blocks().len() % blocks_per_byte,
+            0,
+            "Swapping bytes requires a whole number of bytes, got {} blocks of {num_bits_in_block} bits",
+            ct.blocks().len()
+        );
+
+        // No PBS is needed, the bytes are only a permutation of the blocks
+        let blocks = ct
+            .blocks()
+            .rchunks(blocks_per_byte)
+            .flat_map(|byte| byte.iter().cloned())
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
+
+    /// See [Self::extract_bits_parallelized]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_extract_bits_parallelized<T>(&self, ct: &T, range: Range<u32>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            self.carry_modulus().0 >= self.message_modulus().0,
+            "A carry modulus as least as big as the message modulus is required"
+        );
+
+        let message_modulus = self.message_modulus().0 as u64;
+        let num_bits_in_block = self.message_modulus().0.ilog2();
+        let input_blocks = ct.blocks();
+        let num_blocks = input_blocks.len();
+        let num_bits = num_bits_in_block
+            .checked_mul(num_blocks as u32)
+            .expect("Number of bits encrypted exceeds u32::MAX");
+        assert!(
+            range.start <= range.end && range.end <= num_bits,
+            "Invalid bit range {range:?} for a ciphertext of {num_bits} bits"
+        );
+
+        let num_extracted_bits = range.end - range.start;
+        let block_shift = (range.start / num_bits_in_block) as usize;
+        let bit_shift = range.start % num_bits_in_block;
+        // Only these output blocks may receive extracted bits, the others are zeros
+        let num_output_blocks = num_extracted_bits.div_ceil(num_bits_in_block) as usize;
+
+        // Mask of the bits of the output block at `index` that belong to the range
+        let output_mask = |index: usize| {
+            let first_bit = index as u32 * num_bits_in_block;
+            let num_valid_bits = (num_extracted_bits - first_bit).min(num_bits_in_block);
+            (1u64 << num_valid_bits) - 1
+        };
+
+        let mut blocks = if bit_shift == 0 {
+            // Output blocks are input blocks, only the last one may need to be masked
+            (0..num_output_blocks)
+                .into_par_iter()
+                .map(|index| {
+                    let block = &input_blocks[index + block_shift];
+                    let mask = output_mask(index);
+                    if mask == message_modulus - 1 {
+                        block.clone()
+                    } else {
+                        let lut = self.key.generate_lookup_table(|x| x & mask);
+                        self.key.apply_lookup_table(block, &lut)
+                    }
+                })
+                .collect::<Vec<_>>()
+        } else {
+            // Each output block is made of the high bits of an input block
+            // and the low bits of the next one, both parts are computed
+            // with a single PBS per input block thanks to a many-LUT
+            let num_high_bits = num_bits_in_block - bit_shift;
+            let last_input_block = (block_shift + num_output_blocks).min(num_blocks - 1);
+
+            let parts = (block_shift..=last_input_block)
+                .into_par_iter()
+                .map(|input_index| {
+                    let block = &input_blocks[input_index];
+                    let low_output = input_index - block_shift;
+                    // Part that goes into the output block `input_index - block_shift`
+                    let low_part = (low_output < num_output_blocks).then(|| {
+                        let mask = output_mask(low_output);
+                        move |x: u64| ((x % message_modulus) >> bit_shift) & mask
+                    });
+                    // Part that goes into the output block `input_index - block_shift - 1`
+                    // (unless the range ends before it)
+                    let high_part = low_output
+                        .checked_sub(1)
+                        .map(output_mask)
+                        .filter(|mask| mask >> num_high_bits != 0)
+                        .map(|mask| move |x: u64| ((x << num_high_bits) % message_modulus) & mask);
+
+                    match (low_part, high_part) {
+                        (Some(low_part), Some(high_part)) => {
+                            let lut = self.key.generate_many_lookup_table(&[
+                                &low_part,
+                                &high_part as &dyn Fn(u64) -> u64,
+                            ]);
+                            let mut parts = self.key.apply_many_lookup_table(block, &lut);
+                            let high = parts.pop();
+                            let low = parts.pop();
+                            (low, high)
+                        }
+                        (Some(low_part), None) => {
+                            let lut = self.key.generate_lookup_table(low_part);
+                            (Some(self.key.apply_lookup_table(block, &lut)), None)
+                        }
+                        (None, Some(high_part)) => {
+                            let lut = self.key.generate_lookup_table(high_part);
+                            (None, Some(self.key.apply_lookup_table(block, &lut)))
+                        }
+                        (None, None) => (None, None),
+                    }
+                })
+                .collect::<Vec<(Option<Ciphertext>, Option<Ciphertext>)>>();
+
+            let mut low_parts = Vec::with_capacity(parts.len());
+            let mut high_parts = Vec::with_capacity(parts.len());
+            for (low, high) in parts {
+                low_parts.extend(low);
+                high_parts.extend(high);
+            }
+
+            // The parts of an output block hold disjoint bits,
+            // so adding them can't create a carry
+            low_parts
+                .into_iter()
+                .zip(
+                    high_parts
+                        .into_iter()
+                        .map(Some)
+                        .chain(std::iter::repeat(None)),
+                )
+                .map(|(mut low, high)| {
+                    if let Some(high) = high {
+                        self.key.unchecked_add_assign(&mut low, &high);
+                    }
+                    low
+                })
+                .collect::<Vec<_>>()
+        };
+
+        blocks.resize(num_blocks, self.key.create_trivial(0));
+        T::from_blocks(blocks)
+    }
+
+    //==============================================================================================
+    //  Smart
+    //==============================================================================================
+
+    /// See [Self::reverse_bits_parallelized]
+    pub fn smart_reverse_bits_parallelized<T>(&self, ct: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.unchecked_reverse_bits_parallelized(ct)
+    }
+
+    /// See [Self::swap_bytes_parallelized]
+    pub fn smart_swap_bytes_parallelized<T>(&self, ct: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.unchecked_swap_bytes_parallelized(ct)
+    }
+
+    /// See [Self::extract_bits_parallelized]
+    pub fn smart_extract_bits_parallelized<T>(&self, ct: &mut T, range: Range<u32>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.unchecked_extract_bits_parallelized(ct, range)
+    }
+
+    //==============================================================================================
+    //  Default
+    //==============================================================================================
+
+    /// Reverses the order of the bits of `ct`
+    ///
+    /// The least significant bit becomes the most significant bit,
+    /// the second least significant bit becomes the second most significant bit, etc.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b0001_0110u8;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.reverse_bits_parallelized(&ct1);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.reverse_bits());
+    /// ```
+    pub fn reverse_bits_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_reverse_bits_parallelized(ct)
+    }
+
+    /// Reverses the byte order of `ct`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits in a block does not divide 8,
+    /// or if `ct` does not encrypt a whole number of bytes.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0x12_34u16;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.swap_bytes_parallelized(&ct1);
+    ///
+    /// // Decrypt:
+    /// let res: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.swap_bytes());
+    /// ```
+    pub fn swap_bytes_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_swap_bytes_parallelized(ct)
+    }
+
+    /// Extracts the bits of `ct` within `range`
+    ///
+    /// The result has the same number of blocks as `ct`, the extracted bits
+    /// are moved to the least significant positions and all other bits are 0,
+    /// i.e. the result is `(ct >> range.start) & ((1 << range.len()) - 1)`
+    /// where `ct` is seen as unsigned (the sign bit is not extended).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is reversed or exceeds the number of bits of `ct`
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 0b1011_0110u8;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.extract_bits_parallelized(&ct1, 1..6);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 0b1_1011);
+    /// ```
+    pub fn extract_bits_parallelized<T>(&self, ct: &T, range: Range<u32>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_extract_bits_parallelized(ct, range)
+    }
+}
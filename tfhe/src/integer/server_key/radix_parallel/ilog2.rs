@@ -150,12 +150,58 @@ impl ServerKey {
         let leading_count_per_blocks =
             self.prepare_count_of_consecutive_bits(ct.clone(), direction, bit_value);
 
+        self.sum_block_bit_counts(leading_count_per_blocks, num_bits_in_ciphertext)
+    }
+
+    /// Counts how many bits have the given value, regardless of their position
+    ///
+    /// The returned Ciphertexts has a variable size
+    /// i.e. It contains just the minimum number of block
+    /// needed to represent the maximum possible number of bits.
+    fn count_bits<T>(&self, ct: &T, bit_value: BitValue) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.blocks().is_empty() {
+            return self.create_trivial_zero_radix(0);
+        }
+
+        let num_bits_in_message = self.key.message_modulus.0.ilog2();
+        let num_bits_in_ciphertext = num_bits_in_message
+            .checked_mul(ct.blocks().len() as u32)
+            .expect("Number of bits encrypted exceeds u32::MAX");
+
+        let lut = self.key.generate_lookup_table(|x| {
+            let x = x % self.key.message_modulus.0 as u64;
+            let ones = u64::from(x.count_ones());
+            match bit_value {
+                BitValue::One => ones,
+                BitValue::Zero => u64::from(num_bits_in_message) - ones,
+            }
+        });
+
+        let count_per_blocks = ct
+            .blocks()
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &lut))
+            .collect::<Vec<_>>();
+
+        self.sum_block_bit_counts(count_per_blocks, num_bits_in_ciphertext)
+    }
+
+    /// Sums blocks that each hold a count of bits (at most the number of bits in a block)
+    /// into a radix ciphertext large enough to hold `num_bits_in_ciphertext`
+    fn sum_block_bit_counts(
+        &self,
+        count_per_blocks: Vec<Ciphertext>,
+        num_bits_in_ciphertext: u32,
+    ) -> RadixCiphertext {
         // `num_bits_in_ciphertext` is the max value we want to represent
         // its ilog2 + 1 gives use how many bits we need to be able to represent it.
         let counter_num_blocks =
             (num_bits_in_ciphertext.ilog2() + 1).div_ceil(self.message_modulus().0.ilog2());
 
-        let cts = leading_count_per_blocks
+        let cts = count_per_blocks
             .into_iter()
             .map(|block| {
                 let mut ct: RadixCiphertext =
@@ -213,6 +259,26 @@ impl ServerKey {
         self.count_consecutive_bits(ct, Direction::Leading, BitValue::One)
     }
 
+    /// See [Self::count_ones_parallelized]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_count_ones_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.count_bits(ct, BitValue::One)
+    }
+
+    /// See [Self::count_zeros_parallelized]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_count_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.count_bits(ct, BitValue::Zero)
+    }
+
     /// Returns the base 2 logarithm of the number, rounded down.
     ///
     /// See [Self::ilog2_parallelized] for an example
@@ -395,6 +461,30 @@ impl ServerKey {
         self.unchecked_leading_ones_parallelized(ct)
     }
 
+    /// See [Self::count_ones_parallelized]
+    pub fn smart_count_ones_parallelized<T>(&self, ct: &mut T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.unchecked_count_ones_parallelized(ct)
+    }
+
+    /// See [Self::count_zeros_parallelized]
+    pub fn smart_count_zeros_parallelized<T>(&self, ct: &mut T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        self.unchecked_count_zeros_parallelized(ct)
+    }
+
     /// Returns the base 2 logarithm of the number, rounded down.
     ///
     /// See [Self::ilog2_parallelized] for an example
@@ -609,6 +699,94 @@ impl ServerKey {
         self.unchecked_leading_ones_parallelized(ct)
     }
 
+    /// Returns the number of ones in the binary representation of `ct`
+    ///
+    /// The returned Ciphertexts has a variable size
+    /// i.e. It contains just the minimum number of block
+    /// needed to represent the maximum possible number of bits.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = -100i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg);
+    ///
+    /// let n = sks.count_ones_parallelized(&ct1);
+    ///
+    /// // Decrypt:
+    /// let n: u32 = cks.decrypt(&n);
+    /// assert_eq!(n, msg.count_ones());
+    /// ```
+    pub fn count_ones_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_count_ones_parallelized(ct)
+    }
+
+    /// Returns the number of zeros in the binary representation of `ct`
+    ///
+    /// The returned Ciphertexts has a variable size
+    /// i.e. It contains just the minimum number of block
+    /// needed to represent the maximum possible number of bits.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 37i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg);
+    ///
+    /// let n = sks.count_zeros_parallelized(&ct1);
+    ///
+    /// // Decrypt:
+    /// let n: u32 = cks.decrypt(&n);
+    /// assert_eq!(n, msg.count_zeros());
+    /// ```
+    pub fn count_zeros_parallelized<T>(&self, ct: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_count_zeros_parallelized(ct)
+    }
+
     /// Returns the base 2 logarithm of the number, rounded down.
     ///
     /// # Example
@@ -0,0 +1,154 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Returns a trivial ciphertext encrypting `1 << bit`
+    ///
+    /// Contrary to `create_trivial_radix`, this works for any bit position
+    /// within the `num_blocks` blocks, not only the ones of clear integer types.
+    fn create_trivial_power_of_two(&self, bit: u32, num_blocks: usize) -> RadixCiphertext {
+        let bits_per_block = self.message_modulus().0.ilog2();
+        let block_index = (bit / bits_per_block) as usize;
+
+        let blocks = (0..num_blocks)
+            .map(|i| {
+                let value = if i == block_index {
+                    1u64 << (bit % bits_per_block)
+                } else {
+                    0
+                };
+                self.key.create_trivial(value)
+            })
+            .collect::<Vec<_>>();
+        RadixCiphertext::from(blocks)
+    }
+
+    //==============================================================================================
+    //  Unchecked
+    //==============================================================================================
+
+    /// See [Self::isqrt_parallelized]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_isqrt_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        let num_bits = num_blocks as u32 * self.message_modulus().0.ilog2();
+        if num_bits == 0 {
+            return ct.clone();
+        }
+
+        // Digit by digit computation (in base 2), where each iteration decides one bit
+        // of the root, by trying to subtract the current candidate from the remainder
+        let mut remainder = RadixCiphertext::from(ct.blocks().to_vec());
+        let mut root = self.create_trivial_zero_radix::<RadixCiphertext>(num_blocks);
+
+        // Highest power of 4 that fits in the ciphertext
+        let highest_bit = (num_bits - 1) & !1;
+        for bit in (0..=highest_bit).rev().step_by(2) {
+            let is_last_iteration = bit == 0;
+            let power_of_four = self.create_trivial_power_of_two(bit, num_blocks);
+
+            let ((new_remainder, is_too_big), (shifted_root, shifted_root_plus_bit)) = rayon::join(
+                || {
+                    let candidate = self.add_parallelized(&root, &power_of_four);
+                    self.unchecked_unsigned_overflowing_sub_parallelized(&remainder, &candidate)
+                },
+                || {
+                    let shifted_root = self.unchecked_scalar_right_shift_parallelized(&root, 1);
+                    let shifted_root_plus_bit =
+                        self.add_parallelized(&shifted_root, &power_of_four);
+                    (shifted_root, shifted_root_plus_bit)
+                },
+            );
+
+            // The remainder is not needed after the last bit is known
+            let (next_remainder, next_root) = rayon::join(
+                || {
+                    (!is_last_iteration).then(|| {
+                        self.if_then_else_parallelized(&is_too_big, &remainder, &new_remainder)
+                    })
+                },
+                || {
+                    self.if_then_else_parallelized(
+                        &is_too_big,
+                        &shifted_root,
+                        &shifted_root_plus_bit,
+                    )
+                },
+            );
+
+            if let Some(next_remainder) = next_remainder {
+                remainder = next_remainder;
+            }
+            root = next_root;
+        }
+
+        T::from_blocks(root.blocks)
+    }
+
+    //==============================================================================================
+    //  Smart
+    //==============================================================================================
+
+    /// See [Self::isqrt_parallelized]
+    pub fn smart_isqrt_parallelized<T>(&self, ct: &mut T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        self.unchecked_isqrt_parallelized(ct)
+    }
+
+    //==============================================================================================
+    //  Default
+    //==============================================================================================
+
+    /// Computes homomorphically the integer square root of a ciphertext,
+    /// i.e. the largest integer `r` such that `r * r <= ct`
+    ///
+    /// The input is interpreted as unsigned, so the result is not meaningful
+    /// for negative signed inputs.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let msg = 200u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 14);
+    /// ```
+    pub fn isqrt_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_isqrt_parallelized(ct)
+    }
+}
@@ -0,0 +1,349 @@
+use crate::core_crypto::prelude::UnsignedInteger;
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+/// A clear mapping table, used to map an encrypted value to another one
+///
+/// Each pair is `(input, output)`, the inputs are all distinct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchValues<Clear>(Vec<(Clear, Clear)>);
+
+impl<Clear> MatchValues<Clear>
+where
+    Clear: Copy + Ord,
+{
+    /// Creates a mapping table from `(input, output)` pairs
+    ///
+    /// Returns an error if an input appears more than once.
+    pub fn new(matches: Vec<(Clear, Clear)>) -> crate::Result<Self> {
+        let mut inputs: Vec<_> = matches.iter().map(|(input, _)| *input).collect();
+        inputs.sort_unstable();
+        if inputs.windows(2).any(|w| w[0] == w[1]) {
+            return Err(crate::Error::new(
+                "The inputs of the mapping table must be distinct".to_string(),
+            ));
+        }
+
+        Ok(Self(matches))
+    }
+
+    /// Creates the table mapping each input of `inputs` to `f(input)`
+    ///
+    /// Returns an error if an input appears more than once.
+    pub fn from_fn<I, F>(inputs: I, f: F) -> crate::Result<Self>
+    where
+        I: IntoIterator<Item = Clear>,
+        F: Fn(Clear) -> Clear,
+    {
+        Self::new(inputs.into_iter().map(|input| (input, f(input))).collect())
+    }
+
+    pub fn get_values(&self) -> &[(Clear, Clear)] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl ServerKey {
+    /// Maps the encrypted value to the output of the matching entry of the clear table
+    ///
+    /// Returns the mapped value, encrypted on as few blocks as needed to represent the
+    /// greatest output of the table, and a boolean which is true if an entry matched.
+    /// If no entry matched, the mapped value is 0.
+    ///
+    /// The equality of each block of `ct` with each possible block value is computed once and
+    /// shared by all the entries, so the cost grows with the number of entries only through
+    /// the aggregation of those block equalities.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::MatchValues;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let matches = MatchValues::new(vec![(3u64, 17u64), (9, 4), (200, 1000)]).unwrap();
+    ///
+    /// let ct = cks.encrypt(9u64);
+    ///
+    /// let (ct_res, is_match) = sks.match_value_parallelized(&ct, &matches);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 4);
+    /// assert!(cks.decrypt_bool(&is_match));
+    ///
+    /// let ct = cks.encrypt(10u64);
+    /// let (ct_res, is_match) = sks.match_value_parallelized(&ct, &matches);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0);
+    /// assert!(!cks.decrypt_bool(&is_match));
+    /// ```
+    pub fn match_value_parallelized<Clear>(
+        &self,
+        ct: &RadixCiphertext,
+        matches: &MatchValues<Clear>,
+    ) -> (RadixCiphertext, BooleanBlock)
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64>,
+    {
+        let selectors = self.compute_match_selectors(ct, matches);
+
+        let (result, is_match) = rayon::join(
+            || self.aggregate_match_outputs(&selectors, matches, Clear::ZERO),
+            || {
+                if selectors.is_empty() {
+                    self.key.create_trivial(0)
+                } else {
+                    let selectors = selectors.iter().map(|(_, s)| s.clone()).collect();
+                    self.is_at_least_one_comparisons_block_true(selectors)
+                }
+            },
+        );
+
+        (result, BooleanBlock::new_unchecked(is_match))
+    }
+
+    /// Maps the encrypted value to the output of the matching entry of the clear table,
+    /// or to `default` if no entry matched
+    ///
+    /// The result is encrypted on as few blocks as needed to represent the greatest output
+    /// of the table and `default`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::MatchValues;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let matches = MatchValues::new(vec![(3u64, 17u64), (9, 4), (200, 1000)]).unwrap();
+    ///
+    /// let ct = cks.encrypt(200u64);
+    /// let ct_res = sks.map_with_default_parallelized(&ct, &matches, 42);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 1000);
+    ///
+    /// let ct = cks.encrypt(10u64);
+    /// let ct_res = sks.map_with_default_parallelized(&ct, &matches, 42);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn map_with_default_parallelized<Clear>(
+        &self,
+        ct: &RadixCiphertext,
+        matches: &MatchValues<Clear>,
+        default: Clear,
+    ) -> RadixCiphertext
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64>,
+    {
+        let selectors = self.compute_match_selectors(ct, matches);
+        self.aggregate_match_outputs(&selectors, matches, default)
+    }
+
+    /// Returns, for each entry of the table that can match `ct`, the index of the entry and a
+    /// block encrypting 1 if `ct` is equal to the input of the entry, 0 otherwise
+    ///
+    /// At most one of the returned blocks encrypts 1.
+    fn compute_match_selectors<Clear>(
+        &self,
+        ct: &RadixCiphertext,
+        matches: &MatchValues<Clear>,
+    ) -> Vec<(usize, Ciphertext)>
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64>,
+    {
+        let mut ct = ct.clone();
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut ct);
+        }
+
+        let message_modulus = self.key.message_modulus.0;
+        let bits_per_block = message_modulus.ilog2();
+        let num_blocks = ct.blocks.len();
+
+        // Inputs which do not fit in the ciphertext can never match
+        let entries_digits: Vec<(usize, Vec<u64>)> = matches
+            .get_values()
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (input, _))| {
+                let mut digits: Vec<u64> =
+                    BlockDecomposer::with_early_stop_at_zero(*input, bits_per_block)
+                        .iter_as::<u64>()
+                        .collect();
+                if digits.len() > num_blocks {
+                    return None;
+                }
+                digits.resize(num_blocks, 0);
+                Some((i, digits))
+            })
+            .collect();
+
+        // Only compute the block equalities needed by the table
+        let mut is_needed = vec![vec![false; message_modulus]; num_blocks];
+        for (_, digits) in &entries_digits {
+            for (block_index, digit) in digits.iter().enumerate() {
+                is_needed[block_index][*digit as usize] = true;
+            }
+        }
+
+        let eq_luts: Vec<_> = (0..message_modulus as u64)
+            .map(|value| {
+                self.key
+                    .generate_lookup_table(move |x| u64::from(x == value))
+            })
+            .collect();
+
+        let block_equalities: Vec<Vec<Option<Ciphertext>>> = ct
+            .blocks
+            .par_iter()
+            .zip(is_needed.par_iter())
+            .map(|(block, is_needed)| {
+                is_needed
+                    .par_iter()
+                    .zip(eq_luts.par_iter())
+                    .map(|(is_needed, lut)| {
+                        is_needed.then(|| self.key.apply_lookup_table(block, lut))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        entries_digits
+            .into_par_iter()
+            .map(|(i, digits)| {
+                let equalities = digits
+                    .iter()
+                    .enumerate()
+                    .map(|(block_index, digit)| {
+                        block_equalities[block_index][*digit as usize]
+                            .clone()
+                            .expect("block equality was computed")
+                    })
+                    .collect();
+                (i, self.are_all_comparisons_block_true(equalities))
+            })
+            .collect()
+    }
+
+    /// Computes the output of the table, given the selectors of the entries
+    ///
+    /// Each output block is `default_block + sum_v v * OR(selectors of the entries whose output
+    /// block is default_block + v)`, as at most one selector is set only one term of the sum is
+    /// not zero.
+    fn aggregate_match_outputs<Clear>(
+        &self,
+        selectors: &[(usize, Ciphertext)],
+        matches: &MatchValues<Clear>,
+        default: Clear,
+    ) -> RadixCiphertext
+    where
+        Clear: UnsignedInteger + DecomposableInto<u64>,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2();
+
+        let decompose = |value: Clear| -> Vec<u64> {
+            BlockDecomposer::with_early_stop_at_zero(value, bits_per_block)
+                .iter_as::<u64>()
+                .collect()
+        };
+
+        let default_digits = decompose(default);
+        let outputs_digits: Vec<_> = selectors
+            .iter()
+            .map(|(i, _)| decompose(matches.get_values()[*i].1))
+            .collect();
+        let num_blocks = outputs_digits
+            .iter()
+            .chain(std::iter::once(&default_digits))
+            .map(Vec::len)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let digit_at = |digits: &[u64], block_index: usize| -> u64 {
+            digits.get(block_index).copied().unwrap_or(0)
+        };
+
+        let max_degree = self.key.max_degree.get();
+        let max_noise_level = self.key.max_noise_level.get();
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let default_digit = digit_at(&default_digits, block_index);
+
+                let terms: Vec<(u64, Ciphertext)> = (1..message_modulus)
+                    .into_par_iter()
+                    .filter_map(|value| {
+                        let group: Vec<_> = selectors
+                            .iter()
+                            .zip(outputs_digits.iter())
+                            .filter(|(_, digits)| {
+                                let digit = digit_at(digits, block_index);
+                                (digit + message_modulus - default_digit) % message_modulus == value
+                            })
+                            .map(|((_, selector), _)| selector.clone())
+                            .collect();
+
+                        if group.is_empty() {
+                            None
+                        } else {
+                            Some((value, self.is_at_least_one_comparisons_block_true(group)))
+                        }
+                    })
+                    .collect();
+
+                if terms.is_empty() {
+                    return self.key.create_trivial(default_digit);
+                }
+
+                let mut sum = self.key.create_trivial(0);
+                for (value, is_selected) in terms {
+                    let term = self.key.unchecked_scalar_mul(&is_selected, value as u8);
+                    if sum.degree.get() + term.degree.get() > max_degree
+                        || sum.noise_level().get() + term.noise_level().get() > max_noise_level
+                    {
+                        // The sum is one of the values, so it is below the message modulus
+                        self.key.message_extract_assign(&mut sum);
+                    }
+                    self.key.unchecked_add_assign(&mut sum, &term);
+                }
+
+                if default_digit == 0
+                    && (sum.degree.get() as u64) < message_modulus
+                    && sum.noise_level().get() <= NoiseLevel::NOMINAL.get()
+                {
+                    sum
+                } else {
+                    let lut = self
+                        .key
+                        .generate_lookup_table(|x| (x + default_digit) % message_modulus);
+                    self.key.apply_lookup_table(&sum, &lut)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+}
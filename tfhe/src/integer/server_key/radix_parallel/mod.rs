@@ -1,14 +1,21 @@
 mod abs;
 mod add;
 mod bit_extractor;
+mod bit_manipulation;
 mod bitwise_op;
 mod cmux;
 mod comparison;
 mod div_mod;
+mod isqrt;
+mod match_value;
+mod modular;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod pbs_cost;
+mod pow;
 mod rotate;
+mod saturating;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_comparison;
@@ -18,6 +25,7 @@ mod scalar_rotate;
 mod scalar_shift;
 mod scalar_sub;
 mod shift;
+mod sort;
 pub(crate) mod sub;
 
 mod ilog2;
@@ -30,6 +38,8 @@ pub(crate) mod tests_unsigned;
 
 use super::ServerKey;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
+pub use match_value::MatchValues;
+pub use pbs_cost::{IntegerOperation, PbsCost};
 use rayon::prelude::*;
 pub use scalar_div_mod::{MiniUnsignedInteger, Reciprocable};
 
@@ -0,0 +1,424 @@
+//! Modular arithmetic with a clear modulus
+//!
+//! The operands are expected to already be reduced modulo the modulus, which allows the
+//! additions and subtractions to be reduced with a single conditional correction.
+//!
+//! Multiplications are reduced using
+//! [Barrett reduction](https://en.wikipedia.org/wiki/Barrett_reduction): the reciprocal of the
+//! modulus is computed in the clear, so that reducing the product only costs two multiplications
+//! by clear scalars and at most two conditional subtractions, instead of a full division.
+use crate::core_crypto::prelude::{CastFrom, Numeric, UnsignedNumeric};
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
+use crate::integer::server_key::radix_parallel::{MiniUnsignedInteger, Reciprocable};
+use crate::integer::{RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    fn num_blocks_for_bits(&self, num_bits: u32) -> usize {
+        num_bits.div_ceil(self.message_modulus().0.ilog2()) as usize
+    }
+
+    fn assert_modulus_fits<Scalar>(&self, ct: &RadixCiphertext, modulus: Scalar)
+    where
+        Scalar: MiniUnsignedInteger,
+    {
+        assert!(modulus > Scalar::ONE, "the modulus must be at least 2");
+        let num_bits = self.message_modulus().0.ilog2() * ct.blocks.len() as u32;
+        assert!(
+            modulus.ilog2() < num_bits,
+            "the modulus does not fit in a ciphertext of {num_bits} bits"
+        );
+    }
+
+    /// Subtracts the modulus from `ct` if `ct >= modulus`
+    fn conditional_sub_modulus<Scalar>(
+        &self,
+        ct: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: UnsignedNumeric + DecomposableInto<u8>,
+    {
+        let (reduced, overflowed) = self.unsigned_overflowing_scalar_sub_parallelized(ct, modulus);
+        self.if_then_else_parallelized(&overflowed, ct, &reduced)
+    }
+
+    /// Reduces `ct` modulo `modulus` using Barrett reduction
+    ///
+    /// `ct` must encrypt a value smaller than `modulus^2`, the result has `num_blocks` blocks.
+    fn barrett_reduce<Scalar>(
+        &self,
+        ct: &RadixCiphertext,
+        modulus: Scalar,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        // Number of bits of the modulus, i.e. 2^(k-1) <= modulus < 2^k
+        let k = MiniUnsignedInteger::ilog2(modulus) + 1;
+
+        // reciprocal = floor(2^(2k) / modulus)
+        //
+        // 2^(2k) may not fit in the double precision type, so 2^(2k) - 1 is divided instead,
+        // which only differs when the modulus divides 2^(2k), that is, when it is a power of two.
+        let reciprocal = {
+            let two_pow_k = Scalar::DoublePrecision::ONE << k as usize;
+            let two_pow_2k_minus_one = (two_pow_k - Scalar::DoublePrecision::ONE) * two_pow_k
+                + two_pow_k
+                - Scalar::DoublePrecision::ONE;
+            let quotient = two_pow_2k_minus_one / Scalar::DoublePrecision::cast_from(modulus);
+            if MiniUnsignedInteger::is_power_of_two(modulus) {
+                quotient + Scalar::DoublePrecision::ONE
+            } else {
+                quotient
+            }
+        };
+
+        // As ct < 2^(2k), the estimated quotient q3 is such that q - 2 <= q3 <= q,
+        // where q is the real quotient, so the remainder is in [0, 3 * modulus)
+        //
+        // q1 < 2^(k+1) and reciprocal <= 2^(k+1), so their product fits in 2k + 2 bits
+        let q1 = self.scalar_right_shift_parallelized(ct, k - 1);
+        let q1 = self.cast_to_unsigned(q1, self.num_blocks_for_bits(2 * k + 2));
+        let q2 = self.scalar_mul_parallelized(&q1, reciprocal);
+        let q3 = self.scalar_right_shift_parallelized(&q2, k + 1);
+
+        // 3 * modulus < 2^(k+2)
+        let remainder_num_blocks = self.num_blocks_for_bits(k + 2);
+        let q3 = self.cast_to_unsigned(q3, remainder_num_blocks);
+        let (ct, q3_times_modulus) = rayon::join(
+            || self.cast_to_unsigned(ct.clone(), remainder_num_blocks),
+            || self.scalar_mul_parallelized(&q3, modulus),
+        );
+
+        let mut remainder = self.sub_parallelized(&ct, &q3_times_modulus);
+        for _ in 0..2 {
+            remainder = self.conditional_sub_modulus(&remainder, modulus);
+        }
+        self.cast_to_unsigned(remainder, num_blocks)
+    }
+
+    /// Computes homomorphically `(lhs + rhs) mod modulus`
+    ///
+    /// Both `lhs` and `rhs` must encrypt values smaller than `modulus`, otherwise the result
+    /// is not reduced. The modulus must be at least 2, and fit in the ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    ///
+    /// let ct1 = cks.encrypt(200u64);
+    /// let ct2 = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.mod_add_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (200 + 100) % modulus);
+    /// ```
+    pub fn mod_add_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: Reciprocable + UnsignedNumeric + DecomposableInto<u8>,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        self.assert_modulus_fits(lhs, modulus);
+
+        // The sum may not fit in the ciphertexts, so an extra block is used
+        let num_blocks = lhs.blocks.len();
+        let (lhs, rhs) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(lhs, 1),
+            || self.extend_radix_with_trivial_zero_blocks_msb(rhs, 1),
+        );
+        let sum = self.add_parallelized(&lhs, &rhs);
+        let result = self.conditional_sub_modulus(&sum, modulus);
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Computes homomorphically `(lhs - rhs) mod modulus`
+    ///
+    /// Both `lhs` and `rhs` must encrypt values smaller than `modulus`, otherwise the result
+    /// is not reduced. The modulus must be at least 2, and fit in the ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    ///
+    /// let ct1 = cks.encrypt(100u64);
+    /// let ct2 = cks.encrypt(200u64);
+    ///
+    /// let ct_res = sks.mod_sub_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (100 + modulus - 200) % modulus);
+    /// ```
+    pub fn mod_sub_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: Reciprocable + UnsignedNumeric + DecomposableInto<u8>,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        self.assert_modulus_fits(lhs, modulus);
+
+        // When the subtraction underflows, adding the modulus wraps back to the right value
+        let (difference, overflowed) = self.unsigned_overflowing_sub_parallelized(lhs, rhs);
+        let corrected = self.scalar_add_parallelized(&difference, modulus);
+        self.if_then_else_parallelized(&overflowed, &corrected, &difference)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) mod modulus`
+    ///
+    /// Both `lhs` and `rhs` must encrypt values smaller than `modulus`, otherwise the result
+    /// is not reduced. The modulus must be at least 2, and fit in the ciphertexts.
+    ///
+    /// The full product is reduced using Barrett reduction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    ///
+    /// let ct1 = cks.encrypt(200u64);
+    /// let ct2 = cks.encrypt(100u64);
+    ///
+    /// let ct_res = sks.mod_mul_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (200 * 100) % modulus);
+    /// ```
+    pub fn mod_mul_parallelized<Scalar>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        self.assert_modulus_fits(lhs, modulus);
+
+        let product = self.widening_mul_parallelized(lhs, rhs);
+        self.barrett_reduce(&product, modulus, lhs.blocks.len())
+    }
+
+    /// Computes homomorphically `base^exponent mod modulus` for an encrypted `exponent`
+    ///
+    /// `base` must encrypt a value smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2, and fit in the ciphertext.
+    /// The exponent may have a different number of blocks than the base.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    ///
+    /// let ct_base = cks.encrypt(3u64);
+    /// let ct_exponent = cks.as_ref().encrypt_radix(7u64, 2);
+    ///
+    /// let ct_res = sks.mod_pow_parallelized(&ct_base, &ct_exponent, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 3u64.pow(7) % modulus);
+    /// ```
+    pub fn mod_pow_parallelized<Scalar>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        self.assert_modulus_fits(base, modulus);
+
+        self.with_clean_pow_inputs(base, exponent, |base, exponent| {
+            self.encrypted_exponent_pow(base, exponent, |lhs, rhs| {
+                (self.mod_mul_parallelized(lhs, rhs, modulus), None)
+            })
+            .0
+        })
+    }
+
+    /// Computes homomorphically `base^exponent mod modulus` for a clear `exponent`
+    ///
+    /// `base` must encrypt a value smaller than `modulus`, otherwise the result is not reduced.
+    /// The modulus must be at least 2, and fit in the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    ///
+    /// let ct_base = cks.encrypt(3u64);
+    ///
+    /// let ct_res = sks.scalar_mod_pow_parallelized(&ct_base, 200u64, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (0..200).fold(1, |acc, _| (acc * 3) % modulus));
+    /// ```
+    pub fn scalar_mod_pow_parallelized<Exponent, Scalar>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: Exponent,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Exponent: MiniUnsignedInteger,
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        self.assert_modulus_fits(base, modulus);
+
+        let mut tmp;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp = base.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        // `None` means the trivial 1, this saves the first multiplication
+        let mut result: Option<RadixCiphertext> = None;
+        // Powers of the base are only computed once, and only up to the last bit set
+        let mut base_pow = base.clone();
+
+        let mut remaining_bits = exponent;
+        while remaining_bits != Exponent::ZERO {
+            let bit_is_set = (remaining_bits & Exponent::ONE) == Exponent::ONE;
+            remaining_bits = remaining_bits >> 1;
+
+            let (new_result, next_base_pow) = rayon::join(
+                || match &result {
+                    Some(result) if bit_is_set => {
+                        Some(self.mod_mul_parallelized(result, &base_pow, modulus))
+                    }
+                    None if bit_is_set => Some(base_pow.clone()),
+                    _ => None,
+                },
+                || {
+                    (remaining_bits != Exponent::ZERO)
+                        .then(|| self.mod_mul_parallelized(&base_pow, &base_pow, modulus))
+                },
+            );
+
+            if new_result.is_some() {
+                result = new_result;
+            }
+            if let Some(next_base_pow) = next_base_pow {
+                base_pow = next_base_pow;
+            }
+        }
+
+        result.unwrap_or_else(|| self.create_trivial_radix(1u64, base.blocks.len()))
+    }
+
+    /// Computes homomorphically the inverse of `ct` modulo a prime `modulus`
+    ///
+    /// The inverse is computed as `ct^(modulus - 2) mod modulus`, so the modulus must be prime,
+    /// and `ct` must encrypt a value smaller than `modulus`. The inverse of 0 is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    ///
+    /// let ct = cks.encrypt(17u64);
+    ///
+    /// let ct_res = sks.mod_inverse_parallelized(&ct, modulus);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((res * 17) % modulus, 1);
+    /// ```
+    pub fn mod_inverse_parallelized<Scalar>(
+        &self,
+        ct: &RadixCiphertext,
+        modulus: Scalar,
+    ) -> RadixCiphertext
+    where
+        Scalar: Reciprocable + UnsignedNumeric + ScalarMultiplier + DecomposableInto<u8>,
+    {
+        self.assert_modulus_fits(ct, modulus);
+
+        if modulus == Scalar::TWO {
+            // 0 and 1 are their own inverse
+            let mut result = ct.clone();
+            if !result.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut result);
+            }
+            return result;
+        }
+
+        self.scalar_mod_pow_parallelized(ct, modulus - Scalar::TWO, modulus)
+    }
+}
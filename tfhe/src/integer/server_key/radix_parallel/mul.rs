@@ -295,6 +295,9 @@ impl ServerKey {
     /// This functions computes the terms resulting from multiplying each block
     /// of rhs with lhs. When summed these terms will give the low part of the result.
     /// i.e. in a (lhs: Nbit * rhs: Nbit) multiplication, summing the terms will give a N bit result
+    ///
+    /// rhs may have fewer blocks than lhs, the result then has the number of blocks of lhs,
+    /// which is how the full product is computed by extending lhs with trivial zeros.
     fn compute_terms_for_mul_low<T>(&self, lhs: &T, rhs: &T) -> Vec<T>
     where
         T: IntegerRadixCiphertext,
@@ -335,7 +338,8 @@ impl ServerKey {
             // we have to compute them.
             message_part_terms_generator
                 .chain(
-                    rhs.blocks()[..rhs.blocks().len() - 1] // last block carry would be thrown away
+                    // carries going past the last block of lhs would be thrown away
+                    rhs.blocks()[..rhs.blocks().len().min(lhs.blocks().len() - 1)]
                         .par_iter()
                         .enumerate()
                         .filter(|(_, block)| block.degree.get() != 0)
@@ -605,11 +609,43 @@ impl ServerKey {
         lhs: &RadixCiphertext,
         rhs: &RadixCiphertext,
     ) -> (RadixCiphertext, BooleanBlock) {
+        let (all_shifted_lhs, high_part) = self.compute_terms_for_overflowing_mul(lhs, rhs);
+
+        let (sum_result, high_part_is_non_zero) = rayon::join(
+            || {
+                self.unchecked_unsigned_overflowing_sum_ciphertexts_vec_parallelized(
+                    all_shifted_lhs,
+                )
+            },
+            || {
+                let high_part = RadixCiphertext::from(high_part);
+                self.unchecked_scalar_ne_parallelized(&high_part, 0)
+            },
+        );
+
+        if let Some((result, sum_overflowed)) = sum_result {
+            let final_overflow = self.boolean_bitor(&sum_overflowed, &high_part_is_non_zero);
+            (result, final_overflow)
+        } else {
+            // We can end up here, if all blocks of either rhs, or lhs were trivial zeros
+            let result = self.create_trivial_zero_radix(lhs.blocks.len());
+            (result, high_part_is_non_zero)
+        }
+    }
+
+    /// Computes the terms that sum to the low part of the product, and the blocks
+    /// of the high part of the product that are non-zero when the product does not fit
+    /// in the number of blocks of lhs
+    pub(super) fn compute_terms_for_overflowing_mul(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (Vec<RadixCiphertext>, Vec<crate::shortint::Ciphertext>) {
         let mul_result_is_non_zero = self
             .key
             .generate_lookup_table_bivariate(|x, y| u64::from((x * y) != 0));
 
-        let (all_shifted_lhs, high_part) = rayon::join(
+        rayon::join(
             || self.compute_terms_for_mul_low(lhs, rhs),
             || {
                 let (mut a, mut b) = rayon::join(
@@ -653,28 +689,7 @@ impl ServerKey {
                 a.append(&mut b);
                 a
             },
-        );
-
-        let (sum_result, high_part_is_non_zero) = rayon::join(
-            || {
-                self.unchecked_unsigned_overflowing_sum_ciphertexts_vec_parallelized(
-                    all_shifted_lhs,
-                )
-            },
-            || {
-                let high_part = RadixCiphertext::from(high_part);
-                self.unchecked_scalar_ne_parallelized(&high_part, 0)
-            },
-        );
-
-        if let Some((result, sum_overflowed)) = sum_result {
-            let final_overflow = self.boolean_bitor(&sum_overflowed, &high_part_is_non_zero);
-            (result, final_overflow)
-        } else {
-            // We can end up here, if all blocks of either rhs, or lhs were trivial zeros
-            let result = self.create_trivial_zero_radix(lhs.blocks.len());
-            (result, high_part_is_non_zero)
-        }
+        )
     }
 
     pub fn unchecked_unsigned_overflowing_mul_assign_parallelized(
@@ -791,6 +806,23 @@ impl ServerKey {
         lhs: &SignedRadixCiphertext,
         rhs: &SignedRadixCiphertext,
     ) -> (SignedRadixCiphertext, BooleanBlock) {
+        let (result, is_lt_min, is_gt_max) = self.signed_mul_and_compare_to_bounds(lhs, rhs);
+
+        let mut overflowed = self.boolean_bitor(&is_lt_min, &is_gt_max);
+        // after_bitor does not give the correct degree
+        overflowed.0.degree = Degree::new(1);
+
+        (result, overflowed)
+    }
+
+    /// Computes the product of lhs and rhs, along with whether the exact product
+    /// is smaller than the minimum or greater than the maximum value of the
+    /// ciphertext
+    pub(super) fn signed_mul_and_compare_to_bounds(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock, BooleanBlock) {
         // Note: Naive implementation of signed mul with overflow
         // surely there are optimized way of computing this
 
@@ -834,11 +866,170 @@ impl ServerKey {
         assert_eq!(is_gt_max.0.degree.get(), 1);
         assert_eq!(is_gt_max.0.degree.get(), 1);
 
-        let mut overflowed = self.boolean_bitor(&is_lt_min, &is_gt_max);
-        // after_bitor does not give the correct degree
-        overflowed.0.degree = Degree::new(1);
-
         full_result.blocks.truncate(lhs.blocks.len());
-        (full_result, overflowed)
+        (full_result, is_lt_min, is_gt_max)
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts, on twice their number of
+    /// blocks.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext.
+    ///
+    /// Both ciphertexts must have the same number of blocks.
+    pub fn unchecked_widening_mul_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+        let num_blocks = lhs.blocks().len();
+        if num_blocks == 0 {
+            return T::from_blocks(vec![]);
+        }
+
+        // The product of the unsigned values is computed by extending lhs with zeros,
+        // the trivial blocks do not cost any PBS
+        let mut extended_lhs = RadixCiphertext::from(lhs.blocks().to_vec());
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut extended_lhs, num_blocks);
+        let unsigned_rhs = RadixCiphertext::from(rhs.blocks().to_vec());
+
+        let (product, correction) = rayon::join(
+            || {
+                let terms = self.compute_terms_for_mul_low(&extended_lhs, &unsigned_rhs);
+                self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+                    .unwrap_or_else(|| self.create_trivial_zero_radix(2 * num_blocks))
+            },
+            || {
+                // In two's complement, a negative value x is seen as x + 2^n when unsigned,
+                // so the unsigned product has to be corrected in its high part:
+                // x * y = ux * uy - 2^n * ((x < 0) * uy + (y < 0) * ux) mod 2^2n
+                T::IS_SIGNED.then(|| {
+                    let sign_bit_lut = self.key.generate_lookup_table(|x| {
+                        u64::from(x >= self.key.message_modulus.0 as u64 / 2)
+                    });
+                    let (lhs_correction, rhs_correction) = rayon::join(
+                        || {
+                            let lhs_is_negative = self
+                                .key
+                                .apply_lookup_table(&lhs.blocks()[num_blocks - 1], &sign_bit_lut);
+                            let mut correction = unsigned_rhs.clone();
+                            self.zero_out_if_condition_is_false(&mut correction, &lhs_is_negative);
+                            correction
+                        },
+                        || {
+                            let rhs_is_negative = self
+                                .key
+                                .apply_lookup_table(&rhs.blocks()[num_blocks - 1], &sign_bit_lut);
+                            let mut correction = RadixCiphertext::from(lhs.blocks().to_vec());
+                            self.zero_out_if_condition_is_false(&mut correction, &rhs_is_negative);
+                            correction
+                        },
+                    );
+                    self.add_parallelized(&lhs_correction, &rhs_correction)
+                })
+            },
+        );
+
+        let mut blocks = product.blocks;
+        if let Some(correction) = correction {
+            let high_part = RadixCiphertext::from(blocks.split_off(num_blocks));
+            let high_part = self.sub_parallelized(&high_part, &correction);
+            blocks.extend(high_part.blocks);
+        }
+        T::from_blocks(blocks)
+    }
+
+    /// Computes homomorphically the full product of two ciphertexts, on twice their number of
+    /// blocks.
+    ///
+    /// Both ciphertexts must have the same number of blocks, the product does not wrap around.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear_1 = 170u8;
+    /// let clear_2 = 201u8;
+    ///
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically the 16 bits product of two 8 bits integers
+    /// let ct_res = sks.widening_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// assert_eq!(ct_res.blocks.len(), 2 * num_blocks);
+    ///
+    /// // Decrypt
+    /// let res: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(u16::from(clear_1) * u16::from(clear_2), res);
+    /// ```
+    pub fn widening_mul_parallelized<T>(&self, ct1: &T, ct2: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let lhs = if ct1.block_carries_are_empty() {
+            ct1
+        } else {
+            tmp_lhs = ct1.clone();
+            self.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+        let rhs = if ct2.block_carries_are_empty() {
+            ct2
+        } else {
+            tmp_rhs = ct2.clone();
+            self.full_propagate_parallelized(&mut tmp_rhs);
+            &tmp_rhs
+        };
+
+        self.unchecked_widening_mul_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the high half of the full product of two ciphertexts.
+    ///
+    /// The result has the same number of blocks as the inputs, it is the product shifted right
+    /// by the number of bits of the inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear_1 = -100i8;
+    /// let clear_2 = 77i8;
+    ///
+    /// let ctxt_1 = cks.encrypt_signed(clear_1);
+    /// let ctxt_2 = cks.encrypt_signed(clear_2);
+    ///
+    /// let ct_res = sks.mul_high_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(((i16::from(clear_1) * i16::from(clear_2)) >> 8) as i8, res);
+    /// ```
+    pub fn mul_high_parallelized<T>(&self, ct1: &T, ct2: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct1.blocks().len();
+        let mut blocks = self.widening_mul_parallelized(ct1, ct2).into_blocks();
+        T::from_blocks(blocks.split_off(num_blocks))
     }
 }
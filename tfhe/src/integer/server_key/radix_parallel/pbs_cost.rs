@@ -0,0 +1,546 @@
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::integer::ServerKey;
+use std::ops::Add;
+
+/// The operations on radix ciphertexts
+///
+/// Each operation refers to the default (i.e. not smart, checked or unchecked) `_parallelized`
+/// function of the [ServerKey].
+///
+/// The operations prefixed with `Signed` (and [Self::Abs]) are the ones on signed ciphertexts
+/// which do not run the same algorithm as on unsigned ciphertexts. The other operations run the
+/// same algorithm on both, so they cover signed ciphertexts too.
+///
+/// Apart from [Self::ScalarAdd] and [Self::ScalarSub], the operations with a clear operand are
+/// not covered, as their cost depends on the clear value: the scalar multiplication, division
+/// and remainder, bitwise operations, comparisons, min and max, shifts and rotations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntegerOperation {
+    FullPropagate,
+    Add,
+    Sub,
+    Neg,
+    /// Addition of a clear value whose least significant block is not zero
+    ScalarAdd,
+    /// Subtraction of a clear value whose least significant block is not zero
+    ScalarSub,
+    BitAnd,
+    BitOr,
+    BitXor,
+    BitNot,
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Min,
+    Max,
+    /// Selection between two integers, the condition being an encrypted boolean
+    IfThenElse,
+    Mul,
+    /// Shift by an encrypted amount, which has as many blocks as the shifted integer
+    Shl,
+    /// Shift by an encrypted amount, which has as many blocks as the shifted integer
+    Shr,
+    /// Rotation by an encrypted amount, which has as many blocks as the rotated integer
+    RotateLeft,
+    /// Rotation by an encrypted amount, which has as many blocks as the rotated integer
+    RotateRight,
+    Div,
+    Rem,
+    SignedGt,
+    SignedGe,
+    SignedLt,
+    SignedLe,
+    SignedMin,
+    SignedMax,
+    SignedDiv,
+    SignedRem,
+    /// Absolute value of a signed integer
+    Abs,
+}
+
+/// The cost of an operation, in terms of PBS
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PbsCost {
+    /// The total number of PBS
+    pub pbs_count: u64,
+    /// The number of PBS which have to be done one after the other, that is the latency of the
+    /// operation in PBS, given enough threads
+    pub pbs_depth: u64,
+    /// The total number of keyswitches
+    pub keyswitch_count: u64,
+}
+
+impl PbsCost {
+    /// The cost of `pbs_count` independent PBS
+    fn layer(pbs_count: u64) -> Self {
+        Self {
+            pbs_count,
+            pbs_depth: u64::from(pbs_count != 0),
+            keyswitch_count: pbs_count,
+        }
+    }
+
+    /// The cost of doing `times` times the same work in parallel
+    fn in_parallel(self, times: u64) -> Self {
+        Self {
+            pbs_count: self.pbs_count * times,
+            pbs_depth: if times == 0 { 0 } else { self.pbs_depth },
+            keyswitch_count: self.keyswitch_count * times,
+        }
+    }
+
+    /// The cost of doing two pieces of work in parallel
+    fn join(self, other: Self) -> Self {
+        Self {
+            pbs_count: self.pbs_count + other.pbs_count,
+            pbs_depth: self.pbs_depth.max(other.pbs_depth),
+            keyswitch_count: self.keyswitch_count + other.keyswitch_count,
+        }
+    }
+}
+
+/// The cost of doing two pieces of work one after the other
+impl Add for PbsCost {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            pbs_count: self.pbs_count + rhs.pbs_count,
+            pbs_depth: self.pbs_depth + rhs.pbs_depth,
+            keyswitch_count: self.keyswitch_count + rhs.keyswitch_count,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Estimates the cost of an operation on radix ciphertexts of `num_blocks` blocks, without
+    /// executing it
+    ///
+    /// `operands_are_clean` tells whether the carries of the encrypted operands are empty. If
+    /// they are not, each block is expected to hold at most the carry of one addition, like the
+    /// result of [Self::unchecked_add] on clean ciphertexts.
+    ///
+    /// The estimate is exact for ciphertexts which are not trivial. As some algorithms are
+    /// chosen depending on the number of threads of the current rayon thread pool, it has to be
+    /// made in the thread pool which is going to run the operation.
+    ///
+    /// Returns an error if `num_blocks` is 0, or if the parameters do not have at least 4 bits
+    /// per block with a carry modulus greater than or equal to the message modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::IntegerOperation;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 8;
+    /// let (_cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let cost = sks
+    ///     .estimate_pbs_cost(IntegerOperation::BitAnd, num_blocks, true)
+    ///     .unwrap();
+    /// // One PBS per block, all done in parallel
+    /// assert_eq!(cost.pbs_count, 8);
+    /// assert_eq!(cost.pbs_depth, 1);
+    ///
+    /// // The carries of the operands have to be propagated first
+    /// let cost = sks
+    ///     .estimate_pbs_cost(IntegerOperation::BitAnd, num_blocks, false)
+    ///     .unwrap();
+    /// assert!(cost.pbs_count > 8);
+    /// ```
+    pub fn estimate_pbs_cost(
+        &self,
+        operation: IntegerOperation,
+        num_blocks: usize,
+        operands_are_clean: bool,
+    ) -> crate::Result<PbsCost> {
+        let message_modulus = self.key.message_modulus.0;
+        let carry_modulus = self.key.carry_modulus.0;
+        if num_blocks == 0 {
+            return Err(crate::Error::new(
+                "The number of blocks must not be 0".to_string(),
+            ));
+        }
+        if carry_modulus < message_modulus || message_modulus * carry_modulus < 16 {
+            return Err(crate::Error::new(format!(
+                "The cost can only be estimated for parameters with at least 4 bits per block \
+                and a carry modulus greater than or equal to the message modulus, \
+                got a message modulus of {message_modulus} and a carry modulus of {carry_modulus}"
+            )));
+        }
+
+        let carry_propagation = self.carry_propagation_cost(num_blocks);
+        // Dirty operands are propagated in parallel, before the operation itself
+        let propagate_operands = |num_operands: u64| {
+            if operands_are_clean {
+                PbsCost::default()
+            } else {
+                carry_propagation.in_parallel(num_operands)
+            }
+        };
+
+        let num_blocks_u64 = num_blocks as u64;
+        let cost = match operation {
+            IntegerOperation::FullPropagate | IntegerOperation::BitNot => propagate_operands(1),
+            IntegerOperation::Add | IntegerOperation::Sub => {
+                propagate_operands(2) + carry_propagation
+            }
+            IntegerOperation::Neg | IntegerOperation::ScalarAdd | IntegerOperation::ScalarSub => {
+                propagate_operands(1) + carry_propagation
+            }
+            IntegerOperation::BitAnd | IntegerOperation::BitOr | IntegerOperation::BitXor => {
+                propagate_operands(2) + PbsCost::layer(num_blocks_u64)
+            }
+            IntegerOperation::Eq | IntegerOperation::Ne => {
+                propagate_operands(2)
+                    + PbsCost::layer(num_blocks_u64)
+                    + self.boolean_reduction_cost(num_blocks)
+            }
+            IntegerOperation::Gt
+            | IntegerOperation::Ge
+            | IntegerOperation::Lt
+            | IntegerOperation::Le => propagate_operands(2) + Self::comparison_cost(num_blocks),
+            IntegerOperation::SignedGt
+            | IntegerOperation::SignedGe
+            | IntegerOperation::SignedLt
+            | IntegerOperation::SignedLe => {
+                propagate_operands(2) + Self::signed_comparison_cost(num_blocks)
+            }
+            IntegerOperation::Min | IntegerOperation::Max => {
+                propagate_operands(2)
+                    + Self::comparison_cost(num_blocks)
+                    + Self::if_then_else_cost(num_blocks)
+            }
+            IntegerOperation::SignedMin | IntegerOperation::SignedMax => {
+                propagate_operands(2)
+                    + Self::signed_comparison_cost(num_blocks)
+                    + Self::if_then_else_cost(num_blocks)
+            }
+            IntegerOperation::IfThenElse => {
+                propagate_operands(2) + Self::if_then_else_cost(num_blocks)
+            }
+            IntegerOperation::Mul => propagate_operands(2) + self.mul_cost(num_blocks),
+            IntegerOperation::Shl
+            | IntegerOperation::Shr
+            | IntegerOperation::RotateLeft
+            | IntegerOperation::RotateRight => {
+                propagate_operands(2) + self.barrel_shifter_cost(num_blocks)
+            }
+            // The quotient and the remainder are always both computed
+            IntegerOperation::Div | IntegerOperation::Rem => {
+                propagate_operands(2) + self.div_rem_cost(num_blocks)
+            }
+            IntegerOperation::SignedDiv | IntegerOperation::SignedRem => {
+                propagate_operands(2) + self.signed_div_rem_cost(num_blocks)
+            }
+            IntegerOperation::Abs => propagate_operands(1) + self.abs_cost(num_blocks),
+        };
+
+        Ok(cost)
+    }
+
+    /// Cost of propagating the carries of blocks which hold at most one carry each
+    fn carry_propagation_cost(&self, num_blocks: usize) -> PbsCost {
+        if self.is_eligible_for_parallel_single_carry_propagation_of_blocks(num_blocks) {
+            Self::hillis_steele_carry_propagation_cost(num_blocks)
+        } else {
+            Self::sequential_carry_propagation_cost(num_blocks)
+        }
+    }
+
+    fn hillis_steele_carry_propagation_cost(num_blocks: usize) -> PbsCost {
+        if num_blocks == 0 {
+            return PbsCost::default();
+        }
+        // Whether each block generates or propagates a carry,
+        // then the Hillis-Steele prefix sum, then the carries are added and cleaned
+        let mut cost = PbsCost::layer(num_blocks as u64);
+        let mut space = 1;
+        for _ in 0..num_blocks.ceil_ilog2() {
+            cost = cost + PbsCost::layer((num_blocks - space) as u64);
+            space *= 2;
+        }
+        cost + PbsCost::layer(num_blocks as u64)
+    }
+
+    fn sequential_carry_propagation_cost(num_blocks: usize) -> PbsCost {
+        // The carry and message of each block are extracted in parallel,
+        // one block after the other
+        PbsCost {
+            pbs_count: 2 * num_blocks as u64,
+            pbs_depth: num_blocks as u64,
+            keyswitch_count: 2 * num_blocks as u64,
+        }
+    }
+
+    /// Cost of [Self::full_propagate_parallelized] on blocks of the given degrees, which may hold
+    /// more than one carry
+    fn full_propagation_cost(&self, degrees: &[u64]) -> PbsCost {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let num_blocks = degrees.len();
+        let Some(start_index) = degrees.iter().position(|degree| *degree >= message_modulus) else {
+            return PbsCost::default();
+        };
+        let num_dirty_blocks = num_blocks - start_index;
+        // Message and carry of the blocks, except the carry of the last one
+        let extraction = PbsCost::layer(2 * num_dirty_blocks as u64 - 1);
+
+        if self.is_eligible_for_parallel_single_carry_propagation_of_blocks(num_blocks) {
+            let highest_degree = degrees[start_index..].iter().max().copied().unwrap();
+            if highest_degree <= 2 * (message_modulus - 1) {
+                Self::hillis_steele_carry_propagation_cost(num_dirty_blocks)
+            } else {
+                extraction + Self::hillis_steele_carry_propagation_cost(num_dirty_blocks - 1)
+            }
+        } else {
+            let max_degree = self.key.max_degree.get() as u64;
+            if degrees[start_index + 1..]
+                .iter()
+                .any(|degree| *degree > max_degree)
+            {
+                extraction + Self::sequential_carry_propagation_cost(num_dirty_blocks - 1)
+            } else {
+                Self::sequential_carry_propagation_cost(num_dirty_blocks)
+            }
+        }
+    }
+
+    /// Cost of a multiplication of clean integers
+    fn mul_cost(&self, num_blocks: usize) -> PbsCost {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let carry_modulus = self.key.carry_modulus.0 as u64;
+
+        if message_modulus == 2 && num_blocks == 1 {
+            // The operands are booleans, one of them is zeroed depending on the other
+            return PbsCost::layer(1);
+        }
+
+        // The product of each block of the rhs with the blocks of the lhs, for the blocks of
+        // the result which are kept: its message part, and its carry part when the product of
+        // two blocks can exceed the message modulus
+        let message_degree = message_modulus - 1;
+        let carry_part_degree = (message_degree * message_degree) / message_modulus;
+        let num_terms = if message_modulus > 2 {
+            2 * num_blocks - 1
+        } else {
+            num_blocks
+        };
+        let num_block_products = if message_modulus > 2 {
+            num_blocks * num_blocks
+        } else {
+            num_blocks * (num_blocks + 1) / 2
+        };
+        let terms = PbsCost::layer(num_block_products as u64);
+
+        if num_terms == 1 {
+            // The single block product is the result
+            return terms;
+        }
+        if num_terms == 2 {
+            return terms + self.carry_propagation_cost(num_blocks);
+        }
+
+        // Columns of the degrees of the blocks to sum, in the order the terms are summed:
+        // block j receives the message parts of the products by the rhs blocks 0..=j,
+        // then the carry parts of the products by the rhs blocks 0..j
+        let mut columns = (0..num_blocks)
+            .map(|j| {
+                let mut column = vec![message_degree; j + 1];
+                if message_modulus > 2 {
+                    column.extend(std::iter::repeat(carry_part_degree).take(j));
+                }
+                column
+            })
+            .collect::<Vec<_>>();
+
+        // Blocks of a column are summed by chunks which fill the carry space,
+        // then the message and carry of each chunk are extracted
+        let chunk_size = ((message_modulus * carry_modulus - 1) / message_degree) as usize;
+        let carry_degree = (message_modulus * carry_modulus - 1) / message_modulus;
+        let mut sum = PbsCost::default();
+        while columns.iter().any(|column| column.len() > chunk_size) {
+            let num_chunks = columns
+                .iter()
+                .map(|column| column.len() / chunk_size)
+                .collect::<Vec<_>>();
+
+            // The blocks which do not fill a chunk are kept, then come the carries of the
+            // previous column, then the messages of the column
+            for (j, column) in columns.iter_mut().enumerate() {
+                column.drain(..num_chunks[j] * chunk_size);
+                if j > 0 {
+                    column.extend(std::iter::repeat(carry_degree).take(num_chunks[j - 1]));
+                }
+                column.extend(std::iter::repeat(message_degree).take(num_chunks[j]));
+            }
+
+            // There is no carry to extract from the last column
+            let num_pbs = 2 * num_chunks.iter().sum::<usize>() - num_chunks[num_blocks - 1];
+            sum = sum + PbsCost::layer(num_pbs as u64);
+        }
+
+        let degrees = columns
+            .iter()
+            .map(|column| column.iter().sum::<u64>())
+            .collect::<Vec<_>>();
+        terms + sum + self.full_propagation_cost(&degrees)
+    }
+
+    /// Cost of a shift or rotation of a clean integer by a clean amount of the same number of
+    /// blocks
+    fn barrel_shifter_cost(&self, num_blocks: usize) -> PbsCost {
+        let num_bits = u64::from(self.key.message_modulus.0.ilog2()) * num_blocks as u64;
+        let num_shift_bits = u64::from(num_bits.ilog2()) + u64::from(!num_bits.is_power_of_two());
+
+        // All the bits of the integer and the bits of the shift amount are extracted,
+        // then each bit of the shift amount controls a layer of multiplexers,
+        // then the bits are packed back into blocks
+        let mut cost = PbsCost::layer(num_bits + num_shift_bits);
+        for _ in 0..num_shift_bits {
+            cost = cost + PbsCost::layer(num_bits);
+        }
+        cost + PbsCost::layer(num_blocks as u64)
+    }
+
+    /// Cost of a division of clean unsigned integers, following the long division of
+    /// [ServerKey::div_rem_parallelized], which computes one bit of the quotient per iteration
+    fn div_rem_cost(&self, num_blocks: usize) -> PbsCost {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        let blocks_per_zero_comparison = (total_modulus - 1) / (self.key.message_modulus.0 - 1);
+
+        let mut cost = PbsCost::default();
+        for msb_bit_set in 0..bits_per_block * num_blocks {
+            // Only the low blocks of the remainder can be non zero so far
+            let num_remainder_blocks = msb_bit_set / bits_per_block + 1;
+            let splits_a_block = (msb_bit_set + 1) % bits_per_block != 0;
+            let num_divisor_high_blocks = num_blocks - (msb_bit_set + 1) / bits_per_block;
+
+            // The divisor block split by the current bit is trimmed twice (for its low and high
+            // parts) while both parts of the remainder are shifted by one bit, the first one
+            // with an extra block holding the next bit of the numerator
+            let trims = PbsCost::layer(if splits_a_block { 2 } else { 0 });
+            let shifts = self
+                .one_bit_left_shift_cost(num_remainder_blocks + 1)
+                .join(self.one_bit_left_shift_cost(num_remainder_blocks));
+
+            // The divisor is subtracted from the remainder, with the same borrow propagation
+            // as the carry propagation of an addition, while the high blocks of the divisor are
+            // compared with 0 and the remainder is cleaned
+            let subtraction = self.carry_propagation_cost(num_remainder_blocks);
+            let high_blocks_are_zero = if num_divisor_high_blocks == 0 {
+                PbsCost::default()
+            } else {
+                let num_comparisons = num_divisor_high_blocks.div_ceil(blocks_per_zero_comparison);
+                PbsCost::layer(num_comparisons as u64)
+                    + self.boolean_reduction_cost(num_comparisons)
+            };
+            let clean_remainder = PbsCost::layer(num_remainder_blocks as u64);
+
+            // Either the new or the previous remainder is zeroed out, and the quotient bit is set
+            let select = PbsCost::layer(2 * num_remainder_blocks as u64 + 1);
+
+            cost = cost
+                + trims.join(shifts)
+                + subtraction.join(high_blocks_are_zero).join(clean_remainder)
+                + select;
+        }
+
+        // The two parts of the remainder are summed, then it is cleaned along the quotient
+        cost + PbsCost::layer(2 * num_blocks as u64)
+    }
+
+    /// Cost of a division of clean signed integers, done on their absolute values
+    fn signed_div_rem_cost(&self, num_blocks: usize) -> PbsCost {
+        let abs = self.abs_cost(num_blocks);
+        // The sign of the quotient is computed along the unsigned division,
+        // then the quotient and the remainder are negated depending on the signs
+        let fix_sign =
+            self.carry_propagation_cost(num_blocks) + Self::if_then_else_cost(num_blocks);
+        abs.join(abs)
+            + self.div_rem_cost(num_blocks).join(PbsCost::layer(1))
+            + fix_sign.join(fix_sign)
+    }
+
+    /// Cost of the absolute value of a clean signed integer
+    fn abs_cost(&self, num_blocks: usize) -> PbsCost {
+        // A mask of the sign bit is created with an arithmetic shift, added to the value,
+        // then xored with the sum
+        let sign_mask = if self.key.message_modulus.0 == 2 {
+            // The sign bit already is a block
+            PbsCost::default()
+        } else {
+            PbsCost::layer(2)
+        };
+        sign_mask + self.carry_propagation_cost(num_blocks) + PbsCost::layer(num_blocks as u64)
+    }
+
+    /// Cost of a left shift by one bit of a clean integer
+    fn one_bit_left_shift_cost(&self, num_blocks: usize) -> PbsCost {
+        if self.key.message_modulus.0 == 2 {
+            // The blocks are only rotated
+            PbsCost::default()
+        } else {
+            // Each block gets the bit shifted out of the previous block
+            PbsCost::layer(num_blocks as u64)
+        }
+    }
+
+    /// Cost of reducing blocks encrypting booleans into a block which tells whether they are
+    /// all true (or if any is true)
+    fn boolean_reduction_cost(&self, num_blocks: usize) -> PbsCost {
+        let max_value = self.key.message_modulus.0 * self.key.carry_modulus.0 - 1;
+
+        let mut cost = PbsCost::default();
+        let mut num_blocks = num_blocks;
+        while num_blocks > 1 {
+            num_blocks = num_blocks.div_ceil(max_value);
+            cost = cost + PbsCost::layer(num_blocks as u64);
+        }
+        cost
+    }
+
+    /// Cost of an ordering comparison, blocks being packed two by two
+    fn comparison_cost(num_blocks: usize) -> PbsCost {
+        let num_packed_blocks = num_blocks.div_ceil(2);
+        Self::packed_blocks_comparison_cost(num_packed_blocks)
+            + Self::sign_reduction_cost(num_packed_blocks)
+    }
+
+    /// Cost of an ordering comparison of signed integers: the blocks holding the sign bits are
+    /// compared on their own, while the other blocks are packed two by two
+    fn signed_comparison_cost(num_blocks: usize) -> PbsCost {
+        let num_packed_blocks = (num_blocks - 1).div_ceil(2);
+        Self::packed_blocks_comparison_cost(num_packed_blocks).join(PbsCost::layer(1))
+            + Self::sign_reduction_cost(num_packed_blocks + 1)
+    }
+
+    fn packed_blocks_comparison_cost(num_packed_blocks: usize) -> PbsCost {
+        // Packed blocks are cleaned, then compared
+        PbsCost::layer(2 * num_packed_blocks as u64) + PbsCost::layer(num_packed_blocks as u64)
+    }
+
+    /// Cost of reducing the results of the comparisons of blocks into one
+    fn sign_reduction_cost(num_signs: usize) -> PbsCost {
+        // The signs are reduced two by two, the last two (or the last one) in one final PBS
+        let mut cost = PbsCost::default();
+        let mut num_signs = num_signs;
+        while num_signs > 2 {
+            cost = cost + PbsCost::layer((num_signs / 2) as u64);
+            num_signs = num_signs.div_ceil(2);
+        }
+        cost + PbsCost::layer(1)
+    }
+
+    /// Cost of selecting between two clean integers, with a clean condition
+    fn if_then_else_cost(num_blocks: usize) -> PbsCost {
+        // Both integers are zeroed depending on the condition, then summed and cleaned
+        PbsCost::layer(2 * num_blocks as u64) + PbsCost::layer(num_blocks as u64)
+    }
+}
@@ -0,0 +1,355 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+
+impl ServerKey {
+    /// Extracts each bit of the exponent into its own boolean block, starting from the LSB
+    fn exponent_bits(&self, exponent: &RadixCiphertext) -> Vec<BooleanBlock> {
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        BitExtractor::new(self, bits_per_block)
+            .extract_all_bits(&exponent.blocks)
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect()
+    }
+
+    /// Square-and-multiply over the encrypted bits of the exponent
+    ///
+    /// `mul` is used for every multiplication, its boolean output
+    /// (if any) is accumulated into the returned flag.
+    pub(super) fn encrypted_exponent_pow<T, F>(
+        &self,
+        base: &T,
+        exponent: &RadixCiphertext,
+        mul: F,
+    ) -> (T, Option<BooleanBlock>)
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> (T, Option<BooleanBlock>) + Sync,
+    {
+        let num_blocks = base.blocks().len();
+        let exponent_bits = self.exponent_bits(exponent);
+        let one: T = self.create_trivial_radix(1u64, num_blocks);
+
+        let or = |lhs: Option<BooleanBlock>, rhs: Option<BooleanBlock>| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Some(self.boolean_bitor(&lhs, &rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        };
+
+        // `None` means the trivial 1, this saves the first multiplication
+        let mut result: Option<T> = None;
+        let mut flag = None;
+        // base^(2^i) and whether computing it set the flag
+        let mut base_pow = base.clone();
+        let mut base_pow_flag: Option<BooleanBlock> = None;
+
+        for (i, bit) in exponent_bits.iter().enumerate() {
+            let is_last_bit = i == exponent_bits.len() - 1;
+
+            let (new_result, next_base_pow) = rayon::join(
+                || {
+                    // Multiply by 1 when the bit is not set
+                    let factor = self.if_then_else_parallelized(bit, &base_pow, &one);
+                    match &result {
+                        Some(result) => mul(result, &factor),
+                        None => (factor, None),
+                    }
+                },
+                || (!is_last_bit).then(|| mul(&base_pow, &base_pow)),
+            );
+
+            // A power of the base that set the flag only matters if it is used
+            let used_pow_flag = base_pow_flag
+                .as_ref()
+                .map(|pow_flag| self.boolean_bitand(pow_flag, bit));
+            flag = or(or(flag, new_result.1), used_pow_flag);
+            result = Some(new_result.0);
+
+            if let Some((next_base_pow, next_flag)) = next_base_pow {
+                base_pow = next_base_pow;
+                base_pow_flag = or(base_pow_flag, next_flag);
+            }
+        }
+
+        (result.unwrap_or(one), flag)
+    }
+
+    //==============================================================================================
+    //  Unchecked
+    //==============================================================================================
+
+    /// See [Self::pow_parallelized]
+    ///
+    /// Expects the inputs to have clean carries
+    pub fn unchecked_pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.encrypted_exponent_pow(base, exponent, |lhs, rhs| {
+            (self.unchecked_mul_parallelized(lhs, rhs), None)
+        })
+        .0
+    }
+
+    /// See [Self::scalar_pow_parallelized]
+    ///
+    /// Expects the base to have clean carries
+    pub fn unchecked_scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        // `None` means the trivial 1, this saves the first multiplication
+        let mut result: Option<T> = None;
+        // Powers of the base are only computed once, and only up to the last bit set
+        let mut base_pow = base.clone();
+
+        let mut remaining_bits = exponent;
+        while remaining_bits != 0 {
+            let bit_is_set = remaining_bits & 1 == 1;
+            remaining_bits >>= 1;
+
+            let (new_result, next_base_pow) = rayon::join(
+                || match &result {
+                    Some(result) if bit_is_set => {
+                        Some(self.unchecked_mul_parallelized(result, &base_pow))
+                    }
+                    None if bit_is_set => Some(base_pow.clone()),
+                    _ => None,
+                },
+                || {
+                    (remaining_bits != 0)
+                        .then(|| self.unchecked_mul_parallelized(&base_pow, &base_pow))
+                },
+            );
+
+            if new_result.is_some() {
+                result = new_result;
+            }
+            if let Some(next_base_pow) = next_base_pow {
+                base_pow = next_base_pow;
+            }
+        }
+
+        result.unwrap_or_else(|| self.create_trivial_radix(1u64, base.blocks().len()))
+    }
+
+    //==============================================================================================
+    //  Default
+    //==============================================================================================
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// The result wraps around on overflow (like `wrapping_pow`).
+    /// The exponent is always unsigned, and may have a different number of blocks than the base.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let base = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(exponent as u32));
+    /// ```
+    pub fn pow_parallelized<T>(&self, base: &T, exponent: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.with_clean_pow_inputs(base, exponent, |base, exponent| {
+            self.unchecked_pow_parallelized(base, exponent)
+        })
+    }
+
+    /// Computes homomorphically `base` raised to the power of a clear `exponent`
+    ///
+    /// The result wraps around on overflow (like `wrapping_pow`).
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let base = -3i8;
+    ///
+    /// let ct_base = cks.encrypt_signed(base);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct_base, 5);
+    ///
+    /// // Decrypt:
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(res, base.wrapping_pow(5));
+    /// ```
+    pub fn scalar_pow_parallelized<T>(&self, base: &T, exponent: u32) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let base = if base.block_carries_are_empty() {
+            base
+        } else {
+            tmp = base.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+        self.unchecked_scalar_pow_parallelized(base, exponent)
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// Returns the wrapped result, and a boolean block that encrypts `true`
+    /// if the computation overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let base = 3u8;
+    /// let exponent = 6u8;
+    ///
+    /// let ct_base = cks.encrypt(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let (ct_res, overflowed) = sks.unsigned_checked_pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// let overflowed = cks.decrypt_bool(&overflowed);
+    /// assert_eq!(res, base.wrapping_pow(exponent as u32));
+    /// assert!(overflowed);
+    /// ```
+    pub fn unsigned_checked_pow_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        let (result, overflowed) = self.with_clean_pow_inputs(base, exponent, |base, exponent| {
+            self.encrypted_exponent_pow(base, exponent, |lhs, rhs| {
+                let (result, overflowed) = self.unsigned_overflowing_mul_parallelized(lhs, rhs);
+                (result, Some(overflowed))
+            })
+        });
+        (
+            result,
+            overflowed.unwrap_or_else(|| self.create_trivial_boolean_block(false)),
+        )
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// Returns the wrapped result, and a boolean block that encrypts `true`
+    /// if the computation overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, num_blocks);
+    ///
+    /// let base = -2i8;
+    /// let exponent = 7u8;
+    ///
+    /// let ct_base = cks.encrypt_signed(base);
+    /// let ct_exponent = cks.encrypt(exponent);
+    ///
+    /// let (ct_res, overflowed) = sks.signed_checked_pow_parallelized(&ct_base, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let res: i8 = cks.decrypt_signed(&ct_res);
+    /// let overflowed = cks.decrypt_bool(&overflowed);
+    /// assert_eq!(res, -128);
+    /// assert!(!overflowed);
+    /// ```
+    pub fn signed_checked_pow_parallelized(
+        &self,
+        base: &SignedRadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> (SignedRadixCiphertext, BooleanBlock) {
+        let (result, overflowed) = self.with_clean_pow_inputs(base, exponent, |base, exponent| {
+            self.encrypted_exponent_pow(base, exponent, |lhs, rhs| {
+                let (result, overflowed) = self.signed_overflowing_mul_parallelized(lhs, rhs);
+                (result, Some(overflowed))
+            })
+        });
+        (
+            result,
+            overflowed.unwrap_or_else(|| self.create_trivial_boolean_block(false)),
+        )
+    }
+
+    /// Calls `f` with versions of `base` and `exponent` that have clean carries
+    pub(super) fn with_clean_pow_inputs<T, R, F>(
+        &self,
+        base: &T,
+        exponent: &RadixCiphertext,
+        f: F,
+    ) -> R
+    where
+        T: IntegerRadixCiphertext,
+        F: FnOnce(&T, &RadixCiphertext) -> R,
+    {
+        let mut tmp_base: T;
+        let mut tmp_exponent: RadixCiphertext;
+
+        let (base, exponent) = match (
+            base.block_carries_are_empty(),
+            exponent.block_carries_are_empty(),
+        ) {
+            (true, true) => (base, exponent),
+            (true, false) => {
+                tmp_exponent = exponent.clone();
+                self.full_propagate_parallelized(&mut tmp_exponent);
+                (base, &tmp_exponent)
+            }
+            (false, true) => {
+                tmp_base = base.clone();
+                self.full_propagate_parallelized(&mut tmp_base);
+                (&tmp_base, exponent)
+            }
+            (false, false) => {
+                tmp_base = base.clone();
+                tmp_exponent = exponent.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_base),
+                    || self.full_propagate_parallelized(&mut tmp_exponent),
+                );
+                (&tmp_base, &tmp_exponent)
+            }
+        };
+
+        f(base, exponent)
+    }
+}
@@ -0,0 +1,865 @@
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::server_key::radix_parallel::add::{prefix_sum_carry_propagation, OutputCarry};
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::BivariateLookupTableOwned;
+use crate::shortint::{Ciphertext, MessageModulus};
+use rayon::prelude::*;
+
+/// How the last block of an addition makes the result go past the bounds,
+/// depending on the carry it receives
+#[repr(u64)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LastBlockSaturation {
+    /// The result stays within the bounds
+    None = 0,
+    /// The result is greater than the maximum
+    Max = 1,
+    /// The result is greater than the maximum if the last block receives a carry
+    MaxIfCarry = 2,
+    /// The result is smaller than the minimum
+    Min = 3,
+    /// The result is smaller than the minimum if the last block does not receive a carry
+    MinIfNoCarry = 4,
+}
+
+impl LastBlockSaturation {
+    fn from_u64(value: u64) -> Self {
+        match value {
+            1 => Self::Max,
+            2 => Self::MaxIfCarry,
+            3 => Self::Min,
+            4 => Self::MinIfNoCarry,
+            // Other values are never encrypted, they only exist in the lookup tables
+            _ => Self::None,
+        }
+    }
+
+    /// `without_carry` and `with_carry` are either None, Max or Min
+    fn from_outcomes(without_carry: Self, with_carry: Self) -> Self {
+        match (without_carry, with_carry) {
+            (Self::None, Self::Max) => Self::MaxIfCarry,
+            (Self::Min, Self::None) => Self::MinIfNoCarry,
+            (outcome, _) => outcome,
+        }
+    }
+
+    fn resolve(self, carry: bool) -> Self {
+        match (self, carry) {
+            (Self::MaxIfCarry, true) => Self::Max,
+            (Self::MinIfNoCarry, false) => Self::Min,
+            (Self::MaxIfCarry | Self::MinIfNoCarry, _) => Self::None,
+            (state, _) => state,
+        }
+    }
+
+    /// Returns 0 for None, 1 for Max and 2 for Min, times the scale
+    fn scaled(self, scale: u64) -> u64 {
+        match self {
+            Self::Max => scale,
+            Self::Min => 2 * scale,
+            _ => 0,
+        }
+    }
+}
+
+impl ServerKey {
+    /// Returns whether the saturation can be done in the same pass as the carry propagation
+    ///
+    /// The last layer of the propagation adds to the message of each block its input carry
+    /// and the saturation (0, 1 or 2 times `message_modulus + 1`), so the parameters need
+    /// room for `3 * message_modulus + 2` and for a noise of 4 (the unsigned multiplication
+    /// adds a second saturation flag).
+    fn is_eligible_for_fused_saturation(&self) -> bool {
+        let message_modulus = self.key.message_modulus.0;
+        let carry_modulus = self.key.carry_modulus.0;
+        message_modulus >= 4 && carry_modulus >= 4 && self.key.max_noise_level.get() >= 4
+    }
+
+    fn saturation_scale(&self) -> u64 {
+        self.key.message_modulus.0 as u64 + 1
+    }
+
+    /// Propagates the carries of `sum` and saturates it, in a single pass
+    ///
+    /// - `sum` must be the block-wise sum of two clean ciphertexts, so that each block has at
+    ///   most one carry
+    /// - `last_state` computes, from the last block of `sum`, the [LastBlockSaturation] of the
+    ///   last block
+    /// - `single_block_carry` is the carry the last block receives when it is the only block
+    /// - `forced_saturation` may compute a block encrypting 0 or `saturation_scale()` times
+    ///   the saturation (1 for MAX, 2 for MIN) to apply whatever the carries are. It is
+    ///   computed in parallel to the carry propagation.
+    /// - `bound_block(is_last_block, saturation)` returns a block of the bound the result
+    ///   saturates to, the saturation is 1 for MAX and 2 for MIN, when `forced_saturation` is
+    ///   used it is the sum of both saturations.
+    ///
+    /// The carries are propagated using the Hillis and Steele prefix scan, as in
+    /// [Self::compute_carry_propagation_parallelized_low_latency], except that the state of
+    /// the last block tracks how the result goes past the bounds instead of its output
+    /// carry. The last step of the scan turns this state into the saturation, which is then
+    /// added with the input carry of each block in the last layer, so saturating costs no
+    /// additional layer of PBS.
+    fn propagate_and_saturate<T, L, S, B>(
+        &self,
+        sum: &mut T,
+        last_state: L,
+        single_block_carry: u64,
+        forced_saturation: S,
+        bound_block: B,
+    ) where
+        T: IntegerRadixCiphertext,
+        L: FnOnce(&Ciphertext) -> Ciphertext + Send,
+        S: FnOnce() -> Option<Ciphertext> + Send,
+        B: Fn(bool, u64) -> u64 + Sync,
+    {
+        let Some((last_block, blocks)) = sum.blocks().split_last() else {
+            return;
+        };
+        let num_blocks = sum.blocks().len();
+
+        let (messages, (states, last_state)) = rayon::join(
+            || {
+                sum.blocks()
+                    .par_iter()
+                    .map(|block| self.key.message_extract(block))
+                    .collect::<Vec<_>>()
+            },
+            || {
+                rayon::join(
+                    || self.generate_init_carry_array(blocks),
+                    || last_state(last_block),
+                )
+            },
+        );
+
+        let ((input_carries, saturation), forced_saturation) = rayon::join(
+            || self.compute_carries_and_saturation(states, last_state, single_block_carry),
+            forced_saturation,
+        );
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let scale = self.saturation_scale();
+        let [lut, last_block_lut] = [false, true].map(|is_last_block| {
+            self.key.generate_lookup_table(|x| {
+                let saturation = x / scale;
+                if saturation == 0 {
+                    (x % scale) % message_modulus
+                } else {
+                    bound_block(is_last_block, saturation)
+                }
+            })
+        });
+
+        sum.blocks_mut()
+            .par_iter_mut()
+            .zip(messages)
+            .enumerate()
+            .for_each(|(i, (block, message))| {
+                *block = message;
+                if i > 0 {
+                    self.key.unchecked_add_assign(block, &input_carries[i - 1]);
+                }
+                self.key.unchecked_add_assign(block, &saturation);
+                if let Some(forced_saturation) = forced_saturation.as_ref() {
+                    self.key.unchecked_add_assign(block, forced_saturation);
+                }
+                let lut = if i == num_blocks - 1 {
+                    &last_block_lut
+                } else {
+                    &lut
+                };
+                self.key.apply_lookup_table_assign(block, lut);
+            });
+    }
+
+    /// Returns the output carries of all the blocks but the last one,
+    /// and the saturation (times `saturation_scale()`) resolved from `last_state`
+    fn compute_carries_and_saturation(
+        &self,
+        mut states: Vec<Ciphertext>,
+        mut last_state: Ciphertext,
+        single_block_carry: u64,
+    ) -> (Vec<Ciphertext>, Ciphertext) {
+        let scale = self.saturation_scale();
+        let carry_lut = self
+            .key
+            .generate_lookup_table_bivariate(prefix_sum_carry_propagation);
+        // The last state has 5 possible values, so it is packed with the state of the
+        // previous blocks (3 possible values) using a factor 3 rather than the message modulus
+        let [combine_lut, resolve_lut] =
+            [false, true].map(|is_last_step| BivariateLookupTableOwned {
+                acc: self.key.generate_lookup_table(|x| {
+                    let state = LastBlockSaturation::from_u64(x / 3);
+                    let state = match x % 3 {
+                        x if x == OutputCarry::None as u64 => state.resolve(false),
+                        x if x == OutputCarry::Generated as u64 => state.resolve(true),
+                        _ => state,
+                    };
+                    if is_last_step {
+                        state.scaled(scale)
+                    } else {
+                        state as u64
+                    }
+                }),
+                ct_right_modulus: MessageModulus(3),
+            });
+
+        let num_blocks = states.len() + 1;
+        if num_blocks == 1 {
+            let carry = self.key.create_trivial(single_block_carry);
+            self.key.unchecked_apply_lookup_table_bivariate_assign(
+                &mut last_state,
+                &carry,
+                &resolve_lut,
+            );
+            return (states, last_state);
+        }
+
+        let num_steps = num_blocks.ceil_ilog2();
+        let mut space = 1;
+        for step in 0..num_steps {
+            let last_state_lut = if step == num_steps - 1 {
+                &resolve_lut
+            } else {
+                &combine_lut
+            };
+
+            let mut step_output = states.clone();
+            rayon::join(
+                || {
+                    step_output[space..]
+                        .par_iter_mut()
+                        .zip(states.par_iter())
+                        .for_each(|(state, previous_state)| {
+                            self.key.unchecked_apply_lookup_table_bivariate_assign(
+                                state,
+                                previous_state,
+                                &carry_lut,
+                            );
+                            // The lut does not know that the states are at most 2
+                            state.degree = Degree::new(OutputCarry::Propagated as usize);
+                        });
+                },
+                || {
+                    self.key.unchecked_apply_lookup_table_bivariate_assign(
+                        &mut last_state,
+                        &states[num_blocks - 1 - space],
+                        last_state_lut,
+                    );
+                },
+            );
+            states = step_output;
+            space *= 2;
+        }
+
+        (states, last_state)
+    }
+
+    /// Adds or subtracts (depending on `bound`, Max or Min) two clean unsigned ciphertexts,
+    /// saturating the result
+    fn unsigned_saturating_add_or_sub<S>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        bound: LastBlockSaturation,
+        forced_saturation: S,
+    ) -> RadixCiphertext
+    where
+        S: FnOnce() -> Option<Ciphertext> + Send,
+    {
+        let mut result = lhs.clone();
+        if bound == LastBlockSaturation::Max {
+            self.unchecked_add_assign(&mut result, rhs);
+        } else {
+            // The subtraction is an addition of the negation, for clean blocks this is
+            // lhs + bit_flip(rhs) + 1, with the +1 in the first block
+            self.unchecked_sub_assign(&mut result, rhs);
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let outcome = |value: u64| match bound {
+            LastBlockSaturation::Max if value >= message_modulus => bound,
+            LastBlockSaturation::Min if value < message_modulus => bound,
+            _ => LastBlockSaturation::None,
+        };
+        let last_state_lut = self.key.generate_lookup_table(|last_block| {
+            LastBlockSaturation::from_outcomes(outcome(last_block), outcome(last_block + 1)) as u64
+        });
+
+        let bound_block = if bound == LastBlockSaturation::Max {
+            message_modulus - 1
+        } else {
+            0
+        };
+        self.propagate_and_saturate(
+            &mut result,
+            |last_block| self.key.apply_lookup_table(last_block, &last_state_lut),
+            0,
+            forced_saturation,
+            |_, _| bound_block,
+        );
+        result
+    }
+
+    /// Adds or subtracts two clean signed ciphertexts, saturating the result
+    fn signed_saturating_add_or_sub(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+        is_sub: bool,
+    ) -> SignedRadixCiphertext {
+        let mut result = lhs.clone();
+        if is_sub {
+            // The subtraction is an addition of the negation, for clean blocks this is
+            // lhs + bit_flip(rhs) + 1, with the +1 in the first block
+            self.unchecked_sub_assign(&mut result, rhs);
+        } else {
+            self.unchecked_add_assign(&mut result, rhs);
+        }
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let half_modulus = (message_modulus / 2) as i64;
+        // The last blocks are seen as signed digits, the result goes past the bounds
+        // when the sum of the digits does not fit in a signed digit
+        let last_state_lut = self
+            .key
+            .generate_lookup_table_bivariate(|lhs_block, rhs_block| {
+                let rhs_block = if is_sub {
+                    message_modulus - 1 - rhs_block
+                } else {
+                    rhs_block
+                };
+                let to_digit = |block: u64| {
+                    let block = block as i64;
+                    if block >= half_modulus {
+                        block - 2 * half_modulus
+                    } else {
+                        block
+                    }
+                };
+                let outcome = |carry: i64| {
+                    let digit = to_digit(lhs_block) + to_digit(rhs_block) + carry;
+                    if digit >= half_modulus {
+                        LastBlockSaturation::Max
+                    } else if digit < -half_modulus {
+                        LastBlockSaturation::Min
+                    } else {
+                        LastBlockSaturation::None
+                    }
+                };
+                LastBlockSaturation::from_outcomes(outcome(0), outcome(1)) as u64
+            });
+
+        self.propagate_and_saturate(
+            &mut result,
+            |_| {
+                self.key.unchecked_apply_lookup_table_bivariate(
+                    lhs.blocks().last().unwrap(),
+                    rhs.blocks().last().unwrap(),
+                    &last_state_lut,
+                )
+            },
+            u64::from(is_sub),
+            || None,
+            |is_last_block, saturation| {
+                signed_bound_block(message_modulus, is_last_block, saturation == 2)
+            },
+        );
+        result
+    }
+
+    /// Replaces the blocks of `ct` by the blocks of a bound when `state` is not 0
+    ///
+    /// `bound_block(is_last_block, state)` returns the value of a block of the bound
+    /// selected by `state`.
+    ///
+    /// The state is packed alongside each block, so this costs a single PBS per block
+    /// (done in parallel), however the state must be smaller than the message modulus.
+    ///
+    /// This is a layer of PBS after the operation, it is only used when the saturation
+    /// cannot be done in the carry propagation, see [Self::propagate_and_saturate].
+    fn saturate_blocks<T, F>(&self, ct: &mut T, state: &Ciphertext, bound_block: F)
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(bool, u64) -> u64,
+    {
+        let Some(last_block_index) = ct.blocks().len().checked_sub(1) else {
+            return;
+        };
+        assert!(state.degree.get() < self.message_modulus().0);
+
+        let [lut, last_block_lut] = [false, true].map(|is_last_block| {
+            self.key
+                .generate_lookup_table_bivariate(|block, state| match state {
+                    0 => block,
+                    state => bound_block(is_last_block, state),
+                })
+        });
+
+        ct.blocks_mut()
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, block)| {
+                let lut = if i == last_block_index {
+                    &last_block_lut
+                } else {
+                    &lut
+                };
+                self.key
+                    .unchecked_apply_lookup_table_bivariate_assign(block, state, lut);
+            });
+    }
+
+    /// Saturates `ct` to MAX if `overflowed` is true and `towards_min` is false,
+    /// and to MIN if both are true
+    fn saturate_signed(
+        &self,
+        ct: &mut SignedRadixCiphertext,
+        overflowed: &BooleanBlock,
+        towards_min: &BooleanBlock,
+    ) {
+        let message_modulus = self.message_modulus().0 as u64;
+        let max_block = |is_last_block| signed_bound_block(message_modulus, is_last_block, false);
+        let min_block = |is_last_block| signed_bound_block(message_modulus, is_last_block, true);
+
+        if message_modulus > 2 {
+            // 0 -> no saturation, 1 -> MAX, 2 -> MIN
+            //
+            // The inputs are booleans, they are masked so that the degree of the state, which
+            // is computed over all the possible inputs, stays below the message modulus
+            let lut = self
+                .key
+                .generate_lookup_table_bivariate(|overflowed, towards_min| {
+                    (overflowed & 1) * (1 + (towards_min & 1))
+                });
+            let state = self.key.unchecked_apply_lookup_table_bivariate(
+                &overflowed.0,
+                &towards_min.0,
+                &lut,
+            );
+
+            self.saturate_blocks(ct, &state, |is_last_block, state| {
+                if state == 1 {
+                    max_block(is_last_block)
+                } else {
+                    min_block(is_last_block)
+                }
+            });
+        } else {
+            // The 3 states do not fit in a block, so first saturate to MAX,
+            // then correct the blocks that should have gone to MIN
+            let ((), to_min) = rayon::join(
+                || {
+                    self.saturate_blocks(ct, &overflowed.0, |is_last_block, _| {
+                        max_block(is_last_block)
+                    })
+                },
+                || self.boolean_bitand(overflowed, towards_min),
+            );
+            self.saturate_blocks(ct, &to_min.0, |is_last_block, _| min_block(is_last_block));
+        }
+    }
+
+    /// Returns a boolean block encrypting whether `ct` is negative
+    ///
+    /// Expects ct to have clean carries
+    fn sign_bit(&self, ct: &SignedRadixCiphertext) -> BooleanBlock {
+        let sign_bit_pos = self.message_modulus().0.ilog2() - 1;
+        let lut = self.key.generate_lookup_table(|x| (x >> sign_bit_pos) & 1);
+        BooleanBlock::new_unchecked(
+            self.key
+                .apply_lookup_table(ct.blocks().last().unwrap(), &lut),
+        )
+    }
+
+    /// Computes homomorphically an addition that saturates at the numeric bounds
+    /// instead of wrapping around.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn unsigned_saturating_add_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        if !self.is_eligible_for_fused_saturation() {
+            let (mut result, overflowed) =
+                self.unsigned_overflowing_add_parallelized(ct_left, ct_right);
+            let max_block = self.message_modulus().0 as u64 - 1;
+            self.saturate_blocks(&mut result, &overflowed.0, |_, _| max_block);
+            return result;
+        }
+
+        self.with_clean_saturating_inputs(ct_left, ct_right, |lhs, rhs| {
+            self.unsigned_saturating_add_or_sub(lhs, rhs, LastBlockSaturation::Max, || None)
+        })
+    }
+
+    /// Computes homomorphically a subtraction that saturates at 0
+    /// instead of wrapping around.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 100u8;
+    /// let msg2 = 200u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn unsigned_saturating_sub_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        if !self.is_eligible_for_fused_saturation() {
+            let (mut result, overflowed) =
+                self.unsigned_overflowing_sub_parallelized(ct_left, ct_right);
+            self.saturate_blocks(&mut result, &overflowed.0, |_, _| 0);
+            return result;
+        }
+
+        self.with_clean_saturating_inputs(ct_left, ct_right, |lhs, rhs| {
+            self.unsigned_saturating_add_or_sub(lhs, rhs, LastBlockSaturation::Min, || None)
+        })
+    }
+
+    /// Computes homomorphically a multiplication that saturates at the numeric bounds
+    /// instead of wrapping around.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 20u8;
+    /// let msg2 = 13u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unsigned_saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn unsigned_saturating_mul_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        if !self.is_eligible_for_fused_saturation() {
+            let (mut result, overflowed) =
+                self.unsigned_overflowing_mul_parallelized(ct_left, ct_right);
+            let max_block = self.message_modulus().0 as u64 - 1;
+            self.saturate_blocks(&mut result, &overflowed.0, |_, _| max_block);
+            return result;
+        }
+
+        self.with_clean_saturating_inputs(ct_left, ct_right, |lhs, rhs| {
+            let (terms, high_part) = self.compute_terms_for_overflowing_mul(lhs, rhs);
+            let (partial_sum, high_part_is_non_zero) = rayon::join(
+                || {
+                    self.unchecked_unsigned_overflowing_partial_sum_ciphertexts_vec_parallelized(
+                        terms,
+                    )
+                },
+                || self.unchecked_scalar_ne_parallelized(&RadixCiphertext::from(high_part), 0),
+            );
+            let Some((sum_lhs, sum_rhs, carries)) = partial_sum else {
+                // We can end up here, if all blocks of either rhs, or lhs were trivial zeros
+                return self.create_trivial_zero_radix(lhs.blocks.len());
+            };
+
+            // The product overflowed if the high part is not zero, or if a carry went out
+            // of the last block while summing the terms. This is known before the end of the
+            // final addition, so it is added to the saturation of the addition.
+            let scale = self.saturation_scale();
+            self.with_clean_saturating_inputs(&sum_lhs, &sum_rhs, |sum_lhs, sum_rhs| {
+                self.unsigned_saturating_add_or_sub(
+                    sum_lhs,
+                    sum_rhs,
+                    LastBlockSaturation::Max,
+                    || {
+                        let mut carries = RadixCiphertext::from(carries);
+                        carries.blocks.retain(|block| block.degree.get() != 0);
+                        if carries.blocks.is_empty() {
+                            let lut = self.key.generate_lookup_table(|x| (x & 1) * scale);
+                            return Some(
+                                self.key.apply_lookup_table(&high_part_is_non_zero.0, &lut),
+                            );
+                        }
+
+                        let any_sum_overflowed = self.scalar_ne_parallelized(&carries, 0);
+                        let lut = self
+                            .key
+                            .generate_lookup_table_bivariate(|x, y| ((x | y) & 1) * scale);
+                        Some(self.key.unchecked_apply_lookup_table_bivariate(
+                            &any_sum_overflowed.0,
+                            &high_part_is_non_zero.0,
+                            &lut,
+                        ))
+                    },
+                )
+            })
+        })
+    }
+
+    /// Computes homomorphically an addition that saturates at the numeric bounds
+    /// instead of wrapping around.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn signed_saturating_add_parallelized(
+        &self,
+        ct_left: &SignedRadixCiphertext,
+        ct_right: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.with_clean_saturating_inputs(ct_left, ct_right, |lhs, rhs| {
+            if self.is_eligible_for_fused_saturation() {
+                return self.signed_saturating_add_or_sub(lhs, rhs, false);
+            }
+
+            // Overflow only happens when both operands have the same sign,
+            // which is then the direction of the saturation
+            let ((mut result, overflowed), towards_min) = rayon::join(
+                || self.unchecked_signed_overflowing_add_parallelized(lhs, rhs),
+                || self.sign_bit(lhs),
+            );
+            self.saturate_signed(&mut result, &overflowed, &towards_min);
+            result
+        })
+    }
+
+    /// Computes homomorphically a subtraction that saturates at the numeric bounds
+    /// instead of wrapping around.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn signed_saturating_sub_parallelized(
+        &self,
+        ct_left: &SignedRadixCiphertext,
+        ct_right: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.with_clean_saturating_inputs(ct_left, ct_right, |lhs, rhs| {
+            if self.is_eligible_for_fused_saturation() {
+                return self.signed_saturating_add_or_sub(lhs, rhs, true);
+            }
+
+            // Overflow only happens when the operands have different signs,
+            // the saturation is then in the direction of the lhs
+            let ((mut result, overflowed), towards_min) = rayon::join(
+                || self.unchecked_signed_overflowing_sub_parallelized(lhs, rhs),
+                || self.sign_bit(lhs),
+            );
+            self.saturate_signed(&mut result, &overflowed, &towards_min);
+            result
+        })
+    }
+
+    /// Computes homomorphically a multiplication that saturates at the numeric bounds
+    /// instead of wrapping around.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -20i8;
+    /// let msg2 = 13i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn signed_saturating_mul_parallelized(
+        &self,
+        ct_left: &SignedRadixCiphertext,
+        ct_right: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.with_clean_saturating_inputs(ct_left, ct_right, |lhs, rhs| {
+            // The overflow is only known once the product, with its carries propagated, is
+            // compared to the bounds, so here the saturation is a layer of its own.
+            // The comparisons directly give the direction of the saturation.
+            let (mut result, is_lt_min, is_gt_max) =
+                self.signed_mul_and_compare_to_bounds(lhs, rhs);
+
+            let message_modulus = self.message_modulus().0 as u64;
+            if message_modulus > 2 {
+                // 0 -> no saturation, 1 -> MAX, 2 -> MIN, the comparisons are exclusive
+                let lut = self
+                    .key
+                    .generate_lookup_table_bivariate(|is_gt_max, is_lt_min| {
+                        (is_gt_max & 1) + 2 * (is_lt_min & 1)
+                    });
+                let state = self.key.unchecked_apply_lookup_table_bivariate(
+                    &is_gt_max.0,
+                    &is_lt_min.0,
+                    &lut,
+                );
+                self.saturate_blocks(&mut result, &state, |is_last_block, state| {
+                    signed_bound_block(message_modulus, is_last_block, state == 2)
+                });
+            } else {
+                let overflowed = self.boolean_bitor(&is_lt_min, &is_gt_max);
+                self.saturate_signed(&mut result, &overflowed, &is_lt_min);
+            }
+            result
+        })
+    }
+
+    /// Calls `f` with versions of `lhs` and `rhs` that have clean carries
+    fn with_clean_saturating_inputs<T, R, F>(&self, lhs: &T, rhs: &T, f: F) -> R
+    where
+        T: IntegerRadixCiphertext,
+        F: FnOnce(&T, &T) -> R,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        f(lhs, rhs)
+    }
+}
+
+/// Returns the value of a block of the signed MIN or MAX
+fn signed_bound_block(message_modulus: u64, is_last_block: bool, is_min: bool) -> u64 {
+    // The sign bit is the msb of the last block
+    match (is_last_block, is_min) {
+        (true, false) => (message_modulus / 2) - 1,
+        (true, true) => message_modulus / 2,
+        (false, false) => message_modulus - 1,
+        (false, true) => 0,
+    }
+}
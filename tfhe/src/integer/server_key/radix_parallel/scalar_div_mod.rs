@@ -11,10 +11,14 @@
 //! due to the huge difference between clear computation and FHE computation
 //! it is absolutely worth to compute the approximation of the inverse.
 use crate::core_crypto::prelude::{CastFrom, CastInto, Numeric, SignedNumeric, UnsignedInteger};
+use crate::integer::bigint::i2048::I4096;
+use crate::integer::bigint::u2048::U4096;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
 use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
-use crate::integer::{IntegerCiphertext, ServerKey, I256, I512, U256, U512};
+use crate::integer::{
+    IntegerCiphertext, ServerKey, I1024, I2048, I256, I512, U1024, U2048, U256, U512,
+};
 use std::ops::{Add, AddAssign, BitAnd, Div, Mul, Neg, Shl, Shr, Sub};
 
 #[inline(always)]
@@ -88,6 +92,48 @@ impl MiniUnsignedInteger for U512 {
     }
 }
 
+impl MiniUnsignedInteger for U1024 {
+    fn ceil_ilog2(self) -> u32 {
+        self.ceil_ilog2()
+    }
+
+    fn ilog2(self) -> u32 {
+        self.ilog2()
+    }
+
+    fn is_power_of_two(self) -> bool {
+        self.is_power_of_two()
+    }
+}
+
+impl MiniUnsignedInteger for U2048 {
+    fn ceil_ilog2(self) -> u32 {
+        self.ceil_ilog2()
+    }
+
+    fn ilog2(self) -> u32 {
+        self.ilog2()
+    }
+
+    fn is_power_of_two(self) -> bool {
+        self.is_power_of_two()
+    }
+}
+
+impl MiniUnsignedInteger for U4096 {
+    fn ceil_ilog2(self) -> u32 {
+        self.ceil_ilog2()
+    }
+
+    fn ilog2(self) -> u32 {
+        self.ilog2()
+    }
+
+    fn is_power_of_two(self) -> bool {
+        self.is_power_of_two()
+    }
+}
+
 pub trait Reciprocable: MiniUnsignedInteger {
     // We need the double precision to compute and store the reciprocal
     // u8 -> u16, u32 -> u64
@@ -122,6 +168,18 @@ impl Reciprocable for U256 {
     type DoublePrecision = U512;
 }
 
+impl Reciprocable for U512 {
+    type DoublePrecision = U1024;
+}
+
+impl Reciprocable for U1024 {
+    type DoublePrecision = U2048;
+}
+
+impl Reciprocable for U2048 {
+    type DoublePrecision = U4096;
+}
+
 pub trait SignedReciprocable:
     DecomposableInto<u64>
     + DecomposableInto<u8>
@@ -200,6 +258,36 @@ impl SignedReciprocable for I256 {
     }
 }
 
+impl SignedReciprocable for I512 {
+    type Unsigned = U512;
+
+    type DoublePrecision = I1024;
+
+    fn wrapping_abs(self) -> Self {
+        self.wrapping_abs()
+    }
+}
+
+impl SignedReciprocable for I1024 {
+    type Unsigned = U1024;
+
+    type DoublePrecision = I2048;
+
+    fn wrapping_abs(self) -> Self {
+        self.wrapping_abs()
+    }
+}
+
+impl SignedReciprocable for I2048 {
+    type Unsigned = U2048;
+
+    type DoublePrecision = I4096;
+
+    fn wrapping_abs(self) -> Self {
+        self.wrapping_abs()
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 struct ApproximatedMultiplier<T> {
     // The approximation of the inverse
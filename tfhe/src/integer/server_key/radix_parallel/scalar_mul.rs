@@ -189,4 +189,96 @@ impl ServerKey {
 
         self.unchecked_scalar_mul_assign_parallelized(lhs, scalar);
     }
+
+    /// Computes homomorphically the full product of a ciphertext and a scalar, on twice the
+    /// number of blocks of the ciphertext.
+    ///
+    /// The scalar must fit in the number of bits of the ciphertext for the result to be the
+    /// exact product, otherwise the product is reduced modulo 2^(2 * num_bits).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear = -77i8;
+    /// let scalar = -128i8;
+    ///
+    /// let ctxt = cks.encrypt_signed(clear);
+    ///
+    /// let ct_res = sks.scalar_widening_mul_parallelized(&ctxt, scalar);
+    ///
+    /// // Decrypt
+    /// let res: i16 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(i16::from(clear) * i16::from(scalar), res);
+    /// ```
+    pub fn scalar_widening_mul_parallelized<T, Scalar>(&self, ct: &T, scalar: Scalar) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8> + std::ops::Not<Output = Scalar>,
+    {
+        let num_blocks = ct.blocks().len();
+        let mut extended = ct.clone();
+        if !extended.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut extended);
+        }
+        let extended = T::from_blocks(
+            self.cast_to_unsigned(extended, 2 * num_blocks)
+                .into_blocks(),
+        );
+
+        if scalar < Scalar::ZERO {
+            // The decomposition of a negative scalar only has the bits of its type,
+            // so the product is computed from !scalar = -scalar - 1 which is positive:
+            // ct * scalar = -(ct * !scalar + ct)
+            let product = self.unchecked_scalar_mul_parallelized(&extended, !scalar);
+            let product = self.add_parallelized(&product, &extended);
+            self.neg_parallelized(&product)
+        } else {
+            self.unchecked_scalar_mul_parallelized(&extended, scalar)
+        }
+    }
+
+    /// Computes homomorphically the high half of the full product of a ciphertext and a
+    /// scalar.
+    ///
+    /// See [Self::scalar_widening_mul_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear = 201u8;
+    /// let scalar = 99u8;
+    ///
+    /// let ctxt = cks.encrypt(clear);
+    ///
+    /// let ct_res = sks.scalar_mul_high_parallelized(&ctxt, scalar);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(((u16::from(clear) * u16::from(scalar)) >> 8) as u8, res);
+    /// ```
+    pub fn scalar_mul_high_parallelized<T, Scalar>(&self, ct: &T, scalar: Scalar) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8> + std::ops::Not<Output = Scalar>,
+    {
+        let num_blocks = ct.blocks().len();
+        let mut blocks = self
+            .scalar_widening_mul_parallelized(ct, scalar)
+            .into_blocks();
+        T::from_blocks(blocks.split_off(num_blocks))
+    }
 }
@@ -129,9 +129,11 @@ impl ServerKey {
         let mut scalar_decomposer =
             BlockDecomposer::new(scalar, self.message_modulus().0.ilog2()).iter_as::<u8>();
 
+        // The blocks of lhs beyond the ones of the scalar still need the correcting term,
+        // so the scalar is padded with zeros
         lhs.blocks
             .iter_mut() // Not worth to parallelize
-            .zip(scalar_decomposer.by_ref())
+            .zip(scalar_decomposer.by_ref().chain(std::iter::repeat(0)))
             .for_each(|(lhs_block, rhs_scalar)| {
                 self.key
                     .unchecked_scalar_sub_assign_with_correcting_term(lhs_block, rhs_scalar)
@@ -0,0 +1,301 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+/// Returns the comparators of Batcher's merge exchange sorting network
+/// (Knuth, TAOCP Vol. 3, 5.2.2 Algorithm M) for `len` elements.
+///
+/// The network is returned as a list of passes, the comparators of a pass
+/// work on disjoint pairs of indices so they can be evaluated in parallel.
+/// After applying a comparator `(i, j)` (with `i < j`), the element at `i`
+/// must be the smallest of the two.
+fn merge_exchange_network(len: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut passes = Vec::new();
+    if len < 2 {
+        return passes;
+    }
+
+    let t = (len - 1).ilog2() + 1;
+    let mut p = 1 << (t - 1);
+    while p > 0 {
+        let mut q = 1 << (t - 1);
+        let mut r = 0;
+        let mut d = p;
+        loop {
+            let pass: Vec<_> = (0..len - d)
+                .filter(|i| i & p == r)
+                .map(|i| (i, i + d))
+                .collect();
+            if !pass.is_empty() {
+                passes.push(pass);
+            }
+
+            if q == p {
+                break;
+            }
+            d = q - p;
+            q >>= 1;
+            r = p;
+        }
+        p >>= 1;
+    }
+
+    passes
+}
+
+impl ServerKey {
+    /// Sorts the ciphertexts in ascending order
+    ///
+    /// The ciphertexts are sorted using Batcher's merge exchange sorting network,
+    /// which requires `O(n log^2(n))` comparisons done in `O(log^2(n))` parallel steps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [43u64, 2, 255, 17, 2];
+    ///
+    /// let mut cts: Vec<_> = msgs.iter().map(|&msg| cks.encrypt(msg)).collect();
+    ///
+    /// sks.sort_parallelized(&mut cts);
+    ///
+    /// // Decrypt:
+    /// let decrypted: Vec<u64> = cts.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(decrypted, vec![2, 2, 17, 43, 255]);
+    /// ```
+    pub fn sort_parallelized<T>(&self, cts: &mut [T])
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        for pass in merge_exchange_network(cts.len()) {
+            let exchanged: Vec<_> = pass
+                .par_iter()
+                .map(|&(i, j)| self.compare_exchange_parallelized(&cts[i], &cts[j]))
+                .collect();
+
+            for ((i, j), (min, max)) in pass.into_iter().zip(exchanged) {
+                cts[i] = min;
+                cts[j] = max;
+            }
+        }
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order
+    ///
+    /// If `k` is greater than the number of ciphertexts, all the ciphertexts are returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [43u64, 2, 255, 17, 2];
+    ///
+    /// let cts: Vec<_> = msgs.iter().map(|&msg| cks.encrypt(msg)).collect();
+    ///
+    /// let top = sks.top_k_parallelized(&cts, 2);
+    ///
+    /// // Decrypt:
+    /// let decrypted: Vec<u64> = top.iter().map(|ct| cks.decrypt(ct)).collect();
+    /// assert_eq!(decrypted, vec![255, 43]);
+    /// ```
+    pub fn top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if k == 0 {
+            return vec![];
+        }
+
+        let mut sorted = cts.to_vec();
+        self.sort_parallelized(&mut sorted);
+        sorted.into_iter().rev().take(k).collect()
+    }
+
+    /// Returns the greatest ciphertext alongside the encryption of its index
+    ///
+    /// If the greatest value appears several times, the smallest index is returned.
+    /// The index is encrypted on as few blocks as possible to represent `cts.len() - 1`.
+    ///
+    /// Returns `None` if `cts` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [43u64, 2, 255, 17, 255];
+    ///
+    /// let cts: Vec<_> = msgs.iter().map(|&msg| cks.encrypt(msg)).collect();
+    ///
+    /// let (max, index) = sks.argmax_parallelized(&cts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let max: u64 = cks.decrypt(&max);
+    /// let index: u64 = cks.decrypt(&index);
+    /// assert_eq!(max, 255);
+    /// assert_eq!(index, 2);
+    /// ```
+    pub fn argmax_parallelized<T>(&self, cts: &[T]) -> Option<(T, RadixCiphertext)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.arg_extremum_parallelized(cts, |lhs, rhs| self.gt_parallelized(rhs, lhs))
+    }
+
+    /// Returns the smallest ciphertext alongside the encryption of its index
+    ///
+    /// If the smallest value appears several times, the smallest index is returned.
+    /// The index is encrypted on as few blocks as possible to represent `cts.len() - 1`.
+    ///
+    /// Returns `None` if `cts` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [43u64, 2, 255, 17, 2];
+    ///
+    /// let cts: Vec<_> = msgs.iter().map(|&msg| cks.encrypt(msg)).collect();
+    ///
+    /// let (min, index) = sks.argmin_parallelized(&cts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let min: u64 = cks.decrypt(&min);
+    /// let index: u64 = cks.decrypt(&index);
+    /// assert_eq!(min, 2);
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn argmin_parallelized<T>(&self, cts: &[T]) -> Option<(T, RadixCiphertext)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.arg_extremum_parallelized(cts, |lhs, rhs| self.lt_parallelized(rhs, lhs))
+    }
+
+    /// Returns the pair `(min, max)` of the two ciphertexts
+    fn compare_exchange_parallelized<T>(&self, lhs: &T, rhs: &T) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let lhs_is_greater = self.gt_parallelized(lhs, rhs);
+        rayon::join(
+            || self.if_then_else_parallelized(&lhs_is_greater, rhs, lhs),
+            || self.if_then_else_parallelized(&lhs_is_greater, lhs, rhs),
+        )
+    }
+
+    /// Reduces the ciphertexts with a binary tree, at each node `select_rhs` tells
+    /// whether the value of the right subtree replaces the one of the left subtree.
+    fn arg_extremum_parallelized<T, F>(
+        &self,
+        cts: &[T],
+        select_rhs: F,
+    ) -> Option<(T, RadixCiphertext)>
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> BooleanBlock + Sync,
+    {
+        if cts.is_empty() {
+            return None;
+        }
+
+        let bits_in_block = self.message_modulus().0.ilog2();
+        let index_bits = (cts.len() - 1).checked_ilog2().map_or(1, |log| log + 1);
+        let index_num_blocks = index_bits.div_ceil(bits_in_block) as usize;
+
+        let mut candidates: Vec<(T, RadixCiphertext)> = cts
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                let index = self.create_trivial_radix(i as u64, index_num_blocks);
+                (ct, index)
+            })
+            .collect();
+
+        while candidates.len() > 1 {
+            candidates = candidates
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [(lhs, lhs_index), (rhs, rhs_index)] => {
+                        let condition = select_rhs(lhs, rhs);
+                        rayon::join(
+                            || self.if_then_else_parallelized(&condition, rhs, lhs),
+                            || self.if_then_else_parallelized(&condition, rhs_index, lhs_index),
+                        )
+                    }
+                    [single] => single.clone(),
+                    _ => unreachable!("chunks have 1 or 2 elements"),
+                })
+                .collect();
+        }
+
+        candidates.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_exchange_network;
+
+    #[test]
+    fn test_merge_exchange_network_sorts() {
+        for len in 0..=17usize {
+            let network = merge_exchange_network(len);
+
+            for pass in &network {
+                let mut indices: Vec<_> =
+                    pass.iter().copied().flat_map(<[usize; 2]>::from).collect();
+                let num_indices = indices.len();
+                indices.sort_unstable();
+                indices.dedup();
+                assert_eq!(indices.len(), num_indices, "comparators of a pass overlap");
+            }
+
+            // 0-1 principle: a network sorting all the binary inputs sorts any input
+            if len <= 12 {
+                for bits in 0..(1u32 << len) {
+                    let mut values: Vec<_> = (0..len).map(|i| (bits >> i) & 1).collect();
+                    for pass in &network {
+                        for &(i, j) in pass {
+                            if values[i] > values[j] {
+                                values.swap(i, j);
+                            }
+                        }
+                    }
+                    assert!(values.windows(2).all(|w| w[0] <= w[1]));
+                }
+            }
+        }
+    }
+}
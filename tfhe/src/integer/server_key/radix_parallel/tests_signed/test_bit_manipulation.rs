@@ -0,0 +1,175 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_signed::{
+    random_non_zero_value, signed_add_under_modulus, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_count_ones_zeros);
+create_parametrized_test!(integer_signed_default_reverse_bits);
+create_parametrized_test!(integer_signed_default_swap_bytes {
+    // Blocks must split a byte evenly
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_signed_default_extract_bits);
+
+/// Returns the values to test along their dirty version (i.e. with carries)
+///
+/// The returned clear values are reduced under the modulus
+fn dirty_inputs(
+    cks: &RadixClientKey,
+    sks: &ServerKey,
+    num_blocks: usize,
+) -> Vec<(i64, SignedRadixCiphertext)> {
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(num_blocks as u32) / 2) as i64;
+
+    let mut inputs = vec![
+        (-modulus, cks.encrypt_signed(-modulus)),
+        (modulus - 1, cks.encrypt_signed(modulus - 1)),
+        (-1, sks.create_trivial_radix(-1i64, num_blocks)),
+    ];
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear = rng.gen::<i64>() % modulus;
+        let ctxt = cks.encrypt_signed(clear);
+        inputs.push((clear, ctxt.clone()));
+
+        // Add non-zero scalar to have non-clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        inputs.push((signed_add_under_modulus(clear, clear_2, modulus), ctxt));
+    }
+
+    inputs
+}
+
+/// Returns the bits of the two's complement representation of `value` on `num_bits` bits
+fn as_unsigned(value: i64, num_bits: u32) -> u64 {
+    (value as u64) & (u64::MAX >> (u64::BITS - num_bits))
+}
+
+fn integer_signed_default_count_ones_zeros<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, NB_CTXT) {
+        let expected_ones = as_unsigned(clear, num_bits).count_ones();
+
+        let ct_res = sks.count_ones_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, sks.count_ones_parallelized(&ctxt));
+        let decrypted_result: u32 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result, expected_ones,
+            "Invalid result for {clear}.count_ones()"
+        );
+
+        let ct_res = sks.count_zeros_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let decrypted_result: u32 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted_result,
+            num_bits - expected_ones,
+            "Invalid result for {clear}.count_zeros()"
+        );
+    }
+}
+
+fn integer_signed_default_reverse_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, NB_CTXT) {
+        let ct_res = sks.reverse_bits_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, sks.reverse_bits_parallelized(&ctxt));
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        // reverse on 64 bits, and use an arithmetic shift to sign extend the result
+        let expected_result = (clear as u64).reverse_bits() as i64 >> (u64::BITS - num_bits);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear}.reverse_bits()"
+        );
+    }
+}
+
+fn integer_signed_default_swap_bytes<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    // Work on 16 bits integers, so that there are bytes to swap
+    let num_blocks = (i16::BITS / cks.parameters().message_modulus().0.ilog2()) as usize;
+    let cks = RadixClientKey::from((cks, num_blocks));
+    sks.set_deterministic_pbs_execution(true);
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, num_blocks) {
+        let ct_res = sks.swap_bytes_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, sks.swap_bytes_parallelized(&ctxt));
+
+        let decrypted_result: i16 = cks.decrypt_signed(&ct_res);
+        let expected_result = (clear as i16).swap_bytes();
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear}.swap_bytes()"
+        );
+    }
+}
+
+fn integer_signed_default_extract_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, NB_CTXT) {
+        let start = rng.gen_range(0..=num_bits);
+        let end = rng.gen_range(start..=num_bits);
+        // The sign bit is not extended, so the result is positive unless the sign bit is kept
+        for range in [start..end, 0..num_bits] {
+            let ct_res = sks.extract_bits_parallelized(&ctxt, range.clone());
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+            let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+            let expected_result = if range.len() as u32 == num_bits {
+                clear
+            } else {
+                let mask = (1u64 << range.len()) - 1;
+                ((as_unsigned(clear, num_bits) >> range.start) & mask) as i64
+            };
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result when extracting bits {range:?} of {clear}"
+            );
+        }
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 create_parametrized_test!(integer_signed_unchecked_mul);
 create_parametrized_test!(integer_signed_default_mul);
+create_parametrized_test!(integer_signed_default_widening_mul);
 create_parametrized_test!(
     integer_signed_default_overflowing_mul {
         coverage => {
@@ -51,6 +52,70 @@ where
     signed_default_mul_test(param, executor);
 }
 
+fn integer_signed_default_widening_mul(param: impl Into<PBSParameters>) {
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let full_modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as i64;
+    let modulus = full_modulus / 2;
+
+    const NB_HARDCODED_VALUES: usize = 4;
+    let mut test_inputs = [(0i64, 0i64); NB_TESTS_SMALLER + NB_HARDCODED_VALUES];
+    test_inputs[0] = (-modulus, -modulus);
+    test_inputs[1] = (-modulus, modulus - 1);
+    test_inputs[2] = (modulus - 1, modulus - 1);
+    test_inputs[3] = (-1, -1);
+    for inputs in &mut test_inputs[NB_HARDCODED_VALUES..] {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+        *inputs = (clear_0, clear_1);
+    }
+
+    for (clear_0, clear_1) in test_inputs {
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+        let expected = clear_0 * clear_1;
+        let expected_high = expected.div_euclid(full_modulus);
+
+        let ct_res = sks.widening_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid widening mul result for {clear_0} * {clear_1}"
+        );
+
+        let ct_res = sks.mul_high_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec_res, expected_high,
+            "Invalid mul high result for {clear_0} * {clear_1}"
+        );
+
+        let ct_res = sks.scalar_widening_mul_parallelized(&ctxt_0, clear_1);
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid scalar widening mul result for {clear_0} * {clear_1}"
+        );
+
+        let ct_res = sks.scalar_mul_high_parallelized(&ctxt_0, clear_1);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec_res, expected_high,
+            "Invalid scalar mul high result for {clear_0} * {clear_1}"
+        );
+    }
+}
+
 fn integer_signed_default_overflowing_mul(param: impl Into<PBSParameters>) {
     let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
     let cks = RadixClientKey::from((cks, NB_CTXT));
@@ -0,0 +1,179 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_signed::{
+    random_non_zero_value, signed_add_under_modulus, signed_mul_under_modulus, NB_CTXT,
+    NB_TESTS_SMALLER,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_isqrt);
+create_parametrized_test!(integer_signed_default_pow);
+create_parametrized_test!(integer_signed_default_scalar_pow);
+create_parametrized_test!(integer_signed_default_checked_pow);
+
+/// Computes `base^exponent` under the modulus, and whether it overflowed
+fn signed_pow_under_modulus(base: i64, exponent: u64, modulus: i64) -> (i64, bool) {
+    let mut result = 1i64;
+    let mut overflowed = false;
+    for _ in 0..exponent {
+        let product = result * base;
+        overflowed |= product < -modulus || product >= modulus;
+        result = signed_mul_under_modulus(result, base, modulus);
+    }
+    (result, overflowed)
+}
+
+fn integer_signed_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        // Only non negative values have a meaningful square root
+        let clear = rng.gen_range(0..modulus);
+        let ctxt = cks.encrypt_signed(clear);
+
+        let ct_res = sks.isqrt_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, sks.isqrt_parallelized(&ctxt));
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let expected_result = (clear as f64).sqrt() as i64;
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt({clear})"
+        );
+    }
+}
+
+fn integer_signed_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_base = rng.gen::<i64>() % modulus;
+        let clear_exponent = rng.gen_range(0..2 * modulus) as u64;
+
+        let mut ctxt_base = cks.encrypt_signed(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+
+        let ct_res = sks.pow_parallelized(&ctxt_base, &ctxt_exponent);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, sks.pow_parallelized(&ctxt_base, &ctxt_exponent));
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let (expected_result, _) = signed_pow_under_modulus(clear_base, clear_exponent, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent})"
+        );
+
+        // Non-clean base
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        sks.unchecked_scalar_add_assign(&mut ctxt_base, clear_2);
+        let clear_base = signed_add_under_modulus(clear_base, clear_2, modulus);
+
+        let ct_res = sks.pow_parallelized(&ctxt_base, &ctxt_exponent);
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let (expected_result, _) = signed_pow_under_modulus(clear_base, clear_exponent, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent})"
+        );
+    }
+}
+
+fn integer_signed_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_base = rng.gen::<i64>() % modulus;
+        let ctxt_base = cks.encrypt_signed(clear_base);
+
+        // Also check the trivial exponents
+        for clear_exponent in [0, 1, rng.gen_range(2..64u32)] {
+            let ct_res = sks.scalar_pow_parallelized(&ctxt_base, clear_exponent);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(
+                ct_res,
+                sks.scalar_pow_parallelized(&ctxt_base, clear_exponent)
+            );
+
+            let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+            let (expected_result, _) =
+                signed_pow_under_modulus(clear_base, clear_exponent as u64, modulus);
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for {clear_base}.pow({clear_exponent})"
+            );
+        }
+    }
+}
+
+fn integer_signed_default_checked_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for i in 0..NB_TESTS_SMALLER {
+        // Small bases so that both the overflowing and the non overflowing cases are tested
+        let clear_base = if i % 2 == 0 {
+            rng.gen_range(-2..2i64.min(modulus))
+        } else {
+            rng.gen::<i64>() % modulus
+        };
+        let clear_exponent = rng.gen_range(0..16u64);
+
+        let ctxt_base = cks.encrypt_signed(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+
+        let (ct_res, overflowed) = sks.signed_checked_pow_parallelized(&ctxt_base, &ctxt_exponent);
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&overflowed);
+        let (expected_result, expected_overflowed) =
+            signed_pow_under_modulus(clear_base, clear_exponent, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.checked_pow({clear_exponent})"
+        );
+        assert_eq!(
+            decrypted_overflowed, expected_overflowed,
+            "Invalid overflow flag for {clear_base}.checked_pow({clear_exponent})"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_signed::{
+    random_non_zero_value, signed_add_under_modulus, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_saturating_add);
+create_parametrized_test!(integer_signed_default_saturating_sub);
+create_parametrized_test!(
+    integer_signed_default_saturating_mul {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+        },
+        no_coverage => {
+            // Uses comparisons internally, so no 1_1
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+            PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+            PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS,
+        }
+    }
+);
+
+fn integer_signed_default_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    signed_default_saturating_op_test(
+        param,
+        ServerKey::signed_saturating_add_parallelized,
+        |lhs, rhs| lhs + rhs,
+    );
+}
+
+fn integer_signed_default_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    signed_default_saturating_op_test(
+        param,
+        ServerKey::signed_saturating_sub_parallelized,
+        |lhs, rhs| lhs - rhs,
+    );
+}
+
+fn integer_signed_default_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    signed_default_saturating_op_test(
+        param,
+        ServerKey::signed_saturating_mul_parallelized,
+        |lhs, rhs| lhs * rhs,
+    );
+}
+
+/// `exact_op` must compute the result without any wrapping,
+/// the saturation to the modulus is done by this function
+fn signed_default_saturating_op_test<P, F, E>(param: P, saturating_op: F, exact_op: E)
+where
+    P: Into<PBSParameters>,
+    F: Fn(&ServerKey, &SignedRadixCiphertext, &SignedRadixCiphertext) -> SignedRadixCiphertext,
+    E: Fn(i64, i64) -> i64,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = saturating_op(&sks, &ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, saturating_op(&sks, &ctxt_0, &ctxt_1));
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let expected_result = exact_op(clear_0, clear_1).clamp(-modulus, modulus - 1);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid saturating result for ({clear_0}, {clear_1})"
+        );
+
+        // Non-clean lhs
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_2);
+        let clear_0 = signed_add_under_modulus(clear_0, clear_2, modulus);
+
+        let ct_res = saturating_op(&sks, &ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: i64 = cks.decrypt_signed(&ct_res);
+        let expected_result = exact_op(clear_0, clear_1).clamp(-modulus, modulus - 1);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid saturating result for ({clear_0}, {clear_1})"
+        );
+    }
+
+    // Values at the bounds, also on a single block and on a number of blocks
+    // that is not a power of two, as the carry propagation handles them separately
+    for num_blocks in [1, 3, NB_CTXT] {
+        let modulus = (cks.parameters().message_modulus().0.pow(num_blocks as u32) / 2) as i64;
+        // On a single 1 bit block, the only values are -1 and 0
+        let mut values = vec![-modulus, -modulus + 1, -1, 0, 1, modulus - 1];
+        values.retain(|value| (-modulus..modulus).contains(value));
+        values.sort_unstable();
+        values.dedup();
+        for (clear_0, clear_1) in values
+            .iter()
+            .flat_map(|a| values.iter().map(move |b| (*a, *b)))
+        {
+            let ctxt_0 = cks.as_ref().encrypt_signed_radix(clear_0, num_blocks);
+            let ctxt_1 = cks.as_ref().encrypt_signed_radix(clear_1, num_blocks);
+
+            let ct_res = saturating_op(&sks, &ctxt_0, &ctxt_1);
+            assert!(ct_res.block_carries_are_empty());
+
+            let decrypted_result: i64 = cks.as_ref().decrypt_signed_radix(&ct_res);
+            let expected_result = exact_op(clear_0, clear_1).clamp(-modulus, modulus - 1);
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid saturating result for ({clear_0}, {clear_1}) on {num_blocks} blocks"
+            );
+        }
+    }
+}
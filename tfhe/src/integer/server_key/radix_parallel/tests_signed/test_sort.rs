@@ -0,0 +1,74 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_signed::{NB_CTXT, NB_TESTS_SMALLER};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey, SignedRadixCiphertext};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_signed_default_sort {
+    coverage => {COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS},
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    }
+});
+
+fn integer_signed_default_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for len in [2, 5, 7] {
+        for _ in 0..NB_TESTS_SMALLER {
+            let clears: Vec<i64> = (0..len).map(|_| rng.gen_range(-modulus..modulus)).collect();
+            let mut cts: Vec<SignedRadixCiphertext> = clears
+                .iter()
+                .map(|&clear| cks.encrypt_signed(clear))
+                .collect();
+
+            let (max, max_index) = sks.argmax_parallelized(&cts).unwrap();
+            let (min, min_index) = sks.argmin_parallelized(&cts).unwrap();
+            let top = sks.top_k_parallelized(&cts, 2);
+
+            sks.sort_parallelized(&mut cts);
+
+            let mut expected = clears.clone();
+            expected.sort_unstable();
+
+            let decrypted: Vec<i64> = cts.iter().map(|ct| cks.decrypt_signed(ct)).collect();
+            assert_eq!(decrypted, expected, "Invalid sort of {clears:?}");
+
+            let expected_top: Vec<i64> = expected.iter().rev().take(2).copied().collect();
+            let decrypted_top: Vec<i64> = top.iter().map(|ct| cks.decrypt_signed(ct)).collect();
+            assert_eq!(decrypted_top, expected_top, "Invalid top 2 of {clears:?}");
+
+            let expected_max = *clears.iter().max().unwrap();
+            let expected_min = *clears.iter().min().unwrap();
+            let expected_max_index = clears.iter().position(|&c| c == expected_max).unwrap();
+            let expected_min_index = clears.iter().position(|&c| c == expected_min).unwrap();
+
+            let max: i64 = cks.decrypt_signed(&max);
+            let max_index: u64 = cks.decrypt(&max_index);
+            let min: i64 = cks.decrypt_signed(&min);
+            let min_index: u64 = cks.decrypt(&min_index);
+            assert_eq!(
+                (max, max_index),
+                (expected_max, expected_max_index as u64),
+                "Invalid argmax of {clears:?}"
+            );
+            assert_eq!(
+                (min, min_index),
+                (expected_min, expected_min_index as u64),
+                "Invalid argmin of {clears:?}"
+            );
+        }
+    }
+}
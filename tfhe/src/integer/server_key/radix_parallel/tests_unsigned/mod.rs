@@ -1,11 +1,16 @@
 mod modulus_switch_compression;
 pub(crate) mod test_add;
+pub(crate) mod test_bit_manipulation;
 pub(crate) mod test_bitwise_op;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
+pub(crate) mod test_match_value;
+pub(crate) mod test_modular;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+pub(crate) mod test_pow;
 pub(crate) mod test_rotate;
+pub(crate) mod test_saturating;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
 pub(crate) mod test_scalar_comparison;
@@ -14,6 +19,7 @@ pub(crate) mod test_scalar_rotate;
 pub(crate) mod test_scalar_shift;
 pub(crate) mod test_scalar_sub;
 pub(crate) mod test_shift;
+pub(crate) mod test_sort;
 pub(crate) mod test_sub;
 
 use super::tests_cases_unsigned::*;
@@ -0,0 +1,234 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
+    FunctionExecutor, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    random_non_zero_value, unsigned_modulus, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::ops::Range;
+use std::sync::Arc;
+
+create_parametrized_test!(integer_default_count_ones);
+create_parametrized_test!(integer_default_count_zeros);
+create_parametrized_test!(integer_default_reverse_bits);
+create_parametrized_test!(integer_default_swap_bytes {
+    // Blocks must split a byte evenly
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_default_extract_bits);
+
+fn integer_default_count_ones<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::count_ones_parallelized);
+    default_count_bits_test(param, executor, |x, _| x.count_ones());
+}
+
+fn integer_default_count_zeros<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::count_zeros_parallelized);
+    default_count_bits_test(param, executor, |x, num_bits| num_bits - x.count_ones());
+}
+
+fn integer_default_reverse_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::reverse_bits_parallelized);
+    default_reverse_bits_test(param, executor);
+}
+
+fn integer_default_swap_bytes<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::swap_bytes_parallelized);
+    default_swap_bytes_test(param, executor);
+}
+
+fn integer_default_extract_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::extract_bits_parallelized);
+    default_extract_bits_test(param, executor);
+}
+
+/// Returns the values to test along their dirty version (i.e. with carries)
+///
+/// The returned clear values are reduced under the modulus
+fn dirty_inputs(
+    cks: &RadixClientKey,
+    sks: &ServerKey,
+    num_blocks: usize,
+) -> Vec<(u64, RadixCiphertext)> {
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), num_blocks as u32);
+
+    let mut inputs = vec![
+        (0, cks.encrypt(0u64)),
+        (modulus - 1, cks.encrypt(modulus - 1)),
+        (0, sks.create_trivial_radix(0u64, num_blocks)),
+    ];
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+        inputs.push((clear, ctxt.clone()));
+
+        // Add non-zero scalar to have non-clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        inputs.push((clear.wrapping_add(clear_2) % modulus, ctxt));
+    }
+
+    inputs
+}
+
+pub(crate) fn default_count_bits_test<P, T, E>(param: P, mut executor: T, expected: E)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+    E: Fn(u64, u32) -> u32,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, NB_CTXT) {
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let decrypted_result: u32 = cks.decrypt(&ct_res);
+        let expected_result = expected(clear, num_bits);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid bit count for {clear}, expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_reverse_bits_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, NB_CTXT) {
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear.reverse_bits() >> (u64::BITS - num_bits);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear}.reverse_bits(), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_swap_bytes_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    // Work on 16 bits integers, so that there are bytes to swap
+    let num_blocks = (u16::BITS / cks.parameters().message_modulus().0.ilog2()) as usize;
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, num_blocks) {
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let decrypted_result: u16 = cks.decrypt(&ct_res);
+        let expected_result = (clear as u16).swap_bytes();
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear}.swap_bytes(), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_extract_bits_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, Range<u32>), RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for (clear, ctxt) in dirty_inputs(&cks, &sks, NB_CTXT) {
+        let start = rng.gen_range(0..=num_bits);
+        let end = rng.gen_range(start..=num_bits);
+        // Also check the empty and the full ranges
+        for range in [start..end, start..start, 0..num_bits] {
+            let ct_res = executor.execute((&ctxt, range.clone()));
+            let tmp = executor.execute((&ctxt, range.clone()));
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp);
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+            let decrypted_result: u64 = cks.decrypt(&ct_res);
+            let mask = 1u64
+                .checked_shl(range.len() as u32)
+                .unwrap_or(0)
+                .wrapping_sub(1);
+            let expected_result = (clear >> range.start) & mask;
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result when extracting bits {range:?} of {clear}, \
+                expected {expected_result}, got {decrypted_result}"
+            );
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    unsigned_modulus, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::server_key::MatchValues;
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_default_match_value);
+create_parametrized_test!(integer_default_map_with_default);
+
+fn random_table(rng: &mut impl Rng, modulus: u64, len: usize) -> Vec<(u64, u64)> {
+    let mut inputs: Vec<u64> = (0..modulus).collect();
+    let mut table = Vec::with_capacity(len);
+    for _ in 0..len.min(modulus as usize) {
+        let input = inputs.swap_remove(rng.gen_range(0..inputs.len()));
+        // Outputs may need more blocks than the inputs
+        table.push((input, rng.gen_range(0..4 * modulus)));
+    }
+    // Some inputs that cannot be encrypted in the ciphertext
+    table.push((modulus, 1));
+    table.push((u64::MAX, 2));
+    table
+}
+
+fn integer_default_match_value<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    assert!(MatchValues::new(vec![(1u64, 2u64), (3, 4), (1, 5)]).is_err());
+
+    let empty = MatchValues::<u64>::new(vec![]).unwrap();
+    let (ct_res, is_match) = sks.match_value_parallelized(&cks.encrypt(0u64), &empty);
+    assert_eq!(cks.decrypt::<u64>(&ct_res), 0);
+    assert!(!cks.decrypt_bool(&is_match));
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let len = rng.gen_range(1..=modulus.min(20)) as usize;
+        let table = random_table(&mut rng, modulus, len);
+        let matches = MatchValues::new(table.clone()).unwrap();
+
+        let clear = rng.gen_range(0..modulus);
+        let ctxt: RadixCiphertext = cks.encrypt(clear);
+        // Non clean input
+        let ctxt = sks.unchecked_scalar_add(&sks.unchecked_scalar_sub(&ctxt, 1), 1);
+
+        let (ct_res, is_match) = sks.match_value_parallelized(&ctxt, &matches);
+        let (tmp, tmp_is_match) = sks.match_value_parallelized(&ctxt, &matches);
+        assert_eq!(ct_res, tmp);
+        assert_eq!(is_match, tmp_is_match);
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = table
+            .iter()
+            .find(|(input, _)| *input == clear)
+            .map(|(_, output)| *output);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted,
+            expected.unwrap_or(0),
+            "Invalid match of {clear} in {table:?}"
+        );
+        assert_eq!(cks.decrypt_bool(&is_match), expected.is_some());
+    }
+}
+
+fn integer_default_map_with_default<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let len = rng.gen_range(1..=modulus.min(20)) as usize;
+        let table = random_table(&mut rng, modulus, len);
+        let matches = MatchValues::new(table.clone()).unwrap();
+        let default = rng.gen_range(0..4 * modulus);
+
+        // Make sure both the matching and non matching cases are tested
+        let clear = if rng.gen_bool(0.5) {
+            table[rng.gen_range(0..len)].0
+        } else {
+            rng.gen_range(0..modulus)
+        };
+        let ctxt: RadixCiphertext = cks.encrypt(clear);
+
+        let ct_res = sks.map_with_default_parallelized(&ctxt, &matches, default);
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = table
+            .iter()
+            .find(|(input, _)| *input == clear)
+            .map_or(default, |(_, output)| *output);
+
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid match of {clear} in {table:?} with default {default}"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    unsigned_modulus, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_default_mod_add_sub_mul);
+create_parametrized_test!(integer_default_mod_pow_inverse {
+    coverage => {COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS},
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    }
+});
+
+/// Computes `base^exponent mod modulus` in the clear
+fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    (0..exponent).fold(1 % modulus, |acc, _| (acc * base) % modulus)
+}
+
+fn integer_default_mod_add_sub_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // The largest modulus that fits, a power of two, and random ones
+    let mut moduli = vec![modulus - 1, modulus / 2];
+    moduli.extend((0..NB_TESTS_SMALLER).map(|_| rng.gen_range(2..modulus)));
+
+    for p in moduli {
+        // Includes the edge case where both operands are p - 1
+        let clear_0 = if p == modulus - 1 {
+            p - 1
+        } else {
+            rng.gen_range(0..p)
+        };
+        let clear_1 = if p == modulus - 1 {
+            p - 1
+        } else {
+            rng.gen_range(0..p)
+        };
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.mod_add_parallelized(&ctxt_0, &ctxt_1, p);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            (clear_0 + clear_1) % p,
+            "Invalid mod_add result for ({clear_0} + {clear_1}) % {p}"
+        );
+
+        let ct_res = sks.mod_sub_parallelized(&ctxt_0, &ctxt_1, p);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            (clear_0 + p - clear_1) % p,
+            "Invalid mod_sub result for ({clear_0} - {clear_1}) % {p}"
+        );
+
+        let ct_res = sks.mod_mul_parallelized(&ctxt_0, &ctxt_1, p);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            (clear_0 * clear_1) % p,
+            "Invalid mod_mul result for ({clear_0} * {clear_1}) % {p}"
+        );
+    }
+}
+
+fn integer_default_mod_pow_inverse<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    const PRIMES: [u64; 10] = [2, 3, 5, 7, 11, 13, 31, 61, 127, 251];
+    let primes: Vec<u64> = PRIMES.into_iter().filter(|&p| p < modulus).collect();
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let p = primes[rng.gen_range(0..primes.len())];
+        let clear_base = rng.gen_range(0..p);
+        let clear_exponent = rng.gen_range(0..16u64);
+
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(clear_exponent, 2);
+
+        let ct_res = sks.mod_pow_parallelized(&ctxt_base, &ctxt_exponent, p);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            mod_pow(clear_base, clear_exponent, p),
+            "Invalid mod_pow result for {clear_base}^{clear_exponent} % {p}"
+        );
+
+        let ct_res = sks.scalar_mod_pow_parallelized(&ctxt_base, clear_exponent, p);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            mod_pow(clear_base, clear_exponent, p),
+            "Invalid scalar_mod_pow result for {clear_base}^{clear_exponent} % {p}"
+        );
+
+        let ct_res = sks.mod_inverse_parallelized(&ctxt_base, p);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        if clear_base == 0 {
+            assert_eq!(dec_res, 0, "Invalid mod_inverse result for 0 % {p}");
+        } else {
+            assert_eq!(
+                (dec_res * clear_base) % p,
+                1,
+                "Invalid mod_inverse result {dec_res} for {clear_base} % {p}"
+            );
+        }
+    }
+}
@@ -1,14 +1,18 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_default_block_mul_test, default_mul_test, default_overflowing_mul_test,
     smart_block_mul_test, smart_mul_test, unchecked_block_mul_test,
     unchecked_mul_corner_cases_test, unchecked_mul_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    CpuFunctionExecutor, NB_CTXT, NB_TESTS_SMALLER,
+};
 use crate::integer::tests::create_parametrized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_parametrized_test!(integer_unchecked_mul_corner_cases);
 create_parametrized_test!(integer_unchecked_block_mul);
@@ -18,6 +22,7 @@ create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
 create_parametrized_test!(integer_default_unsigned_overflowing_mul);
 create_parametrized_test!(integer_unchecked_mul);
+create_parametrized_test!(integer_default_widening_mul);
 
 fn integer_unchecked_mul<P>(param: P)
 where
@@ -82,3 +87,68 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::block_mul_parallelized);
     default_default_block_mul_test(param, executor);
 }
+
+fn integer_default_widening_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    const NB_HARDCODED_VALUES: usize = 2;
+    let mut test_inputs = [(0u64, 0u64); NB_TESTS_SMALLER + NB_HARDCODED_VALUES];
+    test_inputs[0] = (modulus - 1, modulus - 1);
+    test_inputs[1] = (modulus - 1, 0);
+    for inputs in &mut test_inputs[NB_HARDCODED_VALUES..] {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        *inputs = (clear_0, clear_1);
+    }
+
+    for (clear_0, clear_1) in test_inputs {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let expected = clear_0 * clear_1;
+
+        let ct_res = sks.widening_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid widening mul result for {clear_0} * {clear_1}"
+        );
+
+        let ct_res = sks.mul_high_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            expected / modulus,
+            "Invalid mul high result for {clear_0} * {clear_1}"
+        );
+
+        let ct_res = sks.scalar_widening_mul_parallelized(&ctxt_0, clear_1);
+        assert_eq!(ct_res.blocks.len(), 2 * NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid scalar widening mul result for {clear_0} * {clear_1}"
+        );
+
+        let ct_res = sks.scalar_mul_high_parallelized(&ctxt_0, clear_1);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            expected / modulus,
+            "Invalid scalar mul high result for {clear_0} * {clear_1}"
+        );
+    }
+}
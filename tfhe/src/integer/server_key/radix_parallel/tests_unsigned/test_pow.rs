@@ -0,0 +1,256 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
+    FunctionExecutor, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    random_non_zero_value, unsigned_modulus, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parametrized_test!(integer_default_isqrt);
+create_parametrized_test!(integer_default_pow);
+create_parametrized_test!(integer_default_scalar_pow);
+create_parametrized_test!(integer_default_checked_pow);
+
+fn integer_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::isqrt_parallelized);
+    default_isqrt_test(param, executor);
+}
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::pow_parallelized);
+    default_pow_test(param, executor);
+}
+
+fn integer_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::scalar_pow_parallelized);
+    default_scalar_pow_test(param, executor);
+}
+
+fn integer_default_checked_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_checked_pow_parallelized);
+    default_checked_pow_test(param, executor);
+}
+
+/// Computes `base^exponent` under the modulus, and whether it overflowed
+fn pow_under_modulus(base: u64, exponent: u64, modulus: u64) -> (u64, bool) {
+    let mut result = 1u64;
+    let mut overflowed = false;
+    for _ in 0..exponent {
+        result *= base;
+        overflowed |= result >= modulus;
+        result %= modulus;
+    }
+    (result, overflowed)
+}
+
+pub(crate) fn default_isqrt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    let mut inputs = vec![
+        (0, cks.encrypt(0u64)),
+        (modulus - 1, cks.encrypt(modulus - 1)),
+    ];
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+        inputs.push((clear, ctxt.clone()));
+
+        // Add non-zero scalar to have non-clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_2);
+        inputs.push(((clear + clear_2) % modulus, ctxt));
+    }
+
+    for (clear, ctxt) in inputs {
+        let ct_res = executor.execute(&ctxt);
+        let tmp = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = (clear as f64).sqrt() as u64;
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for isqrt({clear}), expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_base = rng.gen::<u64>() % modulus;
+        let clear_exponent = rng.gen::<u64>() % modulus;
+
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent));
+        let tmp = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let (expected_result, _) = pow_under_modulus(clear_base, clear_exponent, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+
+        // Non-clean inputs
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt_exponent = sks.unchecked_scalar_add(&ctxt_exponent, clear_2);
+        let clear_exponent = (clear_exponent + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let (expected_result, _) = pow_under_modulus(clear_base, clear_exponent, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.pow({clear_exponent}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+}
+
+pub(crate) fn default_scalar_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u32), RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_base = rng.gen::<u64>() % modulus;
+        let ctxt_base = cks.encrypt(clear_base);
+
+        // Also check the trivial exponents
+        for clear_exponent in [0, 1, rng.gen_range(2..64u32)] {
+            let ct_res = executor.execute((&ctxt_base, clear_exponent));
+            let tmp = executor.execute((&ctxt_base, clear_exponent));
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp);
+
+            let decrypted_result: u64 = cks.decrypt(&ct_res);
+            let (expected_result, _) =
+                pow_under_modulus(clear_base, clear_exponent as u64, modulus);
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for {clear_base}.pow({clear_exponent}), \
+                expected {expected_result}, got {decrypted_result}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_checked_pow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, BooleanBlock),
+    >,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for i in 0..NB_TESTS_SMALLER {
+        // Small bases so that both the overflowing and the non overflowing cases are tested
+        let clear_base = if i % 2 == 0 {
+            rng.gen_range(0..4u64.min(modulus))
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+        let clear_exponent = rng.gen::<u64>() % modulus.min(16);
+
+        let ctxt_base = cks.encrypt(clear_base);
+        let ctxt_exponent = cks.encrypt(clear_exponent);
+
+        let (ct_res, overflowed) = executor.execute((&ctxt_base, &ctxt_exponent));
+        let (tmp, tmp_overflowed) = executor.execute((&ctxt_base, &ctxt_exponent));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+        assert_eq!(overflowed, tmp_overflowed);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let decrypted_overflowed = cks.decrypt_bool(&overflowed);
+        let (expected_result, expected_overflowed) =
+            pow_under_modulus(clear_base, clear_exponent, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for {clear_base}.checked_pow({clear_exponent}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed, expected_overflowed,
+            "Invalid overflow flag for {clear_base}.checked_pow({clear_exponent}), \
+            expected {expected_overflowed}, got {decrypted_overflowed}"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
+    FunctionExecutor, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    overflowing_add_under_modulus, overflowing_mul_under_modulus, overflowing_sub_under_modulus,
+    random_non_zero_value, unsigned_modulus, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parametrized_test!(integer_default_saturating_add);
+create_parametrized_test!(integer_default_saturating_sub);
+create_parametrized_test!(integer_default_saturating_mul);
+
+fn integer_default_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_saturating_add_parallelized);
+    default_saturating_op_test(param, executor, |lhs, rhs, modulus| {
+        let (result, overflowed) = overflowing_add_under_modulus(lhs, rhs, modulus);
+        if overflowed {
+            modulus - 1
+        } else {
+            result
+        }
+    });
+}
+
+fn integer_default_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_saturating_sub_parallelized);
+    default_saturating_op_test(param, executor, |lhs, rhs, modulus| {
+        let (result, overflowed) = overflowing_sub_under_modulus(lhs, rhs, modulus);
+        if overflowed {
+            0
+        } else {
+            result
+        }
+    });
+}
+
+fn integer_default_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unsigned_saturating_mul_parallelized);
+    default_saturating_op_test(param, executor, |lhs, rhs, modulus| {
+        let (result, overflowed) = overflowing_mul_under_modulus(lhs, rhs, modulus);
+        if overflowed {
+            modulus - 1
+        } else {
+            result
+        }
+    });
+}
+
+pub(crate) fn default_saturating_op_test<P, T, F>(param: P, mut executor: T, clear_op: F)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+    F: Fn(u64, u64, u64) -> u64,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_op(clear_0, clear_1, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid saturating result for ({clear_0}, {clear_1}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+
+        // Non-clean inputs
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let expected_result = clear_op(clear_0, clear_1, modulus);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid saturating result for ({clear_0}, {clear_1}), \
+            expected {expected_result}, got {decrypted_result}"
+        );
+    }
+
+    // Values at the bounds, also on a single block and on a number of blocks
+    // that is not a power of two, as the carry propagation handles them separately
+    for num_blocks in [1, 3, NB_CTXT] {
+        let modulus = unsigned_modulus(cks.parameters().message_modulus(), num_blocks as u32);
+        let values = [0, 1, modulus / 2, modulus - 1];
+        for (clear_0, clear_1) in values.into_iter().flat_map(|a| values.map(|b| (a, b))) {
+            let ctxt_0 = cks.as_ref().encrypt_radix(clear_0, num_blocks);
+            let ctxt_1 = cks.as_ref().encrypt_radix(clear_1, num_blocks);
+
+            let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+            assert!(ct_res.block_carries_are_empty());
+
+            let decrypted_result: u64 = cks.as_ref().decrypt_radix(&ct_res);
+            let expected_result = clear_op(clear_0, clear_1, modulus);
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid saturating result for ({clear_0}, {clear_1}) on {num_blocks} blocks, \
+                expected {expected_result}, got {decrypted_result}"
+            );
+        }
+    }
+}
@@ -1,16 +1,21 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_overflowing_scalar_sub_test, default_scalar_sub_test, smart_scalar_sub_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    CpuFunctionExecutor, NB_TESTS_SMALLER,
+};
 use crate::integer::tests::create_parametrized_test;
-use crate::integer::ServerKey;
+use crate::integer::{IntegerKeyKind, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_default_overflowing_scalar_sub);
+create_parametrized_test!(integer_default_overflowing_scalar_sub_narrow_scalar);
 
 fn integer_smart_scalar_sub<P>(param: P)
 where
@@ -36,3 +41,42 @@ where
         CpuFunctionExecutor::new(&ServerKey::unsigned_overflowing_scalar_sub_parallelized);
     default_overflowing_scalar_sub_test(param, executor);
 }
+
+/// The scalar has fewer blocks than the ciphertext, so the blocks of the ciphertext beyond the
+/// ones of the scalar are subtracted 0
+fn integer_default_overflowing_scalar_sub_narrow_scalar<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+
+    // Twice as many bits as the u8 scalar
+    let bits_per_block = cks.parameters().message_modulus().0.ilog2();
+    let num_blocks = 16u32.div_ceil(bits_per_block) as usize;
+    let modulus = 1u64 << (bits_per_block * num_blocks as u32);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_lhs = rng.gen_range(0..modulus);
+        let clear_rhs = rng.gen::<u8>();
+        let ctxt = cks.encrypt_radix(clear_lhs, num_blocks);
+
+        let (ct_res, overflowed) =
+            sks.unsigned_overflowing_scalar_sub_parallelized(&ctxt, clear_rhs);
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected_result = clear_lhs.wrapping_sub(u64::from(clear_rhs)) % modulus;
+        let expected_overflowed = clear_lhs < u64::from(clear_rhs);
+        let decrypted_result: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for ({clear_lhs} - {clear_rhs}) % {modulus}"
+        );
+        assert_eq!(
+            cks.decrypt_bool(&overflowed),
+            expected_overflowed,
+            "Invalid overflow flag for {clear_lhs} - {clear_rhs}"
+        );
+    }
+}
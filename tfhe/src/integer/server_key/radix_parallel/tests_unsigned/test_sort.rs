@@ -0,0 +1,120 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    unsigned_modulus, NB_CTXT, NB_TESTS_SMALLER,
+};
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+create_parametrized_test!(integer_default_sort {
+    coverage => {COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS},
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    }
+});
+create_parametrized_test!(integer_default_argmin_argmax {
+    coverage => {COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS},
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    }
+});
+
+fn integer_default_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // Empty and single element slices are left untouched
+    let mut cts: Vec<RadixCiphertext> = vec![];
+    sks.sort_parallelized(&mut cts);
+    assert!(sks.top_k_parallelized(&cts, 2).is_empty());
+
+    for len in [1, 2, 5, 8] {
+        for _ in 0..NB_TESTS_SMALLER {
+            // Restrict the range to get duplicates
+            let clears: Vec<u64> = (0..len).map(|_| rng.gen_range(0..modulus.min(8))).collect();
+            let mut cts: Vec<RadixCiphertext> = clears
+                .iter()
+                .map(|&clear| {
+                    let ct = cks.encrypt(clear / 2);
+                    // Non clean ciphertexts
+                    sks.unchecked_add(&ct, &cks.encrypt(clear - clear / 2))
+                })
+                .collect();
+
+            let k = rng.gen_range(0..=len + 1);
+            let top = sks.top_k_parallelized(&cts, k);
+
+            sks.sort_parallelized(&mut cts);
+
+            let mut expected = clears.clone();
+            expected.sort_unstable();
+
+            let decrypted: Vec<u64> = cts.iter().map(|ct| cks.decrypt(ct)).collect();
+            assert!(cts.iter().all(RadixCiphertext::block_carries_are_empty));
+            assert_eq!(decrypted, expected, "Invalid sort of {clears:?}");
+
+            let expected_top: Vec<u64> = expected.iter().rev().take(k).copied().collect();
+            let decrypted_top: Vec<u64> = top.iter().map(|ct| cks.decrypt(ct)).collect();
+            assert_eq!(decrypted_top, expected_top, "Invalid top {k} of {clears:?}");
+        }
+    }
+}
+
+fn integer_default_argmin_argmax<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    assert!(sks.argmax_parallelized::<RadixCiphertext>(&[]).is_none());
+    assert!(sks.argmin_parallelized::<RadixCiphertext>(&[]).is_none());
+
+    for len in [1, 3, 6] {
+        for _ in 0..NB_TESTS_SMALLER {
+            let clears: Vec<u64> = (0..len).map(|_| rng.gen_range(0..modulus.min(8))).collect();
+            let cts: Vec<RadixCiphertext> =
+                clears.iter().map(|&clear| cks.encrypt(clear)).collect();
+
+            let (max, max_index) = sks.argmax_parallelized(&cts).unwrap();
+            let (min, min_index) = sks.argmin_parallelized(&cts).unwrap();
+
+            let expected_max = *clears.iter().max().unwrap();
+            let expected_min = *clears.iter().min().unwrap();
+            // The first index is returned on ties
+            let expected_max_index = clears.iter().position(|&c| c == expected_max).unwrap();
+            let expected_min_index = clears.iter().position(|&c| c == expected_min).unwrap();
+
+            let max: u64 = cks.decrypt(&max);
+            let max_index: u64 = cks.decrypt(&max_index);
+            let min: u64 = cks.decrypt(&min);
+            let min_index: u64 = cks.decrypt(&min_index);
+            assert_eq!(
+                (max, max_index),
+                (expected_max, expected_max_index as u64),
+                "Invalid argmax of {clears:?}"
+            );
+            assert_eq!(
+                (min, min_index),
+                (expected_min, expected_min_index as u64),
+                "Invalid argmin of {clears:?}"
+            );
+        }
+    }
+}
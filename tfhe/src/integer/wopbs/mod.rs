@@ -13,7 +13,7 @@ use crate::integer::client_key::utils::i_crt;
 use crate::integer::{ClientKey, CrtCiphertext, IntegerCiphertext, ServerKey};
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::wopbs::WopbsLUTBase;
-use crate::shortint::WopbsParameters;
+use crate::shortint::{CarryModulus, MessageModulus, WopbsParameters};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -280,6 +280,32 @@ impl WopbsKey {
     /// assert_eq!(res, clear);
     /// ```
     pub fn wopbs<T>(&self, ct_in: &T, lut: &IntegerWopbsLUT) -> T
+    where
+        T: IntegerCiphertext,
+    {
+        let vec_ct_out = self.circuit_bootstrap_vertical_packing_radix(ct_in, lut);
+
+        let mut ct_vec_out = vec![];
+        for (block, block_out) in ct_in.blocks().iter().zip(vec_ct_out) {
+            ct_vec_out.push(crate::shortint::Ciphertext::new(
+                block_out,
+                Degree::new(block.message_modulus.0 - 1),
+                NoiseLevel::NOMINAL,
+                block.message_modulus,
+                block.carry_modulus,
+                block.pbs_order,
+            ));
+        }
+        T::from_blocks(ct_vec_out)
+    }
+
+    /// Extracts the bits of the padded radix blocks of `ct_in` and evaluates each small lut of
+    /// `lut` on them, returning one LWE ciphertext per small lut.
+    fn circuit_bootstrap_vertical_packing_radix<T>(
+        &self,
+        ct_in: &T,
+        lut: &IntegerWopbsLUT,
+    ) -> Vec<LweCiphertextOwned<u64>>
     where
         T: IntegerCiphertext,
     {
@@ -327,22 +353,8 @@ impl WopbsKey {
             );
         }
 
-        let vec_ct_out = self
-            .wopbs_key
-            .circuit_bootstrapping_vertical_packing(lut.as_ref(), &extracted_bits_blocks);
-
-        let mut ct_vec_out = vec![];
-        for (block, block_out) in ct_in.blocks().iter().zip(vec_ct_out) {
-            ct_vec_out.push(crate::shortint::Ciphertext::new(
-                block_out,
-                Degree::new(block.message_modulus.0 - 1),
-                NoiseLevel::NOMINAL,
-                block.message_modulus,
-                block.carry_modulus,
-                block.pbs_order,
-            ));
-        }
-        T::from_blocks(ct_vec_out)
+        self.wopbs_key
+            .circuit_bootstrapping_vertical_packing(lut.as_ref(), &extracted_bits_blocks)
     }
 
     /// # Example
@@ -1047,11 +1059,197 @@ impl WopbsKey {
         self.circuit_bootstrap_vertical_packing_native_crt(&[ct1.clone(), ct2.clone()], lut)
     }
 
+    /// Converts a native CRT ciphertext into a radix ciphertext of `num_blocks` blocks
+    /// encrypting the same value.
+    ///
+    /// The blocks of the result are encoded with the padding bit, using the message and carry
+    /// moduli of the wopbs parameters. If `num_blocks` is not enough to represent the value, it
+    /// is reduced modulo the radix modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_4_CARRY_4_KS_PBS;
+    ///
+    /// let basis: Vec<u64> = vec![9, 11];
+    /// let msg_space: u64 = basis.iter().copied().product();
+    ///
+    /// let param = WOPBS_PARAM_MESSAGE_4_CARRY_4_KS_PBS;
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_crt(param, basis);
+    /// let wopbs_key = WopbsKey::new_wopbs_key_only_for_wopbs(&cks, &sks);
+    ///
+    /// let clear = 42 % msg_space;
+    /// let ct = cks.encrypt_native_crt(clear);
+    /// // 2 blocks of 4 bits are enough to represent values up to 9 * 11 - 1
+    /// let ct_res = wopbs_key.native_crt_to_radix(&ct, 2);
+    /// let res: u64 = cks.as_ref().decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn native_crt_to_radix(&self, ct: &CrtCiphertext, num_blocks: usize) -> RadixCiphertext {
+        let mut bit = vec![];
+        let mut total_bit = 0;
+        let mut modulus = 1;
+        let basis: Vec<_> = ct.moduli();
+
+        for i in basis.iter() {
+            modulus *= i;
+            let b = f64::log2(*i as f64).ceil() as u64;
+            total_bit += b;
+            bit.push(b);
+        }
+        let lut_size = if 1 << total_bit < self.wopbs_key.param.polynomial_size.0 as u64 {
+            self.wopbs_key.param.polynomial_size.0
+        } else {
+            1 << total_bit
+        };
+        let mut lut = IntegerWopbsLUT::new(PlaintextCount(lut_size), CiphertextCount(num_blocks));
+
+        let message_modulus = self.wopbs_key.param.message_modulus;
+        let carry_modulus = self.wopbs_key.param.carry_modulus;
+        let delta: u64 = (1 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+
+        for value in 0..modulus {
+            let mut index_lut = 0;
+            let mut tmp = 1;
+            for (base, bit) in basis.iter().zip(bit.iter()) {
+                index_lut += (((value % base) << bit) / base) * tmp;
+                tmp <<= bit;
+            }
+            let mut rest = value;
+            for j in 0..num_blocks {
+                lut[j][index_lut as usize] = (rest % message_modulus.0 as u64) * delta;
+                rest /= message_modulus.0 as u64;
+            }
+        }
+
+        let vec_ct_out =
+            self.native_crt_circuit_bootstrap_vertical_packing(std::slice::from_ref(ct), &lut);
+
+        let pbs_order = ct.blocks[0].pbs_order;
+        let blocks = vec_ct_out
+            .into_iter()
+            .map(|block_out| {
+                crate::shortint::Ciphertext::new(
+                    block_out,
+                    Degree::new(message_modulus.0 - 1),
+                    NoiseLevel::NOMINAL,
+                    message_modulus,
+                    carry_modulus,
+                    pbs_order,
+                )
+            })
+            .collect::<Vec<_>>();
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Converts a radix ciphertext into a native CRT ciphertext with the given basis.
+    ///
+    /// The radix blocks must be encoded with the padding bit, the value is reduced modulo the
+    /// product of the moduli of the basis.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::wopbs::WopbsKey;
+    /// use tfhe::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_4_CARRY_4_KS_PBS;
+    ///
+    /// let nb_block = 2;
+    /// let param = WOPBS_PARAM_MESSAGE_4_CARRY_4_KS_PBS;
+    /// //Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(param, nb_block);
+    /// let wopbs_key = WopbsKey::new_wopbs_key_only_for_wopbs(&cks, &sks);
+    ///
+    /// let basis = [9, 11];
+    /// let clear = 200u64;
+    /// let ct = cks.encrypt(clear);
+    /// let ct_res = wopbs_key.radix_to_native_crt(&ct, &basis);
+    /// let res = cks.as_ref().decrypt_native_crt(&ct_res);
+    /// assert_eq!(res, clear % (9 * 11));
+    /// ```
+    pub fn radix_to_native_crt(&self, ct: &RadixCiphertext, basis: &[u64]) -> CrtCiphertext {
+        let mut total_bit = 0;
+
+        //This contains the basis of each block depending on the degree
+        let mut vec_deg_basis = vec![];
+
+        for block in ct.blocks() {
+            let b = f64::log2((block.degree.get() + 1) as f64).ceil() as u64;
+            vec_deg_basis.push(b);
+            total_bit += b;
+        }
+
+        let lut_size = if 1 << total_bit < self.wopbs_key.param.polynomial_size.0 as u64 {
+            self.wopbs_key.param.polynomial_size.0
+        } else {
+            1 << total_bit
+        };
+        let mut lut = IntegerWopbsLUT::new(PlaintextCount(lut_size), CiphertextCount(basis.len()));
+
+        let radix_basis = ct.moduli()[0];
+        for lut_index_val in 0..(1 << total_bit) {
+            let encoded_with_deg_val = encode_mix_radix(lut_index_val, &vec_deg_basis, radix_basis);
+            let decoded_val = decode_radix(&encoded_with_deg_val, radix_basis);
+            for (j, b) in basis.iter().enumerate() {
+                lut[j][lut_index_val as usize] =
+                    (((decoded_val % b) as u128 * (1 << 64)) / *b as u128) as u64;
+            }
+        }
+
+        let vec_ct_out = self.circuit_bootstrap_vertical_packing_radix(ct, &lut);
+
+        let pbs_order = ct.blocks[0].pbs_order;
+        let blocks = vec_ct_out
+            .into_iter()
+            .zip(basis.iter())
+            .map(|(block_out, b)| {
+                crate::shortint::Ciphertext::new(
+                    block_out,
+                    Degree::new(*b as usize - 1),
+                    NoiseLevel::NOMINAL,
+                    MessageModulus(*b as usize),
+                    CarryModulus(1),
+                    pbs_order,
+                )
+            })
+            .collect();
+        CrtCiphertext::from((blocks, basis.to_vec()))
+    }
+
     fn circuit_bootstrap_vertical_packing_native_crt<T>(
         &self,
         vec_ct_in: &[T],
         lut: &IntegerWopbsLUT,
     ) -> T
+    where
+        T: IntegerCiphertext,
+    {
+        let vec_ct_out = self.native_crt_circuit_bootstrap_vertical_packing(vec_ct_in, lut);
+
+        let mut ct_vec_out = Vec::with_capacity(vec_ct_in.len());
+        for (block, block_out) in vec_ct_in[0].blocks().iter().zip(vec_ct_out) {
+            ct_vec_out.push(crate::shortint::Ciphertext::new(
+                block_out,
+                Degree::new(block.message_modulus.0 - 1),
+                NoiseLevel::NOMINAL,
+                block.message_modulus,
+                block.carry_modulus,
+                block.pbs_order,
+            ));
+        }
+        T::from_blocks(ct_vec_out)
+    }
+
+    /// Extracts the bits of the native CRT blocks of the ciphertexts and evaluates each small lut
+    /// of `lut` on them, returning one LWE ciphertext per small lut.
+    fn native_crt_circuit_bootstrap_vertical_packing<T>(
+        &self,
+        vec_ct_in: &[T],
+        lut: &IntegerWopbsLUT,
+    ) -> Vec<LweCiphertextOwned<u64>>
     where
         T: IntegerCiphertext,
     {
@@ -1111,22 +1309,8 @@ impl WopbsKey {
             }
         }
 
-        let vec_ct_out = self
-            .wopbs_key
-            .circuit_bootstrapping_vertical_packing(lut.as_ref(), &extracted_bits_blocks);
-
-        let mut ct_vec_out = Vec::with_capacity(vec_ct_in.len());
-        for (block, block_out) in vec_ct_in[0].blocks().iter().zip(vec_ct_out) {
-            ct_vec_out.push(crate::shortint::Ciphertext::new(
-                block_out,
-                Degree::new(block.message_modulus.0 - 1),
-                NoiseLevel::NOMINAL,
-                block.message_modulus,
-                block.carry_modulus,
-                block.pbs_order,
-            ));
-        }
-        T::from_blocks(ct_vec_out)
+        self.wopbs_key
+            .circuit_bootstrapping_vertical_packing(lut.as_ref(), &extracted_bits_blocks)
     }
 
     pub fn keyswitch_to_wopbs_params<'a, T>(&self, sks: &ServerKey, ct_in: &'a T) -> T
@@ -51,6 +51,7 @@ create_parametrized_test!(wopbs_crt_non_reg);
 create_parametrized_test!(wopbs_bivariate_radix);
 create_parametrized_test!(wopbs_bivariate_crt);
 create_parametrized_test!(wopbs_radix);
+create_parametrized_test!(wopbs_native_crt_radix_conversion);
 
 fn make_basis(message_modulus: usize) -> Vec<u64> {
     match message_modulus {
@@ -193,6 +194,38 @@ pub fn wopbs_radix(params: (ClassicPBSParameters, WopbsParameters)) {
     }
 }
 
+pub fn wopbs_native_crt_radix_conversion(params: (ClassicPBSParameters, WopbsParameters)) {
+    let mut rng = rand::thread_rng();
+
+    let nb_block = 2;
+    let basis = make_basis(params.1.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(params.0, IntegerKeyKind::Radix);
+    let wopbs_key = KEY_CACHE_WOPBS.get_from_params(params);
+
+    let msg_space = (params.0.message_modulus.0 as u64).pow(nb_block as u32);
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % msg_space;
+        let ct = cks.encrypt_radix(clear, nb_block);
+
+        let ct = wopbs_key.keyswitch_to_wopbs_params(&sks, &ct);
+        let ct_crt = wopbs_key.radix_to_native_crt(&ct, &basis);
+        assert_eq!(ct_crt.moduli, basis);
+
+        let ct_crt_pbs = wopbs_key.keyswitch_to_pbs_params(&ct_crt);
+        let res_crt = cks.decrypt_native_crt(&ct_crt_pbs);
+        assert_eq!(res_crt, clear % modulus);
+
+        let ct_res = wopbs_key.native_crt_to_radix(&ct_crt, nb_block);
+        assert_eq!(ct_res.blocks.len(), nb_block);
+        let ct_res = wopbs_key.keyswitch_to_pbs_params(&ct_res);
+        let res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(res, clear % modulus);
+    }
+}
+
 // test wopbs radix with different degree for each Ct
 pub fn wopbs_bivariate_radix(params: (ClassicPBSParameters, WopbsParameters)) {
     let mut rng = rand::thread_rng();
@@ -1,4 +1,5 @@
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::ciphertext::{CompactCiphertextListExpander, DataKind};
 use crate::integer::encryption::KnowsMessageModulus;
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::IntegerRadixCiphertext;
@@ -13,7 +14,7 @@ impl CompactPublicKey {
         public_params: &CompactPkePublicParams,
         load: ZkComputeLoad,
     ) -> crate::Result<ProvenCompactCiphertextList> {
-        let messages = messages
+        let clear_blocks = messages
             .iter()
             .copied()
             .flat_map(|message| {
@@ -25,11 +26,11 @@ impl CompactPublicKey {
 
         let proved_list = self
             .key
-            .encrypt_and_prove_slice(&messages, public_params, load)?;
+            .encrypt_and_prove_slice(&clear_blocks, public_params, load)?;
 
         Ok(ProvenCompactCiphertextList {
             proved_list,
-            num_blocks_per_integer,
+            info: vec![DataKind::Unsigned(num_blocks_per_integer); messages.len()],
         })
     }
 }
@@ -37,10 +38,13 @@ impl CompactPublicKey {
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactCiphertextList {
     pub(crate) proved_list: crate::shortint::ciphertext::ProvenCompactCiphertextList,
-    // Keep track of the num_blocks, as we allow
-    // storing many integer that have the same num_blocks
-    // into ct_list
-    pub(crate) num_blocks_per_integer: usize,
+    // Keep track of the kind (and thus the num_blocks) of each integer
+    // stored into proved_list
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl crate::named::Named for ProvenCompactCiphertextList {
+    const NAME: &'static str = "integer::ProvenCompactCiphertextList";
 }
 
 impl ProvenCompactCiphertextList {
@@ -52,13 +56,28 @@ impl ProvenCompactCiphertextList {
         let blocks = self
             .proved_list
             .verify_and_expand(public_params, &public_key.key)?;
-        assert_eq!(blocks.len(), self.num_blocks_per_integer);
+        let first_kind = self
+            .info
+            .first()
+            .ok_or_else(|| crate::Error::new("The list is empty".to_string()))?;
+        if blocks.len() != first_kind.num_blocks() {
+            return Err(crate::Error::new(format!(
+                "Expected {} blocks, got {}",
+                first_kind.num_blocks(),
+                blocks.len()
+            )));
+        }
 
         Ok(T::from_blocks(blocks))
     }
 
     pub fn ciphertext_count(&self) -> usize {
-        self.proved_list.ciphertext_count() / self.num_blocks_per_integer
+        self.info.len()
+    }
+
+    /// Returns the kind of the value at the given index, or `None` if the index is out of bounds
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
     }
 
     pub fn verify_and_expand<T: IntegerRadixCiphertext>(
@@ -72,16 +91,45 @@ impl ProvenCompactCiphertextList {
 
         let mut integers = Vec::with_capacity(self.ciphertext_count());
         let mut blocks_iter = blocks.into_iter();
-        for _ in 0..self.ciphertext_count() {
+        for kind in &self.info {
             let radix_blocks = blocks_iter
                 .by_ref()
-                .take(self.num_blocks_per_integer)
+                .take(kind.num_blocks())
                 .collect::<Vec<_>>();
             integers.push(T::from_blocks(radix_blocks));
         }
         Ok(integers)
     }
 
+    /// Verifies the proof and expands the list so that each of its values
+    /// can be retrieved with its own type.
+    pub fn verify_and_expand_heterogeneous(
+        &self,
+        public_params: &CompactPkePublicParams,
+        public_key: &CompactPublicKey,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        let blocks = self
+            .proved_list
+            .verify_and_expand(public_params, &public_key.key)?;
+
+        let expected_num_blocks = self
+            .info
+            .iter()
+            .map(|kind| kind.num_blocks())
+            .sum::<usize>();
+        if blocks.len() != expected_num_blocks {
+            return Err(crate::Error::new(format!(
+                "The list contains {} blocks, but its metadata describes {expected_num_blocks} blocks",
+                blocks.len()
+            )));
+        }
+
+        Ok(CompactCiphertextListExpander::new(
+            blocks,
+            self.info.clone(),
+        ))
+    }
+
     pub fn verify(
         &self,
         public_params: &CompactPkePublicParams,
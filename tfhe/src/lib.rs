@@ -131,9 +131,15 @@ pub mod conformance;
 
 pub mod named;
 
+pub mod versionable;
+
 pub mod error;
 #[cfg(feature = "zk-pok-experimental")]
 pub mod zk;
 
+#[cfg(feature = "threshold-experimental")]
+/// cbindgen:ignore
+pub mod threshold;
+
 pub use error::{Error, ErrorKind};
 pub type Result<T> = std::result::Result<T, Error>;
@@ -1,3 +1,91 @@
 pub trait Named {
     const NAME: &'static str;
 }
+
+#[cfg(feature = "integer")]
+const MAX_BUILT_NAME_LEN: usize = 256;
+
+/// Builds the [Named::NAME] of a generic type in a const context, so that the name can include
+/// the names of its type parameters and the values of its const parameters.
+#[cfg(feature = "integer")]
+#[derive(Copy, Clone)]
+pub(crate) struct NameBuilder {
+    bytes: [u8; MAX_BUILT_NAME_LEN],
+    len: usize,
+}
+
+#[cfg(feature = "integer")]
+impl NameBuilder {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; MAX_BUILT_NAME_LEN],
+            len: 0,
+        }
+    }
+
+    pub(crate) const fn push_str(mut self, value: &str) -> Self {
+        let bytes = value.as_bytes();
+        assert!(
+            self.len + bytes.len() <= MAX_BUILT_NAME_LEN,
+            "The name is too long"
+        );
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn push_u32(mut self, mut value: u32) -> Self {
+        let mut digits = [0u8; 10];
+        let mut num_digits = 0;
+        loop {
+            digits[num_digits] = b'0' + (value % 10) as u8;
+            num_digits += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+
+        assert!(
+            self.len + num_digits <= MAX_BUILT_NAME_LEN,
+            "The name is too long"
+        );
+        while num_digits > 0 {
+            num_digits -= 1;
+            self.bytes[self.len] = digits[num_digits];
+            self.len += 1;
+        }
+        self
+    }
+
+    pub(crate) const fn as_str(&self) -> &str {
+        let (bytes, _) = self.bytes.split_at(self.len);
+        match std::str::from_utf8(bytes) {
+            Ok(name) => name,
+            Err(_) => panic!("The name is not valid UTF-8"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "integer"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_builder() {
+        const NAME: NameBuilder = NameBuilder::new()
+            .push_str("Outer<")
+            .push_str("Inner")
+            .push_str(", ")
+            .push_u32(0)
+            .push_str(", ")
+            .push_u32(4096)
+            .push_str(">");
+
+        assert_eq!(NAME.as_str(), "Outer<Inner, 0, 4096>");
+    }
+}
@@ -1,19 +1,20 @@
 use crate::conformance::ParameterSetConformant;
 use crate::named::Named;
+use crate::versionable::{Versionize, VersionsDispatch};
 use bincode::Options;
-use serde::de::DeserializeOwned;
-use serde::Serialize;
 
 // The `SERIALIZATION_VERSION` is serialized along objects serialized with `safe_serialize`.
-// This `SERIALIZATION_VERSION` should be changed on each release where any object serialization
-// details changes (this can happen when adding a field or reorderging fields of a struct).
+// It identifies the format of the envelope (version, type name, then the object), not the format
+// of the object itself: each serialized type is versioned on its own (see [crate::versionable]).
 // When a object is deserialized using `safe_deserialize`, the deserialized version is checked
-// to be equal to SERIALIZATION_VERSION.
-// This helps prevent users from inadvertently deserializaing an object serialized in another
-// release.
-// When this happens, it also gives a clear version mismatch error rather than a generic
+// to be either SERIALIZATION_VERSION or LEGACY_SERIALIZATION_VERSION.
+// This gives a clear version mismatch error rather than a generic
 // deserialization error or worse, a garbage object.
-const SERIALIZATION_VERSION: &str = "0.3";
+const SERIALIZATION_VERSION: &str = "0.4";
+
+// Version of the envelope written before types were versioned, objects serialized with it are
+// deserialized as the first version of their type and then upgraded.
+const LEGACY_SERIALIZATION_VERSION: &str = "0.3";
 
 // `VERSION_LENGTH_LIMIT` is the maximum `SERIALIZATION_VERSION` size which `safe_deserialization`
 // is going to try to read (it returns an error if it's too big).
@@ -23,11 +24,14 @@ const VERSION_LENGTH_LIMIT: u64 = 100;
 const TYPE_NAME_LENGTH_LIMIT: u64 = 1000;
 
 /// Serializes an object into a [writer](std::io::Write).
-/// The result contains a version of the serialization and the name of the
-/// serialized type to provide checks on deserialization with [safe_deserialize].
+/// The result contains a version of the serialization, the name of the
+/// serialized type and the version of the type to provide checks on deserialization
+/// with [safe_deserialize].
+/// Unlike serializing the object directly with `serde`, this keeps the data readable by
+/// future releases.
 /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
 /// (excluding version and name serialization).
-pub fn safe_serialize<T: Serialize + Named>(
+pub fn safe_serialize<T: Versionize + Named>(
     object: &T,
     mut writer: impl std::io::Write,
     serialized_size_limit: u64,
@@ -46,7 +50,7 @@ pub fn safe_serialize<T: Serialize + Named>(
 
     options
         .with_limit(serialized_size_limit)
-        .serialize_into(&mut writer, object)?;
+        .serialize_into(&mut writer, &object.versionize())?;
 
     Ok(())
 }
@@ -54,9 +58,10 @@ pub fn safe_serialize<T: Serialize + Named>(
 /// Deserializes an object serialized by `safe_serialize` from a [reader](std::io::Read).
 /// Checks that the serialization version and the name of the
 /// deserialized type are correct.
+/// Objects serialized by a previous release are upgraded to the current version of their type.
 /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
 /// (excluding version and name serialization).
-pub fn safe_deserialize<T: DeserializeOwned + Named>(
+pub fn safe_deserialize<T: Versionize + Named>(
     mut reader: impl std::io::Read,
     serialized_size_limit: u64,
 ) -> Result<T, String> {
@@ -69,9 +74,12 @@ pub fn safe_deserialize<T: DeserializeOwned + Named>(
         .deserialize_from::<_, String>(&mut reader)
         .map_err(|err| err.to_string())?;
 
-    if deserialized_version != SERIALIZATION_VERSION {
+    if deserialized_version != SERIALIZATION_VERSION
+        && deserialized_version != LEGACY_SERIALIZATION_VERSION
+    {
         return Err(format!(
-            "On deserialization, expected serialization version {SERIALIZATION_VERSION}, got version {deserialized_version}"
+            "On deserialization, expected serialization version {SERIALIZATION_VERSION} \
+            or {LEGACY_SERIALIZATION_VERSION}, got version {deserialized_version}"
         ));
     }
 
@@ -88,15 +96,43 @@ pub fn safe_deserialize<T: DeserializeOwned + Named>(
         ));
     }
 
-    options
+    let options = options.with_limit(serialized_size_limit);
+    let versioned = if deserialized_version == LEGACY_SERIALIZATION_VERSION {
+        options
+            .deserialize_from::<_, <T::Versions as VersionsDispatch<T>>::Legacy>(&mut reader)
+            .map(T::Versions::from_legacy)
+    } else {
+        options.deserialize_from::<_, T::Versions>(&mut reader)
+    }
+    .map_err(|err| err.to_string())?;
+
+    T::unversionize(versioned).map_err(|err| err.to_string())
+}
+
+/// Deserializes an object serialized without `safe_serialize` by a previous release.
+///
+/// The object was serialized directly with `serde`, using bincode's default configuration
+/// (e.g. with `bincode::serialize`), by a release predating versioning, and is read from a
+/// [reader](std::io::Read).
+/// The object is read as the first version of its type and then upgraded to the current one.
+/// `serialized_size_limit` is the size limit (in number of byte) of the serialized object.
+pub fn legacy_deserialize<T: Versionize>(
+    reader: impl std::io::Read,
+    serialized_size_limit: u64,
+) -> Result<T, String> {
+    let legacy = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
         .with_limit(serialized_size_limit)
-        .deserialize_from(&mut reader)
-        .map_err(|err| err.to_string())
+        .deserialize_from::<_, <T::Versions as VersionsDispatch<T>>::Legacy>(reader)
+        .map_err(|err| err.to_string())?;
+
+    T::unversionize(T::Versions::from_legacy(legacy)).map_err(|err| err.to_string())
 }
 
 /// Deserializes an object with [safe_deserialize] and checks than it is conformant with the given
 /// parameter set
-pub fn safe_deserialize_conformant<T: DeserializeOwned + Named + ParameterSetConformant>(
+pub fn safe_deserialize_conformant<T: Versionize + Named + ParameterSetConformant>(
     reader: impl std::io::Read,
     serialized_size_limit: u64,
     parameter_set: &T::ParameterSet,
@@ -115,11 +151,16 @@ pub fn safe_deserialize_conformant<T: DeserializeOwned + Named + ParameterSetCon
 
 #[cfg(all(test, feature = "shortint"))]
 mod test_shortint {
-    use crate::safe_deserialization::{safe_deserialize_conformant, safe_serialize};
+    use crate::named::Named;
+    use crate::safe_deserialization::{
+        safe_deserialize, safe_deserialize_conformant, safe_serialize,
+        LEGACY_SERIALIZATION_VERSION, SERIALIZATION_VERSION,
+    };
     use crate::shortint::parameters::{
         PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
     };
     use crate::shortint::{gen_keys, Ciphertext};
+    use bincode::Options;
 
     #[test]
     fn safe_desererialization_ct() {
@@ -150,6 +191,41 @@ mod test_shortint {
         let dec = ck.decrypt(&ct2);
         assert_eq!(msg, dec);
     }
+
+    #[test]
+    fn safe_desererialization_ct_versions() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let msg = 1_u64;
+
+        let ct = ck.encrypt(msg);
+
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+        let serialize_with_version = |version: &str| {
+            let mut buffer = vec![];
+            options.serialize_into(&mut buffer, version).unwrap();
+            options
+                .serialize_into(&mut buffer, Ciphertext::NAME)
+                .unwrap();
+            options.serialize_into(&mut buffer, &ct).unwrap();
+            buffer
+        };
+
+        // Objects serialized before versioning are accepted
+        let buffer = serialize_with_version(LEGACY_SERIALIZATION_VERSION);
+        let ct2: Ciphertext = safe_deserialize(buffer.as_slice(), 1 << 20).unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+
+        let buffer = serialize_with_version("0.2");
+        assert!(safe_deserialize::<Ciphertext>(buffer.as_slice(), 1 << 20).is_err());
+
+        // Objects serialized with the current version cannot be read as legacy ones
+        let mut buffer = vec![];
+        safe_serialize(&ct, &mut buffer, 1 << 20).unwrap();
+        let ct2: Ciphertext = safe_deserialize(buffer.as_slice(), 1 << 20).unwrap();
+        assert_eq!(msg, ck.decrypt(&ct2));
+        assert_ne!(buffer, serialize_with_version(SERIALIZATION_VERSION));
+    }
 }
 
 #[cfg(all(test, feature = "integer"))]
@@ -0,0 +1,35 @@
+//! Versions of the serialized shortint types, see [crate::versionable]
+use crate::shortint::{
+    Ciphertext, ClientKey, CompressedCiphertext, CompressedServerKey, ServerKey,
+};
+use crate::versionable::versions_dispatch;
+
+versions_dispatch!(
+    pub enum CiphertextVersions {
+        V0(Ciphertext),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedCiphertextVersions {
+        V0(CompressedCiphertext),
+    }
+);
+
+versions_dispatch!(
+    pub enum ClientKeyVersions {
+        V0(ClientKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum ServerKeyVersions {
+        V0(ServerKey),
+    }
+);
+
+versions_dispatch!(
+    pub enum CompressedServerKeyVersions {
+        V0(CompressedServerKey),
+    }
+);
@@ -20,6 +20,10 @@ pub struct CompressedCiphertext {
     pub noise_level: NoiseLevel,
 }
 
+impl crate::named::Named for CompressedCiphertext {
+    const NAME: &'static str = "shortint::CompressedCiphertext";
+}
+
 impl ParameterSetConformant for CompressedCiphertext {
     type ParameterSet = CiphertextConformanceParams;
 
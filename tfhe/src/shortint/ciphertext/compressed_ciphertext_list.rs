@@ -0,0 +1,42 @@
+use super::common::*;
+use crate::core_crypto::prelude::compressed_modulus_switched_glwe_ciphertext::CompressedModulusSwitchedGlweCiphertext;
+use crate::core_crypto::prelude::LweCiphertextCount;
+use crate::shortint::parameters::CiphertextModulus;
+use crate::shortint::{CarryModulus, MessageModulus};
+
+/// A list of ciphertexts packed into modulus switched GLWE ciphertexts, to be stored in little
+/// memory.
+///
+/// It is created by a [CompressionKey](crate::shortint::list_compression::CompressionKey) and
+/// ciphertexts are retrieved using a
+/// [DecompressionKey](crate::shortint::list_compression::DecompressionKey).
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompressedCiphertextList {
+    pub(crate) modulus_switched_glwe_ciphertext_list:
+        Vec<CompressedModulusSwitchedGlweCiphertext<u64>>,
+    pub(crate) ciphertext_modulus: CiphertextModulus,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+    pub(crate) pbs_order: PBSOrder,
+    pub(crate) lwe_per_glwe: LweCiphertextCount,
+    pub(crate) count: usize,
+}
+
+impl CompressedCiphertextList {
+    /// Returns the number of ciphertexts stored in the list
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
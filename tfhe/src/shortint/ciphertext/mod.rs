@@ -1,12 +1,14 @@
 mod common;
 mod compact_list;
 mod compressed;
+mod compressed_ciphertext_list;
 mod compressed_modulus_switched_ciphertext;
 mod standard;
 
 pub use common::*;
 pub use compact_list::*;
 pub use compressed::*;
+pub use compressed_ciphertext_list::*;
 pub use compressed_modulus_switched_ciphertext::*;
 pub use standard::*;
 #[cfg(feature = "zk-pok-experimental")]
@@ -26,6 +26,10 @@ pub struct ClientKey {
     pub parameters: ShortintParameterSet,
 }
 
+impl crate::named::Named for ClientKey {
+    const NAME: &'static str = "shortint::ClientKey";
+}
+
 impl ClientKey {
     /// Generate a client key.
     ///
@@ -0,0 +1,315 @@
+use super::{CompressionKey, DecompressionKey};
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::prelude::compressed_modulus_switched_glwe_ciphertext::CompressedModulusSwitchedGlweCiphertext;
+use crate::core_crypto::prelude::{
+    extract_lwe_sample_from_glwe_ciphertext,
+    par_keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext, GlweCiphertext,
+    GlweCiphertextParameters, LweCiphertext, LweCiphertextCount, LweCiphertextList, MonomialDegree,
+};
+use crate::shortint::ciphertext::{CompressedCiphertextList, Degree, NoiseLevel};
+use crate::shortint::engine::{fill_accumulator, ShortintEngine};
+use crate::shortint::server_key::{apply_programmable_bootstrap, LookupTableOwned};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl CompressionKey {
+    /// Packs the given ciphertexts into a [CompressedCiphertextList]
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `ciphertexts` is empty
+    /// - Panics if the ciphertexts do not all have the same moduli and PBS order
+    /// - Panics if a ciphertext does not have a nominal noise level or has a non-empty carry
+    pub fn compress_ciphertexts_into_list(
+        &self,
+        ciphertexts: &[Ciphertext],
+    ) -> CompressedCiphertextList {
+        let lwe_pksk = &self.packing_key_switching_key;
+
+        let polynomial_size = lwe_pksk.output_polynomial_size();
+        let ciphertext_modulus = lwe_pksk.ciphertext_modulus();
+        let glwe_size = lwe_pksk.output_glwe_size();
+        let lwe_size = lwe_pksk.input_key_lwe_dimension().to_lwe_size();
+
+        let lwe_per_glwe = self.lwe_per_glwe;
+
+        assert!(
+            lwe_per_glwe.0 <= polynomial_size.0,
+            "Cannot pack more than polynomial_size (={}) ciphertexts in a GLWE (lwe_per_glwe={})",
+            polynomial_size.0,
+            lwe_per_glwe.0,
+        );
+
+        let first_ct = ciphertexts
+            .first()
+            .expect("Cannot compress an empty list of ciphertexts");
+
+        let message_modulus = first_ct.message_modulus;
+        let carry_modulus = first_ct.carry_modulus;
+        let pbs_order = first_ct.pbs_order;
+
+        for ct in ciphertexts {
+            assert_eq!(ct.message_modulus, message_modulus);
+            assert_eq!(ct.carry_modulus, carry_modulus);
+            assert_eq!(ct.pbs_order, pbs_order);
+            assert!(
+                ct.noise_level().get() <= NoiseLevel::NOMINAL.get(),
+                "Ciphertexts must have at most a nominal noise level to be compressed"
+            );
+            assert!(
+                ct.carry_is_empty(),
+                "Ciphertexts must have an empty carry to be compressed"
+            );
+        }
+
+        let modulus_switched_glwe_ciphertext_list = ciphertexts
+            .par_chunks(lwe_per_glwe.0)
+            .map(|ct_list| {
+                let mut list = Vec::with_capacity(ct_list.len() * lwe_size.0);
+                for ct in ct_list {
+                    assert_eq!(
+                        lwe_size,
+                        ct.ct.lwe_size(),
+                        "All ciphertexts must be encrypted under the big LWE key"
+                    );
+                    list.extend_from_slice(ct.ct.as_ref());
+                }
+
+                let list = LweCiphertextList::from_container(list, lwe_size, ciphertext_modulus);
+
+                let bodies_count = list.lwe_ciphertext_count();
+
+                let mut out =
+                    GlweCiphertext::new(0, glwe_size, polynomial_size, ciphertext_modulus);
+
+                par_keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+                    lwe_pksk, &list, &mut out,
+                );
+
+                CompressedModulusSwitchedGlweCiphertext::compress(
+                    &out,
+                    self.storage_log_modulus,
+                    LweCiphertextCount(bodies_count.0),
+                )
+            })
+            .collect();
+
+        CompressedCiphertextList {
+            modulus_switched_glwe_ciphertext_list,
+            ciphertext_modulus,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+            lwe_per_glwe,
+            count: ciphertexts.len(),
+        }
+    }
+}
+
+impl DecompressionKey {
+    /// Gets back the ciphertext at the given `index` of the packed list
+    ///
+    /// This operation uses a PBS, the returned ciphertext has a nominal noise level.
+    ///
+    /// Returns an error if the index is out of bounds, or if the list is malformed or was not
+    /// packed with parameters compatible with this key (e.g. a list received from the network).
+    pub fn unpack(
+        &self,
+        packed: &CompressedCiphertextList,
+        index: usize,
+    ) -> crate::Result<Ciphertext> {
+        if index >= packed.count {
+            return Err(crate::Error::new(format!(
+                "Index {index} is out of bounds, the list stores {} ciphertexts",
+                packed.count
+            )));
+        }
+
+        if packed.lwe_per_glwe != self.lwe_per_glwe {
+            return Err(crate::Error::new(
+                "The list was not packed with parameters compatible with this key".to_string(),
+            ));
+        }
+
+        let carry_modulus = packed.carry_modulus;
+        let message_modulus = packed.message_modulus;
+        let ciphertext_modulus = packed.ciphertext_modulus;
+
+        let lwe_per_glwe = packed.lwe_per_glwe.0;
+
+        let glwe_index = index / lwe_per_glwe;
+        let index_in_glwe = index % lwe_per_glwe;
+
+        let malformed_list_error = || {
+            crate::Error::new(format!(
+                "The list is malformed, cannot unpack index {index}"
+            ))
+        };
+
+        let compressed_glwe = packed
+            .modulus_switched_glwe_ciphertext_list
+            .get(glwe_index)
+            .ok_or_else(malformed_list_error)?;
+
+        let intermediate_lwe_dimension = compressed_glwe
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(compressed_glwe.polynomial_size());
+
+        let is_well_formed = compressed_glwe.is_conformant(&GlweCiphertextParameters {
+            glwe_dim: compressed_glwe.glwe_dimension(),
+            polynomial_size: compressed_glwe.polynomial_size(),
+            ct_modulus: ciphertext_modulus,
+        }) && compressed_glwe.log_modulus().0 > 0
+            && index_in_glwe < compressed_glwe.bodies_count().0
+            && intermediate_lwe_dimension == self.blind_rotate_key.input_lwe_dimension();
+        if !is_well_formed {
+            return Err(malformed_list_error());
+        }
+
+        let glwe = compressed_glwe.extract();
+
+        let mut intermediate_lwe = LweCiphertext::new(
+            0,
+            intermediate_lwe_dimension.to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &glwe,
+            &mut intermediate_lwe,
+            MonomialDegree(index_in_glwe),
+        );
+
+        let mut acc = GlweCiphertext::new(
+            0,
+            self.blind_rotate_key.glwe_size(),
+            self.blind_rotate_key.polynomial_size(),
+            ciphertext_modulus,
+        );
+
+        fill_accumulator(
+            &mut acc,
+            self.blind_rotate_key.polynomial_size(),
+            self.blind_rotate_key.glwe_size(),
+            message_modulus,
+            carry_modulus,
+            |x| x,
+        );
+
+        // Carries were empty when the ciphertexts were compressed
+        let lut = LookupTableOwned {
+            acc,
+            degree: Degree::new(message_modulus.0 - 1),
+        };
+
+        let mut output = LweCiphertext::new(
+            0,
+            self.blind_rotate_key.output_lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            apply_programmable_bootstrap(
+                &self.blind_rotate_key,
+                &intermediate_lwe,
+                &mut output,
+                &lut,
+                &mut engine.computation_buffers,
+            );
+        });
+
+        Ok(Ciphertext::new(
+            output,
+            lut.degree,
+            NoiseLevel::NOMINAL,
+            message_modulus,
+            carry_modulus,
+            packed.pbs_order,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::{gen_keys, ClientKey};
+
+    #[test]
+    fn test_compression_decompression() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+
+        let (compression_key, decompression_key) =
+            cks.new_compression_decompression_keys(&private_compression_key);
+
+        let lwe_per_glwe = COMP_PARAM_MESSAGE_2_CARRY_2.lwe_per_glwe.0;
+
+        // Use more ciphertexts than what fits in a single GLWE
+        for number_to_pack in [1, 3, lwe_per_glwe + 2] {
+            let clears: Vec<u64> = (0..number_to_pack as u64).map(|i| i % 4).collect();
+
+            let cts: Vec<_> = clears
+                .iter()
+                .map(|clear| sks.scalar_add(&cks.unchecked_encrypt(0), *clear as u8))
+                .collect();
+
+            let packed = compression_key.compress_ciphertexts_into_list(&cts);
+            assert_eq!(packed.len(), number_to_pack);
+
+            check_unpacked(&cks, &decompression_key, &packed, &clears);
+
+            let serialized = bincode::serialize(&packed).unwrap();
+            let deserialized = bincode::deserialize(&serialized).unwrap();
+            assert_eq!(packed, deserialized);
+
+            assert!(decompression_key.unpack(&packed, number_to_pack).is_err());
+        }
+    }
+
+    #[test]
+    fn test_unpack_malformed_list() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+
+        let (compression_key, decompression_key) =
+            cks.new_compression_decompression_keys(&private_compression_key);
+
+        let cts: Vec<_> = (0..3).map(|i| sks.scalar_add(&cks.encrypt(0), i)).collect();
+        let packed = compression_key.compress_ciphertexts_into_list(&cts);
+
+        // The declared number of ciphertexts does not match the stored GLWEs
+        let mut malformed = packed.clone();
+        malformed.modulus_switched_glwe_ciphertext_list.clear();
+        assert!(decompression_key.unpack(&malformed, 0).is_err());
+
+        // Values not stored in the GLWE body
+        let mut malformed = packed.clone();
+        malformed.count = 10;
+        assert!(decompression_key.unpack(&malformed, 5).is_err());
+
+        let mut malformed = packed;
+        malformed.lwe_per_glwe.0 += 1;
+        assert!(decompression_key.unpack(&malformed, 0).is_err());
+    }
+
+    fn check_unpacked(
+        cks: &ClientKey,
+        decompression_key: &super::DecompressionKey,
+        packed: &crate::shortint::ciphertext::CompressedCiphertextList,
+        clears: &[u64],
+    ) {
+        // Unpacking all of them is slow, only check the first and last ones of each GLWE
+        let lwe_per_glwe = decompression_key.lwe_per_glwe.0;
+        for (i, clear) in clears.iter().enumerate() {
+            if i % lwe_per_glwe > 1 && i + 1 != clears.len() {
+                continue;
+            }
+            let unpacked = decompression_key.unpack(packed, i).unwrap();
+            assert_eq!(unpacked.degree.get(), 3);
+            assert_eq!(cks.decrypt(&unpacked), *clear);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Compression of already computed ciphertexts.
+//!
+//! Ciphertexts coming out of a PBS can be packed, thanks to a packing keyswitch, into a few GLWE
+//! ciphertexts which are then modulus switched to a small storage modulus. This gives a
+//! [CompressedCiphertextList](crate::shortint::ciphertext::CompressedCiphertextList) that is much
+//! smaller than the original ciphertexts.
+//!
+//! Getting a usable [Ciphertext](crate::shortint::Ciphertext) back requires a PBS, done by the
+//! [DecompressionKey].
+mod compression;
+mod private_key;
+mod server_keys;
+
+pub use private_key::CompressionPrivateKeys;
+pub use server_keys::{CompressionKey, DecompressionKey};
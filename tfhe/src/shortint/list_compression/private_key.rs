@@ -0,0 +1,104 @@
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_glwe_secret_key, GlweSecretKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::list_compression::CompressionParameters;
+use crate::shortint::{ClientKey, EncryptionKeyChoice};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// The private key used to generate the [CompressionKey](super::CompressionKey) and the
+/// [DecompressionKey](super::DecompressionKey)
+///
+/// Packed ciphertexts are encrypted under this key, so it **MUST NOT** be sent to the server.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    pub(crate) post_packing_ks_key: GlweSecretKeyOwned<u64>,
+    pub(crate) params: CompressionParameters,
+}
+
+impl CompressionPrivateKeys {
+    pub fn parameters(&self) -> CompressionParameters {
+        self.params
+    }
+
+    pub fn into_raw_parts(self) -> (GlweSecretKeyOwned<u64>, CompressionParameters) {
+        let Self {
+            post_packing_ks_key,
+            params,
+        } = self;
+
+        (post_packing_ks_key, params)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the key dimensions do not match the parameters
+    pub fn from_raw_parts(
+        post_packing_ks_key: GlweSecretKeyOwned<u64>,
+        params: CompressionParameters,
+    ) -> Self {
+        assert_eq!(
+            post_packing_ks_key.glwe_dimension(),
+            params.packing_ks_glwe_dimension
+        );
+        assert_eq!(
+            post_packing_ks_key.polynomial_size(),
+            params.packing_ks_polynomial_size
+        );
+
+        Self {
+            post_packing_ks_key,
+            params,
+        }
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compression_private_key(
+        &mut self,
+        cks: &ClientKey,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        assert_eq!(
+            cks.parameters.encryption_key_choice(),
+            EncryptionKeyChoice::Big,
+            "Compression is only compatible with ciphertexts in the big LWE dimension (KS_PBS)"
+        );
+
+        assert!(
+            params.lwe_per_glwe.0 <= params.packing_ks_polynomial_size.0,
+            "Cannot pack more than polynomial_size (={}) ciphertexts in a GLWE (lwe_per_glwe={})",
+            params.packing_ks_polynomial_size.0,
+            params.lwe_per_glwe.0,
+        );
+
+        let post_packing_ks_key = allocate_and_generate_new_binary_glwe_secret_key(
+            params.packing_ks_glwe_dimension,
+            params.packing_ks_polynomial_size,
+            &mut self.secret_generator,
+        );
+
+        CompressionPrivateKeys {
+            post_packing_ks_key,
+            params,
+        }
+    }
+}
+
+impl ClientKey {
+    /// Generates the private key needed to create compression keys
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client key parameters do not encrypt ciphertexts under the big LWE key
+    /// ([EncryptionKeyChoice::Big]).
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compression_private_key(self, params)
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use super::CompressionPrivateKeys;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_packing_keyswitch_key,
+    par_allocate_and_generate_new_lwe_bootstrap_key,
+    par_convert_standard_lwe_bootstrap_key_to_fourier, CiphertextModulusLog,
+    FourierLweBootstrapKey, LweBootstrapKeyOwned, LweCiphertextCount, LwePackingKeyswitchKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::{ClientKey, EncryptionKeyChoice};
+use serde::{Deserialize, Serialize};
+
+/// Server key used to compress ciphertexts into a
+/// [CompressedCiphertextList](crate::shortint::ciphertext::CompressedCiphertextList)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    pub(crate) packing_key_switching_key: LwePackingKeyswitchKeyOwned<u64>,
+    pub(crate) lwe_per_glwe: LweCiphertextCount,
+    pub(crate) storage_log_modulus: CiphertextModulusLog,
+}
+
+impl CompressionKey {
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        LwePackingKeyswitchKeyOwned<u64>,
+        LweCiphertextCount,
+        CiphertextModulusLog,
+    ) {
+        let Self {
+            packing_key_switching_key,
+            lwe_per_glwe,
+            storage_log_modulus,
+        } = self;
+
+        (packing_key_switching_key, lwe_per_glwe, storage_log_modulus)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `lwe_per_glwe` is greater than the output polynomial size of the packing key
+    pub fn from_raw_parts(
+        packing_key_switching_key: LwePackingKeyswitchKeyOwned<u64>,
+        lwe_per_glwe: LweCiphertextCount,
+        storage_log_modulus: CiphertextModulusLog,
+    ) -> Self {
+        assert!(
+            lwe_per_glwe.0 <= packing_key_switching_key.output_polynomial_size().0,
+            "Cannot pack more than polynomial_size (={}) ciphertexts in a GLWE (lwe_per_glwe={})",
+            packing_key_switching_key.output_polynomial_size().0,
+            lwe_per_glwe.0,
+        );
+
+        Self {
+            packing_key_switching_key,
+            lwe_per_glwe,
+            storage_log_modulus,
+        }
+    }
+}
+
+/// Server key used to get ciphertexts back from a
+/// [CompressedCiphertextList](crate::shortint::ciphertext::CompressedCiphertextList)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    pub(crate) blind_rotate_key: ShortintBootstrappingKey,
+    pub(crate) lwe_per_glwe: LweCiphertextCount,
+}
+
+impl DecompressionKey {
+    pub fn into_raw_parts(self) -> (ShortintBootstrappingKey, LweCiphertextCount) {
+        let Self {
+            blind_rotate_key,
+            lwe_per_glwe,
+        } = self;
+
+        (blind_rotate_key, lwe_per_glwe)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the blind rotate key is a multi bit key
+    pub fn from_raw_parts(
+        blind_rotate_key: ShortintBootstrappingKey,
+        lwe_per_glwe: LweCiphertextCount,
+    ) -> Self {
+        assert!(
+            matches!(blind_rotate_key, ShortintBootstrappingKey::Classic(_)),
+            "The decompression blind rotate key must be a classic bootstrapping key"
+        );
+
+        Self {
+            blind_rotate_key,
+            lwe_per_glwe,
+        }
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compression_decompression_keys(
+        &mut self,
+        cks: &ClientKey,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        assert_eq!(
+            cks.parameters.encryption_key_choice(),
+            EncryptionKeyChoice::Big,
+            "Compression is only compatible with ciphertexts in the big LWE dimension (KS_PBS)"
+        );
+
+        let params = &private_compression_key.params;
+
+        let packing_key_switching_key = allocate_and_generate_new_lwe_packing_keyswitch_key(
+            &cks.large_lwe_secret_key(),
+            &private_compression_key.post_packing_ks_key,
+            params.packing_ks_base_log,
+            params.packing_ks_level,
+            params.packing_ks_key_noise_distribution,
+            cks.parameters.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
+
+        let compression_key = CompressionKey {
+            packing_key_switching_key,
+            lwe_per_glwe: params.lwe_per_glwe,
+            storage_log_modulus: params.storage_log_modulus,
+        };
+
+        let bootstrap_key: LweBootstrapKeyOwned<u64> =
+            par_allocate_and_generate_new_lwe_bootstrap_key(
+                &private_compression_key
+                    .post_packing_ks_key
+                    .as_lwe_secret_key(),
+                &cks.glwe_secret_key,
+                params.br_base_log,
+                params.br_level,
+                cks.parameters.glwe_noise_distribution(),
+                cks.parameters.ciphertext_modulus(),
+                &mut self.encryption_generator,
+            );
+
+        // Creation of the bootstrapping key in the Fourier domain
+        let mut fourier_bsk = FourierLweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+
+        // Conversion to fourier domain
+        par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
+
+        let decompression_key = DecompressionKey {
+            blind_rotate_key: ShortintBootstrappingKey::Classic(fourier_bsk),
+            lwe_per_glwe: params.lwe_per_glwe,
+        };
+
+        (compression_key, decompression_key)
+    }
+}
+
+impl ClientKey {
+    /// Generates the server keys needed to compress ciphertexts and to decompress them
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{
+    ///     COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// let (compression_key, decompression_key) =
+    ///     cks.new_compression_decompression_keys(&private_compression_key);
+    ///
+    /// let ct1 = sks.scalar_add(&cks.encrypt(1), 2);
+    /// let ct2 = cks.encrypt(2);
+    ///
+    /// // Computed ciphertexts can be compressed
+    /// let packed = compression_key.compress_ciphertexts_into_list(&[ct1, ct2]);
+    /// assert_eq!(packed.len(), 2);
+    ///
+    /// let ct1 = decompression_key.unpack(&packed, 0).unwrap();
+    /// let ct2 = decompression_key.unpack(&packed, 1).unwrap();
+    /// assert!(decompression_key.unpack(&packed, 2).is_err());
+    ///
+    /// assert_eq!(cks.decrypt(&ct1), 3);
+    /// assert_eq!(cks.decrypt(&ct2), 2);
+    /// ```
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compression_decompression_keys(self, private_compression_key)
+        })
+    }
+}
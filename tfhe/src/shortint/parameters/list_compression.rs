@@ -0,0 +1,45 @@
+use crate::core_crypto::prelude::{CiphertextModulusLog, LweCiphertextCount};
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, GlweDimension,
+    PolynomialSize,
+};
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters to compress already computed ciphertexts into lists
+/// of packed GLWE ciphertexts, and to decompress them.
+///
+/// - The `packing_ks_*` parameters define the packing keyswitch key that goes from the big LWE key
+///   of the ciphertexts to the GLWE key used for storage.
+/// - `lwe_per_glwe` is the number of ciphertexts packed in a single GLWE ciphertext.
+/// - `storage_log_modulus` is the (log of the) modulus the packed GLWE ciphertexts are switched to
+///   before being stored.
+/// - The `br_*` parameters define the blind rotation key used for decompression, it goes from the
+///   storage GLWE key to the big LWE key of the ciphertexts.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionParameters {
+    pub br_level: DecompositionLevelCount,
+    pub br_base_log: DecompositionBaseLog,
+    pub packing_ks_level: DecompositionLevelCount,
+    pub packing_ks_base_log: DecompositionBaseLog,
+    pub packing_ks_polynomial_size: PolynomialSize,
+    pub packing_ks_glwe_dimension: GlweDimension,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+    pub packing_ks_key_noise_distribution: DynamicDistribution<u64>,
+}
+
+/// Compression parameters compatible with the `PARAM_MESSAGE_2_CARRY_2_KS_PBS` family of
+/// parameters (GLWE dimension 1, polynomial size 2048)
+pub const COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS: CompressionParameters = CompressionParameters {
+    br_level: DecompositionLevelCount(1),
+    br_base_log: DecompositionBaseLog(23),
+    packing_ks_level: DecompositionLevelCount(4),
+    packing_ks_base_log: DecompositionBaseLog(4),
+    packing_ks_polynomial_size: PolynomialSize(256),
+    packing_ks_glwe_dimension: GlweDimension(4),
+    lwe_per_glwe: LweCiphertextCount(256),
+    storage_log_modulus: CiphertextModulusLog(12),
+    packing_ks_key_noise_distribution: DynamicDistribution::new_t_uniform(42),
+};
+
+pub const COMP_PARAM_MESSAGE_2_CARRY_2: CompressionParameters = COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
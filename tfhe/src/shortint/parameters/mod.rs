@@ -18,6 +18,7 @@ pub mod classic;
 #[cfg(tarpaulin)]
 pub mod coverage_parameters;
 pub mod key_switching;
+pub mod list_compression;
 pub mod multi_bit;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
@@ -29,6 +30,9 @@ pub use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
 pub use crate::shortint::parameters::classic::compact_pk::*;
 use crate::shortint::parameters::classic::p_fail_2_minus_40::{ks_pbs, pbs_ks};
 pub use key_switching::ShortintKeySwitchingParameters;
+pub use list_compression::{
+    CompressionParameters, COMP_PARAM_MESSAGE_2_CARRY_2, COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+};
 pub use multi_bit::*;
 pub use parameters_wopbs::*;
 
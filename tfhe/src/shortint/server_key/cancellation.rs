@@ -0,0 +1,58 @@
+//! Interruption of the PBS run by a thread
+//!
+//! A thread in which a [PbsCancellation] is installed checks it before each PBS, and unwinds
+//! with a [PbsCancelled] payload once the flag it holds is set. The ciphertexts which were being
+//! computed are left in an unspecified state, so the caller catching the unwinding must discard
+//! them.
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// The cancellation flag of the computation currently run by a group of threads
+#[derive(Default)]
+pub(crate) struct PbsCancellation {
+    flag: RwLock<Option<Arc<AtomicBool>>>,
+}
+
+impl PbsCancellation {
+    /// Sets the flag checked before each PBS, `None` meaning the PBS are never interrupted
+    pub(crate) fn set_flag(&self, flag: Option<Arc<AtomicBool>>) {
+        *self.flag.write().unwrap() = flag;
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.flag
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Acquire))
+    }
+}
+
+/// The payload of the unwinding started by a cancelled PBS
+pub(crate) struct PbsCancelled;
+
+thread_local! {
+    static PBS_CANCELLATION: RefCell<Option<Arc<PbsCancellation>>> = const { RefCell::new(None) };
+}
+
+/// Installs the cancellation checked before each PBS run by the current thread
+pub(crate) fn set_pbs_cancellation(cancellation: Option<Arc<PbsCancellation>>) {
+    PBS_CANCELLATION.with(|current| *current.borrow_mut() = cancellation);
+}
+
+/// Unwinds with a [PbsCancelled] payload if the cancellation installed in the current thread
+/// is set
+///
+/// The unwinding does not run the panic hook, so nothing is printed.
+pub(crate) fn check_pbs_cancellation() {
+    let cancelled = PBS_CANCELLATION.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(|cancellation| cancellation.is_cancelled())
+    });
+    if cancelled {
+        std::panic::resume_unwind(Box::new(PbsCancelled));
+    }
+}
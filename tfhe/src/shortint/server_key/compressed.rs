@@ -120,6 +120,10 @@ pub struct CompressedServerKey {
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "shortint::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     /// Generate a compressed server key.
     ///
@@ -5,6 +5,7 @@
 mod add;
 mod bitwise_op;
 mod bivariate_pbs;
+pub(crate) mod cancellation;
 mod comp_op;
 mod div_mod;
 mod modulus_switched_compression;
@@ -344,6 +345,10 @@ pub struct ServerKey {
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "shortint::ServerKey";
+}
+
 impl ServerKey {
     pub fn conformance_params(&self) -> CiphertextConformanceParams {
         let lwe_dim = self.ciphertext_lwe_dimension();
@@ -1422,6 +1427,8 @@ pub(crate) fn apply_blind_rotate<Scalar, InputCont, OutputCont>(
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
 {
+    cancellation::check_pbs_cancellation();
+
     #[cfg(feature = "pbs-stats")]
     let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
 
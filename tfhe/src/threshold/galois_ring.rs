@@ -0,0 +1,192 @@
+//! Arithmetic in the Galois ring $GR(2^{64}, 8) = \mathbb{Z}_{2^{64}}[X] / (F(X))$.
+//!
+//! Shamir secret sharing requires the differences between the evaluation points to be
+//! invertible, which is not possible for more than two points in $\mathbb{Z}_{2^{64}}$.
+//! The Galois ring extension has $2^8$ elements whose pairwise differences are all units,
+//! allowing up to 255 parties.
+
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Degree of the extension
+pub(crate) const DEGREE: usize = 8;
+
+/// Maximum number of parties, the evaluation point 0 being reserved for the secret
+pub const MAX_NUM_PARTIES: usize = (1 << DEGREE) - 1;
+
+/// An element of the Galois ring, stored as the coefficients of a polynomial of degree
+/// smaller than `DEGREE`
+///
+/// The modulus polynomial is $F(X) = X^8 + X^4 + X^3 + X + 1$, which is irreducible over
+/// $\mathbb{Z}_2$.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct GaloisRingElement([u64; DEGREE]);
+
+impl GaloisRingElement {
+    pub(crate) const ZERO: Self = Self([0; DEGREE]);
+    pub(crate) const ONE: Self = Self::from_scalar(1);
+
+    pub(crate) const fn from_scalar(scalar: u64) -> Self {
+        let mut coefficients = [0; DEGREE];
+        coefficients[0] = scalar;
+        Self(coefficients)
+    }
+
+    pub(crate) fn from_coefficients(coefficients: [u64; DEGREE]) -> Self {
+        Self(coefficients)
+    }
+
+    /// Returns the evaluation point of a party
+    ///
+    /// Its coefficients are the bits of the party id, so that the evaluation points of the
+    /// different parties, and 0, are all distinct modulo 2.
+    pub(crate) fn evaluation_point(party_id: usize) -> Self {
+        assert!(
+            (1..=MAX_NUM_PARTIES).contains(&party_id),
+            "party id must be in [1, {MAX_NUM_PARTIES}], got {party_id}"
+        );
+        Self(std::array::from_fn(|i| ((party_id >> i) & 1) as u64))
+    }
+
+    /// The constant coefficient, i.e. the component in $\mathbb{Z}_{2^{64}}$
+    pub(crate) fn constant_coefficient(&self) -> u64 {
+        self.0[0]
+    }
+
+    pub(crate) fn scalar_mul(&self, scalar: u64) -> Self {
+        Self(self.0.map(|c| c.wrapping_mul(scalar)))
+    }
+
+    fn is_unit(&self) -> bool {
+        self.0.iter().any(|c| c & 1 == 1)
+    }
+
+    /// Returns the multiplicative inverse
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is not a unit, i.e. if self is 0 modulo 2
+    pub(crate) fn inverse(&self) -> Self {
+        assert!(self.is_unit(), "Cannot invert a non unit element");
+
+        // Find the inverse modulo 2 by exhaustive search in GF(2^8)
+        let mut inverse = (1..=MAX_NUM_PARTIES)
+            .map(Self::evaluation_point)
+            .find(|candidate| {
+                let product = *self * *candidate;
+                product.0[0] & 1 == 1 && product.0[1..].iter().all(|c| c & 1 == 0)
+            })
+            .expect("Units always have an inverse modulo 2");
+
+        // Newton iteration doubles the number of correct bits each time: 1, 2, 4, ..., 64
+        let two = Self::from_scalar(2);
+        for _ in 0..u64::BITS.ilog2() {
+            inverse = inverse * (two - *self * inverse);
+        }
+
+        debug_assert_eq!(*self * inverse, Self::ONE);
+        inverse
+    }
+}
+
+impl Add for GaloisRingElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i].wrapping_add(rhs.0[i])))
+    }
+}
+
+impl Sub for GaloisRingElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self.0[i].wrapping_sub(rhs.0[i])))
+    }
+}
+
+impl Neg for GaloisRingElement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(u64::wrapping_neg))
+    }
+}
+
+impl Mul for GaloisRingElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut product = [0u64; 2 * DEGREE - 1];
+        for (i, lhs_coefficient) in self.0.iter().enumerate() {
+            for (j, rhs_coefficient) in rhs.0.iter().enumerate() {
+                product[i + j] =
+                    product[i + j].wrapping_add(lhs_coefficient.wrapping_mul(*rhs_coefficient));
+            }
+        }
+
+        // X^8 = -(X^4 + X^3 + X + 1), reducing from the highest degree
+        // takes care of the terms that overflow again
+        for k in (DEGREE..2 * DEGREE - 1).rev() {
+            let coefficient = product[k];
+            for offset in [0, 1, 3, 4] {
+                product[k - DEGREE + offset] =
+                    product[k - DEGREE + offset].wrapping_sub(coefficient);
+            }
+        }
+
+        Self(std::array::from_fn(|i| product[i]))
+    }
+}
+
+/// Computes the Lagrange coefficient of `party_id` to interpolate at 0 a polynomial
+/// evaluated at the points of the `party_ids`
+pub(crate) fn lagrange_coefficient_at_zero(
+    party_id: usize,
+    party_ids: &[usize],
+) -> GaloisRingElement {
+    let point = GaloisRingElement::evaluation_point(party_id);
+    party_ids
+        .iter()
+        .filter(|&&other_id| other_id != party_id)
+        .fold(GaloisRingElement::ONE, |acc, &other_id| {
+            let other_point = GaloisRingElement::evaluation_point(other_id);
+            acc * other_point * (other_point - point).inverse()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse() {
+        for party_id in 1..=MAX_NUM_PARTIES {
+            let point = GaloisRingElement::evaluation_point(party_id);
+            assert_eq!(point * point.inverse(), GaloisRingElement::ONE);
+        }
+
+        let element = GaloisRingElement([3, 1 << 60, 5, 0, 8, u64::MAX, 2, 0]);
+        assert_eq!(element * element.inverse(), GaloisRingElement::ONE);
+    }
+
+    #[test]
+    fn test_lagrange_interpolation() {
+        // f(X) = s + c1 * X + c2 * X^2
+        let secret = GaloisRingElement::from_scalar(0xdead_beef_cafe_f00d);
+        let c1 = GaloisRingElement([1, 2, 3, 4, 5, 6, 7, 8]);
+        let c2 = GaloisRingElement([u64::MAX, 0, 1 << 63, 0, 0, 42, 0, 1]);
+        let evaluate = |party_id| {
+            let x = GaloisRingElement::evaluation_point(party_id);
+            secret + c1 * x + c2 * x * x
+        };
+
+        let party_ids = [2, 7, 200];
+        let interpolated = party_ids
+            .iter()
+            .fold(GaloisRingElement::ZERO, |acc, &party_id| {
+                acc + lagrange_coefficient_at_zero(party_id, &party_ids) * evaluate(party_id)
+            });
+        assert_eq!(interpolated, secret);
+    }
+}
@@ -0,0 +1,311 @@
+//! Threshold decryption of [RadixCiphertext].
+
+use super::lwe::{
+    check_decrypting_parties, combine_lwe_partial_decryptions, partial_decrypt_lwe_ciphertext,
+    share_lwe_secret_key, LwePartialDecryption, LweSecretKeyShare,
+};
+use crate::core_crypto::prelude::{TUniform, UnsignedNumeric};
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::{ClientKey, RadixCiphertext};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{Ciphertext, PBSOrder, ShortintParameterSet};
+use serde::{Deserialize, Serialize};
+
+/// The share of an integer [ClientKey] held by one party.
+///
+/// Both the large (GLWE) and the small (LWE) secret keys are shared, so that
+/// ciphertexts can be decrypted whatever their [PBSOrder].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientKeyShare {
+    large_lwe_secret_key_share: LweSecretKeyShare,
+    small_lwe_secret_key_share: LweSecretKeyShare,
+    parameters: ShortintParameterSet,
+}
+
+/// The contribution of one party to the decryption of a [RadixCiphertext].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RadixPartialDecryption {
+    party_id: usize,
+    // Sorted, the Lagrange coefficient used depends on the whole set
+    decrypting_parties: Vec<usize>,
+    blocks: Vec<LwePartialDecryption>,
+}
+
+impl RadixPartialDecryption {
+    pub fn party_id(&self) -> usize {
+        self.party_id
+    }
+
+    /// The parties for which this partial decryption was computed, sorted
+    pub fn decrypting_parties(&self) -> &[usize] {
+        &self.decrypting_parties
+    }
+}
+
+/// Splits an integer [ClientKey] into `num_parties` shares, any `threshold` of which
+/// can be used to decrypt.
+///
+/// The party ids are `1..=num_parties`, the share of party `i` is at index `i - 1` in the
+/// returned vec.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::ClientKey;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::threshold::{combine_radix_partial_decryptions, share_client_key};
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// // 3 out of 5 parties are needed to decrypt
+/// let shares = share_client_key(&cks, 3, 5);
+///
+/// let msg = 201u64;
+/// let ct = cks.encrypt_radix(msg, 4);
+///
+/// let decrypting_parties = [2, 4, 5];
+/// let partial_decryptions: Vec<_> = decrypting_parties
+///     .iter()
+///     .map(|&party_id| shares[party_id - 1].partial_decrypt_radix(&ct, &decrypting_parties))
+///     .collect();
+///
+/// let dec: u64 = combine_radix_partial_decryptions(&ct, &partial_decryptions);
+/// assert_eq!(dec, msg);
+/// ```
+pub fn share_client_key(
+    client_key: &ClientKey,
+    threshold: usize,
+    num_parties: usize,
+) -> Vec<ClientKeyShare> {
+    let shortint_key = &client_key.key;
+    let (large_shares, small_shares) = ShortintEngine::with_thread_local_mut(|engine| {
+        let large_shares = share_lwe_secret_key(
+            &shortint_key.large_lwe_secret_key(),
+            threshold,
+            num_parties,
+            &mut engine.encryption_generator,
+        );
+        let small_shares = share_lwe_secret_key(
+            &shortint_key.small_lwe_secret_key(),
+            threshold,
+            num_parties,
+            &mut engine.encryption_generator,
+        );
+        (large_shares, small_shares)
+    });
+
+    large_shares
+        .into_iter()
+        .zip(small_shares)
+        .map(|(large_lwe_secret_key_share, small_lwe_secret_key_share)| {
+            ClientKeyShare::from_lwe_secret_key_shares(
+                large_lwe_secret_key_share,
+                small_lwe_secret_key_share,
+                shortint_key.parameters,
+            )
+        })
+        .collect()
+}
+
+impl ClientKeyShare {
+    pub(crate) fn from_lwe_secret_key_shares(
+        large_lwe_secret_key_share: LweSecretKeyShare,
+        small_lwe_secret_key_share: LweSecretKeyShare,
+        parameters: ShortintParameterSet,
+    ) -> Self {
+        assert_eq!(
+            large_lwe_secret_key_share.party_id(),
+            small_lwe_secret_key_share.party_id()
+        );
+        Self {
+            large_lwe_secret_key_share,
+            small_lwe_secret_key_share,
+            parameters,
+        }
+    }
+
+    /// Id of the party owning this share, in `[1, num_parties]`
+    pub fn party_id(&self) -> usize {
+        self.large_lwe_secret_key_share.party_id()
+    }
+
+    /// Minimum number of parties required to decrypt
+    pub fn threshold(&self) -> usize {
+        self.large_lwe_secret_key_share.threshold()
+    }
+
+    pub fn parameters(&self) -> ShortintParameterSet {
+        self.parameters
+    }
+
+    /// Computes the contribution of this party to the decryption of `ct` by the
+    /// parties in `decrypting_parties`.
+    ///
+    /// The smudging noise is sampled from [Self::default_smudging_noise_distribution], see its
+    /// documentation for the protection it provides.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this party is not in `decrypting_parties`, or if there are less than
+    /// `threshold` decrypting parties.
+    pub fn partial_decrypt_radix(
+        &self,
+        ct: &RadixCiphertext,
+        decrypting_parties: &[usize],
+    ) -> RadixPartialDecryption {
+        self.partial_decrypt_radix_with_smudging_noise(
+            ct,
+            decrypting_parties,
+            self.default_smudging_noise_distribution(decrypting_parties.len()),
+        )
+    }
+
+    /// Computes the contribution of this party to the decryption of `ct` by the
+    /// parties in `decrypting_parties`, adding to each block a smudging noise sampled from
+    /// `smudging_noise_distribution`.
+    ///
+    /// The sum of the smudging noises of all the decrypting parties and of the noise of the
+    /// ciphertext must stay below `delta / 2`, `delta` being the scaling of the message and
+    /// carry in a block, otherwise the decryption is incorrect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this party is not in `decrypting_parties`, or if there are less than
+    /// `threshold` decrypting parties.
+    pub fn partial_decrypt_radix_with_smudging_noise(
+        &self,
+        ct: &RadixCiphertext,
+        decrypting_parties: &[usize],
+        smudging_noise_distribution: TUniform<u64>,
+    ) -> RadixPartialDecryption {
+        let blocks = ShortintEngine::with_thread_local_mut(|engine| {
+            ct.blocks
+                .iter()
+                .map(|block| {
+                    partial_decrypt_lwe_ciphertext(
+                        self.key_share_for(block),
+                        &block.ct,
+                        decrypting_parties,
+                        smudging_noise_distribution,
+                        &mut engine.encryption_generator,
+                    )
+                })
+                .collect()
+        });
+
+        let mut decrypting_parties = decrypting_parties.to_vec();
+        decrypting_parties.sort_unstable();
+
+        RadixPartialDecryption {
+            party_id: self.party_id(),
+            decrypting_parties,
+            blocks,
+        }
+    }
+
+    fn key_share_for(&self, block: &Ciphertext) -> &LweSecretKeyShare {
+        match block.pbs_order {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key_share,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key_share,
+        }
+    }
+
+    /// The smudging noise distribution used by [Self::partial_decrypt_radix] for
+    /// `num_decrypting_parties` parties
+    ///
+    /// It is the largest [TUniform] distribution such that the sum of the smudging noises of
+    /// all the parties is bounded by `delta / 4`, `delta` being the scaling of the message and
+    /// carry in a block, leaving the other half of the decoding margin for the noise of the
+    /// ciphertext.
+    ///
+    /// This is **not** a statistical smudging of the noise of the ciphertext: for a ciphertext
+    /// noise bounded by `2^e` and a bound of `2^b` for this distribution, the statistical
+    /// distance between the partial decryptions and ones computed without the noise of the
+    /// ciphertext is about `2^(e - b)`. With the default parameters, the noise of the
+    /// ciphertexts after a keyswitch is only a few orders of magnitude below `delta`, so a
+    /// partial decryption may leak some information on the noise of the ciphertext, and thus
+    /// on the key share. Negligible leakage requires parameters with a decoding margin large
+    /// enough for `b` to exceed `e` by the statistical security parameter, and passing the
+    /// matching distribution to [Self::partial_decrypt_radix_with_smudging_noise].
+    pub fn default_smudging_noise_distribution(
+        &self,
+        num_decrypting_parties: usize,
+    ) -> TUniform<u64> {
+        let delta = (1_u64 << 63)
+            / (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64;
+        let bound_log2 =
+            delta.ilog2() - 2 - (num_decrypting_parties as u64).next_power_of_two().ilog2();
+        TUniform::new(bound_log2)
+    }
+}
+
+/// Combines the partial decryptions of the decrypting parties into the clear value
+/// encrypted by `ct`.
+///
+/// See [share_client_key] for an example.
+///
+/// # Panics
+///
+/// Panics if two partial decryptions come from the same party, if the partial decryptions were
+/// not all computed for the set of parties they come from, or if a partial decryption does not
+/// have the same number of blocks as `ct`.
+pub fn combine_radix_partial_decryptions<T>(
+    ct: &RadixCiphertext,
+    partial_decryptions: &[RadixPartialDecryption],
+) -> T
+where
+    T: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    let party_ids: Vec<_> = partial_decryptions
+        .iter()
+        .map(RadixPartialDecryption::party_id)
+        .collect();
+    check_decrypting_parties(&party_ids, 1);
+    let mut sorted_party_ids = party_ids;
+    sorted_party_ids.sort_unstable();
+    for partial in partial_decryptions {
+        // The Lagrange coefficients only sum to the secret for the set they were computed for
+        assert_eq!(
+            partial.decrypting_parties, sorted_party_ids,
+            "Partial decryption of party {} was computed for other decrypting parties",
+            partial.party_id
+        );
+        assert_eq!(
+            partial.blocks.len(),
+            ct.blocks.len(),
+            "Partial decryption of party {} does not have the same number of blocks \
+            as the ciphertext",
+            partial.party_id
+        );
+    }
+
+    if ct.blocks.is_empty() {
+        return T::ZERO;
+    }
+
+    let bits_in_block = ct.blocks[0].message_modulus.0.ilog2();
+    let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+    let mut block_partial_decryptions = Vec::with_capacity(partial_decryptions.len());
+    for (i, block) in ct.blocks.iter().enumerate() {
+        block_partial_decryptions.clear();
+        block_partial_decryptions.extend(partial_decryptions.iter().map(|p| p.blocks[i]));
+
+        let decrypted_u64 =
+            combine_lwe_partial_decryptions(&block.ct, &block_partial_decryptions).0;
+
+        // Same decoding as the shortint decrypt_message_and_carry
+        let delta = (1_u64 << 63) / (block.message_modulus.0 * block.carry_modulus.0) as u64;
+        let rounding_bit = delta >> 1;
+        let rounding = (decrypted_u64 & rounding_bit) << 1;
+        let decrypted_block = (decrypted_u64.wrapping_add(rounding)) / delta;
+
+        if !recomposer.add_unmasked(decrypted_block) {
+            // End of T::BITS reached no need to try more
+            // recomposition
+            break;
+        }
+    }
+
+    recomposer.value()
+}
@@ -0,0 +1,572 @@
+//! Distributed generation of a shortint server key.
+//!
+//! The secret keys are additively shared among the parties: each key bit is the xor of
+//! random bits sampled by every party, so no party knows any of them. The xors, and the
+//! products of the small key bits with the GLWE key needed by the bootstrapping key, are
+//! computed on the shares using the multiplication triples given by a [TripleDealer].
+//!
+//! Every party then generates its contribution to the key switching and bootstrapping keys
+//! with the seeded key generation algorithms, using its key shares as keys and a public seed
+//! shared by all parties, so that the masks are the same for all parties. Summing the bodies
+//! of the contributions gives the keys for the shared secret keys, the noise of the keys being
+//! the sum of the noises of all the parties. Each party samples its noise with the standard
+//! deviation of the parameters divided by the square root of the number of parties, so that
+//! the noise of the keys has the variance the parameters were chosen for.
+//!
+//! [TripleDealer]: super::TripleDealer
+
+use super::integer::ClientKeyShare;
+use super::lwe::{check_decrypting_parties, LweSecretKeyShare};
+use super::preprocessing::DkgPreprocessing;
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator, Seed};
+use crate::core_crypto::prelude::*;
+use crate::shortint::ciphertext::MaxDegree;
+use crate::shortint::server_key::ShortintCompressedBootstrappingKey;
+use crate::shortint::{ClassicPBSParameters, CompressedServerKey, ShortintParameterSet};
+use serde::{Deserialize, Serialize};
+
+/// A message broadcast by a party to all the others during a round of the distributed key
+/// generation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DkgRoundMessage {
+    party_id: usize,
+    round: usize,
+    /// Shares of masked values opened to compute products of shared values
+    openings: Vec<u64>,
+}
+
+impl DkgRoundMessage {
+    pub fn party_id(&self) -> usize {
+        self.party_id
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
+
+/// The contribution of one party to the server key, see [aggregate_server_key_shares].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerKeyShare {
+    party_id: usize,
+    num_parties: usize,
+    parameters: ClassicPBSParameters,
+    key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: SeededLweBootstrapKeyOwned<u64>,
+}
+
+impl ServerKeyShare {
+    pub fn party_id(&self) -> usize {
+        self.party_id
+    }
+}
+
+/// The state of one party of the distributed key generation.
+///
+/// The protocol runs for [DkgParty::num_rounds] rounds, in each round every party broadcasts
+/// its [DkgParty::round_message] and then receives the messages of all the parties, including
+/// its own. Once all the rounds are done, the [ServerKeyShare] of each party are aggregated
+/// with [aggregate_server_key_shares].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+/// use tfhe::integer::RadixCiphertext;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::threshold::{
+///     aggregate_server_key_shares, combine_radix_partial_decryptions, DkgParty, TripleDealer,
+/// };
+///
+/// let num_parties = 3;
+/// let preprocessing = TripleDealer::new()
+///     .generate_dkg_preprocessing(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_parties);
+/// let public_seed = new_seeder().seed();
+///
+/// let mut parties: Vec<_> = preprocessing
+///     .into_iter()
+///     .map(|preprocessing| DkgParty::new(preprocessing, public_seed))
+///     .collect();
+///
+/// for _ in 0..parties[0].num_rounds() {
+///     let messages: Vec<_> = parties.iter().map(DkgParty::round_message).collect();
+///     for party in parties.iter_mut() {
+///         party.receive_round_messages(&messages);
+///     }
+/// }
+///
+/// let server_key_shares: Vec<_> = parties.iter().map(DkgParty::server_key_share).collect();
+/// let server_key = aggregate_server_key_shares(&server_key_shares).decompress();
+///
+/// // The output of a PBS is encrypted under the generated keys
+/// let lut = server_key.generate_lookup_table(|x| (x + 1) % 4);
+/// let ct = server_key.apply_lookup_table(&server_key.create_trivial(2), &lut);
+/// let ct = RadixCiphertext::from(vec![ct]);
+///
+/// // All the parties are needed to decrypt it
+/// let decrypting_parties: Vec<_> = (1..=num_parties).collect();
+/// let partial_decryptions: Vec<_> = parties
+///     .iter()
+///     .map(|party| {
+///         party
+///             .client_key_share()
+///             .partial_decrypt_radix(&ct, &decrypting_parties)
+///     })
+///     .collect();
+/// let decrypted: u64 = combine_radix_partial_decryptions(&ct, &partial_decryptions);
+/// assert_eq!(decrypted, 3);
+/// ```
+pub struct DkgParty {
+    preprocessing: DkgPreprocessing,
+    public_seed: Seed,
+    /// The random bits of this party, mixed with the ones of the other parties
+    own_bits: Vec<u64>,
+    /// Shares of the bits of the small LWE key followed by the ones of the GLWE key
+    pub(super) key_bit_shares: Vec<u64>,
+    /// Shares of the products of each bit of the small LWE key with the GLWE key
+    key_product_shares: Vec<u64>,
+    round: usize,
+}
+
+impl DkgParty {
+    /// Creates a party of the distributed key generation.
+    ///
+    /// The `public_seed` must be the same for all the parties, it is used to generate the
+    /// masks of the keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters do not use the native ciphertext modulus, or if there are
+    /// several parties and the parameters do not use Gaussian noise.
+    pub fn new(preprocessing: DkgPreprocessing, public_seed: Seed) -> Self {
+        let parameters = preprocessing.parameters;
+        assert!(
+            parameters.ciphertext_modulus.is_native_modulus(),
+            "Distributed key generation only supports the native ciphertext modulus"
+        );
+        // The sum of T-uniform noises is not T-uniform, so it cannot be split between parties
+        let is_gaussian = |distribution: DynamicDistribution<u64>| {
+            matches!(distribution, DynamicDistribution::Gaussian(_))
+        };
+        assert!(
+            preprocessing.num_parties == 1
+                || (is_gaussian(parameters.lwe_noise_distribution)
+                    && is_gaussian(parameters.glwe_noise_distribution)),
+            "Distributed key generation between several parties requires Gaussian noise"
+        );
+
+        let num_key_bits =
+            parameters.lwe_dimension.0 + parameters.glwe_dimension.0 * parameters.polynomial_size.0;
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+        let mut own_bits = vec![0u64; num_key_bits];
+        generator.fill_slice_with_random_uniform_binary(&mut own_bits);
+
+        // The first party starts the mixing with its own bits
+        let key_bit_shares = if preprocessing.party_id == 1 {
+            own_bits.clone()
+        } else {
+            vec![0u64; num_key_bits]
+        };
+
+        Self {
+            preprocessing,
+            public_seed,
+            own_bits,
+            key_bit_shares,
+            key_product_shares: Vec::new(),
+            round: 0,
+        }
+    }
+
+    pub fn party_id(&self) -> usize {
+        self.preprocessing.party_id
+    }
+
+    pub fn num_parties(&self) -> usize {
+        self.preprocessing.num_parties
+    }
+
+    /// The number of rounds of the protocol: one per party to mix their bits in the keys,
+    /// the first party excepted, and one to compute the products of the keys.
+    pub fn num_rounds(&self) -> usize {
+        self.num_parties()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.round == self.num_rounds()
+    }
+
+    fn parameters(&self) -> &ClassicPBSParameters {
+        &self.preprocessing.parameters
+    }
+
+    fn is_bit_mixing_round(&self) -> bool {
+        self.round + 1 < self.num_rounds()
+    }
+
+    /// Shares of the bits of the party whose bits are mixed in the current round
+    fn mixed_bits_shares(&self) -> Vec<u64> {
+        let mixed_party_id = self.round + 2;
+        if self.party_id() == mixed_party_id {
+            self.own_bits.clone()
+        } else {
+            vec![0u64; self.own_bits.len()]
+        }
+    }
+
+    /// Returns the message of this party for the current round.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the protocol is done.
+    pub fn round_message(&self) -> DkgRoundMessage {
+        assert!(!self.is_done(), "The distributed key generation is done");
+
+        let openings = if self.is_bit_mixing_round() {
+            // Open x - a and y - b to compute x * y
+            let triples = &self.preprocessing.bit_mixing_triples[self.round];
+            let mixed_bits_shares = self.mixed_bits_shares();
+            wrapping_sub_slices(&self.key_bit_shares, &triples.a)
+                .chain(wrapping_sub_slices(&mixed_bits_shares, &triples.b))
+                .collect()
+        } else {
+            let triples = &self.preprocessing.key_product_triples;
+            let (small_key_shares, glwe_key_shares) = self
+                .key_bit_shares
+                .split_at(self.parameters().lwe_dimension.0);
+            wrapping_sub_slices(small_key_shares, &triples.a)
+                .chain(wrapping_sub_slices(glwe_key_shares, &triples.b))
+                .collect()
+        };
+
+        DkgRoundMessage {
+            party_id: self.party_id(),
+            round: self.round,
+            openings,
+        }
+    }
+
+    /// Receives the messages of all the parties for the current round, and moves to the next
+    /// round.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the messages do not come from all the parties or are not for the current
+    /// round.
+    pub fn receive_round_messages(&mut self, messages: &[DkgRoundMessage]) {
+        assert!(!self.is_done(), "The distributed key generation is done");
+        assert_eq!(
+            messages.len(),
+            self.num_parties(),
+            "Expected one message per party"
+        );
+        let party_ids: Vec<_> = messages.iter().map(DkgRoundMessage::party_id).collect();
+        check_decrypting_parties(&party_ids, self.num_parties());
+
+        // The bit mixing rounds open two values per key bit
+        let num_openings = if self.is_bit_mixing_round() {
+            2 * self.key_bit_shares.len()
+        } else {
+            self.key_bit_shares.len()
+        };
+        let mut opened = vec![0u64; num_openings];
+        for message in messages {
+            assert_eq!(
+                message.round, self.round,
+                "Received a message of party {} for round {} during round {}",
+                message.party_id, message.round, self.round
+            );
+            assert_eq!(message.openings.len(), num_openings);
+            for (opened, opening) in opened.iter_mut().zip(message.openings.iter()) {
+                *opened = opened.wrapping_add(*opening);
+            }
+        }
+
+        // Only one party adds the public product d * e of the Beaver multiplication
+        let is_first_party = self.party_id() == 1;
+
+        if self.is_bit_mixing_round() {
+            let triples = &self.preprocessing.bit_mixing_triples[self.round];
+            let mixed_bits_shares = self.mixed_bits_shares();
+            let (d, e) = opened.split_at(num_openings / 2);
+
+            for (i, key_bit_share) in self.key_bit_shares.iter_mut().enumerate() {
+                let mut product_share = triples.c[i]
+                    .wrapping_add(d[i].wrapping_mul(triples.b[i]))
+                    .wrapping_add(e[i].wrapping_mul(triples.a[i]));
+                if is_first_party {
+                    product_share = product_share.wrapping_add(d[i].wrapping_mul(e[i]));
+                }
+
+                // x xor y = x + y - 2 * x * y
+                *key_bit_share = key_bit_share
+                    .wrapping_add(mixed_bits_shares[i])
+                    .wrapping_sub(product_share.wrapping_mul(2));
+            }
+        } else {
+            let triples = &self.preprocessing.key_product_triples;
+            let (d, e) = opened.split_at(self.parameters().lwe_dimension.0);
+
+            self.key_product_shares = d
+                .iter()
+                .zip(triples.a.iter())
+                .zip(triples.c.chunks_exact(e.len()))
+                .flat_map(|((&d_i, &a_i), c_i)| {
+                    c_i.iter().zip(triples.b.iter()).zip(e.iter()).map(
+                        move |((&c_ij, &b_j), &e_j)| {
+                            let product_share = c_ij
+                                .wrapping_add(d_i.wrapping_mul(b_j))
+                                .wrapping_add(e_j.wrapping_mul(a_i));
+                            if is_first_party {
+                                product_share.wrapping_add(d_i.wrapping_mul(e_j))
+                            } else {
+                                product_share
+                            }
+                        },
+                    )
+                })
+                .collect();
+        }
+
+        self.round += 1;
+    }
+
+    fn key_shares(&self) -> (LweSecretKey<&[u64]>, GlweSecretKey<&[u64]>) {
+        let (small_key_shares, glwe_key_shares) = self
+            .key_bit_shares
+            .split_at(self.parameters().lwe_dimension.0);
+        (
+            LweSecretKey::from_container(small_key_shares),
+            GlweSecretKey::from_container(glwe_key_shares, self.parameters().polynomial_size),
+        )
+    }
+
+    /// Returns the contribution of this party to the server key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the protocol is not done.
+    pub fn server_key_share(&self) -> ServerKeyShare {
+        assert!(self.is_done(), "The distributed key generation is not done");
+
+        let parameters = *self.parameters();
+        let (small_lwe_secret_key_share, glwe_secret_key_share) = self.key_shares();
+        let large_lwe_secret_key_share = glwe_secret_key_share.as_lwe_secret_key();
+
+        // All the parties derive the same mask seeds, while the noise is private
+        let mut public_seeder =
+            DeterministicSeeder::<ActivatedRandomGenerator>::new(self.public_seed);
+        let mut noise_seeder = new_seeder();
+
+        let mut key_switching_key = SeededLweKeyswitchKey::new(
+            0u64,
+            parameters.ks_base_log,
+            parameters.ks_level,
+            large_lwe_secret_key_share.lwe_dimension(),
+            small_lwe_secret_key_share.lwe_dimension(),
+            public_seeder.seed().into(),
+            parameters.ciphertext_modulus,
+        );
+        generate_seeded_lwe_keyswitch_key(
+            &large_lwe_secret_key_share,
+            &small_lwe_secret_key_share,
+            &mut key_switching_key,
+            self.party_noise_distribution(parameters.lwe_noise_distribution),
+            noise_seeder.as_mut(),
+        );
+
+        let mut bootstrapping_key = SeededLweBootstrapKey::new(
+            0u64,
+            parameters.glwe_dimension.to_glwe_size(),
+            parameters.polynomial_size,
+            parameters.pbs_base_log,
+            parameters.pbs_level,
+            parameters.lwe_dimension,
+            public_seeder.seed().into(),
+            parameters.ciphertext_modulus,
+        );
+        par_generate_seeded_lwe_bootstrap_key(
+            &small_lwe_secret_key_share,
+            &glwe_secret_key_share,
+            &mut bootstrapping_key,
+            self.party_noise_distribution(parameters.glwe_noise_distribution),
+            noise_seeder.as_mut(),
+        );
+        self.correct_bootstrapping_key_products(&mut bootstrapping_key);
+
+        ServerKeyShare {
+            party_id: self.party_id(),
+            num_parties: self.num_parties(),
+            parameters,
+            key_switching_key,
+            bootstrapping_key,
+        }
+    }
+
+    /// The distribution of the noise of this party, such that the sum of the noises of all the
+    /// parties follows `distribution`
+    fn party_noise_distribution(
+        &self,
+        distribution: DynamicDistribution<u64>,
+    ) -> DynamicDistribution<u64> {
+        match distribution {
+            DynamicDistribution::Gaussian(gaussian) => {
+                let std_dev = gaussian.std / (self.num_parties() as f64).sqrt();
+                DynamicDistribution::new_gaussian_from_std_dev(StandardDev(std_dev))
+            }
+            // Only a single party can use T-uniform noise, see DkgParty::new
+            DynamicDistribution::TUniform(_) => distribution,
+        }
+    }
+
+    /// The rows of the GGSW of a small key bit `m` encrypt the products `m * S_k` with the GLWE
+    /// key polynomials. Generated from the key shares, they contain `m_i * S_k_i`, which is
+    /// replaced here by the share of `m * S_k` of this party.
+    fn correct_bootstrapping_key_products(
+        &self,
+        bootstrapping_key: &mut SeededLweBootstrapKeyOwned<u64>,
+    ) {
+        let (small_lwe_secret_key_share, glwe_secret_key_share) = self.key_shares();
+        let polynomial_size = self.parameters().polynomial_size.0;
+        let decomp_base_log = bootstrapping_key.decomposition_base_log().0;
+
+        for ((mut ggsw, &small_key_bit_share), product_shares) in bootstrapping_key
+            .iter_mut()
+            .zip(small_lwe_secret_key_share.as_ref().iter())
+            .zip(
+                self.key_product_shares
+                    .chunks_exact(glwe_secret_key_share.as_ref().len()),
+            )
+        {
+            let correction: Vec<_> = product_shares
+                .iter()
+                .zip(glwe_secret_key_share.as_ref().iter())
+                .map(|(&product_share, &glwe_key_share)| {
+                    product_share.wrapping_sub(small_key_bit_share.wrapping_mul(glwe_key_share))
+                })
+                .collect();
+
+            for (level_index, mut level_matrix) in ggsw.iter_mut().enumerate() {
+                let decomposition_factor =
+                    1u64 << (u64::BITS as usize - decomp_base_log * (level_index + 1));
+
+                // The last row does not depend on the GLWE key, the zip skips it
+                for (mut row, correction_polynomial) in level_matrix
+                    .as_mut_seeded_glwe_list()
+                    .iter_mut()
+                    .zip(correction.chunks_exact(polynomial_size))
+                {
+                    for (body, &correction) in row
+                        .get_mut_body()
+                        .as_mut()
+                        .iter_mut()
+                        .zip(correction_polynomial.iter())
+                    {
+                        *body = body.wrapping_sub(correction.wrapping_mul(decomposition_factor));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the share of the client key of this party, which can be used for the threshold
+    /// decryption of ciphertexts encrypted under the generated keys by all the parties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the protocol is not done.
+    pub fn client_key_share(&self) -> ClientKeyShare {
+        assert!(self.is_done(), "The distributed key generation is not done");
+
+        let (small_lwe_secret_key_share, glwe_secret_key_share) = self.key_shares();
+
+        ClientKeyShare::from_lwe_secret_key_shares(
+            LweSecretKeyShare::from_additive_share(
+                self.party_id(),
+                self.num_parties(),
+                glwe_secret_key_share.as_ref(),
+            ),
+            LweSecretKeyShare::from_additive_share(
+                self.party_id(),
+                self.num_parties(),
+                small_lwe_secret_key_share.as_ref(),
+            ),
+            ShortintParameterSet::from(*self.parameters()),
+        )
+    }
+}
+
+/// Aggregates the contributions of all the parties of a distributed key generation into a
+/// server key.
+///
+/// See [DkgParty] for an example.
+///
+/// # Panics
+///
+/// Panics if the shares do not come from all the parties of the same distributed key
+/// generation.
+pub fn aggregate_server_key_shares(shares: &[ServerKeyShare]) -> CompressedServerKey {
+    assert!(!shares.is_empty(), "No server key share to aggregate");
+    let first = &shares[0];
+    assert_eq!(
+        shares.len(),
+        first.num_parties,
+        "Expected one server key share per party"
+    );
+    let party_ids: Vec<_> = shares.iter().map(ServerKeyShare::party_id).collect();
+    check_decrypting_parties(&party_ids, first.num_parties);
+
+    let mut key_switching_key = first.key_switching_key.clone();
+    let mut bootstrapping_key = first.bootstrapping_key.clone();
+
+    for share in &shares[1..] {
+        assert_eq!(share.parameters, first.parameters, "Mismatched parameters");
+        assert_eq!(
+            share.key_switching_key.compression_seed(),
+            key_switching_key.compression_seed(),
+            "Mismatched public seed"
+        );
+        assert_eq!(
+            share.bootstrapping_key.compression_seed(),
+            bootstrapping_key.compression_seed(),
+            "Mismatched public seed"
+        );
+
+        for (body, share_body) in key_switching_key
+            .as_mut()
+            .iter_mut()
+            .zip(share.key_switching_key.as_ref().iter())
+        {
+            *body = body.wrapping_add(*share_body);
+        }
+        for (body, share_body) in bootstrapping_key
+            .as_mut()
+            .iter_mut()
+            .zip(share.bootstrapping_key.as_ref().iter())
+        {
+            *body = body.wrapping_add(*share_body);
+        }
+    }
+
+    let parameters = first.parameters;
+    let max_value = parameters.message_modulus.0 * parameters.carry_modulus.0 - 1;
+
+    CompressedServerKey {
+        key_switching_key,
+        bootstrapping_key: ShortintCompressedBootstrappingKey::Classic(bootstrapping_key),
+        message_modulus: parameters.message_modulus,
+        carry_modulus: parameters.carry_modulus,
+        max_degree: MaxDegree::new(max_value),
+        max_noise_level: parameters.max_noise_level,
+        ciphertext_modulus: parameters.ciphertext_modulus,
+        pbs_order: parameters.encryption_key_choice.into(),
+    }
+}
+
+fn wrapping_sub_slices<'a>(lhs: &'a [u64], rhs: &'a [u64]) -> impl Iterator<Item = u64> + 'a {
+    lhs.iter()
+        .zip(rhs.iter())
+        .map(|(lhs, rhs)| lhs.wrapping_sub(*rhs))
+}
@@ -0,0 +1,309 @@
+//! Threshold decryption of [LweCiphertext].
+
+use super::galois_ring::{
+    lagrange_coefficient_at_zero, GaloisRingElement, DEGREE, MAX_NUM_PARTIES,
+};
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{
+    ByteRandomGenerator, Distribution, RandomGenerable, Uniform,
+};
+use crate::core_crypto::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The share of an [LweSecretKey] held by one party.
+///
+/// Each coefficient of the key is shared using a Shamir secret sharing of degree
+/// `threshold - 1` over the Galois ring extension of $\mathbb{Z}_{2^{64}}$, so that any
+/// `threshold` parties can decrypt, while fewer parties learn nothing about the key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LweSecretKeyShare {
+    party_id: usize,
+    threshold: usize,
+    shares: Vec<GaloisRingElement>,
+}
+
+impl LweSecretKeyShare {
+    /// Id of the party owning this share, in `[1, num_parties]`
+    pub fn party_id(&self) -> usize {
+        self.party_id
+    }
+
+    /// Minimum number of parties required to decrypt
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.shares.len())
+    }
+
+    /// Converts the additive share of party `party_id` of a key shared among `num_parties`
+    /// parties into a share requiring all the parties to decrypt.
+    ///
+    /// Scaling the additive share by the inverse of the Lagrange coefficient of the party makes
+    /// it a valid share of degree `num_parties - 1`.
+    pub(crate) fn from_additive_share(
+        party_id: usize,
+        num_parties: usize,
+        additive_share: &[u64],
+    ) -> Self {
+        let all_parties: Vec<_> = (1..=num_parties).collect();
+        let scaling = lagrange_coefficient_at_zero(party_id, &all_parties).inverse();
+
+        Self {
+            party_id,
+            threshold: num_parties,
+            shares: additive_share
+                .iter()
+                .map(|&share| scaling.scalar_mul(share))
+                .collect(),
+        }
+    }
+
+    /// Computes $\sum_i a_i \cdot \[s_i\]$ for the given mask
+    fn mask_share_dot_product(&self, mask: &[u64]) -> GaloisRingElement {
+        mask.iter()
+            .zip(self.shares.iter())
+            .fold(GaloisRingElement::ZERO, |acc, (&mask_element, share)| {
+                acc + share.scalar_mul(mask_element)
+            })
+    }
+}
+
+/// The contribution of one party to the decryption of an [LweCiphertext].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LwePartialDecryption {
+    party_id: usize,
+    value: u64,
+}
+
+impl LwePartialDecryption {
+    pub fn party_id(&self) -> usize {
+        self.party_id
+    }
+}
+
+/// Splits an [LweSecretKey] into `num_parties` shares, any `threshold` of which
+/// can be used to decrypt.
+///
+/// The party ids are `1..=num_parties`, the share of party `i` is at index `i - 1` in the
+/// returned vec.
+///
+/// # Panics
+///
+/// Panics if `threshold` is 0 or greater than `num_parties`, or if `num_parties` is greater
+/// than [MAX_NUM_PARTIES].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+/// use tfhe::threshold::{
+///     combine_lwe_partial_decryptions, partial_decrypt_lwe_ciphertext, share_lwe_secret_key,
+/// };
+///
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let plaintext = Plaintext(3u64 << 60);
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 2 out of 3 parties are needed to decrypt
+/// let shares = share_lwe_secret_key(&lwe_secret_key, 2, 3, &mut encryption_generator);
+///
+/// let decrypting_parties = [1, 3];
+/// let smudging_noise_distribution = TUniform::<u64>::new(40);
+/// let partial_decryptions: Vec<_> = decrypting_parties
+///     .iter()
+///     .map(|&party_id| {
+///         partial_decrypt_lwe_ciphertext(
+///             &shares[party_id - 1],
+///             &lwe,
+///             &decrypting_parties,
+///             smudging_noise_distribution,
+///             &mut encryption_generator,
+///         )
+///     })
+///     .collect();
+///
+/// let decrypted = combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+///
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// assert_eq!(decomposer.closest_representable(decrypted.0), plaintext.0);
+/// ```
+pub fn share_lwe_secret_key<KeyCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    threshold: usize,
+    num_parties: usize,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Vec<LweSecretKeyShare>
+where
+    KeyCont: Container<Element = u64>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        (1..=num_parties).contains(&threshold),
+        "threshold must be in [1, {num_parties}], got {threshold}"
+    );
+    assert!(
+        num_parties <= MAX_NUM_PARTIES,
+        "At most {MAX_NUM_PARTIES} parties are supported, got {num_parties}"
+    );
+
+    let evaluation_points: Vec<_> = (1..=num_parties)
+        .map(GaloisRingElement::evaluation_point)
+        .collect();
+
+    let mut shares: Vec<_> = (1..=num_parties)
+        .map(|party_id| LweSecretKeyShare {
+            party_id,
+            threshold,
+            shares: Vec::with_capacity(lwe_secret_key.lwe_dimension().0),
+        })
+        .collect();
+
+    let mut randomness = vec![0u64; (threshold - 1) * DEGREE];
+
+    for &secret_coefficient in lwe_secret_key.as_ref() {
+        // The sharing polynomial is f(X) = s + r_1 * X + ... + r_{t-1} * X^{t-1}
+        generator.fill_slice_with_random_noise_from_distribution(&mut randomness, Uniform);
+        let polynomial: Vec<_> = randomness
+            .chunks_exact(DEGREE)
+            .map(|chunk| GaloisRingElement::from_coefficients(chunk.try_into().unwrap()))
+            .collect();
+        let secret = GaloisRingElement::from_scalar(secret_coefficient);
+
+        for (share, point) in shares.iter_mut().zip(evaluation_points.iter()) {
+            // Horner's method
+            let evaluation = polynomial
+                .iter()
+                .rev()
+                .fold(GaloisRingElement::ZERO, |acc, coefficient| {
+                    (acc + *coefficient) * *point
+                })
+                + secret;
+            share.shares.push(evaluation);
+        }
+    }
+
+    shares
+}
+
+/// Computes the contribution of the party owning `key_share` to the decryption of
+/// `lwe_ciphertext` by the parties in `decrypting_parties`.
+///
+/// A noise sampled from `smudging_noise_distribution` is added to hide the secret key share,
+/// the sum of the smudging noises of all the decrypting parties is part of the noise of the
+/// decrypted plaintext, so the distribution must be chosen according to the encoding.
+///
+/// All the decrypting parties must use the same `decrypting_parties`.
+///
+/// # Panics
+///
+/// Panics if the party owning `key_share` is not in `decrypting_parties`, if there are less
+/// than `threshold` decrypting parties or if the ciphertext modulus is not the native one.
+pub fn partial_decrypt_lwe_ciphertext<C, D, Gen>(
+    key_share: &LweSecretKeyShare,
+    lwe_ciphertext: &LweCiphertext<C>,
+    decrypting_parties: &[usize],
+    smudging_noise_distribution: D,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LwePartialDecryption
+where
+    C: Container<Element = u64>,
+    D: Distribution,
+    u64: RandomGenerable<D>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        key_share.lwe_dimension(),
+        "Mismatch between LweDimension of the ciphertext and of the key share"
+    );
+    assert!(
+        lwe_ciphertext.ciphertext_modulus().is_native_modulus(),
+        "Threshold decryption only supports the native ciphertext modulus"
+    );
+    check_decrypting_parties(decrypting_parties, key_share.threshold);
+    assert!(
+        decrypting_parties.contains(&key_share.party_id),
+        "Party {} is not part of the decrypting parties",
+        key_share.party_id
+    );
+
+    let lagrange_coefficient = lagrange_coefficient_at_zero(key_share.party_id, decrypting_parties);
+    let mask_key_dot_product =
+        lagrange_coefficient * key_share.mask_share_dot_product(lwe_ciphertext.get_mask().as_ref());
+
+    let smudging_noise: u64 = generator.random_noise_from_distribution(smudging_noise_distribution);
+
+    LwePartialDecryption {
+        party_id: key_share.party_id,
+        value: mask_key_dot_product
+            .constant_coefficient()
+            .wrapping_add(smudging_noise),
+    }
+}
+
+/// Combines the partial decryptions of the decrypting parties into the [Plaintext] encrypted
+/// by `lwe_ciphertext`.
+///
+/// # Panics
+///
+/// Panics if two partial decryptions come from the same party.
+pub fn combine_lwe_partial_decryptions<C>(
+    lwe_ciphertext: &LweCiphertext<C>,
+    partial_decryptions: &[LwePartialDecryption],
+) -> Plaintext<u64>
+where
+    C: Container<Element = u64>,
+{
+    let party_ids: Vec<_> = partial_decryptions
+        .iter()
+        .map(LwePartialDecryption::party_id)
+        .collect();
+    check_decrypting_parties(&party_ids, 1);
+
+    let mask_key_dot_product = partial_decryptions
+        .iter()
+        .fold(0u64, |acc, partial| acc.wrapping_add(partial.value));
+
+    Plaintext(
+        lwe_ciphertext
+            .get_body()
+            .data
+            .wrapping_sub(mask_key_dot_product),
+    )
+}
+
+pub(crate) fn check_decrypting_parties(decrypting_parties: &[usize], threshold: usize) {
+    assert!(
+        decrypting_parties.len() >= threshold,
+        "At least {threshold} parties are required to decrypt, got {}",
+        decrypting_parties.len()
+    );
+    for (i, party_id) in decrypting_parties.iter().enumerate() {
+        assert!(
+            !decrypting_parties[..i].contains(party_id),
+            "Party {party_id} appears more than once in the decrypting parties"
+        );
+    }
+}
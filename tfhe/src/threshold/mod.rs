@@ -0,0 +1,37 @@
+//! Threshold decryption of LWE based ciphertexts.
+//!
+//! A secret key is split into `num_parties` shares using Shamir secret sharing, such that
+//! any `threshold` parties can jointly decrypt a ciphertext, while fewer parties learn
+//! nothing about the key.
+//!
+//! Each decrypting party computes a partial decryption from its key share, to which a
+//! smudging noise is added so that it does not leak the share. The partial decryptions are
+//! then combined by anyone into the plaintext.
+//!
+//! As $\mathbb{Z}_{2^{64}}$ is not a field, the sharing is done over a Galois ring extension of
+//! it, which limits the number of parties to [MAX_NUM_PARTIES].
+//!
+//! The keys themselves can be generated without any party learning them, see [DkgParty].
+//!
+//! The parties are only simulated in process, the communication between them is left to
+//! the user.
+
+mod galois_ring;
+mod integer;
+mod key_gen;
+mod lwe;
+mod preprocessing;
+
+#[cfg(test)]
+mod tests;
+
+pub use galois_ring::MAX_NUM_PARTIES;
+pub use integer::{
+    combine_radix_partial_decryptions, share_client_key, ClientKeyShare, RadixPartialDecryption,
+};
+pub use key_gen::{aggregate_server_key_shares, DkgParty, DkgRoundMessage, ServerKeyShare};
+pub use lwe::{
+    combine_lwe_partial_decryptions, partial_decrypt_lwe_ciphertext, share_lwe_secret_key,
+    LwePartialDecryption, LweSecretKeyShare,
+};
+pub use preprocessing::{DkgPreprocessing, TripleDealer};
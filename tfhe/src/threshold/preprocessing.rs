@@ -0,0 +1,161 @@
+//! Preprocessing material for the multiplications of secret shared values done during the
+//! distributed key generation.
+
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
+use crate::core_crypto::prelude::new_seeder;
+use crate::shortint::ClassicPBSParameters;
+use serde::{Deserialize, Serialize};
+
+/// A batch of additive shares of multiplication triples `(a, b, c = a * b)`,
+/// stored as structure of arrays.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ScalarTripleShares {
+    pub(crate) a: Vec<u64>,
+    pub(crate) b: Vec<u64>,
+    pub(crate) c: Vec<u64>,
+}
+
+/// Additive shares of the triples `(a_i, b, c_i = a_i * b)` where `b` is a vector,
+/// used to multiply several scalars with the same vector.
+///
+/// `c` stores the `c_i` contiguously.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct VectorTripleShares {
+    pub(crate) a: Vec<u64>,
+    pub(crate) b: Vec<u64>,
+    pub(crate) c: Vec<u64>,
+}
+
+/// The preprocessing material given to one party of the distributed key generation.
+///
+/// See [TripleDealer].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DkgPreprocessing {
+    pub(crate) party_id: usize,
+    pub(crate) num_parties: usize,
+    pub(crate) parameters: ClassicPBSParameters,
+    /// One batch of triples per secret bit mixing round
+    pub(crate) bit_mixing_triples: Vec<ScalarTripleShares>,
+    pub(crate) key_product_triples: VectorTripleShares,
+}
+
+impl DkgPreprocessing {
+    pub fn party_id(&self) -> usize {
+        self.party_id
+    }
+}
+
+/// Generates the multiplication triples consumed by the distributed key generation.
+///
+/// The triples do not depend on the keys, so they can be generated before the protocol
+/// starts. The dealer is trusted to not take part in the protocol and to not have access to
+/// the messages exchanged by the parties, in a deployment it should be replaced by a
+/// dedicated multi-party protocol.
+pub struct TripleDealer {
+    generator: RandomGenerator<ActivatedRandomGenerator>,
+}
+
+impl Default for TripleDealer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TripleDealer {
+    pub fn new() -> Self {
+        let mut seeder = new_seeder();
+        Self {
+            generator: RandomGenerator::new(seeder.seed()),
+        }
+    }
+
+    /// Generates the preprocessing material of each of the `num_parties` parties of a
+    /// distributed key generation for the given parameters.
+    ///
+    /// The material of party `i` is at index `i - 1` in the returned vec.
+    pub fn generate_dkg_preprocessing(
+        &mut self,
+        parameters: ClassicPBSParameters,
+        num_parties: usize,
+    ) -> Vec<DkgPreprocessing> {
+        assert!(num_parties > 0, "There must be at least one party");
+
+        let lwe_dimension = parameters.lwe_dimension.0;
+        let glwe_key_len = parameters.glwe_dimension.0 * parameters.polynomial_size.0;
+        let num_key_bits = lwe_dimension + glwe_key_len;
+
+        let mut bit_mixing_triples: Vec<_> = (0..num_parties)
+            .map(|_| Vec::with_capacity(num_parties - 1))
+            .collect();
+        for _ in 1..num_parties {
+            let a = self.random_vec(num_key_bits);
+            let b = self.random_vec(num_key_bits);
+            let c: Vec<_> = a
+                .iter()
+                .zip(b.iter())
+                .map(|(a, b)| a.wrapping_mul(*b))
+                .collect();
+
+            let shares = self
+                .share(&a, num_parties)
+                .into_iter()
+                .zip(self.share(&b, num_parties))
+                .zip(self.share(&c, num_parties))
+                .map(|((a, b), c)| ScalarTripleShares { a, b, c });
+            for (party_triples, triples) in bit_mixing_triples.iter_mut().zip(shares) {
+                party_triples.push(triples);
+            }
+        }
+
+        let a = self.random_vec(lwe_dimension);
+        let b = self.random_vec(glwe_key_len);
+        let c: Vec<_> = a
+            .iter()
+            .flat_map(|a_i| b.iter().map(move |b_j| a_i.wrapping_mul(*b_j)))
+            .collect();
+        let key_product_triples = self
+            .share(&a, num_parties)
+            .into_iter()
+            .zip(self.share(&b, num_parties))
+            .zip(self.share(&c, num_parties))
+            .map(|((a, b), c)| VectorTripleShares { a, b, c });
+
+        bit_mixing_triples
+            .into_iter()
+            .zip(key_product_triples)
+            .enumerate()
+            .map(
+                |(i, (bit_mixing_triples, key_product_triples))| DkgPreprocessing {
+                    party_id: i + 1,
+                    num_parties,
+                    parameters,
+                    bit_mixing_triples,
+                    key_product_triples,
+                },
+            )
+            .collect()
+    }
+
+    fn random_vec(&mut self, len: usize) -> Vec<u64> {
+        let mut values = vec![0u64; len];
+        self.generator.fill_slice_with_random_uniform(&mut values);
+        values
+    }
+
+    /// Splits the values into `num_parties` additive shares
+    fn share(&mut self, values: &[u64], num_parties: usize) -> Vec<Vec<u64>> {
+        let mut shares: Vec<_> = (1..num_parties)
+            .map(|_| self.random_vec(values.len()))
+            .collect();
+
+        let mut last_share = values.to_vec();
+        for share in shares.iter() {
+            for (last, value) in last_share.iter_mut().zip(share.iter()) {
+                *last = last.wrapping_sub(*value);
+            }
+        }
+        shares.push(last_share);
+
+        shares
+    }
+}
@@ -0,0 +1,236 @@
+use super::{
+    aggregate_server_key_shares, combine_radix_partial_decryptions, share_client_key,
+    ClientKeyShare, DkgParty, TripleDealer,
+};
+use crate::core_crypto::prelude::*;
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::tests::create_parametrized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext};
+use crate::shortint::parameters::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const NB_CTXT: usize = 8;
+const NB_TESTS: usize = 10;
+const NUM_PARTIES: usize = 5;
+const THRESHOLD: usize = 3;
+
+create_parametrized_test!(threshold_decrypt_radix {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+
+fn threshold_decrypt(
+    shares: &[ClientKeyShare],
+    ct: &RadixCiphertext,
+    decrypting_parties: &[usize],
+) -> u64 {
+    let partial_decryptions: Vec<_> = decrypting_parties
+        .iter()
+        .map(|&party_id| shares[party_id - 1].partial_decrypt_radix(ct, decrypting_parties))
+        .collect();
+
+    combine_radix_partial_decryptions(ct, &partial_decryptions)
+}
+
+fn threshold_decrypt_radix<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let shares = share_client_key(&cks, THRESHOLD, NUM_PARTIES);
+
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.party_id(), i + 1);
+        assert_eq!(share.threshold(), THRESHOLD);
+    }
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let all_parties: Vec<_> = (1..=NUM_PARTIES).collect();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt_radix(clear_0, NB_CTXT);
+        let ct_1 = cks.encrypt_radix(clear_1, NB_CTXT);
+
+        let num_decrypting_parties = rng.gen_range(THRESHOLD..=NUM_PARTIES);
+        let decrypting_parties: Vec<_> = all_parties
+            .choose_multiple(&mut rng, num_decrypting_parties)
+            .copied()
+            .collect();
+
+        // Fresh ciphertext
+        let decrypted = threshold_decrypt(&shares, &ct_0, &decrypting_parties);
+        assert_eq!(decrypted, clear_0);
+
+        // Ciphertext after a PBS
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+        let decrypted = threshold_decrypt(&shares, &ct_res, &decrypting_parties);
+        assert_eq!(
+            decrypted,
+            clear_0.wrapping_add(clear_1) % modulus,
+            "Invalid threshold decryption with parties {decrypting_parties:?}"
+        );
+        assert_eq!(decrypted, cks.decrypt_radix::<u64>(&ct_res));
+    }
+}
+
+#[test]
+#[should_panic(expected = "At least 3 parties are required to decrypt")]
+fn test_threshold_decrypt_radix_not_enough_parties() {
+    let (cks, _) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let shares = share_client_key(&cks, THRESHOLD, NUM_PARTIES);
+
+    let ct = cks.encrypt_radix(42u64, NB_CTXT);
+    let _ = threshold_decrypt(&shares, &ct, &[1, 4]);
+}
+
+#[test]
+#[should_panic(expected = "was computed for other decrypting parties")]
+fn test_threshold_decrypt_radix_mixed_decrypting_parties() {
+    let (cks, _) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let shares = share_client_key(&cks, THRESHOLD, NUM_PARTIES);
+
+    let ct = cks.encrypt_radix(42u64, NB_CTXT);
+    // Party 3 computed its partial decryption for a different set of parties
+    let partial_decryptions = vec![
+        shares[0].partial_decrypt_radix(&ct, &[1, 2, 3]),
+        shares[1].partial_decrypt_radix(&ct, &[2, 1, 3]),
+        shares[2].partial_decrypt_radix(&ct, &[1, 3, 4]),
+    ];
+    let _: u64 = combine_radix_partial_decryptions(&ct, &partial_decryptions);
+}
+
+create_parametrized_test!(distributed_key_generation {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS
+});
+
+/// Runs the distributed key generation between `num_parties` parties and returns the parties,
+/// along with the keys reconstructed from their shares
+fn run_distributed_key_generation(
+    params: ClassicPBSParameters,
+    num_parties: usize,
+) -> (
+    Vec<DkgParty>,
+    crate::shortint::ClientKey,
+    crate::shortint::ServerKey,
+) {
+    let preprocessing = TripleDealer::new().generate_dkg_preprocessing(params, num_parties);
+    let public_seed = new_seeder().seed();
+
+    let mut parties: Vec<_> = preprocessing
+        .into_iter()
+        .map(|preprocessing| DkgParty::new(preprocessing, public_seed))
+        .collect();
+
+    for _ in 0..parties[0].num_rounds() {
+        let messages: Vec<_> = parties.iter().map(DkgParty::round_message).collect();
+        for party in parties.iter_mut() {
+            party.receive_round_messages(&messages);
+        }
+    }
+    assert!(parties.iter().all(DkgParty::is_done));
+
+    let server_key_shares: Vec<_> = parties.iter().map(DkgParty::server_key_share).collect();
+    let sks = aggregate_server_key_shares(&server_key_shares).decompress();
+
+    // Reconstruct the shared keys to check they are valid
+    let mut key_bits = vec![0u64; parties[0].key_bit_shares.len()];
+    for party in parties.iter() {
+        for (bit, share) in key_bits.iter_mut().zip(party.key_bit_shares.iter()) {
+            *bit = bit.wrapping_add(*share);
+        }
+    }
+    assert!(key_bits.iter().all(|&bit| bit <= 1));
+
+    let glwe_key_bits = key_bits.split_off(params.lwe_dimension.0);
+    let cks = crate::shortint::ClientKey {
+        glwe_secret_key: GlweSecretKey::from_container(glwe_key_bits, params.polynomial_size),
+        lwe_secret_key: LweSecretKey::from_container(key_bits),
+        parameters: params.into(),
+    };
+
+    (parties, cks, sks)
+}
+
+fn distributed_key_generation(params: ClassicPBSParameters) {
+    let num_parties = 3;
+    let (parties, cks, sks) = run_distributed_key_generation(params, num_parties);
+
+    let client_key_shares: Vec<_> = parties.iter().map(DkgParty::client_key_share).collect();
+    let all_parties: Vec<_> = (1..=num_parties).collect();
+
+    let mut rng = rand::thread_rng();
+    let modulus = params.message_modulus.0 as u64;
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.mul_lsb(&ct_0, &ct_1);
+        let expected = (clear_0 * clear_1) % modulus;
+        assert_eq!(cks.decrypt(&ct_res), expected);
+
+        let ct_res = RadixCiphertext::from(vec![ct_res]);
+        let decrypted = threshold_decrypt(&client_key_shares, &ct_res, &all_parties);
+        assert_eq!(decrypted, expected);
+    }
+}
+
+/// Returns the variance of the noise of the outputs of `num_samples` PBS
+fn pbs_output_noise_variance(
+    cks: &crate::shortint::ClientKey,
+    sks: &crate::shortint::ServerKey,
+    num_samples: usize,
+) -> f64 {
+    let params = cks.parameters;
+    let delta = (1u64 << 63) / (params.message_modulus().0 * params.carry_modulus().0) as u64;
+    let identity = sks.generate_lookup_table(|x| x);
+
+    let mut rng = rand::thread_rng();
+    let noises: Vec<f64> = (0..num_samples)
+        .map(|_| {
+            let clear = rng.gen::<u64>() % params.message_modulus().0 as u64;
+            let ct = sks.apply_lookup_table(&cks.encrypt(clear), &identity);
+
+            let plaintext = decrypt_lwe_ciphertext(&cks.large_lwe_secret_key(), &ct.ct);
+            plaintext.0.wrapping_sub(clear * delta) as i64 as f64
+        })
+        .collect();
+
+    let mean = noises.iter().sum::<f64>() / num_samples as f64;
+    noises
+        .iter()
+        .map(|noise| (noise - mean).powi(2))
+        .sum::<f64>()
+        / (num_samples - 1) as f64
+}
+
+#[test]
+fn test_distributed_key_generation_noise() {
+    const NUM_SAMPLES: usize = 1000;
+
+    let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    let (_, cks, sks) = run_distributed_key_generation(params, 3);
+    let distributed_variance = pbs_output_noise_variance(&cks, &sks, NUM_SAMPLES);
+
+    // The keys must be as noisy as keys generated by a single party, not once per party
+    let (cks, sks) = crate::shortint::gen_keys(params);
+    let expected_variance = pbs_output_noise_variance(&cks, &sks, NUM_SAMPLES);
+
+    assert!(
+        distributed_variance < 1.5 * expected_variance,
+        "PBS outputs have a noise variance of {distributed_variance} with distributed keys, \
+        against {expected_variance} with keys generated by a single party"
+    );
+}
@@ -0,0 +1,180 @@
+//! Versioning of the serialized types
+//!
+//! Each type that can be serialized with
+//! [safe_serialize](crate::safe_deserialization::safe_serialize) has an associated enum, its
+//! "versions dispatch", with one variant per serialization format the type had over the releases.
+//! The last variant holds the current type.
+//!
+//! A type is serialized as if it was wrapped in the current variant of its versions dispatch,
+//! so that the version is serialized along the data. When deserializing, any variant is accepted
+//! and older versions are upgraded one version at a time (using [Upgrade]) until the
+//! current type is reached.
+//!
+//! When the serialization format of a type changes, the previous definition of the type is kept
+//! (e.g. as `MyTypeV1`), a new variant is added to the dispatch enum and [Upgrade] is implemented
+//! from the previous version to the new one.
+//!
+//! Only [safe_serialize](crate::safe_deserialization::safe_serialize) and
+//! [safe_deserialize](crate::safe_deserialization::safe_deserialize) go through the versions
+//! dispatch. The `Serialize` and `Deserialize` implementations of the types themselves are not
+//! versioned: data serialized with them directly (e.g. with `bincode::serialize`) holds the
+//! current representation only, and is not upgraded on deserialization. Data that a release
+//! predating versioning serialized directly with `serde` and bincode can still be loaded with
+//! [legacy_deserialize](crate::safe_deserialization::legacy_deserialize), which reads it as the
+//! first version of its type and upgrades it.
+use serde::de::DeserializeOwned;
+use serde::{Serialize, Serializer};
+
+/// Converts a previous version of a type into the next version
+pub trait Upgrade<T> {
+    fn upgrade(self) -> crate::Result<T>;
+}
+
+/// Enum with one variant for each version of a type, the last variant being the current one
+///
+/// This should be implemented using the `versions_dispatch!` macro.
+pub trait VersionsDispatch<Unversioned>: DeserializeOwned {
+    /// Name of the enum
+    const ENUM_NAME: &'static str;
+    /// Index of the variant holding the current version
+    const CURRENT_VERSION: u32;
+    /// Name of the variant holding the current version
+    const CURRENT_VARIANT: &'static str;
+
+    /// The type as it was serialized before versioning was introduced,
+    /// this is always the first version
+    type Legacy: DeserializeOwned;
+
+    fn from_legacy(legacy: Self::Legacy) -> Self;
+
+    /// Upgrades the held version up to the current one
+    fn unversionize(self) -> crate::Result<Unversioned>;
+}
+
+/// A type that is serialized along its version
+pub trait Versionize: Serialize + Sized {
+    type Versions: VersionsDispatch<Self>;
+
+    /// Returns a serializable view of `self`, tagged with its version
+    fn versionize(&self) -> Versioned<'_, Self> {
+        Versioned(self)
+    }
+
+    /// Converts a deserialized version into the current type, upgrading it if needed
+    fn unversionize(versioned: Self::Versions) -> crate::Result<Self> {
+        versioned.unversionize()
+    }
+}
+
+/// A reference to a value which serializes as the current variant of its versions dispatch enum
+pub struct Versioned<'a, T>(&'a T);
+
+impl<T: Versionize> Serialize for Versioned<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_variant(
+            T::Versions::ENUM_NAME,
+            T::Versions::CURRENT_VERSION,
+            T::Versions::CURRENT_VARIANT,
+            self.0,
+        )
+    }
+}
+
+/// Declares the versions dispatch enum of a type and implements [Versionize] for it
+///
+/// The enum can be generic over a type, optionally followed by a const parameter (e.g.
+/// `<T: Bound, const N: u32>`). Each previous version is followed by the variant it upgrades to, the current version is last:
+///
+/// ```ignore
+/// versions_dispatch!(
+///     pub enum MyTypeVersions {
+///         V0(MyTypeV0) => V1,
+///         V1(MyType),
+///     }
+/// );
+/// ```
+macro_rules! versions_dispatch {
+    (@first_ty $first:ty $(, $rest:ty)* $(,)?) => { $first };
+    (@first_variant $first:ident $(, $rest:ident)* $(,)?) => { Self::$first };
+    // Collects the previous versions one at a time, as the current version cannot be told apart
+    // from them in a single repetition
+    (
+        @collect $attrs:tt $vis:tt $name:tt $generics:tt [$($previous:tt)*]
+        $old:ident($old_ty:ty) => $next:ident, $($rest:tt)*
+    ) => {
+        $crate::versionable::versions_dispatch!(
+            @collect $attrs $vis $name $generics [$($previous)* $old($old_ty) => $next,] $($rest)*
+        );
+    };
+    (
+        @collect [$(#[$attr:meta])*] [$vis:vis] [$name:ident]
+        [$(<$gen:ident: $bound:path $(, const $cgen:ident: $cty:ty)?>)?]
+        [$($old:ident($old_ty:ty) => $next:ident,)*]
+        $current:ident($current_ty:ty) $(,)?
+    ) => {
+        $(#[$attr])*
+        // Only used transiently on deserialization, the size of the variants does not matter
+        #[allow(clippy::large_enum_variant)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        $vis enum $name $(<$gen: $bound $(, const $cgen: $cty)?>)? {
+            $($old($old_ty),)*
+            $current($current_ty),
+        }
+
+        impl $(<$gen: $bound $(, const $cgen: $cty)?>)?
+            $crate::versionable::VersionsDispatch<$current_ty> for $name $(<$gen $(, $cgen)?>)?
+        where
+            Self: serde::de::DeserializeOwned,
+            $crate::versionable::versions_dispatch!(@first_ty $($old_ty,)* $current_ty):
+                serde::de::DeserializeOwned,
+        {
+            const ENUM_NAME: &'static str = stringify!($name);
+            const CURRENT_VERSION: u32 = <[&str]>::len(&[$(stringify!($old)),*]) as u32;
+            const CURRENT_VARIANT: &'static str = stringify!($current);
+
+            type Legacy = $crate::versionable::versions_dispatch!(@first_ty $($old_ty,)* $current_ty);
+
+            fn from_legacy(legacy: Self::Legacy) -> Self {
+                $crate::versionable::versions_dispatch!(@first_variant $($old,)* $current)(legacy)
+            }
+
+            fn unversionize(self) -> $crate::Result<$current_ty> {
+                match self {
+                    $(
+                        Self::$old(value) => {
+                            let next = $crate::versionable::Upgrade::upgrade(value)?;
+                            Self::$next(next).unversionize()
+                        }
+                    )*
+                    Self::$current(value) => Ok(value),
+                }
+            }
+        }
+
+        impl $(<$gen: $bound $(, const $cgen: $cty)?>)? $crate::versionable::Versionize
+            for $current_ty
+        where
+            Self: serde::Serialize,
+            $name $(<$gen $(, $cgen)?>)?: $crate::versionable::VersionsDispatch<Self>,
+        {
+            type Versions = $name $(<$gen $(, $cgen)?>)?;
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident
+        $(<$gen:ident: $bound:path $(, const $cgen:ident: $cty:ty)?>)? {
+            $($variants:tt)*
+        }
+    ) => {
+        $crate::versionable::versions_dispatch!(
+            @collect [$(#[$attr])*] [$vis] [$name] [$(<$gen: $bound $(, const $cgen: $cty)?>)?] []
+            $($variants)*
+        );
+    };
+}
+
+pub(crate) use versions_dispatch;
@@ -0,0 +1,301 @@
+//! Checks the PBS cost estimations against the number of PBS actually executed.
+//!
+//! The PBS counter is global to the process, so these tests must not run alongside other tests
+//! doing PBS, this is why they live in their own test binary.
+use rayon::ThreadPoolBuilder;
+use tfhe::integer::server_key::IntegerOperation;
+use tfhe::integer::{
+    gen_keys_radix, BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey,
+};
+use tfhe::shortint::parameters::{PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+use tfhe::{get_pbs_count, reset_pbs_count};
+
+/// The operations which run the same algorithm on unsigned and signed integers
+const COMMON_OPERATIONS: [IntegerOperation; 18] = [
+    IntegerOperation::FullPropagate,
+    IntegerOperation::Add,
+    IntegerOperation::Sub,
+    IntegerOperation::Neg,
+    IntegerOperation::ScalarAdd,
+    IntegerOperation::ScalarSub,
+    IntegerOperation::BitAnd,
+    IntegerOperation::BitOr,
+    IntegerOperation::BitXor,
+    IntegerOperation::BitNot,
+    IntegerOperation::Eq,
+    IntegerOperation::Ne,
+    IntegerOperation::IfThenElse,
+    IntegerOperation::Mul,
+    IntegerOperation::Shl,
+    IntegerOperation::Shr,
+    IntegerOperation::RotateLeft,
+    IntegerOperation::RotateRight,
+];
+
+const UNSIGNED_OPERATIONS: [IntegerOperation; 8] = [
+    IntegerOperation::Gt,
+    IntegerOperation::Ge,
+    IntegerOperation::Lt,
+    IntegerOperation::Le,
+    IntegerOperation::Min,
+    IntegerOperation::Max,
+    IntegerOperation::Div,
+    IntegerOperation::Rem,
+];
+
+const SIGNED_OPERATIONS: [IntegerOperation; 9] = [
+    IntegerOperation::SignedGt,
+    IntegerOperation::SignedGe,
+    IntegerOperation::SignedLt,
+    IntegerOperation::SignedLe,
+    IntegerOperation::SignedMin,
+    IntegerOperation::SignedMax,
+    IntegerOperation::SignedDiv,
+    IntegerOperation::SignedRem,
+    IntegerOperation::Abs,
+];
+
+/// The encrypted operands of an operation
+struct Operands<T> {
+    lhs: T,
+    rhs: T,
+    /// The amount of the shifts and rotations, which is always unsigned
+    amount: RadixCiphertext,
+    condition: BooleanBlock,
+}
+
+impl<T: IntegerRadixCiphertext> Operands<T> {
+    /// Returns operands whose blocks hold the carry of one addition
+    fn dirty(&self, sks: &ServerKey) -> Self {
+        Self {
+            lhs: sks.unchecked_add(&self.lhs, &self.rhs),
+            rhs: sks.unchecked_add(&self.rhs, &self.lhs),
+            amount: sks.unchecked_add(&self.amount, &self.amount),
+            condition: self.condition.clone(),
+        }
+    }
+}
+
+fn run_operation<T: IntegerRadixCiphertext>(
+    sks: &ServerKey,
+    operation: IntegerOperation,
+    operands: &Operands<T>,
+) {
+    let Operands {
+        lhs,
+        rhs,
+        amount,
+        condition,
+    } = operands;
+    // The scalar has its least significant block set
+    let scalar = 3u64;
+    match operation {
+        IntegerOperation::FullPropagate => {
+            let mut ct = lhs.clone();
+            sks.full_propagate_parallelized(&mut ct);
+        }
+        IntegerOperation::Add => {
+            let _ = sks.add_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Sub => {
+            let _ = sks.sub_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Neg => {
+            let _ = sks.neg_parallelized(lhs);
+        }
+        IntegerOperation::ScalarAdd => {
+            let _ = sks.scalar_add_parallelized(lhs, scalar);
+        }
+        IntegerOperation::ScalarSub => {
+            let _ = sks.scalar_sub_parallelized(lhs, scalar);
+        }
+        IntegerOperation::BitAnd => {
+            let _ = sks.bitand_parallelized(lhs, rhs);
+        }
+        IntegerOperation::BitOr => {
+            let _ = sks.bitor_parallelized(lhs, rhs);
+        }
+        IntegerOperation::BitXor => {
+            let _ = sks.bitxor_parallelized(lhs, rhs);
+        }
+        IntegerOperation::BitNot => {
+            let _ = sks.bitnot(lhs);
+        }
+        IntegerOperation::Eq => {
+            let _ = sks.eq_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Ne => {
+            let _ = sks.ne_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Gt | IntegerOperation::SignedGt => {
+            let _ = sks.gt_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Ge | IntegerOperation::SignedGe => {
+            let _ = sks.ge_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Lt | IntegerOperation::SignedLt => {
+            let _ = sks.lt_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Le | IntegerOperation::SignedLe => {
+            let _ = sks.le_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Min | IntegerOperation::SignedMin => {
+            let _ = sks.min_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Max | IntegerOperation::SignedMax => {
+            let _ = sks.max_parallelized(lhs, rhs);
+        }
+        IntegerOperation::IfThenElse => {
+            let _ = sks.if_then_else_parallelized(condition, lhs, rhs);
+        }
+        IntegerOperation::Mul => {
+            let _ = sks.mul_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Shl => {
+            let _ = sks.left_shift_parallelized(lhs, amount);
+        }
+        IntegerOperation::Shr => {
+            let _ = sks.right_shift_parallelized(lhs, amount);
+        }
+        IntegerOperation::RotateLeft => {
+            let _ = sks.rotate_left_parallelized(lhs, amount);
+        }
+        IntegerOperation::RotateRight => {
+            let _ = sks.rotate_right_parallelized(lhs, amount);
+        }
+        IntegerOperation::Div | IntegerOperation::SignedDiv => {
+            let _ = sks.div_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Rem | IntegerOperation::SignedRem => {
+            let _ = sks.rem_parallelized(lhs, rhs);
+        }
+        IntegerOperation::Abs => {
+            let _ = sks.abs_parallelized(lhs);
+        }
+        _ => unreachable!("{operation:?} is not estimated"),
+    }
+}
+
+/// Checks the estimates of the operations against the number of PBS they do, with clean and
+/// dirty operands
+fn check_estimates<T: IntegerRadixCiphertext>(
+    sks: &ServerKey,
+    operations: &[IntegerOperation],
+    operands: &Operands<T>,
+) {
+    let num_blocks = operands.lhs.blocks().len();
+    let dirty_operands = operands.dirty(sks);
+
+    for &operation in operations {
+        for (operands, operands_are_clean) in [(operands, true), (&dirty_operands, false)] {
+            let estimate = sks
+                .estimate_pbs_cost(operation, num_blocks, operands_are_clean)
+                .unwrap();
+
+            reset_pbs_count();
+            run_operation(sks, operation, operands);
+            let measured = get_pbs_count();
+
+            assert_eq!(
+                estimate.pbs_count,
+                measured,
+                "Invalid estimate for {operation:?} on {num_blocks} blocks, with {} threads \
+                and clean operands: {operands_are_clean}",
+                rayon::current_num_threads()
+            );
+            assert_eq!(estimate.keyswitch_count, estimate.pbs_count);
+            assert!(estimate.pbs_depth <= estimate.pbs_count);
+        }
+    }
+}
+
+// The PBS counter is global, so a single test counts the PBS of all the operations
+#[test]
+fn test_pbs_cost_estimation_matches_pbs_count() {
+    let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 1);
+    let cks = cks.as_ref();
+    let condition = cks.encrypt_bool(true);
+
+    // The algorithm propagating the carries depends on the number of threads
+    for num_threads in [1, 32] {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+
+        for num_blocks in [1, 5, 16] {
+            let num_bits = 2 * num_blocks as u32;
+
+            // All ones plus one propagates a carry through all the blocks
+            for (lhs, rhs) in [(1234u64, 4321u64), (u64::MAX, 1), (u64::MAX, u64::MAX)] {
+                let operands = Operands {
+                    lhs: cks.encrypt_radix(lhs, num_blocks),
+                    rhs: cks.encrypt_radix(rhs, num_blocks),
+                    amount: cks.encrypt_radix(rhs, num_blocks),
+                    condition: condition.clone(),
+                };
+                pool.install(|| {
+                    check_estimates(&sks, &COMMON_OPERATIONS, &operands);
+                    check_estimates(&sks, &UNSIGNED_OPERATIONS, &operands);
+                });
+            }
+
+            let min = -1i64 << (num_bits - 1);
+            for (lhs, rhs) in [(1234i64, -4321i64), (-1, 1), (min, -1)] {
+                let operands = Operands {
+                    lhs: cks.encrypt_signed_radix(lhs, num_blocks),
+                    rhs: cks.encrypt_signed_radix(rhs, num_blocks),
+                    amount: cks.encrypt_radix(rhs as u64, num_blocks),
+                    condition: condition.clone(),
+                };
+                pool.install(|| {
+                    check_estimates(&sks, &COMMON_OPERATIONS, &operands);
+                    check_estimates(&sks, &SIGNED_OPERATIONS, &operands);
+                });
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pbs_cost_estimation_depth() {
+    let num_blocks = 16;
+    let (_cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+
+    // The carry propagation algorithm depends on the number of threads
+    let estimate_with_threads = |operation, num_threads| {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        pool.install(|| sks.estimate_pbs_cost(operation, num_blocks, true))
+            .unwrap()
+    };
+
+    // One layer telling whether each block generates or propagates a carry, log2(16) = 4 layers
+    // of Hillis-Steele prefix sum, then one layer adding the carries
+    let cost = estimate_with_threads(IntegerOperation::Add, 64);
+    assert_eq!(cost.pbs_depth, 6);
+    // Without enough threads, the carries are propagated one block after the other
+    let cost = estimate_with_threads(IntegerOperation::Add, 1);
+    assert_eq!(cost.pbs_depth, 16);
+
+    let cost = estimate_with_threads(IntegerOperation::BitXor, 64);
+    assert_eq!(cost.pbs_depth, 1);
+    // One layer comparing the blocks, then the 16 booleans are reduced to 2 blocks, then to 1
+    let cost = estimate_with_threads(IntegerOperation::Eq, 64);
+    assert_eq!(cost.pbs_depth, 3);
+}
+
+#[test]
+fn test_pbs_cost_estimation_rejects_invalid_inputs() {
+    let (_cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+    assert!(sks
+        .estimate_pbs_cost(IntegerOperation::Mul, 0, true)
+        .is_err());
+
+    let (_cks, sks) = gen_keys_radix(PARAM_MESSAGE_1_CARRY_1_KS_PBS, 4);
+    assert!(sks
+        .estimate_pbs_cost(IntegerOperation::Mul, 4, true)
+        .is_err());
+}
@@ -0,0 +1,88 @@
+//! Generates the data loaded by the backward compatibility tests of the high level api, in
+//! `tfhe/src/high_level_api/backward_compatibility/data`, with tfhe 0.6.3.
+//!
+//! Run it from the root of the repository with:
+//! `cargo run --release --manifest-path utils/backward_compat_data_gen/Cargo.toml --
+//! tfhe/src/high_level_api/backward_compatibility/data`
+//!
+//! The ciphertexts are written with `safe_serialize` (serialization version "0.3"), the client
+//! keys, which this release could not `safe_serialize`, with `bincode::serialize`.
+use std::fs::File;
+use std::path::Path;
+
+use serde::Serialize;
+use tfhe::named::Named;
+use tfhe::prelude::*;
+use tfhe::safe_deserialization::safe_serialize;
+use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+use tfhe::{
+    ClientKey, CompactFheBool, CompactFheInt8, CompactFheUint8List, CompactPublicKey,
+    CompressedFheBool, CompressedFheInt16, CompressedFheUint8, ConfigBuilder, FheBool, FheUint2,
+};
+
+const SIZE_LIMIT: u64 = 1 << 30;
+
+fn store<T: Serialize + Named>(dir: &Path, name: &str, value: &T) {
+    let file = File::create(dir.join(name)).unwrap();
+    safe_serialize(value, file, SIZE_LIMIT).unwrap();
+}
+
+fn store_key(dir: &Path, name: &str, key: &ClientKey) {
+    let file = File::create(dir.join(name)).unwrap();
+    bincode::serialize_into(file, key).unwrap();
+}
+
+fn main() {
+    let dir = std::env::args()
+        .nth(1)
+        .expect("Usage: backward_compat_data_gen <output directory>");
+    let dir = Path::new(&dir);
+
+    let client_key = ClientKey::generate(ConfigBuilder::default().build());
+    store_key(dir, "client_key.bin", &client_key);
+
+    store(dir, "fhe_uint2.bin", &FheUint2::encrypt(3u8, &client_key));
+    store(
+        dir,
+        "compressed_fhe_uint8.bin",
+        &CompressedFheUint8::encrypt(213u8, &client_key),
+    );
+    store(
+        dir,
+        "compressed_fhe_int16.bin",
+        &CompressedFheInt16::encrypt(-1234i16, &client_key),
+    );
+    store(dir, "fhe_bool.bin", &FheBool::encrypt(true, &client_key));
+    store(
+        dir,
+        "compressed_fhe_bool.bin",
+        &CompressedFheBool::encrypt(false, &client_key),
+    );
+
+    let (integer_key, _) = client_key.into_raw_parts();
+    let shortint_key: &tfhe::shortint::ClientKey = integer_key.as_ref();
+    store(dir, "shortint_ciphertext.bin", &shortint_key.encrypt(2));
+
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+        .build();
+    let client_key = ClientKey::generate(config);
+    let public_key = CompactPublicKey::new(&client_key);
+    store_key(dir, "compact_client_key.bin", &client_key);
+
+    store(
+        dir,
+        "compact_fhe_uint8_list.bin",
+        &CompactFheUint8List::encrypt(&[1u8, 2, 3], &public_key),
+    );
+    store(
+        dir,
+        "compact_fhe_int8.bin",
+        &CompactFheInt8::encrypt(-5i8, &public_key),
+    );
+    store(
+        dir,
+        "compact_fhe_bool.bin",
+        &CompactFheBool::encrypt(true, &public_key),
+    );
+}